@@ -12,7 +12,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         "setting.proto",
         "cms.proto",
         "general.proto",
-        "asset.proto"
+        "asset.proto",
+        "cms_api_key.proto",
+        "webhook.proto",
+        "form.proto",
+        "email.proto"
     ];
 
     // Tell cargo to rerun this build script only if proto files change
@@ -3,7 +3,13 @@ use crate::api::proto::admin_user::admin_user_paginate_response::{
 };
 use crate::api::proto::admin_user::admin_user_server::AdminUser;
 use crate::api::proto::admin_user::{
-    AdminUserPaginateRequest, AdminUserPaginateResponse, DeleteRoleRequest, DeleteRoleResponse,
+    AdminUserPaginateRequest, AdminUserPaginateResponse, AdminUserSessionAllRequest,
+    AdminUserSessionAllResponse, TerminateAdminUserSessionRequest,
+    TerminateAdminUserSessionResponse, TerminateAllAdminUserSessionsRequest,
+    TerminateAllAdminUserSessionsResponse, DeleteRoleRequest, DeleteRoleResponse,
+    DisableTwoFactorRequest, DisableTwoFactorResponse, RequireTwoFactorRequest,
+    RequireTwoFactorResponse, UnlockAdminUserRequest, UnlockAdminUserResponse, AuditLogAllRequest,
+    AuditLogAllResponse,
     GetAdminUserRequest, GetAdminUserResponse, GetRoleRequest, GetRoleResponse,
     PutRoleIdentifierRequest, PutRoleIdentifierResponse, RoleOptionRequest, RoleOptionResponse,
     RolePaginateRequest, RolePaginateResponse, StoreAdminUserRequest, StoreAdminUserResponse,
@@ -34,10 +40,10 @@ impl AdminUser for AdminUserApi {
 
         let claims = request.get_token_claim()?;
 
-        let logged_in_user = claims.admin_user_model;
-        logged_in_user
+        claims
             .check_user_has_resouce_access(
                 &self.state.admin_user_service,
+                &self.state.db,
                 String::from("paginate_admin_user"),
             )
             .await?;
@@ -87,29 +93,34 @@ impl AdminUser for AdminUserApi {
 
         let claims = request.get_token_claim()?;
 
-        let logged_in_user = claims.admin_user_model;
-        logged_in_user
+        let logged_in_user = claims
             .check_user_has_resouce_access(
                 &self.state.admin_user_service,
+                &self.state.db,
                 String::from("store_admin_user"),
             )
             .await?;
 
         let req = request.into_inner();
         req.validate(&self.state).await?;
+        let (email, password) = (req.email.clone(), req.password.clone());
 
         match self
             .state
             .admin_user_service
             .store(
                 req,
-                claims.email,
-                &self.state.config.password_salt,
+                logged_in_user.email,
+                &self.state.config.password_hash,
                 &self.state.db,
             )
             .await
         {
             Ok(reply) => {
+                self.state
+                    .password_policy_service
+                    .remember_password(&self.state.db, &email, &password)
+                    .await;
                 let res = Response::new(reply);
 
                 Ok(res)
@@ -129,10 +140,10 @@ impl AdminUser for AdminUserApi {
 
         let claims = request.get_token_claim()?;
 
-        let logged_in_user = claims.admin_user_model;
-        logged_in_user
+        claims
             .check_user_has_resouce_access(
                 &self.state.admin_user_service,
+                &self.state.db,
                 String::from("get_admin_user"),
             )
             .await?;
@@ -170,10 +181,10 @@ impl AdminUser for AdminUserApi {
 
         let claims = request.get_token_claim()?;
 
-        let logged_in_user = claims.admin_user_model;
-        logged_in_user
+        let logged_in_user = claims
             .check_user_has_resouce_access(
                 &self.state.admin_user_service,
+                &self.state.db,
                 String::from("update_admin_user"),
             )
             .await?;
@@ -184,7 +195,7 @@ impl AdminUser for AdminUserApi {
         match self
             .state
             .admin_user_service
-            .update_admin_user(req, claims.email, &self.state.db)
+            .update_admin_user(req, logged_in_user.email, &self.state.db)
             .await
         {
             Ok(reply) => {
@@ -206,10 +217,10 @@ impl AdminUser for AdminUserApi {
         println!("->> {:<12} - role_paginate", "gRPC_Admin_User_Api_Service");
 
         let claims = request.get_token_claim()?;
-        let logged_in_user = claims.admin_user_model;
-        logged_in_user
+        claims
             .check_user_has_resouce_access(
                 &self.state.admin_user_service,
+                &self.state.db,
                 String::from("role_paginate"),
             )
             .await?;
@@ -241,10 +252,10 @@ impl AdminUser for AdminUserApi {
         println!("->> {:<12} - role_paginate", "gRPC_Admin_User_Api_Service");
 
         let claims = request.get_token_claim()?;
-        let logged_in_user = claims.admin_user_model;
-        logged_in_user
+        claims
             .check_user_has_resouce_access(
                 &self.state.admin_user_service,
+                &self.state.db,
                 String::from("role_option"),
             )
             .await?;
@@ -274,10 +285,10 @@ impl AdminUser for AdminUserApi {
         println!("->> {:<12} - store_role", "gRPC_Admin_User_Api_Service");
 
         let claims = request.get_token_claim()?;
-        let logged_in_user = claims.admin_user_model;
-        logged_in_user
+        let logged_in_user = claims
             .check_user_has_resouce_access(
                 &self.state.admin_user_service,
+                &self.state.db,
                 String::from("store_role"),
             )
             .await?;
@@ -288,7 +299,7 @@ impl AdminUser for AdminUserApi {
         let created_role_request = CreatableRole {
             name: req.name,
             identifier: req.identifier,
-            logged_in_username: claims.email,
+            logged_in_username: logged_in_user.email,
             permissions: req.permissions,
         };
 
@@ -317,9 +328,8 @@ impl AdminUser for AdminUserApi {
         println!("->> {:<12} - get_role", "gRPC_Admin_User_Api_Service");
 
         let claims = request.get_token_claim()?;
-        let logged_in_user = claims.admin_user_model;
-        logged_in_user
-            .check_user_has_resouce_access(&self.state.admin_user_service, String::from("get_role"))
+        claims
+            .check_user_has_resouce_access(&self.state.admin_user_service, &self.state.db, String::from("get_role"))
             .await?;
 
         let req = request.into_inner();
@@ -347,10 +357,10 @@ impl AdminUser for AdminUserApi {
         println!("->> {:<12} - update_role", "gRPC_Admin_User_Api_Service");
 
         let claims = request.get_token_claim()?;
-        let logged_in_user = claims.admin_user_model;
-        logged_in_user
+        let logged_in_user = claims
             .check_user_has_resouce_access(
                 &self.state.admin_user_service,
+                &self.state.db,
                 String::from("update_role"),
             )
             .await?;
@@ -361,7 +371,7 @@ impl AdminUser for AdminUserApi {
         match self
             .state
             .admin_user_service
-            .update_role(req, claims.email, &self.state.db)
+            .update_role(req, logged_in_user.email, &self.state.db)
             .await
         {
             Ok(reply) => {
@@ -386,10 +396,10 @@ impl AdminUser for AdminUserApi {
         );
 
         let claims = request.get_token_claim()?;
-        let logged_in_user = claims.admin_user_model;
-        logged_in_user
+        let logged_in_user = claims
             .check_user_has_resouce_access(
                 &self.state.admin_user_service,
+                &self.state.db,
                 String::from("put_role_identifier"),
             )
             .await?;
@@ -400,7 +410,7 @@ impl AdminUser for AdminUserApi {
         match self
             .state
             .admin_user_service
-            .put_role_identifier(req, claims.email, &self.state.db)
+            .put_role_identifier(req, logged_in_user.email, &self.state.db)
             .await
         {
             Ok(reply) => {
@@ -422,10 +432,10 @@ impl AdminUser for AdminUserApi {
         println!("->> {:<12} - delete_role", "gRPC_Admin_User_Api_Service");
 
         let claims = request.get_token_claim()?;
-        let logged_in_user = claims.admin_user_model;
-        logged_in_user
+        claims
             .check_user_has_resouce_access(
                 &self.state.admin_user_service,
+                &self.state.db,
                 String::from("delete_role"),
             )
             .await?;
@@ -450,4 +460,217 @@ impl AdminUser for AdminUserApi {
             },
         }
     }
+
+    async fn admin_user_session_all(
+        &self,
+        request: Request<AdminUserSessionAllRequest>,
+    ) -> Result<Response<AdminUserSessionAllResponse>, Status> {
+        println!(
+            "->> {:<12} - admin_user_session_all",
+            "gRPC_Admin_User_Api_Service"
+        );
+
+        let claims = request.get_token_claim()?;
+        let logged_in_user = claims
+            .logged_in_admin_user(&self.state.admin_user_service, &self.state.db)
+            .await?;
+        let req = request.into_inner();
+
+        match self
+            .state
+            .auth_service
+            .admin_user_session_all(&self.state.db, &claims, &logged_in_user, &req.admin_user_id)
+            .await
+        {
+            Ok(reply) => Ok(Response::new(reply)),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn terminate_admin_user_session(
+        &self,
+        request: Request<TerminateAdminUserSessionRequest>,
+    ) -> Result<Response<TerminateAdminUserSessionResponse>, Status> {
+        println!(
+            "->> {:<12} - terminate_admin_user_session",
+            "gRPC_Admin_User_Api_Service"
+        );
+
+        let claims = request.get_token_claim()?;
+        let logged_in_user = claims
+            .logged_in_admin_user(&self.state.admin_user_service, &self.state.db)
+            .await?;
+        let req = request.into_inner();
+        req.validate()?;
+
+        match self
+            .state
+            .auth_service
+            .terminate_admin_user_session(&self.state.db, &logged_in_user, &req.session_id)
+            .await
+        {
+            Ok(terminated_status) => {
+                let response = TerminateAdminUserSessionResponse {
+                    status: terminated_status,
+                };
+                Ok(Response::new(response))
+            }
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn terminate_all_admin_user_sessions(
+        &self,
+        request: Request<TerminateAllAdminUserSessionsRequest>,
+    ) -> Result<Response<TerminateAllAdminUserSessionsResponse>, Status> {
+        println!(
+            "->> {:<12} - terminate_all_admin_user_sessions",
+            "gRPC_Admin_User_Api_Service"
+        );
+
+        let claims = request.get_token_claim()?;
+        let logged_in_user = claims
+            .logged_in_admin_user(&self.state.admin_user_service, &self.state.db)
+            .await?;
+        let req = request.into_inner();
+        req.validate()?;
+
+        match self
+            .state
+            .auth_service
+            .terminate_all_admin_user_sessions(&self.state.db, &logged_in_user, &req.admin_user_id)
+            .await
+        {
+            Ok(terminated) => {
+                let response = TerminateAllAdminUserSessionsResponse {
+                    status: true,
+                    terminated,
+                };
+                Ok(Response::new(response))
+            }
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn disable_two_factor(
+        &self,
+        request: Request<DisableTwoFactorRequest>,
+    ) -> Result<Response<DisableTwoFactorResponse>, Status> {
+        println!("->> {:<12} - disable_two_factor", "gRPC_Admin_User_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        let ip_address = request.get_client_ip(&self.state.config.client_ip);
+        let logged_in_user = claims
+            .logged_in_admin_user(&self.state.admin_user_service, &self.state.db)
+            .await?;
+        let req = request.into_inner();
+        req.validate()?;
+
+        match self
+            .state
+            .auth_service
+            .disable_two_factor(&self.state.db, &logged_in_user, &req.code, &ip_address)
+            .await
+        {
+            Ok(status) => Ok(Response::new(DisableTwoFactorResponse { status })),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn require_two_factor(
+        &self,
+        request: Request<RequireTwoFactorRequest>,
+    ) -> Result<Response<RequireTwoFactorResponse>, Status> {
+        println!("->> {:<12} - require_two_factor", "gRPC_Admin_User_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        let logged_in_user = claims
+            .logged_in_admin_user(&self.state.admin_user_service, &self.state.db)
+            .await?;
+        let req = request.into_inner();
+
+        match self
+            .state
+            .auth_service
+            .require_two_factor(&self.state.db, &logged_in_user, req.required)
+            .await
+        {
+            Ok(status) => Ok(Response::new(RequireTwoFactorResponse { status })),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn unlock_admin_user(
+        &self,
+        request: Request<UnlockAdminUserRequest>,
+    ) -> Result<Response<UnlockAdminUserResponse>, Status> {
+        println!("->> {:<12} - unlock_admin_user", "gRPC_Admin_User_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        let logged_in_user = claims
+            .logged_in_admin_user(&self.state.admin_user_service, &self.state.db)
+            .await?;
+        let ip_address = request.get_client_ip(&self.state.config.client_ip);
+        let req = request.into_inner();
+        req.validate()?;
+
+        match self
+            .state
+            .auth_service
+            .unlock_admin_user(&self.state.db, &logged_in_user, &req.admin_user_id, &ip_address)
+            .await
+        {
+            Ok(unlocked) => {
+                let response = UnlockAdminUserResponse {
+                    status: true,
+                    unlocked,
+                };
+                Ok(Response::new(response))
+            }
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn audit_log_all(
+        &self,
+        request: Request<AuditLogAllRequest>,
+    ) -> Result<Response<AuditLogAllResponse>, Status> {
+        println!("->> {:<12} - audit_log_all", "gRPC_Admin_User_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        let logged_in_user = claims
+            .logged_in_admin_user(&self.state.admin_user_service, &self.state.db)
+            .await?;
+
+        match self
+            .state
+            .auth_service
+            .audit_log_all(&self.state.db, &logged_in_user)
+            .await
+        {
+            Ok(audit_log_all_response) => Ok(Response::new(audit_log_all_response)),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
 }
@@ -20,10 +20,10 @@ impl Asset for AssetApi {
     {
         println!("->> {:<12} - paginate_asset", "gRPC_Asset_Api_Service");
         let claims = request.get_token_claim()?;
-        let logged_in_user = claims.admin_user_model;
-        logged_in_user
+        claims
             .check_user_has_resouce_access(
                 &self.state.admin_user_service,
+                &self.state.db,
                 String::from("paginate_asset"),
             )
             .await?;
@@ -58,10 +58,10 @@ impl Asset for AssetApi {
         println!("->> {:<12} - create_folder", "gRPC_Asset_Api_Service");
 
         let claims = request.get_token_claim()?;
-        let logged_in_user = claims.admin_user_model;
-        logged_in_user
+        let logged_in_user = claims
             .check_user_has_resouce_access(
                 &self.state.admin_user_service,
+                &self.state.db,
                 String::from("create_folder"),
             )
             .await?;
@@ -95,10 +95,10 @@ impl Asset for AssetApi {
     {
         println!("->> {:<12} - delete_asset", "gRPC_Asset_Api_Service");
         let claims = request.get_token_claim()?;
-        let logged_in_user = claims.admin_user_model;
-        logged_in_user
+        claims
             .check_user_has_resouce_access(
                 &self.state.admin_user_service,
+                &self.state.db,
                 String::from("asset_delete"),
             )
             .await?;
@@ -131,10 +131,10 @@ impl Asset for AssetApi {
     {
         println!("->> {:<12} - delete_folder", "gRPC_Asset_Api_Service");
         let claims = request.get_token_claim()?;
-        let logged_in_user = claims.admin_user_model;
-        logged_in_user
+        claims
             .check_user_has_resouce_access(
                 &self.state.admin_user_service,
+                &self.state.db,
                 String::from("delete_folder"),
             )
             .await?;
@@ -168,10 +168,10 @@ impl Asset for AssetApi {
         println!("->> {:<12} - rename_asset", "gRPC_Asset_Api_Service");
 
         let claims = request.get_token_claim()?;
-        let logged_in_user = claims.admin_user_model;
-        logged_in_user
+        let logged_in_user = claims
             .check_user_has_resouce_access(
                 &self.state.admin_user_service,
+                &self.state.db,
                 String::from("rename_asset"),
             )
             .await?;
@@ -184,7 +184,7 @@ impl Asset for AssetApi {
             rename_asset(
                 &self.state.db,
                 req,
-                &claims.email
+                &logged_in_user.email
             ).await {
             Ok(reply) => {
                 let res = Response::new(reply);
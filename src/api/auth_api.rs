@@ -1,10 +1,13 @@
 use crate::api::proto::auth::auth_server::Auth;
 use crate::api::proto::auth::{
-    ForgotPasswordRequest, ForgotPasswordResponse, LoginRequest, LoginResponse,
-    ResetPasswordRequest, ResetPasswordResponse,
+    ConfirmTwoFactorRequest, ConfirmTwoFactorResponse, EnrollTwoFactorRequest,
+    EnrollTwoFactorResponse, ForgotPasswordRequest, ForgotPasswordResponse, LoginRequest,
+    LoginResponse, LogoutRequest, LogoutResponse, RefreshTokenRequest, RefreshTokenResponse,
+    ResetPasswordRequest, ResetPasswordResponse, VerifyTwoFactorRequest,
 };
 use crate::avored_state::AvoRedState;
 use crate::error::Error::TonicError;
+use crate::extensions::tonic_request::TonicRequest;
 use std::sync::Arc;
 use tonic::{async_trait, Request, Response, Status};
 
@@ -19,8 +22,9 @@ impl Auth for AuthApi {
         request: Request<LoginRequest>,
     ) -> Result<Response<LoginResponse>, Status> {
         println!("->> {:<12} - login", "GRPC_Auth_API_SERVICE");
-        
 
+        let ip_address = request.get_client_ip(&self.state.config.client_ip);
+        let user_agent = request.get_user_agent();
         let req = request.into_inner();
         let (valid, error_messages) = req.validate()?;
         if !valid {
@@ -34,16 +38,83 @@ impl Auth for AuthApi {
                 &req.email,
                 &req.password,
                 &self.state.db,
+                &user_agent,
+                &ip_address,
                 &self.state.config.jwt_secret_key,
             )
             .await
         {
-            Ok(token) => {
-                let login_response = LoginResponse {
+            Ok(auth_token) => Ok(Response::new(auth_token.into())),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn refresh_token(
+        &self,
+        request: Request<RefreshTokenRequest>,
+    ) -> Result<Response<RefreshTokenResponse>, Status> {
+        println!("->> {:<12} - refresh_token", "GRPC_Auth_API_SERVICE");
+
+        let ip_address = request.get_client_ip(&self.state.config.client_ip);
+        let req = request.into_inner();
+        let (valid, error_messages) = req.validate()?;
+        if !valid {
+            return Err(Status::invalid_argument(error_messages));
+        }
+
+        match self
+            .state
+            .auth_service
+            .refresh_token(
+                &self.state.db,
+                &req.refresh_token,
+                &ip_address,
+                &self.state.config.jwt_secret_key,
+            )
+            .await
+        {
+            Ok(auth_token) => {
+                let refresh_token_response = RefreshTokenResponse {
                     status: true,
-                    data: token,
+                    data: auth_token.access_token,
+                    refresh_token: auth_token.refresh_token,
+                    expires_in: auth_token.expires_in,
+                };
+                Ok(Response::new(refresh_token_response))
+            }
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn logout(
+        &self,
+        request: Request<LogoutRequest>,
+    ) -> Result<Response<LogoutResponse>, Status> {
+        println!("->> {:<12} - logout", "GRPC_Auth_API_SERVICE");
+
+        let req = request.into_inner();
+        let (valid, error_messages) = req.validate()?;
+        if !valid {
+            return Err(Status::invalid_argument(error_messages));
+        }
+
+        match self
+            .state
+            .auth_service
+            .logout(&self.state.db, &req.refresh_token)
+            .await
+        {
+            Ok(logout_status) => {
+                let logout_response = LogoutResponse {
+                    status: logout_status,
                 };
-                Ok(Response::new(login_response))
+                Ok(Response::new(logout_response))
             }
             Err(e) => match e {
                 TonicError(status) => Err(status),
@@ -52,6 +123,99 @@ impl Auth for AuthApi {
         }
     }
 
+    async fn verify_two_factor(
+        &self,
+        request: Request<VerifyTwoFactorRequest>,
+    ) -> Result<Response<LoginResponse>, Status> {
+        println!("->> {:<12} - verify_two_factor", "GRPC_Auth_API_SERVICE");
+
+        let ip_address = request.get_client_ip(&self.state.config.client_ip);
+        let user_agent = request.get_user_agent();
+        let req = request.into_inner();
+        let (valid, error_messages) = req.validate()?;
+        if !valid {
+            return Err(Status::invalid_argument(error_messages));
+        }
+
+        match self
+            .state
+            .auth_service
+            .verify_two_factor(
+                &self.state.db,
+                &req.two_factor_token,
+                &req.code,
+                &user_agent,
+                &ip_address,
+                &self.state.config.jwt_secret_key,
+            )
+            .await
+        {
+            Ok(auth_token) => Ok(Response::new(auth_token.into())),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn enroll_two_factor(
+        &self,
+        request: Request<EnrollTwoFactorRequest>,
+    ) -> Result<Response<EnrollTwoFactorResponse>, Status> {
+        println!("->> {:<12} - enroll_two_factor", "GRPC_Auth_API_SERVICE");
+
+        let bearer_token = request.get_bearer_token();
+
+        match self
+            .state
+            .auth_service
+            .enroll_two_factor(&self.state.db, &bearer_token, &self.state.config.jwt_secret_key)
+            .await
+        {
+            Ok(enroll_two_factor_response) => Ok(Response::new(enroll_two_factor_response)),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn confirm_two_factor(
+        &self,
+        request: Request<ConfirmTwoFactorRequest>,
+    ) -> Result<Response<ConfirmTwoFactorResponse>, Status> {
+        println!("->> {:<12} - confirm_two_factor", "GRPC_Auth_API_SERVICE");
+
+        let ip_address = request.get_client_ip(&self.state.config.client_ip);
+        let user_agent = request.get_user_agent();
+        let bearer_token = request.get_bearer_token();
+        let req = request.into_inner();
+        let (valid, error_messages) = req.validate()?;
+        if !valid {
+            return Err(Status::invalid_argument(error_messages));
+        }
+
+        match self
+            .state
+            .auth_service
+            .confirm_two_factor(
+                &self.state.db,
+                &bearer_token,
+                &req.code,
+                &user_agent,
+                &ip_address,
+                &self.state.config.jwt_secret_key,
+            )
+            .await
+        {
+            Ok(confirm_two_factor_response) => Ok(Response::new(confirm_two_factor_response)),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
     async fn forgot_password(
         &self,
         request: Request<ForgotPasswordRequest>,
@@ -59,7 +223,7 @@ impl Auth for AuthApi {
         println!("->> {:<12} - forgot_password", "GRPC_Auth_API_SERVICE");
 
         let req = request.into_inner();
-        let (valid, error_messages) = req.validate(&self.state).await?;
+        let (valid, error_messages) = req.validate()?;
         if !valid {
             return Err(Status::invalid_argument(error_messages));
         }
@@ -70,6 +234,7 @@ impl Auth for AuthApi {
             .forgot_password(
                 &self.state.db,
                 &self.state.template,
+                &self.state.email_service,
                 &self.state.config.react_admin_app_url,
                 &req.email,
             )
@@ -105,11 +270,15 @@ impl Auth for AuthApi {
         match self
             .state
             .auth_service
-            .reset_password(&self.state.db, &req.email, req.password, &self.state.config.password_salt, &req.token)
+            .reset_password(&self.state.db, &req.email, req.password.clone(), &req.token)
             .await
         {
             Ok(reset_password_status) => {
-                
+                self.state
+                    .password_policy_service
+                    .remember_password(&self.state.db, &req.email, &req.password)
+                    .await;
+
                 let reset_password_response = ResetPasswordResponse {
                     status: reset_password_status
                 };
@@ -126,3 +295,53 @@ impl Auth for AuthApi {
     
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+    use axum::extract::ConnectInfo;
+    use tonic::{Code, Request};
+    use crate::api::proto::auth::auth_server::Auth;
+    use crate::api::proto::auth::LoginRequest;
+    use crate::test_helpers::{test_config, test_state};
+    use super::AuthApi;
+
+    /// A login as axum hands it over, with the peer address of the connection.
+    fn login_request(email: &str, peer_addr: &str) -> Request<LoginRequest> {
+        let mut request = Request::new(LoginRequest {
+            email: email.to_string(),
+            password: String::from("wrong password"),
+        });
+        request
+            .extensions_mut()
+            .insert(ConnectInfo(peer_addr.parse::<SocketAddr>().unwrap()));
+
+        request
+    }
+
+    #[tokio::test]
+    async fn login_limits_failed_attempts_per_client_ip() {
+        let mut config = test_config();
+        config.login_ip_max_attempts = 2;
+        let auth_api = AuthApi {
+            state: test_state(config).await,
+        };
+
+        for email in ["jane@example.com", "john@example.com"] {
+            let status = auth_api.login(login_request(email, "203.0.113.7:50000")).await.unwrap_err();
+            assert_eq!(status.code(), Code::InvalidArgument);
+        }
+
+        let status = auth_api
+            .login(login_request("joe@example.com", "203.0.113.7:50001"))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::ResourceExhausted);
+
+        let status = auth_api
+            .login(login_request("joe@example.com", "198.51.100.1:50000"))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument);
+    }
+}
@@ -1,8 +1,15 @@
+use std::pin::Pin;
 use std::sync::Arc;
+use tokio_stream::{Stream, StreamExt};
 use tonic::{async_trait, Request, Response, Status};
+use tonic::metadata::MetadataValue;
 use crate::api::proto::cms::cms_server::Cms;
-use crate::api::proto::cms::{GetCmsContentRequest, GetCmsContentResponse, SentContactFormRequest, SentContactFormResponse};
+use crate::api::proto::cms::{CmsContentShape, ContentEventModel, GetCmsContentRequest, GetCmsContentResponse, SentContactFormRequest, SentContactFormResponse, SubmitFormRequest, SubmitFormResponse, WatchContentRequest};
 use crate::avored_state::AvoRedState;
+use crate::error::Error;
+use crate::extensions::bearer_token::BearerToken;
+use crate::extensions::tonic_request::TonicRequest;
+use crate::models::form_model::{FormSubmissionAttempt, FormSubmissionFile, CONTACT_US_FORM_IDENTIFIER};
 
 pub struct CmsApi {
     pub state: Arc<AvoRedState>,
@@ -10,6 +17,8 @@ pub struct CmsApi {
 
 #[async_trait]
 impl Cms for CmsApi {
+    type WatchContentStream = Pin<Box<dyn Stream<Item = Result<ContentEventModel, Status>> + Send>>;
+
     async fn get_cms_content(
         &self, 
         request: Request<GetCmsContentRequest>
@@ -17,28 +26,54 @@ impl Cms for CmsApi {
 
         println!("->> {:<12} - get_cms_content", "gRPC_Cms_Api_Service");
 
-        // let claims = request.extensions().get::<TokenClaims>().cloned().unwrap();
-        // let logged_in_user = claims.admin_user_model;
-        // 
-        // let has_permission_bool = self.state
-        //     .admin_user_service
-        //     .has_permission(logged_in_user, String::from("get_cms_content"))
-        //     .await?;
-        // if !has_permission_bool {
-        //     let status = Status::permission_denied("You don't have permission to access this resource");
-        //     return Err(status);
-        // }
-        
+        self.state
+            .cms_api_key_service
+            .authorize(
+                &self.state.db,
+                &request.metadata().get_bearer_token(),
+                "get_cms_content",
+                Some(&request.get_ref().content_type),
+            )
+            .await?;
+
         let req = request.into_inner();
-      
+
+        let content_model = match self
+            .state
+            .cms_service
+            .find_cms_content(&self.state.db, &req.content_type, &req.content_identifier)
+            .await {
+            Ok(content_model) => content_model,
+            Err(e @ Error::NotFound(_)) => return Err(e.into()),
+            Err(e) => return Err(Status::internal(e.to_string()))
+        };
+        let field_identifiers = req
+            .field_mask
+            .as_ref()
+            .map(|field_mask| field_mask.paths.clone())
+            .unwrap_or_default();
+        let etag = content_model
+            .representation_etag(&field_identifiers, req.shape() == CmsContentShape::Compact);
+        let last_modified = content_model.last_modified();
+
         match self.
             state.
             cms_service.
             get_cms_content(
                 req,
-                &self.state.db
-            ).await {
-            Ok(reply) => Ok(Response::new(reply)),
+                content_model
+            ) {
+            Ok(reply) => {
+                let mut response = Response::new(reply);
+                let etag = MetadataValue::try_from(etag)
+                    .map_err(|e| Status::internal(e.to_string()))?;
+                let last_modified = MetadataValue::try_from(last_modified)
+                    .map_err(|e| Status::internal(e.to_string()))?;
+                response.metadata_mut().insert("etag", etag);
+                response.metadata_mut().insert("last-modified", last_modified);
+
+                Ok(response)
+            },
             Err(e) => Err(Status::internal(e.to_string()))
         }
     }
@@ -47,17 +82,139 @@ impl Cms for CmsApi {
         &self,
         request: Request<SentContactFormRequest>
     ) -> Result<Response<SentContactFormResponse>, Status> {
+        println!("->> {:<12} - sent_contact_form", "gRPC_Cms_Api_Service");
+
+        self.state
+            .cms_api_key_service
+            .authorize(
+                &self.state.db,
+                &request.metadata().get_bearer_token(),
+                "sent_contact_form",
+                None,
+            )
+            .await?;
+
+        let client_ip = request.get_client_ip(&self.state.config.client_ip);
         let req = request.into_inner();
+        let attempt = FormSubmissionAttempt {
+            form_identifier: String::from(CONTACT_US_FORM_IDENTIFIER),
+            client_ip,
+            challenge_token: req.challenge_token,
+            data: [
+                (String::from("first_name"), req.first_name),
+                (String::from("last_name"), req.last_name),
+                (String::from("email"), req.email),
+                (String::from("phone"), req.phone),
+                (String::from("message"), req.message),
+                (self.state.config.form_honeypot_field.clone(), req.honeypot),
+            ]
+            .into(),
+            files: vec![],
+        };
 
-        match self.
-            state.
-            cms_service.
-            sent_contact_form(
-                &self.state.template,
-                req
-            ).await {
+        match self
+            .state
+            .form_service
+            .submit_form(&self.state.db, &self.state.template, &self.state.email_service, attempt)
+            .await
+        {
+            Ok(reply) => Ok(Response::new(SentContactFormResponse { status: reply.status })),
+            Err(e) => match e {
+                Error::InvalidArgument(_) | Error::NotFound(_) | Error::TooManyRequests(_) => Err(e.into()),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn submit_form(
+        &self,
+        request: Request<SubmitFormRequest>
+    ) -> Result<Response<SubmitFormResponse>, Status> {
+        println!("->> {:<12} - submit_form", "gRPC_Cms_Api_Service");
+
+        self.state
+            .cms_api_key_service
+            .authorize(
+                &self.state.db,
+                &request.metadata().get_bearer_token(),
+                "submit_form",
+                None,
+            )
+            .await?;
+
+        let client_ip = request.get_client_ip(&self.state.config.client_ip);
+        let req = request.into_inner();
+        let attempt = FormSubmissionAttempt {
+            form_identifier: req.form_identifier,
+            client_ip,
+            challenge_token: req.challenge_token,
+            data: req.data,
+            files: req
+                .files
+                .into_iter()
+                .map(|file| FormSubmissionFile {
+                    field_identifier: file.field_identifier,
+                    file_name: file.file_name,
+                    content_type: file.content_type,
+                    content: file.content,
+                })
+                .collect(),
+        };
+
+        match self
+            .state
+            .form_service
+            .submit_form(&self.state.db, &self.state.template, &self.state.email_service, attempt)
+            .await
+        {
             Ok(reply) => Ok(Response::new(reply)),
-            Err(e) => Err(Status::internal(e.to_string()))
-        }   
+            Err(e) => match e {
+                Error::InvalidArgument(_) | Error::NotFound(_) | Error::TooManyRequests(_) => Err(e.into()),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
     }
-}
\ No newline at end of file
+
+    async fn watch_content(
+        &self,
+        request: Request<WatchContentRequest>
+    ) -> Result<Response<Self::WatchContentStream>, Status> {
+        println!("->> {:<12} - watch_content", "gRPC_Cms_Api_Service");
+
+        let api_key = request.metadata().get_bearer_token();
+        let cms_api_key_model = self.state
+            .cms_api_key_service
+            .authorize(
+                &self.state.db,
+                &api_key,
+                "watch_content",
+                None,
+            )
+            .await?;
+
+        let req = request.into_inner();
+        let collections = cms_api_key_model.resolve_collections(req.collections)?;
+
+        let state = self.state.clone();
+        let watched_collections = collections.clone();
+        let still_authorized = move || {
+            let state = state.clone();
+            let api_key = api_key.clone();
+            let watched_collections = watched_collections.clone();
+            async move {
+                state
+                    .cms_api_key_service
+                    .can_watch(&state.db, &api_key, &watched_collections)
+                    .await
+            }
+        };
+
+        let stream = self
+            .state
+            .cms_service
+            .watch_content_while_authorized(collections, still_authorized)
+            .map(|content_event_model| content_event_model.try_into().map_err(Status::from));
+
+        Ok(Response::new(Box::pin(stream)))
+    }
+}
@@ -0,0 +1,172 @@
+use std::sync::Arc;
+use tonic::{async_trait, Request, Response, Status};
+use crate::api::proto::cms_api_key::cms_api_key_server::CmsApiKey;
+use crate::api::proto::cms_api_key::{CmsApiKeyAllRequest, CmsApiKeyAllResponse, RevokeCmsApiKeyRequest, RevokeCmsApiKeyResponse, RotateCmsApiKeyRequest, RotateCmsApiKeyResponse, StoreCmsApiKeyRequest, StoreCmsApiKeyResponse, UpdateCmsApiKeyRequest, UpdateCmsApiKeyResponse};
+use crate::avored_state::AvoRedState;
+use crate::error::Error::TonicError;
+use crate::extensions::tonic_request::TonicRequest;
+use crate::models::admin_user_model::AdminUserModelExtension;
+
+pub struct CmsApiKeyApi {
+    pub state: Arc<AvoRedState>,
+}
+
+#[async_trait]
+impl CmsApiKey for CmsApiKeyApi {
+    async fn cms_api_key_all(
+        &self,
+        request: Request<CmsApiKeyAllRequest>,
+    ) -> Result<Response<CmsApiKeyAllResponse>, Status> {
+        println!("->> {:<12} - cms_api_key_all", "gRPC_Cms_Api_Key_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        claims
+            .check_user_has_resouce_access(
+                &self.state.admin_user_service,
+                &self.state.db,
+                String::from("cms_api_key_all"),
+            )
+            .await?;
+
+        match self
+            .state
+            .cms_api_key_service
+            .cms_api_key_all(&self.state.db)
+            .await
+        {
+            Ok(reply) => Ok(Response::new(reply)),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn store_cms_api_key(
+        &self,
+        request: Request<StoreCmsApiKeyRequest>,
+    ) -> Result<Response<StoreCmsApiKeyResponse>, Status> {
+        println!("->> {:<12} - store_cms_api_key", "gRPC_Cms_Api_Key_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        let logged_in_user = claims
+            .check_user_has_resouce_access(
+                &self.state.admin_user_service,
+                &self.state.db,
+                String::from("store_cms_api_key"),
+            )
+            .await?;
+
+        let req = request.into_inner();
+        req.validate()?;
+
+        match self
+            .state
+            .cms_api_key_service
+            .store_cms_api_key(&self.state.db, req, logged_in_user.email)
+            .await
+        {
+            Ok(reply) => Ok(Response::new(reply)),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn update_cms_api_key(
+        &self,
+        request: Request<UpdateCmsApiKeyRequest>,
+    ) -> Result<Response<UpdateCmsApiKeyResponse>, Status> {
+        println!("->> {:<12} - update_cms_api_key", "gRPC_Cms_Api_Key_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        let logged_in_user = claims
+            .check_user_has_resouce_access(
+                &self.state.admin_user_service,
+                &self.state.db,
+                String::from("update_cms_api_key"),
+            )
+            .await?;
+
+        let req = request.into_inner();
+        req.validate()?;
+
+        match self
+            .state
+            .cms_api_key_service
+            .update_cms_api_key(&self.state.db, req, logged_in_user.email)
+            .await
+        {
+            Ok(reply) => Ok(Response::new(reply)),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn rotate_cms_api_key(
+        &self,
+        request: Request<RotateCmsApiKeyRequest>,
+    ) -> Result<Response<RotateCmsApiKeyResponse>, Status> {
+        println!("->> {:<12} - rotate_cms_api_key", "gRPC_Cms_Api_Key_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        let logged_in_user = claims
+            .check_user_has_resouce_access(
+                &self.state.admin_user_service,
+                &self.state.db,
+                String::from("rotate_cms_api_key"),
+            )
+            .await?;
+
+        let req = request.into_inner();
+        req.validate()?;
+
+        match self
+            .state
+            .cms_api_key_service
+            .rotate_cms_api_key(&self.state.db, &req.cms_api_key_id, &logged_in_user.email)
+            .await
+        {
+            Ok(reply) => Ok(Response::new(reply)),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn revoke_cms_api_key(
+        &self,
+        request: Request<RevokeCmsApiKeyRequest>,
+    ) -> Result<Response<RevokeCmsApiKeyResponse>, Status> {
+        println!("->> {:<12} - revoke_cms_api_key", "gRPC_Cms_Api_Key_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        let logged_in_user = claims
+            .check_user_has_resouce_access(
+                &self.state.admin_user_service,
+                &self.state.db,
+                String::from("revoke_cms_api_key"),
+            )
+            .await?;
+
+        let req = request.into_inner();
+        req.validate()?;
+
+        match self
+            .state
+            .cms_api_key_service
+            .revoke_cms_api_key(&self.state.db, &req.cms_api_key_id, &logged_in_user.email)
+            .await
+        {
+            Ok(reply) => Ok(Response::new(reply)),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+}
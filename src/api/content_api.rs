@@ -1,6 +1,6 @@
 use crate::api::proto::content::content_server::Content;
 use crate::api::proto::content::{
-    CollectionAllRequest, CollectionAllResponse, ContentPaginateRequest, ContentPaginateResponse, DeleteContentRequest, DeleteContentResponse, GetCollectionRequest, GetCollectionResponse, GetContentRequest, GetContentResponse, PutContentIdentifierRequest, PutContentIdentifierResponse, StoreCollectionRequest, StoreCollectionResponse, StoreContentRequest, StoreContentResponse, UpdateCollectionRequest, UpdateCollectionResponse, UpdateContentRequest, UpdateContentResponse
+    CollectionAllRequest, CollectionAllResponse, ContentPaginateRequest, ContentPaginateResponse, DeleteContentRequest, DeleteContentResponse, ExportStaticSiteRequest, ExportStaticSiteResponse, GetCollectionRequest, GetStaticSiteExportRequest, GetCollectionResponse, GetContentRequest, GetContentResponse, PutContentIdentifierRequest, PutContentIdentifierResponse, StoreCollectionRequest, StoreCollectionResponse, StoreContentRequest, StoreContentResponse, UpdateCollectionRequest, UpdateCollectionResponse, UpdateContentRequest, UpdateContentResponse
 };
 use crate::avored_state::AvoRedState;
 use crate::error::Error::TonicError;
@@ -22,10 +22,10 @@ impl Content for ContentApi {
         println!("->> {:<12} - collection_all", "gRPC_Content_Api_Service");
 
         let claims = request.get_token_claim()?;
-        let logged_in_user = claims.admin_user_model;
-        logged_in_user
+        claims
             .check_user_has_resouce_access(
                 &self.state.admin_user_service,
+                &self.state.db,
                 String::from("collection_all"),
             )
             .await?;
@@ -55,10 +55,10 @@ impl Content for ContentApi {
         println!("->> {:<12} - content_paginate", "gRPC_Content_Api_Service");
 
         let claims = request.get_token_claim()?;
-        let logged_in_user = claims.admin_user_model;
-        logged_in_user
+        claims
             .check_user_has_resouce_access(
                 &self.state.admin_user_service,
+                &self.state.db,
                 String::from("content_paginate"),
             )
             .await?;
@@ -89,10 +89,10 @@ impl Content for ContentApi {
         println!("->> {:<12} - store_content", "gRPC_Content_Api_Service");
 
         let claims = request.get_token_claim()?;
-        let logged_in_user = claims.admin_user_model;
-        logged_in_user
+        let logged_in_user = claims
             .check_user_has_resouce_access(
                 &self.state.admin_user_service,
+                &self.state.db,
                 String::from("store_content"),
             )
             .await?;
@@ -103,7 +103,7 @@ impl Content for ContentApi {
         match self
             .state
             .content_service
-            .store_content(req, claims.email, &self.state.db)
+            .store_content(req, logged_in_user.email, &self.state.db)
             .await
         {
             Ok(reply) => {
@@ -125,10 +125,10 @@ impl Content for ContentApi {
         println!("->> {:<12} - get_content", "gRPC_Content_Api_Service");
 
         let claims = request.get_token_claim()?;
-        let logged_in_user = claims.admin_user_model;
-        logged_in_user
+        claims
             .check_user_has_resouce_access(
                 &self.state.admin_user_service,
+                &self.state.db,
                 String::from("get_content"),
             )
             .await?;
@@ -159,10 +159,10 @@ impl Content for ContentApi {
         println!("->> {:<12} - update_content", "gRPC_Content_Api_Service");
 
         let claims = request.get_token_claim()?;
-        let logged_in_user = claims.admin_user_model;
-        logged_in_user
+        let logged_in_user = claims
             .check_user_has_resouce_access(
                 &self.state.admin_user_service,
+                &self.state.db,
                 String::from("update_content"),
             )
             .await?;
@@ -173,7 +173,7 @@ impl Content for ContentApi {
         match self
             .state
             .content_service
-            .update_content(&self.state.db, req, claims.email)
+            .update_content(&self.state.db, req, logged_in_user.email)
             .await
         {
             Ok(reply) => {
@@ -198,10 +198,10 @@ impl Content for ContentApi {
         );
 
         let claims = request.get_token_claim()?;
-        let logged_in_user = claims.admin_user_model;
-        logged_in_user
+        let logged_in_user = claims
             .check_user_has_resouce_access(
                 &self.state.admin_user_service,
+                &self.state.db,
                 String::from("put_content_identifier"),
             )
             .await?;
@@ -212,7 +212,7 @@ impl Content for ContentApi {
         match self
             .state
             .content_service
-            .put_content_identifier(&self.state.db, req, claims.email)
+            .put_content_identifier(&self.state.db, req, logged_in_user.email)
             .await
         {
             Ok(reply) => {
@@ -234,10 +234,10 @@ impl Content for ContentApi {
         println!("->> {:<12} - get_collection", "gRPC_Content_Api_Service");
 
         let claims = request.get_token_claim()?;
-        let logged_in_user = claims.admin_user_model;
-        logged_in_user
+        claims
             .check_user_has_resouce_access(
                 &self.state.admin_user_service,
+                &self.state.db,
                 String::from("get_collection"),
             )
             .await?;
@@ -267,10 +267,10 @@ impl Content for ContentApi {
         println!("->> {:<12} - store_collection", "gRPC_Content_Api_Service");
 
         let claims = request.get_token_claim()?;
-        let logged_in_user = claims.admin_user_model;
-        logged_in_user
+        let logged_in_user = claims
             .check_user_has_resouce_access(
                 &self.state.admin_user_service,
+                &self.state.db,
                 String::from("store_collection"),
             )
             .await?;
@@ -303,10 +303,10 @@ impl Content for ContentApi {
         println!("->> {:<12} - update_collection", "gRPC_Content_Api_Service");
 
         let claims = request.get_token_claim()?;
-        let logged_in_user = claims.admin_user_model;
-        logged_in_user
+        let logged_in_user = claims
             .check_user_has_resouce_access(
                 &self.state.admin_user_service,
+                &self.state.db,
                 String::from("update_collection"),
             )
             .await?;
@@ -339,10 +339,10 @@ impl Content for ContentApi {
         println!("->> {:<12} - delete_content", "gRPC_Content_Api_Service");
 
         let claims = request.get_token_claim()?;
-        let logged_in_user = claims.admin_user_model;
-        logged_in_user
+        claims
             .check_user_has_resouce_access(
                 &self.state.admin_user_service,
+                &self.state.db,
                 String::from("delete_content"),
             )
             .await?;
@@ -367,4 +367,64 @@ impl Content for ContentApi {
             },
         }
     }
+
+    async fn export_static_site(
+        &self,
+        request: Request<ExportStaticSiteRequest>,
+    ) -> Result<Response<ExportStaticSiteResponse>, Status> {
+        println!("->> {:<12} - export_static_site", "gRPC_Content_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        claims
+            .check_user_has_resouce_access(
+                &self.state.admin_user_service,
+                &self.state.db,
+                String::from("export_static_site"),
+            )
+            .await?;
+
+        let static_site_format = request.into_inner().format();
+
+        match self.state.static_site_service.start_export() {
+            Ok(reply) => {
+                let state = self.state.clone();
+                let export_id = reply.id.clone();
+                tokio::spawn(async move {
+                    state
+                        .static_site_service
+                        .run_export(&state.db, &state.page_service, &state.theme, &export_id, static_site_format)
+                        .await
+                });
+
+                Ok(Response::new(reply))
+            }
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn get_static_site_export(
+        &self,
+        request: Request<GetStaticSiteExportRequest>,
+    ) -> Result<Response<ExportStaticSiteResponse>, Status> {
+        println!("->> {:<12} - get_static_site_export", "gRPC_Content_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        claims
+            .check_user_has_resouce_access(
+                &self.state.admin_user_service,
+                &self.state.db,
+                String::from("export_static_site"),
+            )
+            .await?;
+
+        let req = request.into_inner();
+
+        match self.state.static_site_service.get_export(&req.id) {
+            Ok(reply) => Ok(Response::new(reply)),
+            Err(e) => Err(e.into()),
+        }
+    }
 }
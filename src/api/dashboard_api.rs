@@ -1,7 +1,7 @@
 use std::sync::Arc;
 use tonic::{async_trait, Request, Response, Status};
 use crate::api::proto::dashboard::dashboard_server::Dashboard;
-use crate::api::proto::dashboard::{DashboardRequest, DashboardResponse};
+use crate::api::proto::dashboard::{CmsCacheStatsRequest, CmsCacheStatsResponse, DashboardRequest, DashboardResponse, FormSpamStatsRequest, FormSpamStatsResponse};
 use crate::avored_state::AvoRedState;
 use crate::extensions::tonic_request::TonicRequest;
 use crate::models::admin_user_model::AdminUserModelExtension;
@@ -17,10 +17,10 @@ impl Dashboard for DashboardApi {
         println!("->> {:<12} - dashboard", "gRPC_Dashboard_Api_Service");
 
         let claims = request.get_token_claim()?;
-        let logged_in_user = claims.admin_user_model;
-        logged_in_user
+        claims
             .check_user_has_resouce_access(
                 &self.state.admin_user_service,
+                &self.state.db,
                 String::from("dashboard"),
             )
             .await?;
@@ -29,4 +29,60 @@ impl Dashboard for DashboardApi {
         let reply = DashboardResponse { status: true };
         Ok(Response::new(reply))
     }
-}
\ No newline at end of file
+
+    async fn cms_cache_stats(
+        &self,
+        request: Request<CmsCacheStatsRequest>
+    ) -> Result<Response<CmsCacheStatsResponse>, Status> {
+
+        println!("->> {:<12} - cms_cache_stats", "gRPC_Dashboard_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        claims
+            .check_user_has_resouce_access(
+                &self.state.admin_user_service,
+                &self.state.db,
+                String::from("dashboard"),
+            )
+            .await?;
+
+        let cache_stats = self.state.cms_service.content_cache_stats();
+        let reply = CmsCacheStatsResponse {
+            status: true,
+            hits: cache_stats.hits,
+            misses: cache_stats.misses,
+            entries: cache_stats.entries,
+            max_entries: cache_stats.max_entries,
+            ttl_seconds: cache_stats.ttl_seconds,
+        };
+        Ok(Response::new(reply))
+    }
+
+    async fn form_spam_stats(
+        &self,
+        request: Request<FormSpamStatsRequest>
+    ) -> Result<Response<FormSpamStatsResponse>, Status> {
+
+        println!("->> {:<12} - form_spam_stats", "gRPC_Dashboard_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        claims
+            .check_user_has_resouce_access(
+                &self.state.admin_user_service,
+                &self.state.db,
+                String::from("dashboard"),
+            )
+            .await?;
+
+        let form_spam_stats = self.state.form_service.form_spam_stats();
+        let reply = FormSpamStatsResponse {
+            status: true,
+            accepted: form_spam_stats.accepted,
+            ip_rate_limited: form_spam_stats.ip_rate_limited,
+            form_rate_limited: form_spam_stats.form_rate_limited,
+            honeypot: form_spam_stats.honeypot,
+            verification_failed: form_spam_stats.verification_failed,
+        };
+        Ok(Response::new(reply))
+    }
+}
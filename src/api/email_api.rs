@@ -0,0 +1,272 @@
+use std::sync::Arc;
+use tonic::{async_trait, Request, Response, Status};
+use crate::api::proto::email::email_server::Email;
+use crate::api::proto::email::{
+    DeleteEmailTemplateRequest, DeleteEmailTemplateResponse, EmailPaginateRequest, EmailPaginateResponse,
+    EmailTemplateAllRequest, EmailTemplateAllResponse, GetEmailTemplateRequest, GetEmailTemplateResponse,
+    PreviewEmailTemplateRequest, PreviewEmailTemplateResponse, ResendEmailRequest, ResendEmailResponse,
+    StoreEmailTemplateRequest, StoreEmailTemplateResponse, UpdateEmailTemplateRequest, UpdateEmailTemplateResponse,
+};
+use crate::avored_state::AvoRedState;
+use crate::error::Error::TonicError;
+use crate::extensions::tonic_request::TonicRequest;
+use crate::models::admin_user_model::AdminUserModelExtension;
+
+pub struct EmailApi {
+    pub state: Arc<AvoRedState>,
+}
+
+#[async_trait]
+impl Email for EmailApi {
+    async fn email_paginate(
+        &self,
+        request: Request<EmailPaginateRequest>,
+    ) -> Result<Response<EmailPaginateResponse>, Status> {
+        println!("->> {:<12} - email_paginate", "gRPC_Email_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        claims
+            .check_user_has_resouce_access(
+                &self.state.admin_user_service,
+                &self.state.db,
+                String::from("email_paginate"),
+            )
+            .await?;
+
+        let req = request.into_inner();
+        req.validate()?;
+
+        match self
+            .state
+            .email_service
+            .email_paginate(&self.state.db, &req.status.unwrap_or_default(), req.page.unwrap_or(0))
+            .await
+        {
+            Ok(reply) => Ok(Response::new(reply)),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn resend_email(
+        &self,
+        request: Request<ResendEmailRequest>,
+    ) -> Result<Response<ResendEmailResponse>, Status> {
+        println!("->> {:<12} - resend_email", "gRPC_Email_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        claims
+            .check_user_has_resouce_access(
+                &self.state.admin_user_service,
+                &self.state.db,
+                String::from("resend_email"),
+            )
+            .await?;
+
+        let req = request.into_inner();
+        req.validate()?;
+
+        match self
+            .state
+            .email_service
+            .resend_email(&self.state.db, &req.email_id)
+            .await
+        {
+            Ok(reply) => Ok(Response::new(reply)),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn email_template_all(
+        &self,
+        request: Request<EmailTemplateAllRequest>,
+    ) -> Result<Response<EmailTemplateAllResponse>, Status> {
+        println!("->> {:<12} - email_template_all", "gRPC_Email_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        claims
+            .check_user_has_resouce_access(
+                &self.state.admin_user_service,
+                &self.state.db,
+                String::from("email_template_all"),
+            )
+            .await?;
+
+        match self
+            .state
+            .email_template_service
+            .email_template_all(&self.state.db, &self.state.template)
+            .await
+        {
+            Ok(reply) => Ok(Response::new(reply)),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn get_email_template(
+        &self,
+        request: Request<GetEmailTemplateRequest>,
+    ) -> Result<Response<GetEmailTemplateResponse>, Status> {
+        println!("->> {:<12} - get_email_template", "gRPC_Email_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        claims
+            .check_user_has_resouce_access(
+                &self.state.admin_user_service,
+                &self.state.db,
+                String::from("get_email_template"),
+            )
+            .await?;
+
+        let req = request.into_inner();
+        req.validate()?;
+
+        match self
+            .state
+            .email_template_service
+            .get_email_template(&self.state.db, &req.email_template_id)
+            .await
+        {
+            Ok(reply) => Ok(Response::new(reply)),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn store_email_template(
+        &self,
+        request: Request<StoreEmailTemplateRequest>,
+    ) -> Result<Response<StoreEmailTemplateResponse>, Status> {
+        println!("->> {:<12} - store_email_template", "gRPC_Email_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        let logged_in_user = claims
+            .check_user_has_resouce_access(
+                &self.state.admin_user_service,
+                &self.state.db,
+                String::from("store_email_template"),
+            )
+            .await?;
+
+        let req = request.into_inner();
+        req.validate(&self.state).await?;
+
+        match self
+            .state
+            .email_template_service
+            .store_email_template(&self.state.db, &self.state.template, req, logged_in_user.email)
+            .await
+        {
+            Ok(reply) => Ok(Response::new(reply)),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn update_email_template(
+        &self,
+        request: Request<UpdateEmailTemplateRequest>,
+    ) -> Result<Response<UpdateEmailTemplateResponse>, Status> {
+        println!("->> {:<12} - update_email_template", "gRPC_Email_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        let logged_in_user = claims
+            .check_user_has_resouce_access(
+                &self.state.admin_user_service,
+                &self.state.db,
+                String::from("update_email_template"),
+            )
+            .await?;
+
+        let req = request.into_inner();
+        req.validate()?;
+
+        match self
+            .state
+            .email_template_service
+            .update_email_template(&self.state.db, &self.state.template, req, logged_in_user.email)
+            .await
+        {
+            Ok(reply) => Ok(Response::new(reply)),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn delete_email_template(
+        &self,
+        request: Request<DeleteEmailTemplateRequest>,
+    ) -> Result<Response<DeleteEmailTemplateResponse>, Status> {
+        println!("->> {:<12} - delete_email_template", "gRPC_Email_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        claims
+            .check_user_has_resouce_access(
+                &self.state.admin_user_service,
+                &self.state.db,
+                String::from("delete_email_template"),
+            )
+            .await?;
+
+        let req = request.into_inner();
+        req.validate()?;
+
+        match self
+            .state
+            .email_template_service
+            .delete_email_template(&self.state.db, &self.state.template, &req.email_template_id)
+            .await
+        {
+            Ok(status) => Ok(Response::new(DeleteEmailTemplateResponse { status })),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn preview_email_template(
+        &self,
+        request: Request<PreviewEmailTemplateRequest>,
+    ) -> Result<Response<PreviewEmailTemplateResponse>, Status> {
+        println!("->> {:<12} - preview_email_template", "gRPC_Email_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        claims
+            .check_user_has_resouce_access(
+                &self.state.admin_user_service,
+                &self.state.db,
+                String::from("preview_email_template"),
+            )
+            .await?;
+
+        let req = request.into_inner();
+        req.validate()?;
+
+        match self
+            .state
+            .email_template_service
+            .preview_email_template(&self.state.template, req)
+        {
+            Ok(reply) => Ok(Response::new(reply)),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+}
\ No newline at end of file
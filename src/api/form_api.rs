@@ -0,0 +1,236 @@
+use std::sync::Arc;
+use tonic::{async_trait, Request, Response, Status};
+use crate::api::proto::form::form_server::Form;
+use crate::api::proto::form::{DeleteFormRequest, DeleteFormResponse, ExportFormSubmissionsRequest, ExportFormSubmissionsResponse, FormAllRequest, FormAllResponse, FormSubmissionPaginateRequest, FormSubmissionPaginateResponse, GetFormRequest, GetFormResponse, StoreFormRequest, StoreFormResponse, UpdateFormRequest, UpdateFormResponse};
+use crate::avored_state::AvoRedState;
+use crate::error::Error::TonicError;
+use crate::extensions::tonic_request::TonicRequest;
+use crate::models::admin_user_model::AdminUserModelExtension;
+
+pub struct FormApi {
+    pub state: Arc<AvoRedState>,
+}
+
+#[async_trait]
+impl Form for FormApi {
+    async fn form_all(
+        &self,
+        request: Request<FormAllRequest>,
+    ) -> Result<Response<FormAllResponse>, Status> {
+        println!("->> {:<12} - form_all", "gRPC_Form_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        claims
+            .check_user_has_resouce_access(
+                &self.state.admin_user_service,
+                &self.state.db,
+                String::from("form_all"),
+            )
+            .await?;
+
+        match self
+            .state
+            .form_service
+            .form_all(&self.state.db)
+            .await
+        {
+            Ok(reply) => Ok(Response::new(reply)),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn get_form(
+        &self,
+        request: Request<GetFormRequest>,
+    ) -> Result<Response<GetFormResponse>, Status> {
+        println!("->> {:<12} - get_form", "gRPC_Form_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        claims
+            .check_user_has_resouce_access(
+                &self.state.admin_user_service,
+                &self.state.db,
+                String::from("get_form"),
+            )
+            .await?;
+
+        let req = request.into_inner();
+        req.validate()?;
+
+        match self
+            .state
+            .form_service
+            .get_form(&self.state.db, &req.form_id)
+            .await
+        {
+            Ok(reply) => Ok(Response::new(reply)),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn store_form(
+        &self,
+        request: Request<StoreFormRequest>,
+    ) -> Result<Response<StoreFormResponse>, Status> {
+        println!("->> {:<12} - store_form", "gRPC_Form_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        let logged_in_user = claims
+            .check_user_has_resouce_access(
+                &self.state.admin_user_service,
+                &self.state.db,
+                String::from("store_form"),
+            )
+            .await?;
+
+        let req = request.into_inner();
+        req.validate(&self.state).await?;
+
+        match self
+            .state
+            .form_service
+            .store_form(&self.state.db, req, logged_in_user.email)
+            .await
+        {
+            Ok(reply) => Ok(Response::new(reply)),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn update_form(
+        &self,
+        request: Request<UpdateFormRequest>,
+    ) -> Result<Response<UpdateFormResponse>, Status> {
+        println!("->> {:<12} - update_form", "gRPC_Form_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        let logged_in_user = claims
+            .check_user_has_resouce_access(
+                &self.state.admin_user_service,
+                &self.state.db,
+                String::from("update_form"),
+            )
+            .await?;
+
+        let req = request.into_inner();
+        req.validate()?;
+
+        match self
+            .state
+            .form_service
+            .update_form(&self.state.db, req, logged_in_user.email)
+            .await
+        {
+            Ok(reply) => Ok(Response::new(reply)),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn delete_form(
+        &self,
+        request: Request<DeleteFormRequest>,
+    ) -> Result<Response<DeleteFormResponse>, Status> {
+        println!("->> {:<12} - delete_form", "gRPC_Form_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        claims
+            .check_user_has_resouce_access(
+                &self.state.admin_user_service,
+                &self.state.db,
+                String::from("delete_form"),
+            )
+            .await?;
+
+        let req = request.into_inner();
+        req.validate()?;
+
+        match self
+            .state
+            .form_service
+            .delete_form(&self.state.db, &req.form_id)
+            .await
+        {
+            Ok(status) => Ok(Response::new(DeleteFormResponse { status })),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn form_submission_paginate(
+        &self,
+        request: Request<FormSubmissionPaginateRequest>,
+    ) -> Result<Response<FormSubmissionPaginateResponse>, Status> {
+        println!("->> {:<12} - form_submission_paginate", "gRPC_Form_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        claims
+            .check_user_has_resouce_access(
+                &self.state.admin_user_service,
+                &self.state.db,
+                String::from("form_submission_paginate"),
+            )
+            .await?;
+
+        let req = request.into_inner();
+        req.validate()?;
+
+        match self
+            .state
+            .form_service
+            .form_submission_paginate(&self.state.db, &req.form_id, req.page.unwrap_or(0))
+            .await
+        {
+            Ok(reply) => Ok(Response::new(reply)),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn export_form_submissions(
+        &self,
+        request: Request<ExportFormSubmissionsRequest>,
+    ) -> Result<Response<ExportFormSubmissionsResponse>, Status> {
+        println!("->> {:<12} - export_form_submissions", "gRPC_Form_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        claims
+            .check_user_has_resouce_access(
+                &self.state.admin_user_service,
+                &self.state.db,
+                String::from("export_form_submissions"),
+            )
+            .await?;
+
+        let req = request.into_inner();
+        req.validate()?;
+
+        match self
+            .state
+            .form_service
+            .export_form_submissions(&self.state.db, &req.form_id)
+            .await
+        {
+            Ok(reply) => Ok(Response::new(reply)),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+}
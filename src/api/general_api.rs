@@ -3,6 +3,7 @@ use tonic::{async_trait, Response, Status};
 use crate::api::proto::general::general_service_server::GeneralService;
 use crate::api::proto::general::{LoggedInUserRequest, LoggedInUserResponse};
 use crate::avored_state::AvoRedState;
+use crate::models::admin_user_model::AdminUserModelExtension;
 use crate::models::token_claim_model::TokenClaims;
 
 pub struct GeneralApi {
@@ -17,12 +18,15 @@ impl GeneralService for GeneralApi {
     ) -> Result<Response<LoggedInUserResponse>, tonic::Status> {
         println!("->> {:<12} - logged_in_user", "gRPC_General_Service");
         let claims = request.extensions().get::<TokenClaims>().cloned().unwrap();
+        let logged_in_user = claims
+            .logged_in_admin_user(&self.state.admin_user_service, &self.state.db)
+            .await?;
         
         match self.
             state.
             general_service.
             logged_in_user(
-                claims
+                logged_in_user
             ).await {
             Ok(reply) => Ok(Response::new(reply)),
             Err(e) => Err(Status::internal(e.to_string()))
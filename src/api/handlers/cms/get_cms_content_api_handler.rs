@@ -0,0 +1,81 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::extensions::bearer_token::BearerToken;
+use crate::models::content_model::ContentModel;
+use crate::{avored_state::AvoRedState, error::Result};
+use axum::extract::{Path, Query};
+use axum::http::header::{ETAG, LAST_MODIFIED};
+use axum::http::HeaderMap;
+use axum::{extract::State, response::IntoResponse, Json};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+pub async fn get_cms_content_api_handler(
+    state: State<Arc<AvoRedState>>,
+    Path((content_type, content_identifier)): Path<(String, String)>,
+    Query(query_param): Query<GetCmsContentQuery>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse> {
+    println!("->> {:<12} - get_cms_content_api_handler", "HANDLER");
+
+    state
+        .cms_api_key_service
+        .authorize(
+            &state.db,
+            &headers.get_bearer_token(),
+            "get_cms_content",
+            Some(&content_type),
+        )
+        .await?;
+
+    let content_model = state
+        .cms_service
+        .find_cms_content(&state.db, &content_type, &content_identifier)
+        .await?;
+
+    let field_identifiers = query_param
+        .fields
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|identifier| !identifier.is_empty())
+        .map(str::to_string)
+        .collect::<Vec<String>>();
+    let compact = query_param.shape.as_deref() == Some("compact");
+    let headers = [
+        (ETAG, content_model.representation_etag(&field_identifiers, compact)),
+        (LAST_MODIFIED, content_model.last_modified()),
+    ];
+    let mut content_model = content_model.project_fields(&field_identifiers);
+
+    let mut fields = None;
+    if compact {
+        fields = Some(content_model.compact_fields());
+        content_model.content_fields = vec![];
+    }
+
+    let cms_content_response = CmsContentResponseViewModel {
+        data: content_model,
+        fields,
+        status: true,
+    };
+
+    Ok((headers, Json(cms_content_response)).into_response())
+}
+
+#[derive(Serialize)]
+pub struct CmsContentResponseViewModel {
+    pub data: ContentModel,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<BTreeMap<String, JsonValue>>,
+    pub status: bool,
+}
+
+/// `?fields=title,body` limits the content fields and `?shape=compact`
+/// returns them in `fields` as identifier => value.
+#[derive(Deserialize, Debug)]
+pub struct GetCmsContentQuery {
+    pub fields: Option<String>,
+    pub shape: Option<String>,
+}
@@ -0,0 +1,2 @@
+pub mod get_cms_content_api_handler;
+pub mod watch_cms_content_api_handler;
@@ -0,0 +1,77 @@
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use crate::extensions::bearer_token::BearerToken;
+use crate::{avored_state::AvoRedState, error::Result};
+use axum::extract::Query;
+use axum::http::HeaderMap;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::extract::State;
+use serde::Deserialize;
+use tokio_stream::{Stream, StreamExt};
+
+/// Server sent events version of the WatchContent rpc. Browsers cannot set an
+/// authorization header on `EventSource`, when `AVORED_CMS_WATCH_QUERY_API_KEY`
+/// is turned on the api key may also be passed as the `api_key` query parameter.
+pub async fn watch_cms_content_api_handler(
+    state: State<Arc<AvoRedState>>,
+    Query(query_param): Query<WatchCmsContentQuery>,
+    headers: HeaderMap,
+) -> Result<Sse<impl Stream<Item = std::result::Result<Event, Infallible>>>> {
+    println!("->> {:<12} - watch_cms_content_api_handler", "HANDLER");
+
+    let mut api_key = headers.get_bearer_token();
+    if api_key.is_empty() && state.config.cms_watch_query_api_key {
+        api_key = query_param.api_key.unwrap_or_default();
+    }
+
+    let cms_api_key_model = state
+        .cms_api_key_service
+        .authorize(&state.db, &api_key, "watch_content", None)
+        .await?;
+
+    let collections = query_param
+        .collections
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|collection| !collection.is_empty())
+        .map(str::to_string)
+        .collect::<Vec<String>>();
+    let collections = cms_api_key_model.resolve_collections(collections)?;
+
+    let watch_state = state.0.clone();
+    let watched_collections = collections.clone();
+    let still_authorized = move || {
+        let state = watch_state.clone();
+        let api_key = api_key.clone();
+        let watched_collections = watched_collections.clone();
+        async move {
+            state
+                .cms_api_key_service
+                .can_watch(&state.db, &api_key, &watched_collections)
+                .await
+        }
+    };
+
+    let stream = state
+        .cms_service
+        .watch_content_while_authorized(collections, still_authorized)
+        .filter_map(|content_event_model| {
+            let event_type: String = content_event_model.event_type.clone().try_into().ok()?;
+            let event = Event::default()
+                .event(event_type)
+                .json_data(&content_event_model)
+                .ok()?;
+
+            Some(Ok(event))
+        });
+
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
+
+#[derive(Deserialize, Debug)]
+pub struct WatchCmsContentQuery {
+    pub collections: Option<String>,
+    pub api_key: Option<String>,
+}
@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use crate::error::Error;
+use crate::services::feed_service::FeedFormat;
+use crate::{avored_state::AvoRedState, error::Result};
+use axum::extract::Path;
+use axum::http::header::CONTENT_TYPE;
+use axum::{extract::State, response::IntoResponse};
+
+/// `/feeds/{collection}.xml` serves RSS 2.0 and `/feeds/{collection}.atom` serves Atom.
+pub async fn collection_feed_handler(
+    state: State<Arc<AvoRedState>>,
+    Path(file_name): Path<String>,
+) -> Result<impl IntoResponse> {
+    println!("->> {:<12} - collection_feed_handler", "HANDLER");
+
+    let (collection, feed_format, content_type) = match file_name.rsplit_once('.') {
+        Some((collection, "xml")) => (collection, FeedFormat::Rss, "application/rss+xml; charset=utf-8"),
+        Some((collection, "atom")) => (collection, FeedFormat::Atom, "application/atom+xml; charset=utf-8"),
+        _ => return Err(Error::NotFound(file_name.clone())),
+    };
+
+    let xml = state
+        .feed_service
+        .collection_feed(&state.db, collection, feed_format)
+        .await?;
+
+    Ok(([(CONTENT_TYPE, content_type)], xml))
+}
@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use crate::extensions::bearer_token::BearerToken;
+use crate::{avored_state::AvoRedState, error::Result};
+use axum::http::HeaderMap;
+use axum::{extract::State, response::IntoResponse, Json};
+
+pub async fn graphql_api_handler(
+    state: State<Arc<AvoRedState>>,
+    headers: HeaderMap,
+    Json(request): Json<async_graphql::Request>,
+) -> Result<impl IntoResponse> {
+    println!("->> {:<12} - graphql_api_handler", "HANDLER");
+
+    let cms_api_key_model = state
+        .cms_api_key_service
+        .authorize(&state.db, &headers.get_bearer_token(), "get_cms_content", None)
+        .await?;
+
+    let schema = state
+        .graphql_service
+        .schema(&state.db, &cms_api_key_model)
+        .await?;
+    let response = schema
+        .execute(request.data(state.0.clone()).data(cms_api_key_model))
+        .await;
+
+    Ok(Json(response))
+}
@@ -1 +1,6 @@
-pub mod asset;
\ No newline at end of file
+pub mod asset;
+pub mod cms;
+pub mod feed;
+pub mod graphql;
+pub mod page;
+pub mod sitemap;
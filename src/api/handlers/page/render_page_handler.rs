@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use crate::error::Error;
+use crate::{avored_state::AvoRedState, error::Result};
+use axum::extract::Path;
+use axum::http::header::ETAG;
+use axum::http::StatusCode;
+use axum::response::Html;
+use axum::{extract::State, response::IntoResponse};
+
+pub async fn render_page_handler(
+    state: State<Arc<AvoRedState>>,
+    Path((collection, identifier)): Path<(String, String)>,
+) -> Result<impl IntoResponse> {
+    println!("->> {:<12} - render_page_handler", "HANDLER");
+
+    match state
+        .page_service
+        .render_page(&state.db, &state.theme, &collection, &identifier)
+        .await
+    {
+        Ok(rendered_page) => {
+            Ok(([(ETAG, rendered_page.etag())], Html(rendered_page.html)).into_response())
+        }
+        Err(Error::NotFound(_)) => {
+            let not_found_html = state
+                .page_service
+                .render_not_found(&state.db, &state.theme)
+                .await?
+                .unwrap_or_else(|| String::from("<h1>Page not found</h1>"));
+
+            Ok((StatusCode::NOT_FOUND, Html(not_found_html)).into_response())
+        }
+        Err(e) => Err(e),
+    }
+}
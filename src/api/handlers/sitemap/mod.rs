@@ -0,0 +1,3 @@
+pub mod robots_txt_handler;
+pub mod sitemap_handler;
+pub mod sitemap_page_handler;
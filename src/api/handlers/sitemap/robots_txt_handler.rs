@@ -0,0 +1,13 @@
+use std::sync::Arc;
+
+use crate::{avored_state::AvoRedState, error::Result};
+use axum::http::header::CONTENT_TYPE;
+use axum::{extract::State, response::IntoResponse};
+
+pub async fn robots_txt_handler(state: State<Arc<AvoRedState>>) -> Result<impl IntoResponse> {
+    println!("->> {:<12} - robots_txt_handler", "HANDLER");
+
+    let robots_txt = state.sitemap_service.robots_txt(&state.db).await?;
+
+    Ok(([(CONTENT_TYPE, "text/plain; charset=utf-8")], robots_txt))
+}
@@ -0,0 +1,13 @@
+use std::sync::Arc;
+
+use crate::{avored_state::AvoRedState, error::Result};
+use axum::http::header::CONTENT_TYPE;
+use axum::{extract::State, response::IntoResponse};
+
+pub async fn sitemap_handler(state: State<Arc<AvoRedState>>) -> Result<impl IntoResponse> {
+    println!("->> {:<12} - sitemap_handler", "HANDLER");
+
+    let xml = state.sitemap_service.sitemap_xml(&state.db).await?;
+
+    Ok(([(CONTENT_TYPE, "application/xml; charset=utf-8")], xml))
+}
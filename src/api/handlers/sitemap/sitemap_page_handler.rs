@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use crate::error::Error;
+use crate::{avored_state::AvoRedState, error::Result};
+use axum::extract::Path;
+use axum::http::header::CONTENT_TYPE;
+use axum::{extract::State, response::IntoResponse};
+
+/// Serves `/sitemaps/sitemap-{n}.xml`, the files listed by the sitemap index.
+pub async fn sitemap_page_handler(
+    state: State<Arc<AvoRedState>>,
+    Path(file_name): Path<String>,
+) -> Result<impl IntoResponse> {
+    println!("->> {:<12} - sitemap_page_handler", "HANDLER");
+
+    let page = file_name
+        .strip_prefix("sitemap-")
+        .and_then(|name| name.strip_suffix(".xml"))
+        .and_then(|page| page.parse::<usize>().ok())
+        .ok_or_else(|| Error::NotFound(file_name.clone()))?;
+
+    let xml = state.sitemap_service.sitemap_page_xml(&state.db, page).await?;
+
+    Ok(([(CONTENT_TYPE, "application/xml; charset=utf-8")], xml))
+}
@@ -8,6 +8,7 @@ use crate::api::proto::misc::{DeleteDemoDataRequest, DeleteDemoDataResponse, Hea
 use crate::api::proto::misc::misc_server::Misc;
 use crate::avored_state::AvoRedState;
 use crate::models::role_model::CreatableRole;
+use crate::models::validation_error::ErrorResponse;
 
 pub struct MiscApi {
     pub state: Arc<AvoRedState>,
@@ -17,16 +18,38 @@ pub struct MiscApi {
 impl Misc for MiscApi {
     async fn setup(&self, request: Request<SetupRequest>) -> Result<Response<SetupResponse>, Status> {
         let req = request.into_inner();
+        let (email, password) = (req.email.clone(), req.password.clone());
+
+        let password_errors = self
+            .state
+            .password_policy_service
+            .validate(&self.state.db, &password, &email)
+            .await?;
+        if !password_errors.is_empty() {
+            let error_response = ErrorResponse {
+                status: false,
+                errors: password_errors,
+            };
+            let error_string = serde_json::to_string(&error_response)
+                .map_err(|e| Status::internal(e.to_string()))?;
+            return Err(Status::invalid_argument(error_string));
+        }
         
         match self.
             state.
             misc_service.
             setup(
                 req,
-                &self.state.config.password_salt,
+                &self.state.config.password_hash,
                 &self.state.db
             ).await {
-                Ok(reply) => Ok(Response::new(reply)),
+                Ok(reply) => {
+                    self.state
+                        .password_policy_service
+                        .remember_password(&self.state.db, &email, &password)
+                        .await;
+                    Ok(Response::new(reply))
+                },
                 Err(e) => Err(Status::internal(e.to_string()))
             }
 
@@ -380,7 +403,7 @@ impl Misc for MiscApi {
             .store(
                 creatable_admin_user, 
                 "admin@admin.com".to_string(), 
-                &self.state.config.password_salt, 
+                &self.state.config.password_hash, 
                 &self.state.db
             )
             .await?;
@@ -10,6 +10,10 @@ pub mod content_api;
 pub mod setting_api;
 
 pub mod cms_api;
+pub mod cms_api_key_api;
+pub mod webhook_api;
+pub mod form_api;
+pub mod email_api;
 pub mod general_api;
 
 pub mod asset_api;
@@ -244,6 +244,122 @@ pub struct DeleteRoleResponse {
 }
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct RoleOptionRequest {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AdminUserSessionModel {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub admin_user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub user_agent: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub ip_address: ::prost::alloc::string::String,
+    #[prost(bool, tag = "5")]
+    pub is_current: bool,
+    #[prost(message, optional, tag = "6")]
+    pub last_activity_at: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "7")]
+    pub expires_at: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "8")]
+    pub created_at: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// an empty admin_user_id lists the sessions of the logged in admin user
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AdminUserSessionAllRequest {
+    #[prost(string, tag = "1")]
+    pub admin_user_id: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AdminUserSessionAllResponse {
+    #[prost(bool, tag = "1")]
+    pub status: bool,
+    #[prost(message, repeated, tag = "2")]
+    pub data: ::prost::alloc::vec::Vec<AdminUserSessionModel>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TerminateAdminUserSessionRequest {
+    #[prost(string, tag = "1")]
+    pub session_id: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct TerminateAdminUserSessionResponse {
+    #[prost(bool, tag = "1")]
+    pub status: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TerminateAllAdminUserSessionsRequest {
+    #[prost(string, tag = "1")]
+    pub admin_user_id: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct TerminateAllAdminUserSessionsResponse {
+    #[prost(bool, tag = "1")]
+    pub status: bool,
+    #[prost(int64, tag = "2")]
+    pub terminated: i64,
+}
+/// turns two factor authentication off for the logged in admin user
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DisableTwoFactorRequest {
+    #[prost(string, tag = "1")]
+    pub code: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DisableTwoFactorResponse {
+    #[prost(bool, tag = "1")]
+    pub status: bool,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct RequireTwoFactorRequest {
+    #[prost(bool, tag = "1")]
+    pub required: bool,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct RequireTwoFactorResponse {
+    #[prost(bool, tag = "1")]
+    pub status: bool,
+}
+/// lifts the login lockout after too many failed attempts
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnlockAdminUserRequest {
+    #[prost(string, tag = "1")]
+    pub admin_user_id: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct UnlockAdminUserResponse {
+    #[prost(bool, tag = "1")]
+    pub status: bool,
+    #[prost(bool, tag = "2")]
+    pub unlocked: bool,
+}
+/// action is LoginLockout or LoginUnlock, email is the account it is about
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuditLogModel {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub action: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub email: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub ip_address: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub detail: ::prost::alloc::string::String,
+    #[prost(string, tag = "6")]
+    pub created_by: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "7")]
+    pub created_at: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// the most recent entries first
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct AuditLogAllRequest {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuditLogAllResponse {
+    #[prost(bool, tag = "1")]
+    pub status: bool,
+    #[prost(message, repeated, tag = "2")]
+    pub data: ::prost::alloc::vec::Vec<AuditLogModel>,
+}
 /// Generated client implementations.
 pub mod admin_user_client {
     #![allow(
@@ -599,6 +715,181 @@ pub mod admin_user_client {
                 .insert(GrpcMethod::new("admin_user.AdminUser", "DeleteRole"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn admin_user_session_all(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AdminUserSessionAllRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AdminUserSessionAllResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/admin_user.AdminUser/AdminUserSessionAll",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("admin_user.AdminUser", "AdminUserSessionAll"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn terminate_admin_user_session(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TerminateAdminUserSessionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TerminateAdminUserSessionResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/admin_user.AdminUser/TerminateAdminUserSession",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("admin_user.AdminUser", "TerminateAdminUserSession"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn terminate_all_admin_user_sessions(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TerminateAllAdminUserSessionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TerminateAllAdminUserSessionsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/admin_user.AdminUser/TerminateAllAdminUserSessions",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "admin_user.AdminUser",
+                        "TerminateAllAdminUserSessions",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn disable_two_factor(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DisableTwoFactorRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DisableTwoFactorResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/admin_user.AdminUser/DisableTwoFactor",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("admin_user.AdminUser", "DisableTwoFactor"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn require_two_factor(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RequireTwoFactorRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RequireTwoFactorResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/admin_user.AdminUser/RequireTwoFactor",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("admin_user.AdminUser", "RequireTwoFactor"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn unlock_admin_user(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UnlockAdminUserRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UnlockAdminUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/admin_user.AdminUser/UnlockAdminUser",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("admin_user.AdminUser", "UnlockAdminUser"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn audit_log_all(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AuditLogAllRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AuditLogAllResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/admin_user.AdminUser/AuditLogAll",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("admin_user.AdminUser", "AuditLogAll"));
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -688,6 +979,55 @@ pub mod admin_user_server {
             tonic::Response<super::DeleteRoleResponse>,
             tonic::Status,
         >;
+        async fn admin_user_session_all(
+            &self,
+            request: tonic::Request<super::AdminUserSessionAllRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AdminUserSessionAllResponse>,
+            tonic::Status,
+        >;
+        async fn terminate_admin_user_session(
+            &self,
+            request: tonic::Request<super::TerminateAdminUserSessionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TerminateAdminUserSessionResponse>,
+            tonic::Status,
+        >;
+        async fn terminate_all_admin_user_sessions(
+            &self,
+            request: tonic::Request<super::TerminateAllAdminUserSessionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TerminateAllAdminUserSessionsResponse>,
+            tonic::Status,
+        >;
+        async fn disable_two_factor(
+            &self,
+            request: tonic::Request<super::DisableTwoFactorRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DisableTwoFactorResponse>,
+            tonic::Status,
+        >;
+        async fn require_two_factor(
+            &self,
+            request: tonic::Request<super::RequireTwoFactorRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RequireTwoFactorResponse>,
+            tonic::Status,
+        >;
+        async fn unlock_admin_user(
+            &self,
+            request: tonic::Request<super::UnlockAdminUserRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UnlockAdminUserResponse>,
+            tonic::Status,
+        >;
+        async fn audit_log_all(
+            &self,
+            request: tonic::Request<super::AuditLogAllRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AuditLogAllResponse>,
+            tonic::Status,
+        >;
     }
     #[derive(Debug)]
     pub struct AdminUserServer<T> {
@@ -1258,6 +1598,336 @@ pub mod admin_user_server {
                     };
                     Box::pin(fut)
                 }
+                "/admin_user.AdminUser/AdminUserSessionAll" => {
+                    #[allow(non_camel_case_types)]
+                    struct AdminUserSessionAllSvc<T: AdminUser>(pub Arc<T>);
+                    impl<
+                        T: AdminUser,
+                    > tonic::server::UnaryService<super::AdminUserSessionAllRequest>
+                    for AdminUserSessionAllSvc<T> {
+                        type Response = super::AdminUserSessionAllResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AdminUserSessionAllRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AdminUser>::admin_user_session_all(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AdminUserSessionAllSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/admin_user.AdminUser/TerminateAdminUserSession" => {
+                    #[allow(non_camel_case_types)]
+                    struct TerminateAdminUserSessionSvc<T: AdminUser>(pub Arc<T>);
+                    impl<
+                        T: AdminUser,
+                    > tonic::server::UnaryService<
+                        super::TerminateAdminUserSessionRequest,
+                    > for TerminateAdminUserSessionSvc<T> {
+                        type Response = super::TerminateAdminUserSessionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::TerminateAdminUserSessionRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AdminUser>::terminate_admin_user_session(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = TerminateAdminUserSessionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/admin_user.AdminUser/TerminateAllAdminUserSessions" => {
+                    #[allow(non_camel_case_types)]
+                    struct TerminateAllAdminUserSessionsSvc<T: AdminUser>(pub Arc<T>);
+                    impl<
+                        T: AdminUser,
+                    > tonic::server::UnaryService<
+                        super::TerminateAllAdminUserSessionsRequest,
+                    > for TerminateAllAdminUserSessionsSvc<T> {
+                        type Response = super::TerminateAllAdminUserSessionsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::TerminateAllAdminUserSessionsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AdminUser>::terminate_all_admin_user_sessions(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = TerminateAllAdminUserSessionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/admin_user.AdminUser/DisableTwoFactor" => {
+                    #[allow(non_camel_case_types)]
+                    struct DisableTwoFactorSvc<T: AdminUser>(pub Arc<T>);
+                    impl<
+                        T: AdminUser,
+                    > tonic::server::UnaryService<super::DisableTwoFactorRequest>
+                    for DisableTwoFactorSvc<T> {
+                        type Response = super::DisableTwoFactorResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DisableTwoFactorRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AdminUser>::disable_two_factor(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DisableTwoFactorSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/admin_user.AdminUser/RequireTwoFactor" => {
+                    #[allow(non_camel_case_types)]
+                    struct RequireTwoFactorSvc<T: AdminUser>(pub Arc<T>);
+                    impl<
+                        T: AdminUser,
+                    > tonic::server::UnaryService<super::RequireTwoFactorRequest>
+                    for RequireTwoFactorSvc<T> {
+                        type Response = super::RequireTwoFactorResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequireTwoFactorRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AdminUser>::require_two_factor(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RequireTwoFactorSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/admin_user.AdminUser/UnlockAdminUser" => {
+                    #[allow(non_camel_case_types)]
+                    struct UnlockAdminUserSvc<T: AdminUser>(pub Arc<T>);
+                    impl<
+                        T: AdminUser,
+                    > tonic::server::UnaryService<super::UnlockAdminUserRequest>
+                    for UnlockAdminUserSvc<T> {
+                        type Response = super::UnlockAdminUserResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UnlockAdminUserRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AdminUser>::unlock_admin_user(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = UnlockAdminUserSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/admin_user.AdminUser/AuditLogAll" => {
+                    #[allow(non_camel_case_types)]
+                    struct AuditLogAllSvc<T: AdminUser>(pub Arc<T>);
+                    impl<
+                        T: AdminUser,
+                    > tonic::server::UnaryService<super::AuditLogAllRequest>
+                    for AuditLogAllSvc<T> {
+                        type Response = super::AuditLogAllResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AuditLogAllRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AdminUser>::audit_log_all(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AuditLogAllSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(
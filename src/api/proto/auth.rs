@@ -7,12 +7,91 @@ pub struct LoginRequest {
     #[prost(string, tag = "2")]
     pub password: ::prost::alloc::string::String,
 }
+/// when two_factor_required or two_factor_setup_required is set, data is empty and
+/// two_factor_token has to be sent to VerifyTwoFactor or EnrollTwoFactor first
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LoginResponse {
     #[prost(bool, tag = "1")]
     pub status: bool,
     #[prost(string, tag = "2")]
     pub data: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub refresh_token: ::prost::alloc::string::String,
+    #[prost(int64, tag = "4")]
+    pub expires_in: i64,
+    #[prost(bool, tag = "5")]
+    pub two_factor_required: bool,
+    #[prost(string, tag = "6")]
+    pub two_factor_token: ::prost::alloc::string::String,
+    #[prost(bool, tag = "7")]
+    pub two_factor_setup_required: bool,
+}
+/// code is a code of the authenticator app or one of the recovery codes
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VerifyTwoFactorRequest {
+    #[prost(string, tag = "1")]
+    pub two_factor_token: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub code: ::prost::alloc::string::String,
+}
+/// authorization metadata holds an access token or the two_factor_token of a login
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct EnrollTwoFactorRequest {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EnrollTwoFactorResponse {
+    #[prost(bool, tag = "1")]
+    pub status: bool,
+    #[prost(string, tag = "2")]
+    pub provisioning_uri: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub secret: ::prost::alloc::string::String,
+}
+/// recovery_codes are only returned here. When enrolling during login the
+/// response also carries the tokens of the new session.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ConfirmTwoFactorRequest {
+    #[prost(string, tag = "1")]
+    pub code: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ConfirmTwoFactorResponse {
+    #[prost(bool, tag = "1")]
+    pub status: bool,
+    #[prost(string, repeated, tag = "2")]
+    pub recovery_codes: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, tag = "3")]
+    pub data: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub refresh_token: ::prost::alloc::string::String,
+    #[prost(int64, tag = "5")]
+    pub expires_in: i64,
+}
+/// data is the new access token, the refresh token sent in can not be used again
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RefreshTokenRequest {
+    #[prost(string, tag = "1")]
+    pub refresh_token: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RefreshTokenResponse {
+    #[prost(bool, tag = "1")]
+    pub status: bool,
+    #[prost(string, tag = "2")]
+    pub data: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub refresh_token: ::prost::alloc::string::String,
+    #[prost(int64, tag = "4")]
+    pub expires_in: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LogoutRequest {
+    #[prost(string, tag = "1")]
+    pub refresh_token: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct LogoutResponse {
+    #[prost(bool, tag = "1")]
+    pub status: bool,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ForgotPasswordRequest {
@@ -149,6 +228,112 @@ pub mod auth_client {
             req.extensions_mut().insert(GrpcMethod::new("auth.Auth", "Login"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn refresh_token(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RefreshTokenRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RefreshTokenResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/auth.Auth/RefreshToken");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("auth.Auth", "RefreshToken"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn logout(
+            &mut self,
+            request: impl tonic::IntoRequest<super::LogoutRequest>,
+        ) -> std::result::Result<tonic::Response<super::LogoutResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/auth.Auth/Logout");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("auth.Auth", "Logout"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn verify_two_factor(
+            &mut self,
+            request: impl tonic::IntoRequest<super::VerifyTwoFactorRequest>,
+        ) -> std::result::Result<tonic::Response<super::LoginResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/auth.Auth/VerifyTwoFactor",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("auth.Auth", "VerifyTwoFactor"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn enroll_two_factor(
+            &mut self,
+            request: impl tonic::IntoRequest<super::EnrollTwoFactorRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EnrollTwoFactorResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/auth.Auth/EnrollTwoFactor",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("auth.Auth", "EnrollTwoFactor"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn confirm_two_factor(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ConfirmTwoFactorRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ConfirmTwoFactorResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/auth.Auth/ConfirmTwoFactor",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("auth.Auth", "ConfirmTwoFactor"));
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn forgot_password(
             &mut self,
             request: impl tonic::IntoRequest<super::ForgotPasswordRequest>,
@@ -210,6 +395,35 @@ pub mod auth_server {
             &self,
             request: tonic::Request<super::LoginRequest>,
         ) -> std::result::Result<tonic::Response<super::LoginResponse>, tonic::Status>;
+        async fn refresh_token(
+            &self,
+            request: tonic::Request<super::RefreshTokenRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RefreshTokenResponse>,
+            tonic::Status,
+        >;
+        async fn logout(
+            &self,
+            request: tonic::Request<super::LogoutRequest>,
+        ) -> std::result::Result<tonic::Response<super::LogoutResponse>, tonic::Status>;
+        async fn verify_two_factor(
+            &self,
+            request: tonic::Request<super::VerifyTwoFactorRequest>,
+        ) -> std::result::Result<tonic::Response<super::LoginResponse>, tonic::Status>;
+        async fn enroll_two_factor(
+            &self,
+            request: tonic::Request<super::EnrollTwoFactorRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EnrollTwoFactorResponse>,
+            tonic::Status,
+        >;
+        async fn confirm_two_factor(
+            &self,
+            request: tonic::Request<super::ConfirmTwoFactorRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ConfirmTwoFactorResponse>,
+            tonic::Status,
+        >;
         async fn forgot_password(
             &self,
             request: tonic::Request<super::ForgotPasswordRequest>,
@@ -344,6 +558,227 @@ pub mod auth_server {
                     };
                     Box::pin(fut)
                 }
+                "/auth.Auth/RefreshToken" => {
+                    #[allow(non_camel_case_types)]
+                    struct RefreshTokenSvc<T: Auth>(pub Arc<T>);
+                    impl<T: Auth> tonic::server::UnaryService<super::RefreshTokenRequest>
+                    for RefreshTokenSvc<T> {
+                        type Response = super::RefreshTokenResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RefreshTokenRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Auth>::refresh_token(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RefreshTokenSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/auth.Auth/Logout" => {
+                    #[allow(non_camel_case_types)]
+                    struct LogoutSvc<T: Auth>(pub Arc<T>);
+                    impl<T: Auth> tonic::server::UnaryService<super::LogoutRequest>
+                    for LogoutSvc<T> {
+                        type Response = super::LogoutResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::LogoutRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Auth>::logout(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = LogoutSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/auth.Auth/VerifyTwoFactor" => {
+                    #[allow(non_camel_case_types)]
+                    struct VerifyTwoFactorSvc<T: Auth>(pub Arc<T>);
+                    impl<
+                        T: Auth,
+                    > tonic::server::UnaryService<super::VerifyTwoFactorRequest>
+                    for VerifyTwoFactorSvc<T> {
+                        type Response = super::LoginResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::VerifyTwoFactorRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Auth>::verify_two_factor(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = VerifyTwoFactorSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/auth.Auth/EnrollTwoFactor" => {
+                    #[allow(non_camel_case_types)]
+                    struct EnrollTwoFactorSvc<T: Auth>(pub Arc<T>);
+                    impl<
+                        T: Auth,
+                    > tonic::server::UnaryService<super::EnrollTwoFactorRequest>
+                    for EnrollTwoFactorSvc<T> {
+                        type Response = super::EnrollTwoFactorResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::EnrollTwoFactorRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Auth>::enroll_two_factor(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = EnrollTwoFactorSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/auth.Auth/ConfirmTwoFactor" => {
+                    #[allow(non_camel_case_types)]
+                    struct ConfirmTwoFactorSvc<T: Auth>(pub Arc<T>);
+                    impl<
+                        T: Auth,
+                    > tonic::server::UnaryService<super::ConfirmTwoFactorRequest>
+                    for ConfirmTwoFactorSvc<T> {
+                        type Response = super::ConfirmTwoFactorResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ConfirmTwoFactorRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Auth>::confirm_two_factor(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ConfirmTwoFactorSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/auth.Auth/ForgotPassword" => {
                     #[allow(non_camel_case_types)]
                     struct ForgotPasswordSvc<T: Auth>(pub Arc<T>);
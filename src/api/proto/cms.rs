@@ -5,6 +5,11 @@ pub struct GetCmsContentRequest {
     pub content_identifier: ::prost::alloc::string::String,
     #[prost(string, tag = "2")]
     pub content_type: ::prost::alloc::string::String,
+    /// paths are content field identifiers, an empty mask returns every field
+    #[prost(message, optional, tag = "4")]
+    pub field_mask: ::core::option::Option<::prost_types::FieldMask>,
+    #[prost(enumeration = "CmsContentShape", tag = "5")]
+    pub shape: i32,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetCmsContentResponse {
@@ -12,7 +17,13 @@ pub struct GetCmsContentResponse {
     pub status: bool,
     #[prost(message, optional, tag = "2")]
     pub data: ::core::option::Option<super::content::ContentModel>,
+    #[prost(map = "string, message", tag = "3")]
+    pub fields: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost_types::Value,
+    >,
 }
+/// Kept for existing sites, the message is submitted to the `contact-us` form.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SentContactFormRequest {
     #[prost(string, tag = "1")]
@@ -25,12 +36,106 @@ pub struct SentContactFormRequest {
     pub phone: ::prost::alloc::string::String,
     #[prost(string, tag = "5")]
     pub message: ::prost::alloc::string::String,
+    /// token of the challenge widget, required when a challenge check is configured
+    #[prost(string, tag = "6")]
+    pub challenge_token: ::prost::alloc::string::String,
+    /// hidden field for bots, real visitors leave it empty
+    #[prost(string, tag = "7")]
+    pub honeypot: ::prost::alloc::string::String,
 }
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct SentContactFormResponse {
     #[prost(bool, tag = "1")]
     pub status: bool,
 }
+/// Values are keyed by form field identifier, unknown keys are ignored.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubmitFormRequest {
+    #[prost(string, tag = "1")]
+    pub form_identifier: ::prost::alloc::string::String,
+    #[prost(map = "string, string", tag = "2")]
+    pub data: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
+    /// token of the challenge widget, required when a challenge check is configured
+    #[prost(string, tag = "3")]
+    pub challenge_token: ::prost::alloc::string::String,
+    /// uploads for the file fields of the form
+    #[prost(message, repeated, tag = "4")]
+    pub files: ::prost::alloc::vec::Vec<SubmitFormFile>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubmitFormFile {
+    /// identifier of the file field
+    #[prost(string, tag = "1")]
+    pub field_identifier: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub file_name: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub content_type: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "4")]
+    pub content: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubmitFormResponse {
+    #[prost(bool, tag = "1")]
+    pub status: bool,
+    /// success message of the form
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WatchContentRequest {
+    /// collections to follow, empty follows every collection the api key can read
+    #[prost(string, repeated, tag = "1")]
+    pub collections: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ContentEventModel {
+    /// created, updated, deleted or published
+    #[prost(string, tag = "1")]
+    pub event_type: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub collection: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub content_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub identifier: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "5")]
+    pub occurred_at: ::core::option::Option<::prost_types::Timestamp>,
+    /// the content after the change, not set for deleted events
+    #[prost(message, optional, tag = "6")]
+    pub data: ::core::option::Option<super::content::ContentModel>,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum CmsContentShape {
+    /// every content field with its type metadata and option lists
+    Full = 0,
+    /// content fields are returned in `fields` as identifier => typed value
+    Compact = 1,
+}
+impl CmsContentShape {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Full => "CMS_CONTENT_SHAPE_FULL",
+            Self::Compact => "CMS_CONTENT_SHAPE_COMPACT",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "CMS_CONTENT_SHAPE_FULL" => Some(Self::Full),
+            "CMS_CONTENT_SHAPE_COMPACT" => Some(Self::Compact),
+            _ => None,
+        }
+    }
+}
 /// Generated client implementations.
 pub mod cms_client {
     #![allow(
@@ -164,6 +269,48 @@ pub mod cms_client {
             req.extensions_mut().insert(GrpcMethod::new("cms.Cms", "SentContactForm"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn submit_form(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SubmitFormRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SubmitFormResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/cms.Cms/SubmitForm");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("cms.Cms", "SubmitForm"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn watch_content(
+            &mut self,
+            request: impl tonic::IntoRequest<super::WatchContentRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ContentEventModel>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/cms.Cms/WatchContent");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("cms.Cms", "WatchContent"));
+            self.inner.server_streaming(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -193,6 +340,26 @@ pub mod cms_server {
             tonic::Response<super::SentContactFormResponse>,
             tonic::Status,
         >;
+        async fn submit_form(
+            &self,
+            request: tonic::Request<super::SubmitFormRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SubmitFormResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the WatchContent method.
+        type WatchContentStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::ContentEventModel, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        async fn watch_content(
+            &self,
+            request: tonic::Request<super::WatchContentRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::WatchContentStream>,
+            tonic::Status,
+        >;
     }
     #[derive(Debug)]
     pub struct CmsServer<T> {
@@ -358,6 +525,95 @@ pub mod cms_server {
                     };
                     Box::pin(fut)
                 }
+                "/cms.Cms/SubmitForm" => {
+                    #[allow(non_camel_case_types)]
+                    struct SubmitFormSvc<T: Cms>(pub Arc<T>);
+                    impl<T: Cms> tonic::server::UnaryService<super::SubmitFormRequest>
+                    for SubmitFormSvc<T> {
+                        type Response = super::SubmitFormResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SubmitFormRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Cms>::submit_form(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SubmitFormSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cms.Cms/WatchContent" => {
+                    #[allow(non_camel_case_types)]
+                    struct WatchContentSvc<T: Cms>(pub Arc<T>);
+                    impl<
+                        T: Cms,
+                    > tonic::server::ServerStreamingService<super::WatchContentRequest>
+                    for WatchContentSvc<T> {
+                        type Response = super::ContentEventModel;
+                        type ResponseStream = T::WatchContentStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::WatchContentRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Cms>::watch_content(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = WatchContentSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(
@@ -1,4 +1,17 @@
 // This file is @generated by prost-build.
+/// identifiers of the content fields used when the collection is published as a feed,
+/// an empty value falls back to the content name, nothing, nothing and created_at.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CollectionFeedMapping {
+    #[prost(string, tag = "1")]
+    pub title_field: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub summary_field: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub content_field: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub date_field: ::prost::alloc::string::String,
+}
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CollectionModel {
     #[prost(string, tag = "1")]
@@ -15,6 +28,10 @@ pub struct CollectionModel {
     pub created_by: ::prost::alloc::string::String,
     #[prost(string, tag = "7")]
     pub updated_by: ::prost::alloc::string::String,
+    #[prost(bool, tag = "8")]
+    pub is_routable: bool,
+    #[prost(message, optional, tag = "9")]
+    pub feed_mapping: ::core::option::Option<CollectionFeedMapping>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetCollectionRequest {
@@ -34,6 +51,10 @@ pub struct StoreCollectionRequest {
     pub name: ::prost::alloc::string::String,
     #[prost(string, tag = "2")]
     pub identifier: ::prost::alloc::string::String,
+    #[prost(bool, tag = "3")]
+    pub is_routable: bool,
+    #[prost(message, optional, tag = "4")]
+    pub feed_mapping: ::core::option::Option<CollectionFeedMapping>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct StoreCollectionResponse {
@@ -50,6 +71,10 @@ pub struct UpdateCollectionRequest {
     pub name: ::prost::alloc::string::String,
     #[prost(string, tag = "3")]
     pub identifier: ::prost::alloc::string::String,
+    #[prost(bool, tag = "4")]
+    pub is_routable: bool,
+    #[prost(message, optional, tag = "5")]
+    pub feed_mapping: ::core::option::Option<CollectionFeedMapping>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateCollectionResponse {
@@ -134,6 +159,8 @@ pub struct ContentModel {
     pub updated_by: ::prost::alloc::string::String,
     #[prost(message, repeated, tag = "8")]
     pub content_fields: ::prost::alloc::vec::Vec<ContentFieldModel>,
+    #[prost(string, tag = "9")]
+    pub status: ::prost::alloc::string::String,
 }
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CollectionAllRequest {}
@@ -201,6 +228,8 @@ pub struct StoreContentRequest {
     pub content_type: ::prost::alloc::string::String,
     #[prost(message, repeated, tag = "4")]
     pub content_fields: ::prost::alloc::vec::Vec<StoreContentFieldModel>,
+    #[prost(string, optional, tag = "5")]
+    pub status: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct StoreContentResponse {
@@ -248,6 +277,8 @@ pub struct UpdateContentRequest {
     pub content_type: ::prost::alloc::string::String,
     #[prost(message, repeated, tag = "4")]
     pub content_fields: ::prost::alloc::vec::Vec<UpdateContentFieldModel>,
+    #[prost(string, optional, tag = "5")]
+    pub status: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateContentResponse {
@@ -284,6 +315,91 @@ pub struct DeleteContentResponse {
     #[prost(bool, tag = "1")]
     pub status: bool,
 }
+/// Renders every published page of the routable collections through the theme.
+/// The export runs in the background, poll GetStaticSiteExport with the returned id.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ExportStaticSiteRequest {
+    #[prost(enumeration = "StaticSiteFormat", tag = "1")]
+    pub format: i32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExportStaticSiteResponse {
+    #[prost(bool, tag = "1")]
+    pub status: bool,
+    /// export folder or zip file on the server, once the export succeeded
+    #[prost(string, tag = "2")]
+    pub path: ::prost::alloc::string::String,
+    #[prost(int64, tag = "3")]
+    pub pages: i64,
+    #[prost(int64, tag = "4")]
+    pub assets: i64,
+    #[prost(string, tag = "5")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(enumeration = "StaticSiteExportStatus", tag = "6")]
+    pub export_status: i32,
+    #[prost(string, tag = "7")]
+    pub error: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetStaticSiteExportRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum StaticSiteFormat {
+    Directory = 0,
+    Zip = 1,
+}
+impl StaticSiteFormat {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Directory => "STATIC_SITE_FORMAT_DIRECTORY",
+            Self::Zip => "STATIC_SITE_FORMAT_ZIP",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "STATIC_SITE_FORMAT_DIRECTORY" => Some(Self::Directory),
+            "STATIC_SITE_FORMAT_ZIP" => Some(Self::Zip),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum StaticSiteExportStatus {
+    Running = 0,
+    Succeeded = 1,
+    Failed = 2,
+}
+impl StaticSiteExportStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Running => "STATIC_SITE_EXPORT_STATUS_RUNNING",
+            Self::Succeeded => "STATIC_SITE_EXPORT_STATUS_SUCCEEDED",
+            Self::Failed => "STATIC_SITE_EXPORT_STATUS_FAILED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "STATIC_SITE_EXPORT_STATUS_RUNNING" => Some(Self::Running),
+            "STATIC_SITE_EXPORT_STATUS_SUCCEEDED" => Some(Self::Succeeded),
+            "STATIC_SITE_EXPORT_STATUS_FAILED" => Some(Self::Failed),
+            _ => None,
+        }
+    }
+}
 /// Generated client implementations.
 pub mod content_client {
     #![allow(
@@ -615,6 +731,54 @@ pub mod content_client {
                 .insert(GrpcMethod::new("content.content", "DeleteContent"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn export_static_site(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ExportStaticSiteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ExportStaticSiteResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/content.content/ExportStaticSite",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("content.content", "ExportStaticSite"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_static_site_export(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetStaticSiteExportRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ExportStaticSiteResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/content.content/GetStaticSiteExport",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("content.content", "GetStaticSiteExport"));
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -700,6 +864,20 @@ pub mod content_server {
             tonic::Response<super::DeleteContentResponse>,
             tonic::Status,
         >;
+        async fn export_static_site(
+            &self,
+            request: tonic::Request<super::ExportStaticSiteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ExportStaticSiteResponse>,
+            tonic::Status,
+        >;
+        async fn get_static_site_export(
+            &self,
+            request: tonic::Request<super::GetStaticSiteExportRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ExportStaticSiteResponse>,
+            tonic::Status,
+        >;
     }
     #[derive(Debug)]
     pub struct ContentServer<T> {
@@ -1228,6 +1406,96 @@ pub mod content_server {
                     };
                     Box::pin(fut)
                 }
+                "/content.content/ExportStaticSite" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExportStaticSiteSvc<T: Content>(pub Arc<T>);
+                    impl<
+                        T: Content,
+                    > tonic::server::UnaryService<super::ExportStaticSiteRequest>
+                    for ExportStaticSiteSvc<T> {
+                        type Response = super::ExportStaticSiteResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ExportStaticSiteRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Content>::export_static_site(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ExportStaticSiteSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/content.content/GetStaticSiteExport" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetStaticSiteExportSvc<T: Content>(pub Arc<T>);
+                    impl<
+                        T: Content,
+                    > tonic::server::UnaryService<super::GetStaticSiteExportRequest>
+                    for GetStaticSiteExportSvc<T> {
+                        type Response = super::ExportStaticSiteResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetStaticSiteExportRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Content>::get_static_site_export(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetStaticSiteExportSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(
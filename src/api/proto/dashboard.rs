@@ -7,6 +7,42 @@ pub struct DashboardResponse {
     #[prost(bool, tag = "1")]
     pub status: bool,
 }
+/// Cms content cache stats API
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CmsCacheStatsRequest {}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CmsCacheStatsResponse {
+    #[prost(bool, tag = "1")]
+    pub status: bool,
+    #[prost(uint64, tag = "2")]
+    pub hits: u64,
+    #[prost(uint64, tag = "3")]
+    pub misses: u64,
+    #[prost(uint64, tag = "4")]
+    pub entries: u64,
+    #[prost(uint64, tag = "5")]
+    pub max_entries: u64,
+    #[prost(uint64, tag = "6")]
+    pub ttl_seconds: u64,
+}
+/// Public form spam protection counters since the server started
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct FormSpamStatsRequest {}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct FormSpamStatsResponse {
+    #[prost(bool, tag = "1")]
+    pub status: bool,
+    #[prost(uint64, tag = "2")]
+    pub accepted: u64,
+    #[prost(uint64, tag = "3")]
+    pub ip_rate_limited: u64,
+    #[prost(uint64, tag = "4")]
+    pub form_rate_limited: u64,
+    #[prost(uint64, tag = "5")]
+    pub honeypot: u64,
+    #[prost(uint64, tag = "6")]
+    pub verification_failed: u64,
+}
 /// Generated client implementations.
 pub mod dashboard_client {
     #![allow(
@@ -122,6 +158,54 @@ pub mod dashboard_client {
                 .insert(GrpcMethod::new("dashboard.Dashboard", "Dashboard"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn cms_cache_stats(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CmsCacheStatsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CmsCacheStatsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/dashboard.Dashboard/CmsCacheStats",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("dashboard.Dashboard", "CmsCacheStats"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn form_spam_stats(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FormSpamStatsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::FormSpamStatsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/dashboard.Dashboard/FormSpamStats",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("dashboard.Dashboard", "FormSpamStats"));
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -144,6 +228,20 @@ pub mod dashboard_server {
             tonic::Response<super::DashboardResponse>,
             tonic::Status,
         >;
+        async fn cms_cache_stats(
+            &self,
+            request: tonic::Request<super::CmsCacheStatsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CmsCacheStatsResponse>,
+            tonic::Status,
+        >;
+        async fn form_spam_stats(
+            &self,
+            request: tonic::Request<super::FormSpamStatsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::FormSpamStatsResponse>,
+            tonic::Status,
+        >;
     }
     #[derive(Debug)]
     pub struct DashboardServer<T> {
@@ -266,6 +364,96 @@ pub mod dashboard_server {
                     };
                     Box::pin(fut)
                 }
+                "/dashboard.Dashboard/CmsCacheStats" => {
+                    #[allow(non_camel_case_types)]
+                    struct CmsCacheStatsSvc<T: Dashboard>(pub Arc<T>);
+                    impl<
+                        T: Dashboard,
+                    > tonic::server::UnaryService<super::CmsCacheStatsRequest>
+                    for CmsCacheStatsSvc<T> {
+                        type Response = super::CmsCacheStatsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CmsCacheStatsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Dashboard>::cms_cache_stats(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CmsCacheStatsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/dashboard.Dashboard/FormSpamStats" => {
+                    #[allow(non_camel_case_types)]
+                    struct FormSpamStatsSvc<T: Dashboard>(pub Arc<T>);
+                    impl<
+                        T: Dashboard,
+                    > tonic::server::UnaryService<super::FormSpamStatsRequest>
+                    for FormSpamStatsSvc<T> {
+                        type Response = super::FormSpamStatsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FormSpamStatsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Dashboard>::form_spam_stats(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = FormSpamStatsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(
@@ -0,0 +1,1038 @@
+// This file is @generated by prost-build.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EmailModel {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// sender name and address, from the email sender settings
+    #[prost(string, tag = "2")]
+    pub from_address: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub to_address: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub subject: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub body: ::prost::alloc::string::String,
+    /// handlebars template the body was rendered from
+    #[prost(string, tag = "6")]
+    pub template: ::prost::alloc::string::String,
+    /// Pending, Sent or Dead
+    #[prost(string, tag = "7")]
+    pub status: ::prost::alloc::string::String,
+    #[prost(int64, tag = "8")]
+    pub attempts: i64,
+    #[prost(string, tag = "9")]
+    pub error: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "10")]
+    pub next_attempt_at: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "11")]
+    pub created_at: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "12")]
+    pub updated_at: ::core::option::Option<::prost_types::Timestamp>,
+    /// plain text alternative, derived from the html body when empty
+    #[prost(string, tag = "13")]
+    pub text_body: ::prost::alloc::string::String,
+    #[prost(string, tag = "14")]
+    pub reply_to: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "15")]
+    pub attachments: ::prost::alloc::vec::Vec<EmailAttachmentModel>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EmailAttachmentModel {
+    #[prost(string, tag = "1")]
+    pub file_name: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub content_type: ::prost::alloc::string::String,
+    /// location on the server, read when the email is sent
+    #[prost(string, tag = "3")]
+    pub path: ::prost::alloc::string::String,
+}
+/// Email outbox paginate API, the dead letter list is status Dead
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EmailPaginateRequest {
+    #[prost(string, optional, tag = "1")]
+    pub status: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(int64, optional, tag = "2")]
+    pub page: ::core::option::Option<i64>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EmailPaginateResponse {
+    #[prost(bool, tag = "1")]
+    pub status: bool,
+    #[prost(message, optional, tag = "2")]
+    pub data: ::core::option::Option<email_paginate_response::EmailPaginateData>,
+}
+/// Nested message and enum types in `EmailPaginateResponse`.
+pub mod email_paginate_response {
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct EmailPagination {
+        #[prost(int64, tag = "1")]
+        pub total: i64,
+    }
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct EmailPaginateData {
+        #[prost(message, optional, tag = "1")]
+        pub pagination: ::core::option::Option<EmailPagination>,
+        #[prost(message, repeated, tag = "2")]
+        pub data: ::prost::alloc::vec::Vec<super::EmailModel>,
+    }
+}
+/// Puts a dead or sent email back in the outbox with a fresh set of attempts
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResendEmailRequest {
+    #[prost(string, tag = "1")]
+    pub email_id: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResendEmailResponse {
+    #[prost(bool, tag = "1")]
+    pub status: bool,
+    #[prost(message, optional, tag = "2")]
+    pub data: ::core::option::Option<EmailModel>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EmailTemplateModel {
+    /// empty when the template is still the one on disk
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub identifier: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub name: ::prost::alloc::string::String,
+    /// subject, html_body and text_body are handlebars templates
+    #[prost(string, tag = "4")]
+    pub subject: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub html_body: ::prost::alloc::string::String,
+    #[prost(string, tag = "6")]
+    pub text_body: ::prost::alloc::string::String,
+    /// true when there is no stored template and the file on disk is used
+    #[prost(bool, tag = "7")]
+    pub is_default: bool,
+    #[prost(message, optional, tag = "8")]
+    pub created_at: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "9")]
+    pub updated_at: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(string, tag = "10")]
+    pub created_by: ::prost::alloc::string::String,
+    #[prost(string, tag = "11")]
+    pub updated_by: ::prost::alloc::string::String,
+}
+/// One entry per email the application sends, stored or not
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct EmailTemplateAllRequest {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EmailTemplateAllResponse {
+    #[prost(bool, tag = "1")]
+    pub status: bool,
+    #[prost(message, repeated, tag = "2")]
+    pub data: ::prost::alloc::vec::Vec<EmailTemplateModel>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetEmailTemplateRequest {
+    #[prost(string, tag = "1")]
+    pub email_template_id: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetEmailTemplateResponse {
+    #[prost(bool, tag = "1")]
+    pub status: bool,
+    #[prost(message, optional, tag = "2")]
+    pub data: ::core::option::Option<EmailTemplateModel>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StoreEmailTemplateRequest {
+    #[prost(string, tag = "1")]
+    pub identifier: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub subject: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub html_body: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub text_body: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StoreEmailTemplateResponse {
+    #[prost(bool, tag = "1")]
+    pub status: bool,
+    #[prost(message, optional, tag = "2")]
+    pub data: ::core::option::Option<EmailTemplateModel>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateEmailTemplateRequest {
+    #[prost(string, tag = "1")]
+    pub email_template_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub subject: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub html_body: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub text_body: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateEmailTemplateResponse {
+    #[prost(bool, tag = "1")]
+    pub status: bool,
+    #[prost(message, optional, tag = "2")]
+    pub data: ::core::option::Option<EmailTemplateModel>,
+}
+/// Deleting a stored template goes back to the file on disk
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteEmailTemplateRequest {
+    #[prost(string, tag = "1")]
+    pub email_template_id: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DeleteEmailTemplateResponse {
+    #[prost(bool, tag = "1")]
+    pub status: bool,
+}
+/// Renders unsaved template sources with sample data, or with the given JSON data
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PreviewEmailTemplateRequest {
+    #[prost(string, tag = "1")]
+    pub identifier: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub subject: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub html_body: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub text_body: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "5")]
+    pub data: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PreviewEmailTemplateResponse {
+    #[prost(bool, tag = "1")]
+    pub status: bool,
+    #[prost(string, tag = "2")]
+    pub subject: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub html_body: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub text_body: ::prost::alloc::string::String,
+}
+/// Generated client implementations.
+pub mod email_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    #[derive(Debug, Clone)]
+    pub struct EmailClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl EmailClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> EmailClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> EmailClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            EmailClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        pub async fn email_paginate(
+            &mut self,
+            request: impl tonic::IntoRequest<super::EmailPaginateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EmailPaginateResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/email.Email/EmailPaginate",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("email.Email", "EmailPaginate"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn resend_email(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ResendEmailRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ResendEmailResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/email.Email/ResendEmail");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("email.Email", "ResendEmail"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn email_template_all(
+            &mut self,
+            request: impl tonic::IntoRequest<super::EmailTemplateAllRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EmailTemplateAllResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/email.Email/EmailTemplateAll",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("email.Email", "EmailTemplateAll"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_email_template(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetEmailTemplateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetEmailTemplateResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/email.Email/GetEmailTemplate",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("email.Email", "GetEmailTemplate"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn store_email_template(
+            &mut self,
+            request: impl tonic::IntoRequest<super::StoreEmailTemplateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::StoreEmailTemplateResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/email.Email/StoreEmailTemplate",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("email.Email", "StoreEmailTemplate"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn update_email_template(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UpdateEmailTemplateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateEmailTemplateResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/email.Email/UpdateEmailTemplate",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("email.Email", "UpdateEmailTemplate"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn delete_email_template(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteEmailTemplateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteEmailTemplateResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/email.Email/DeleteEmailTemplate",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("email.Email", "DeleteEmailTemplate"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn preview_email_template(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PreviewEmailTemplateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PreviewEmailTemplateResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/email.Email/PreviewEmailTemplate",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("email.Email", "PreviewEmailTemplate"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod email_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with EmailServer.
+    #[async_trait]
+    pub trait Email: std::marker::Send + std::marker::Sync + 'static {
+        async fn email_paginate(
+            &self,
+            request: tonic::Request<super::EmailPaginateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EmailPaginateResponse>,
+            tonic::Status,
+        >;
+        async fn resend_email(
+            &self,
+            request: tonic::Request<super::ResendEmailRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ResendEmailResponse>,
+            tonic::Status,
+        >;
+        async fn email_template_all(
+            &self,
+            request: tonic::Request<super::EmailTemplateAllRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EmailTemplateAllResponse>,
+            tonic::Status,
+        >;
+        async fn get_email_template(
+            &self,
+            request: tonic::Request<super::GetEmailTemplateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetEmailTemplateResponse>,
+            tonic::Status,
+        >;
+        async fn store_email_template(
+            &self,
+            request: tonic::Request<super::StoreEmailTemplateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::StoreEmailTemplateResponse>,
+            tonic::Status,
+        >;
+        async fn update_email_template(
+            &self,
+            request: tonic::Request<super::UpdateEmailTemplateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateEmailTemplateResponse>,
+            tonic::Status,
+        >;
+        async fn delete_email_template(
+            &self,
+            request: tonic::Request<super::DeleteEmailTemplateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteEmailTemplateResponse>,
+            tonic::Status,
+        >;
+        async fn preview_email_template(
+            &self,
+            request: tonic::Request<super::PreviewEmailTemplateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PreviewEmailTemplateResponse>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct EmailServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> EmailServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for EmailServer<T>
+    where
+        T: Email,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/email.Email/EmailPaginate" => {
+                    #[allow(non_camel_case_types)]
+                    struct EmailPaginateSvc<T: Email>(pub Arc<T>);
+                    impl<
+                        T: Email,
+                    > tonic::server::UnaryService<super::EmailPaginateRequest>
+                    for EmailPaginateSvc<T> {
+                        type Response = super::EmailPaginateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::EmailPaginateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Email>::email_paginate(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = EmailPaginateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/email.Email/ResendEmail" => {
+                    #[allow(non_camel_case_types)]
+                    struct ResendEmailSvc<T: Email>(pub Arc<T>);
+                    impl<T: Email> tonic::server::UnaryService<super::ResendEmailRequest>
+                    for ResendEmailSvc<T> {
+                        type Response = super::ResendEmailResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ResendEmailRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Email>::resend_email(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ResendEmailSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/email.Email/EmailTemplateAll" => {
+                    #[allow(non_camel_case_types)]
+                    struct EmailTemplateAllSvc<T: Email>(pub Arc<T>);
+                    impl<
+                        T: Email,
+                    > tonic::server::UnaryService<super::EmailTemplateAllRequest>
+                    for EmailTemplateAllSvc<T> {
+                        type Response = super::EmailTemplateAllResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::EmailTemplateAllRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Email>::email_template_all(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = EmailTemplateAllSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/email.Email/GetEmailTemplate" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetEmailTemplateSvc<T: Email>(pub Arc<T>);
+                    impl<
+                        T: Email,
+                    > tonic::server::UnaryService<super::GetEmailTemplateRequest>
+                    for GetEmailTemplateSvc<T> {
+                        type Response = super::GetEmailTemplateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetEmailTemplateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Email>::get_email_template(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetEmailTemplateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/email.Email/StoreEmailTemplate" => {
+                    #[allow(non_camel_case_types)]
+                    struct StoreEmailTemplateSvc<T: Email>(pub Arc<T>);
+                    impl<
+                        T: Email,
+                    > tonic::server::UnaryService<super::StoreEmailTemplateRequest>
+                    for StoreEmailTemplateSvc<T> {
+                        type Response = super::StoreEmailTemplateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::StoreEmailTemplateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Email>::store_email_template(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = StoreEmailTemplateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/email.Email/UpdateEmailTemplate" => {
+                    #[allow(non_camel_case_types)]
+                    struct UpdateEmailTemplateSvc<T: Email>(pub Arc<T>);
+                    impl<
+                        T: Email,
+                    > tonic::server::UnaryService<super::UpdateEmailTemplateRequest>
+                    for UpdateEmailTemplateSvc<T> {
+                        type Response = super::UpdateEmailTemplateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UpdateEmailTemplateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Email>::update_email_template(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = UpdateEmailTemplateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/email.Email/DeleteEmailTemplate" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteEmailTemplateSvc<T: Email>(pub Arc<T>);
+                    impl<
+                        T: Email,
+                    > tonic::server::UnaryService<super::DeleteEmailTemplateRequest>
+                    for DeleteEmailTemplateSvc<T> {
+                        type Response = super::DeleteEmailTemplateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeleteEmailTemplateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Email>::delete_email_template(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DeleteEmailTemplateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/email.Email/PreviewEmailTemplate" => {
+                    #[allow(non_camel_case_types)]
+                    struct PreviewEmailTemplateSvc<T: Email>(pub Arc<T>);
+                    impl<
+                        T: Email,
+                    > tonic::server::UnaryService<super::PreviewEmailTemplateRequest>
+                    for PreviewEmailTemplateSvc<T> {
+                        type Response = super::PreviewEmailTemplateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PreviewEmailTemplateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Email>::preview_email_template(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PreviewEmailTemplateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(
+                            tonic::body::Body::default(),
+                        );
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for EmailServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "email.Email";
+    impl<T> tonic::server::NamedService for EmailServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
@@ -0,0 +1,916 @@
+// This file is @generated by prost-build.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FormFieldModel {
+    /// label shown to visitors and used in validation messages
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// key of the value in a submission
+    #[prost(string, tag = "2")]
+    pub identifier: ::prost::alloc::string::String,
+    /// text, email, phone, number, textarea, select, checkbox or file
+    #[prost(string, tag = "3")]
+    pub field_type: ::prost::alloc::string::String,
+    #[prost(bool, tag = "4")]
+    pub is_required: bool,
+    /// 0 means no limit, a file field reads max_length as its size limit in kilobytes
+    #[prost(int64, tag = "5")]
+    pub min_length: i64,
+    #[prost(int64, tag = "6")]
+    pub max_length: i64,
+    /// allowed values of a select field, allowed content types of a file field
+    #[prost(string, repeated, tag = "7")]
+    pub options: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FormModel {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub identifier: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "4")]
+    pub fields: ::prost::alloc::vec::Vec<FormFieldModel>,
+    /// every submission is emailed to these addresses, leave empty to only store it
+    #[prost(string, repeated, tag = "5")]
+    pub recipients: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, tag = "6")]
+    pub email_subject: ::prost::alloc::string::String,
+    #[prost(string, tag = "7")]
+    pub success_message: ::prost::alloc::string::String,
+    #[prost(bool, tag = "8")]
+    pub is_active: bool,
+    #[prost(message, optional, tag = "9")]
+    pub created_at: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "10")]
+    pub updated_at: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(string, tag = "11")]
+    pub created_by: ::prost::alloc::string::String,
+    #[prost(string, tag = "12")]
+    pub updated_by: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FormSubmissionModel {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub form_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub form_identifier: ::prost::alloc::string::String,
+    #[prost(map = "string, string", tag = "4")]
+    pub data: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
+    #[prost(message, optional, tag = "5")]
+    pub created_at: ::core::option::Option<::prost_types::Timestamp>,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct FormAllRequest {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FormAllResponse {
+    #[prost(bool, tag = "1")]
+    pub status: bool,
+    #[prost(message, repeated, tag = "2")]
+    pub data: ::prost::alloc::vec::Vec<FormModel>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetFormRequest {
+    #[prost(string, tag = "1")]
+    pub form_id: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetFormResponse {
+    #[prost(bool, tag = "1")]
+    pub status: bool,
+    #[prost(message, optional, tag = "2")]
+    pub data: ::core::option::Option<FormModel>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StoreFormRequest {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub identifier: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub fields: ::prost::alloc::vec::Vec<FormFieldModel>,
+    #[prost(string, repeated, tag = "4")]
+    pub recipients: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, tag = "5")]
+    pub email_subject: ::prost::alloc::string::String,
+    #[prost(string, tag = "6")]
+    pub success_message: ::prost::alloc::string::String,
+    #[prost(bool, tag = "7")]
+    pub is_active: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StoreFormResponse {
+    #[prost(bool, tag = "1")]
+    pub status: bool,
+    #[prost(message, optional, tag = "2")]
+    pub data: ::core::option::Option<FormModel>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateFormRequest {
+    #[prost(string, tag = "1")]
+    pub form_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub fields: ::prost::alloc::vec::Vec<FormFieldModel>,
+    #[prost(string, repeated, tag = "4")]
+    pub recipients: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, tag = "5")]
+    pub email_subject: ::prost::alloc::string::String,
+    #[prost(string, tag = "6")]
+    pub success_message: ::prost::alloc::string::String,
+    #[prost(bool, tag = "7")]
+    pub is_active: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateFormResponse {
+    #[prost(bool, tag = "1")]
+    pub status: bool,
+    #[prost(message, optional, tag = "2")]
+    pub data: ::core::option::Option<FormModel>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteFormRequest {
+    #[prost(string, tag = "1")]
+    pub form_id: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DeleteFormResponse {
+    #[prost(bool, tag = "1")]
+    pub status: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FormSubmissionPaginateRequest {
+    #[prost(string, tag = "1")]
+    pub form_id: ::prost::alloc::string::String,
+    #[prost(int64, optional, tag = "2")]
+    pub page: ::core::option::Option<i64>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FormSubmissionPaginateResponse {
+    #[prost(bool, tag = "1")]
+    pub status: bool,
+    #[prost(message, optional, tag = "2")]
+    pub data: ::core::option::Option<
+        form_submission_paginate_response::FormSubmissionPaginateData,
+    >,
+}
+/// Nested message and enum types in `FormSubmissionPaginateResponse`.
+pub mod form_submission_paginate_response {
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct FormSubmissionPagination {
+        #[prost(int64, tag = "1")]
+        pub total: i64,
+    }
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct FormSubmissionPaginateData {
+        #[prost(message, optional, tag = "1")]
+        pub pagination: ::core::option::Option<FormSubmissionPagination>,
+        #[prost(message, repeated, tag = "2")]
+        pub data: ::prost::alloc::vec::Vec<super::FormSubmissionModel>,
+    }
+}
+/// Every submission of a form as CSV, one column per form field.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExportFormSubmissionsRequest {
+    #[prost(string, tag = "1")]
+    pub form_id: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExportFormSubmissionsResponse {
+    #[prost(bool, tag = "1")]
+    pub status: bool,
+    #[prost(string, tag = "2")]
+    pub file_name: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub content: ::prost::alloc::string::String,
+}
+/// Generated client implementations.
+pub mod form_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    #[derive(Debug, Clone)]
+    pub struct FormClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl FormClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> FormClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> FormClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            FormClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        pub async fn form_all(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FormAllRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::FormAllResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/form.Form/FormAll");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("form.Form", "FormAll"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_form(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetFormRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetFormResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/form.Form/GetForm");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("form.Form", "GetForm"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn store_form(
+            &mut self,
+            request: impl tonic::IntoRequest<super::StoreFormRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::StoreFormResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/form.Form/StoreForm");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("form.Form", "StoreForm"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn update_form(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UpdateFormRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateFormResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/form.Form/UpdateForm");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("form.Form", "UpdateForm"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn delete_form(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteFormRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteFormResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/form.Form/DeleteForm");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("form.Form", "DeleteForm"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn form_submission_paginate(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FormSubmissionPaginateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::FormSubmissionPaginateResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/form.Form/FormSubmissionPaginate",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("form.Form", "FormSubmissionPaginate"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn export_form_submissions(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ExportFormSubmissionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ExportFormSubmissionsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/form.Form/ExportFormSubmissions",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("form.Form", "ExportFormSubmissions"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod form_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with FormServer.
+    #[async_trait]
+    pub trait Form: std::marker::Send + std::marker::Sync + 'static {
+        async fn form_all(
+            &self,
+            request: tonic::Request<super::FormAllRequest>,
+        ) -> std::result::Result<tonic::Response<super::FormAllResponse>, tonic::Status>;
+        async fn get_form(
+            &self,
+            request: tonic::Request<super::GetFormRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetFormResponse>, tonic::Status>;
+        async fn store_form(
+            &self,
+            request: tonic::Request<super::StoreFormRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::StoreFormResponse>,
+            tonic::Status,
+        >;
+        async fn update_form(
+            &self,
+            request: tonic::Request<super::UpdateFormRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateFormResponse>,
+            tonic::Status,
+        >;
+        async fn delete_form(
+            &self,
+            request: tonic::Request<super::DeleteFormRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteFormResponse>,
+            tonic::Status,
+        >;
+        async fn form_submission_paginate(
+            &self,
+            request: tonic::Request<super::FormSubmissionPaginateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::FormSubmissionPaginateResponse>,
+            tonic::Status,
+        >;
+        async fn export_form_submissions(
+            &self,
+            request: tonic::Request<super::ExportFormSubmissionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ExportFormSubmissionsResponse>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct FormServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> FormServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for FormServer<T>
+    where
+        T: Form,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/form.Form/FormAll" => {
+                    #[allow(non_camel_case_types)]
+                    struct FormAllSvc<T: Form>(pub Arc<T>);
+                    impl<T: Form> tonic::server::UnaryService<super::FormAllRequest>
+                    for FormAllSvc<T> {
+                        type Response = super::FormAllResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FormAllRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Form>::form_all(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = FormAllSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/form.Form/GetForm" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetFormSvc<T: Form>(pub Arc<T>);
+                    impl<T: Form> tonic::server::UnaryService<super::GetFormRequest>
+                    for GetFormSvc<T> {
+                        type Response = super::GetFormResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetFormRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Form>::get_form(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetFormSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/form.Form/StoreForm" => {
+                    #[allow(non_camel_case_types)]
+                    struct StoreFormSvc<T: Form>(pub Arc<T>);
+                    impl<T: Form> tonic::server::UnaryService<super::StoreFormRequest>
+                    for StoreFormSvc<T> {
+                        type Response = super::StoreFormResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::StoreFormRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Form>::store_form(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = StoreFormSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/form.Form/UpdateForm" => {
+                    #[allow(non_camel_case_types)]
+                    struct UpdateFormSvc<T: Form>(pub Arc<T>);
+                    impl<T: Form> tonic::server::UnaryService<super::UpdateFormRequest>
+                    for UpdateFormSvc<T> {
+                        type Response = super::UpdateFormResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UpdateFormRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Form>::update_form(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = UpdateFormSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/form.Form/DeleteForm" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteFormSvc<T: Form>(pub Arc<T>);
+                    impl<T: Form> tonic::server::UnaryService<super::DeleteFormRequest>
+                    for DeleteFormSvc<T> {
+                        type Response = super::DeleteFormResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeleteFormRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Form>::delete_form(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DeleteFormSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/form.Form/FormSubmissionPaginate" => {
+                    #[allow(non_camel_case_types)]
+                    struct FormSubmissionPaginateSvc<T: Form>(pub Arc<T>);
+                    impl<
+                        T: Form,
+                    > tonic::server::UnaryService<super::FormSubmissionPaginateRequest>
+                    for FormSubmissionPaginateSvc<T> {
+                        type Response = super::FormSubmissionPaginateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FormSubmissionPaginateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Form>::form_submission_paginate(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = FormSubmissionPaginateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/form.Form/ExportFormSubmissions" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExportFormSubmissionsSvc<T: Form>(pub Arc<T>);
+                    impl<
+                        T: Form,
+                    > tonic::server::UnaryService<super::ExportFormSubmissionsRequest>
+                    for ExportFormSubmissionsSvc<T> {
+                        type Response = super::ExportFormSubmissionsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ExportFormSubmissionsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Form>::export_form_submissions(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ExportFormSubmissionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(
+                            tonic::body::Body::default(),
+                        );
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for FormServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "form.Form";
+    impl<T> tonic::server::NamedService for FormServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
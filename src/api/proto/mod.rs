@@ -8,4 +8,8 @@ pub mod setting;
 pub mod cms;
 pub mod general;
 
-pub mod asset;
\ No newline at end of file
+pub mod asset;pub mod cms_api_key;
+
+pub mod webhook;
+pub mod form;
+pub mod email;
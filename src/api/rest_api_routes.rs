@@ -54,7 +54,6 @@ use crate::api::handlers::{
 };
 use crate::avored_state::AvoRedState;
 use crate::middleware::require_jwt_authentication::require_jwt_authentication;
-use crate::middleware::validate_cms_authentication::validate_cms_authentication;
 use axum::http::header::{AUTHORIZATION, CONTENT_TYPE};
 use axum::http::HeaderValue;
 use axum::routing::{delete, post, put};
@@ -86,10 +85,6 @@ fn cms_api_routes(state: Arc<AvoRedState>) -> Router {
             "/cms/sent-contact-us-email",
             post(sent_contact_us_email_handler),
         )
-        .route_layer(middleware::from_fn_with_state(
-            state.clone(),
-            validate_cms_authentication,
-        ))
         .with_state(state)
         .layer(cors)
 }
@@ -315,10 +310,8 @@ pub mod tests {
         let admin_user_model = AdminUserModel::default();
 
         let claims: TokenClaims = TokenClaims {
-            sub: admin_user_model.clone().id,
-            name: admin_user_model.clone().full_name,
-            email: admin_user_model.clone().email,
-            admin_user_model: admin_user_model.clone(),
+            sub: admin_user_model.id,
+            sid: String::from(""),
             exp,
             iat,
         };
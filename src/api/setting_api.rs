@@ -20,10 +20,10 @@ impl Setting for SettingApi {
         println!("->> {:<12} - get_setting", "gRPC_Setting_Service");
 
         let claims = request.get_token_claim()?;
-        let logged_in_user = claims.admin_user_model;
-        logged_in_user
+        claims
             .check_user_has_resouce_access(
                 &self.state.admin_user_service,
+                &self.state.db,
                 String::from("get_setting"),
             )
             .await?;
@@ -47,10 +47,10 @@ impl Setting for SettingApi {
         println!("->> {:<12} - store_setting", "gRPC_Setting_Service");
 
         let claims = request.get_token_claim()?;
-        let logged_in_user = claims.admin_user_model;
-        logged_in_user
+        let logged_in_user = claims
             .check_user_has_resouce_access(
                 &self.state.admin_user_service,
+                &self.state.db,
                 String::from("store_setting"),
             )
             .await?;
@@ -62,7 +62,7 @@ impl Setting for SettingApi {
             store_setting(
                 &self.state.db,
                 req,
-                claims.email
+                logged_in_user.email
             ).await {
             Ok(reply) => Ok(Response::new(reply)),
             Err(e) => Err(Status::internal(e.to_string()))
@@ -0,0 +1,236 @@
+use std::sync::Arc;
+use tonic::{async_trait, Request, Response, Status};
+use crate::api::proto::webhook::webhook_server::Webhook;
+use crate::api::proto::webhook::{DeleteWebhookRequest, DeleteWebhookResponse, PingWebhookRequest, PingWebhookResponse, RedeliverWebhookRequest, RedeliverWebhookResponse, StoreWebhookRequest, StoreWebhookResponse, UpdateWebhookRequest, UpdateWebhookResponse, WebhookAllRequest, WebhookAllResponse, WebhookDeliveryPaginateRequest, WebhookDeliveryPaginateResponse};
+use crate::avored_state::AvoRedState;
+use crate::error::Error::TonicError;
+use crate::extensions::tonic_request::TonicRequest;
+use crate::models::admin_user_model::AdminUserModelExtension;
+
+pub struct WebhookApi {
+    pub state: Arc<AvoRedState>,
+}
+
+#[async_trait]
+impl Webhook for WebhookApi {
+    async fn webhook_all(
+        &self,
+        request: Request<WebhookAllRequest>,
+    ) -> Result<Response<WebhookAllResponse>, Status> {
+        println!("->> {:<12} - webhook_all", "gRPC_Webhook_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        claims
+            .check_user_has_resouce_access(
+                &self.state.admin_user_service,
+                &self.state.db,
+                String::from("webhook_all"),
+            )
+            .await?;
+
+        match self
+            .state
+            .webhook_service
+            .webhook_all(&self.state.db)
+            .await
+        {
+            Ok(reply) => Ok(Response::new(reply)),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn store_webhook(
+        &self,
+        request: Request<StoreWebhookRequest>,
+    ) -> Result<Response<StoreWebhookResponse>, Status> {
+        println!("->> {:<12} - store_webhook", "gRPC_Webhook_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        let logged_in_user = claims
+            .check_user_has_resouce_access(
+                &self.state.admin_user_service,
+                &self.state.db,
+                String::from("store_webhook"),
+            )
+            .await?;
+
+        let req = request.into_inner();
+        req.validate()?;
+
+        match self
+            .state
+            .webhook_service
+            .store_webhook(&self.state.db, req, logged_in_user.email)
+            .await
+        {
+            Ok(reply) => Ok(Response::new(reply)),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn update_webhook(
+        &self,
+        request: Request<UpdateWebhookRequest>,
+    ) -> Result<Response<UpdateWebhookResponse>, Status> {
+        println!("->> {:<12} - update_webhook", "gRPC_Webhook_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        let logged_in_user = claims
+            .check_user_has_resouce_access(
+                &self.state.admin_user_service,
+                &self.state.db,
+                String::from("update_webhook"),
+            )
+            .await?;
+
+        let req = request.into_inner();
+        req.validate()?;
+
+        match self
+            .state
+            .webhook_service
+            .update_webhook(&self.state.db, req, logged_in_user.email)
+            .await
+        {
+            Ok(reply) => Ok(Response::new(reply)),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn delete_webhook(
+        &self,
+        request: Request<DeleteWebhookRequest>,
+    ) -> Result<Response<DeleteWebhookResponse>, Status> {
+        println!("->> {:<12} - delete_webhook", "gRPC_Webhook_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        claims
+            .check_user_has_resouce_access(
+                &self.state.admin_user_service,
+                &self.state.db,
+                String::from("delete_webhook"),
+            )
+            .await?;
+
+        let req = request.into_inner();
+        req.validate()?;
+
+        match self
+            .state
+            .webhook_service
+            .delete_webhook(&self.state.db, &req.webhook_id)
+            .await
+        {
+            Ok(status) => Ok(Response::new(DeleteWebhookResponse { status })),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn webhook_delivery_paginate(
+        &self,
+        request: Request<WebhookDeliveryPaginateRequest>,
+    ) -> Result<Response<WebhookDeliveryPaginateResponse>, Status> {
+        println!("->> {:<12} - webhook_delivery_paginate", "gRPC_Webhook_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        claims
+            .check_user_has_resouce_access(
+                &self.state.admin_user_service,
+                &self.state.db,
+                String::from("webhook_delivery_paginate"),
+            )
+            .await?;
+
+        let req = request.into_inner();
+        req.validate()?;
+
+        match self
+            .state
+            .webhook_service
+            .webhook_delivery_paginate(&self.state.db, &req.webhook_id, req.page.unwrap_or(0))
+            .await
+        {
+            Ok(reply) => Ok(Response::new(reply)),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn redeliver_webhook(
+        &self,
+        request: Request<RedeliverWebhookRequest>,
+    ) -> Result<Response<RedeliverWebhookResponse>, Status> {
+        println!("->> {:<12} - redeliver_webhook", "gRPC_Webhook_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        claims
+            .check_user_has_resouce_access(
+                &self.state.admin_user_service,
+                &self.state.db,
+                String::from("redeliver_webhook"),
+            )
+            .await?;
+
+        let req = request.into_inner();
+        req.validate()?;
+
+        match self
+            .state
+            .webhook_service
+            .redeliver_webhook(&self.state.db, &req.webhook_delivery_id)
+            .await
+        {
+            Ok(reply) => Ok(Response::new(reply)),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+
+    async fn ping_webhook(
+        &self,
+        request: Request<PingWebhookRequest>,
+    ) -> Result<Response<PingWebhookResponse>, Status> {
+        println!("->> {:<12} - ping_webhook", "gRPC_Webhook_Api_Service");
+
+        let claims = request.get_token_claim()?;
+        claims
+            .check_user_has_resouce_access(
+                &self.state.admin_user_service,
+                &self.state.db,
+                String::from("ping_webhook"),
+            )
+            .await?;
+
+        let req = request.into_inner();
+        req.validate()?;
+
+        match self
+            .state
+            .webhook_service
+            .ping_webhook(&self.state.db, &req.webhook_id)
+            .await
+        {
+            Ok(reply) => Ok(Response::new(reply)),
+            Err(e) => match e {
+                TonicError(status) => Err(status),
+                _ => Err(Status::internal(e.to_string())),
+            },
+        }
+    }
+}
@@ -1,27 +1,78 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::error;
 use crate::error::Result;
+use crate::models::admin_user_model::AdminUserCache;
+use crate::models::asset_event_model::AssetEventBus;
+use crate::models::content_event_model::ContentEventBus;
+use crate::models::content_model::CmsContentCache;
+use crate::models::collection_model::{GraphqlSchemaCache, PageMenuCache};
+use crate::models::feed_model::FeedCache;
+use crate::models::sitemap_model::SitemapCache;
+use crate::providers::avored_cache_provider::AvoRedCacheProvider;
+use crate::providers::avored_clock_provider::AvoRedClockProvider;
 use crate::providers::avored_config_provider::AvoRedConfigProvider;
 use crate::providers::avored_database_provider::{AvoRedDatabaseProvider, DB};
+use crate::providers::avored_event_bus_provider::AvoRedEventBusProvider;
 use crate::providers::avored_template_provider::AvoRedTemplateProvider;
+use crate::providers::avored_theme_provider::AvoRedThemeProvider;
 use crate::repositories::admin_user_repository::AdminUserRepository;
+use crate::repositories::admin_user_session_repository::AdminUserSessionRepository;
+use crate::repositories::admin_user_two_factor_repository::AdminUserTwoFactorRepository;
+use crate::repositories::audit_log_repository::AuditLogRepository;
+use crate::repositories::password_history_repository::PasswordHistoryRepository;
 use crate::repositories::asset_repository::AssetRepository;
+use crate::repositories::cms_api_key_repository::CmsApiKeyRepository;
 use crate::repositories::collection_repository::CollectionRepository;
+use crate::repositories::email_repository::EmailRepository;
+use crate::repositories::email_template_repository::EmailTemplateRepository;
+use crate::repositories::form_repository::FormRepository;
+use crate::repositories::form_submission_repository::FormSubmissionRepository;
 use crate::repositories::content_repository::ContentRepository;
 use crate::repositories::password_reset_repository::PasswordResetRepository;
+use crate::repositories::refresh_token_repository::RefreshTokenRepository;
 use crate::repositories::role_repository::RoleRepository;
 use crate::repositories::setting_repository::SettingRepository;
+use crate::repositories::webhook_delivery_repository::WebhookDeliveryRepository;
+use crate::repositories::webhook_repository::WebhookRepository;
 use crate::services::admin_user_service::AdminUserService;
 use crate::services::asset_service::AssetService;
 use crate::services::auth_service::AuthService;
+use crate::services::cms_api_key_service::CmsApiKeyService;
 use crate::services::cms_service::CmsService;
 use crate::services::content_service::ContentService;
+use crate::services::feed_service::FeedService;
+use crate::services::email_service::EmailService;
+use crate::services::email_template_service::EmailTemplateService;
+use crate::services::form_service::FormService;
+use crate::services::form_spam_service::{ChallengeTokenVerifier, FormSpamService, FormSubmissionVerifier};
 use crate::services::general_service::GeneralService;
+use crate::services::login_throttle_service::LoginThrottleService;
+use crate::services::password_policy_service::PasswordPolicyService;
+use crate::services::graphql_service::GraphqlService;
 use crate::services::misc_service::MiscService;
+use crate::services::page_service::PageService;
 use crate::services::setting_service::SettingService;
+use crate::services::sitemap_service::SitemapService;
+use crate::services::static_site_service::StaticSiteService;
+use crate::services::webhook_service::WebhookService;
+
+/// Events a slow watcher may fall behind before it starts skipping them.
+const CONTENT_EVENT_BUS_CAPACITY: usize = 1024;
+const ASSET_EVENT_BUS_CAPACITY: usize = 256;
+
+/// Logged in admin users kept with their roles, see `AdminUserCache`.
+const ADMIN_USER_CACHE_MAX_ENTRIES: usize = 1000;
+/// One schema per distinct set of collections the api keys can read.
+const GRAPHQL_SCHEMA_CACHE_MAX_ENTRIES: usize = 100;
+/// One feed per routable collection.
+const FEED_CACHE_MAX_ENTRIES: usize = 100;
 
 pub struct AvoRedState {
     pub db: DB,
     pub config: AvoRedConfigProvider,
     pub template: AvoRedTemplateProvider,
+    pub theme: AvoRedThemeProvider,
     pub misc_service: MiscService,
     pub auth_service: AuthService,
     pub admin_user_service: AdminUserService,
@@ -29,14 +80,29 @@ pub struct AvoRedState {
     pub asset_service: AssetService,
     pub setting_service: SettingService,
     pub cms_service: CmsService,
-    pub general_service: GeneralService
+    pub general_service: GeneralService,
+    pub graphql_service: GraphqlService,
+    pub cms_api_key_service: CmsApiKeyService,
+    pub page_service: PageService,
+    pub sitemap_service: SitemapService,
+    pub feed_service: FeedService,
+    pub webhook_service: WebhookService,
+    pub static_site_service: StaticSiteService,
+    pub form_service: FormService,
+    pub email_service: EmailService,
+    pub email_template_service: EmailTemplateService,
+    pub password_policy_service: PasswordPolicyService,
 }
 
 impl AvoRedState {
     pub async fn new() -> Result<AvoRedState> {
-        let avored_config_provider = AvoRedConfigProvider::register()?;
+        Self::with_config(AvoRedConfigProvider::register()?).await
+    }
+
+    pub async fn with_config(avored_config_provider: AvoRedConfigProvider) -> Result<AvoRedState> {
         let avored_template_provider =
             AvoRedTemplateProvider::register(avored_config_provider.clone()).await?;
+        let avored_theme_provider = AvoRedThemeProvider::register(avored_config_provider.clone())?;
         let avored_database_provider =
             AvoRedDatabaseProvider::register(avored_config_provider.clone()).await?;
 
@@ -47,20 +113,168 @@ impl AvoRedState {
         let asset_repository = AssetRepository::new();
         let password_reset_repository = PasswordResetRepository::new();
         let setting_repository = SettingRepository::new();
+        let cms_api_key_repository = CmsApiKeyRepository::new();
+        let webhook_repository = WebhookRepository::new();
+        let webhook_delivery_repository = WebhookDeliveryRepository::new();
+        let form_repository = FormRepository::new();
+        let email_repository = EmailRepository::new();
+        let email_template_repository = EmailTemplateRepository::new();
+        let form_submission_repository = FormSubmissionRepository::new();
+        let admin_user_session_repository = AdminUserSessionRepository::new();
+        let refresh_token_repository = RefreshTokenRepository::new();
+        let admin_user_two_factor_repository = AdminUserTwoFactorRepository::new();
+
+        let cms_content_cache: Arc<CmsContentCache> = Arc::new(AvoRedCacheProvider::register(
+            avored_config_provider.cms_cache_max_entries,
+            Duration::from_secs(avored_config_provider.cms_cache_ttl_seconds),
+        ));
+        let content_event_bus: Arc<ContentEventBus> =
+            Arc::new(AvoRedEventBusProvider::register(CONTENT_EVENT_BUS_CAPACITY));
+        let asset_event_bus: Arc<AssetEventBus> =
+            Arc::new(AvoRedEventBusProvider::register(ASSET_EVENT_BUS_CAPACITY));
+        let sitemap_cache: Arc<SitemapCache> = Arc::new(AvoRedCacheProvider::register(
+            1,
+            Duration::from_secs(avored_config_provider.cms_cache_ttl_seconds),
+        ));
+        let feed_cache: Arc<FeedCache> = Arc::new(AvoRedCacheProvider::register(
+            FEED_CACHE_MAX_ENTRIES,
+            Duration::from_secs(avored_config_provider.cms_cache_ttl_seconds),
+        ));
+
+        let page_menu_cache: Arc<PageMenuCache> = Arc::new(AvoRedCacheProvider::register(
+            1,
+            Duration::from_secs(avored_config_provider.cms_cache_ttl_seconds),
+        ));
+
+        let graphql_schema_cache: Arc<GraphqlSchemaCache> = Arc::new(AvoRedCacheProvider::register(
+            GRAPHQL_SCHEMA_CACHE_MAX_ENTRIES,
+            Duration::from_secs(avored_config_provider.cms_cache_ttl_seconds),
+        ));
 
+        let clock = Arc::new(AvoRedClockProvider::register());
 
         let misc_service = MiscService::new().await?;
-        let auth_service = AuthService::new(admin_user_repository.clone(), password_reset_repository).await?;
-        let admin_user_service = AdminUserService::new(admin_user_repository, role_repository)?;
-        let content_service = ContentService::new(content_repository.clone(), collection_repository)?;
-        let asset_service = AssetService::new(asset_repository)?;
+        let login_throttle_service = LoginThrottleService::new(
+            AuditLogRepository::new(),
+            avored_config_provider.login_max_attempts,
+            avored_config_provider.login_ip_max_attempts,
+            avored_config_provider.login_lockout_seconds,
+            clock.clone(),
+        )?;
+        let auth_service = AuthService::new(
+            admin_user_repository.clone(),
+            password_reset_repository,
+            admin_user_session_repository,
+            refresh_token_repository,
+            admin_user_two_factor_repository,
+            setting_repository.clone(),
+            login_throttle_service,
+            avored_config_provider.password_hash.clone(),
+            avored_config_provider.jwt_expired_in_seconds,
+            avored_config_provider.refresh_token_expired_in_seconds,
+            avored_config_provider.password_reset_token_expired_in_seconds,
+        )
+        .await?;
+        if let Err(e) = auth_service
+            .load_revoked_sessions(&avored_database_provider.db)
+            .await
+        {
+            error!("unable to load the revoked sessions: {e:?}");
+        }
+        let password_policy_service = PasswordPolicyService::new(
+            admin_user_repository.clone(),
+            PasswordHistoryRepository::new(),
+            avored_config_provider.password_policy.clone(),
+            avored_config_provider.password_hash.clone(),
+        )?;
+        let admin_user_cache: AdminUserCache = AvoRedCacheProvider::register(
+            ADMIN_USER_CACHE_MAX_ENTRIES,
+            Duration::from_secs(avored_config_provider.admin_user_cache_ttl_seconds),
+        );
+        let admin_user_service = AdminUserService::new(admin_user_repository, role_repository, admin_user_cache)?;
+        let content_service = ContentService::new(content_repository.clone(), collection_repository.clone(), cms_content_cache.clone(), sitemap_cache.clone(), feed_cache.clone(), page_menu_cache.clone(), graphql_schema_cache.clone(), content_event_bus.clone())?;
+        let asset_service = AssetService::new(asset_repository, asset_event_bus.clone())?;
+        let page_service = PageService::new(content_repository.clone(), collection_repository.clone(), setting_repository.clone(), page_menu_cache)?;
+        let sitemap_service = SitemapService::new(
+            content_repository.clone(),
+            collection_repository.clone(),
+            setting_repository.clone(),
+            sitemap_cache,
+            avored_config_provider.back_end_app_url.clone(),
+            avored_config_provider.sitemap_max_urls,
+        )?;
+        let feed_service = FeedService::new(
+            content_repository.clone(),
+            collection_repository.clone(),
+            setting_repository.clone(),
+            feed_cache,
+            avored_config_provider.back_end_app_url.clone(),
+            avored_config_provider.feed_items,
+        )?;
+        let static_site_service = StaticSiteService::new(
+            content_repository.clone(),
+            collection_repository.clone(),
+            avored_config_provider.static_export_dir.clone(),
+            avored_config_provider.back_end_app_url.clone(),
+            avored_config_provider.static_export_keep,
+        )?;
+        let form_submission_verifier: Option<Arc<dyn FormSubmissionVerifier>> =
+            match avored_config_provider.form_challenge_verify_url.is_empty() {
+                true => None,
+                false => Some(Arc::new(ChallengeTokenVerifier::new(
+                    avored_config_provider.form_challenge_verify_url.clone(),
+                    avored_config_provider.form_challenge_secret.clone(),
+                )?)),
+            };
+        let form_spam_service = FormSpamService::new(
+            avored_config_provider.form_ip_max_submissions,
+            avored_config_provider.form_max_submissions,
+            Duration::from_secs(avored_config_provider.form_rate_limit_window_seconds),
+            avored_config_provider.form_honeypot_field.clone(),
+            form_submission_verifier,
+        )?;
+        let form_service = FormService::new(
+            form_repository,
+            form_submission_repository,
+            form_spam_service,
+            avored_config_provider.form_upload_dir.clone(),
+            avored_config_provider.form_max_upload_kb,
+        )?;
+        let email_service = EmailService::new(
+            email_repository,
+            setting_repository.clone(),
+            avored_config_provider.email_max_attempts,
+            avored_config_provider.email_retry_base_seconds,
+            clock.clone(),
+        )?;
+        let email_template_service = EmailTemplateService::new(email_template_repository)?;
+        if let Err(e) = email_template_service
+            .register_stored_templates(&avored_database_provider.db, &avored_template_provider)
+            .await
+        {
+            error!("unable to load the stored email templates: {e:?}");
+        }
         let setting_service = SettingService::new(setting_repository)?;
-        let cms_service = CmsService::new(content_repository)?;
+        let cms_service = CmsService::new(content_repository.clone(), cms_content_cache, content_event_bus.clone())?;
         let general_service = GeneralService::new()?;
+        let graphql_service = GraphqlService::new(content_repository, collection_repository, graphql_schema_cache)?;
+        let cms_api_key_service = CmsApiKeyService::new(cms_api_key_repository)?;
+        let webhook_service = WebhookService::new(
+            webhook_repository,
+            webhook_delivery_repository,
+            content_event_bus,
+            asset_event_bus,
+            avored_config_provider.webhook_timeout_seconds,
+            avored_config_provider.webhook_max_attempts,
+            avored_config_provider.webhook_retry_base_seconds,
+            avored_config_provider.webhook_allow_private_targets,
+            clock,
+        )?;
 
         Ok(AvoRedState {
             config: avored_config_provider,
             template: avored_template_provider,
+            theme: avored_theme_provider,
             db: avored_database_provider.db,
             misc_service,
             auth_service,
@@ -70,6 +284,17 @@ impl AvoRedState {
             setting_service,
             cms_service,
             general_service,
+            graphql_service,
+            cms_api_key_service,
+            page_service,
+            sitemap_service,
+            feed_service,
+            webhook_service,
+            static_site_service,
+            form_service,
+            email_service,
+            email_template_service,
+            password_policy_service,
         })
     }
 }
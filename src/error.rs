@@ -19,6 +19,8 @@ pub enum Error {
     Unauthorizeed(String),
     Unauthenticated(String),
     InvalidArgument(String),
+    NotFound(String),
+    TooManyRequests(String),
     Argon2Error(argon2::password_hash::Error),
 }
 
@@ -76,6 +78,12 @@ impl From<Error> for Status {
             Error::Unauthenticated(error_message) => {
                 Self::unauthenticated(error_message)
             },
+            Error::NotFound(resource_name) => {
+                Self::not_found(format!("{resource_name} not found"))
+            },
+            Error::TooManyRequests(error_message) => {
+                Self::resource_exhausted(error_message)
+            },
             _ => Self::invalid_argument("500 Internal server error")
         } 
     }
@@ -116,6 +124,13 @@ impl From<jsonwebtoken::errors::Error> for Error {
     }
 }
 
+impl From<argon2::Error> for Error {
+    fn from(actual_error: argon2::Error) -> Self {
+        error!("argon2 params error: {actual_error:?}");
+        Error::Generic("argon2 params error".to_string())
+    }
+}
+
 impl From<argon2::password_hash::Error> for Error {
     fn from(actual_error: argon2::password_hash::Error) -> Self {
         error!("argon2 password hash error: {actual_error:?}");
@@ -130,6 +145,27 @@ impl From<lettre::error::Error> for Error {
     }
 }
 
+impl From<lettre::transport::smtp::Error> for Error {
+    fn from(actual_error: lettre::transport::smtp::Error) -> Self {
+        error!("there is an issue with the smtp transport: {actual_error:?}");
+        Error::Generic("smtp transport error".to_string())
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(actual_error: reqwest::Error) -> Self {
+        error!("there is an issue with the http client: {actual_error:?}");
+        Error::Generic("http client error".to_string())
+    }
+}
+
+impl From<zip::result::ZipError> for Error {
+    fn from(actual_error: zip::result::ZipError) -> Self {
+        error!("there is an issue while writing the zip archive: {actual_error:?}");
+        Error::Generic("zip archive error".to_string())
+    }
+}
+
 impl From<TemplateError> for Error {
     fn from(actual_error: TemplateError) -> Self {
         error!("there is an issue while registering the handlebar template with avored: {actual_error:?}");
@@ -151,6 +187,20 @@ impl From<AddressError> for Error {
     }
 }
 
+impl From<totp_rs::TotpUrlError> for Error {
+    fn from(actual_error: totp_rs::TotpUrlError) -> Self {
+        error!("there is an issue while creating the totp: {actual_error:?}");
+        Error::Generic("totp error".to_string())
+    }
+}
+
+impl From<totp_rs::SecretParseError> for Error {
+    fn from(actual_error: totp_rs::SecretParseError) -> Self {
+        error!("there is an issue while reading the totp secret: {actual_error:?}");
+        Error::Generic("totp secret error".to_string())
+    }
+}
+
 impl IntoResponse for Error {
     fn into_response(self) -> Response {
 
@@ -160,6 +210,15 @@ impl IntoResponse for Error {
                 let error_message = format!("unauthorized: you do not have access to access this ({}) resource", resource_name);
                 (StatusCode::UNAUTHORIZED, error_message).into_response()
             },
+            Error::Unauthenticated(error_message) => {
+                (StatusCode::UNAUTHORIZED, error_message).into_response()
+            },
+            Error::NotFound(resource_name) => {
+                (StatusCode::NOT_FOUND, format!("{resource_name} not found")).into_response()
+            },
+            Error::TooManyRequests(error_message) => {
+                (StatusCode::TOO_MANY_REQUESTS, error_message).into_response()
+            },
             _ => (StatusCode::INTERNAL_SERVER_ERROR, "test 500").into_response(),
         }
     }
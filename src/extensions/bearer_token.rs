@@ -0,0 +1,26 @@
+use axum::http::HeaderMap;
+use tonic::metadata::MetadataMap;
+
+pub trait BearerToken {
+    fn get_bearer_token(&self) -> String;
+}
+
+impl BearerToken for HeaderMap {
+    fn get_bearer_token(&self) -> String {
+        self.get("authorization")
+            .and_then(|auth_value| auth_value.to_str().ok())
+            .and_then(|auth_value| auth_value.strip_prefix("Bearer "))
+            .unwrap_or_default()
+            .to_string()
+    }
+}
+
+impl BearerToken for MetadataMap {
+    fn get_bearer_token(&self) -> String {
+        self.get("authorization")
+            .and_then(|auth_value| auth_value.to_str().ok())
+            .and_then(|auth_value| auth_value.strip_prefix("Bearer "))
+            .unwrap_or_default()
+            .to_string()
+    }
+}
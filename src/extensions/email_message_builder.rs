@@ -1,37 +1,173 @@
 use lettre::Message;
-use lettre::message::{header, MessageBuilder, MultiPart, SinglePart};
+use lettre::message::header::ContentType;
+use lettre::message::{Attachment, MessageBuilder, MultiPart, SinglePart};
+use crate::models::email_model::EmailModel;
 
 pub trait EmailMessageBuilder {
     fn build_email_message(
         &self,
-        from_address: &str,
-        to_address: &str,
-        email_subject: &str,
-        forgot_password_email_content: String,
+        email_model: &EmailModel,
+        attachments: Vec<(String, ContentType, Vec<u8>)>,
     ) -> crate::error::Result<Message>;
 }
 
 
 impl EmailMessageBuilder for MessageBuilder {
+    /// multipart/alternative with a text and an html part, wrapped in
+    /// multipart/mixed when there are attachments. A missing text part is
+    /// derived from the html body. Attachments are file name, content type and content.
     fn build_email_message(
-        &self, 
-        from_address: &str, 
-        to_address: &str, 
-        email_subject: &str,
-        forgot_password_email_content: String,
+        &self,
+        email_model: &EmailModel,
+        attachments: Vec<(String, ContentType, Vec<u8>)>,
     ) -> crate::error::Result<Message> {
-        let message = Message::builder()
-            .from(from_address.parse()?)
-            .to(to_address.parse()?)
-            .subject(email_subject)
-            .multipart(
-                MultiPart::alternative()
-                    .singlepart(
-                        SinglePart::builder()
-                            .header(header::ContentType::TEXT_HTML)
-                            .body(forgot_password_email_content),
-                    ),
-            )?;
-        Ok(message)
-    }
-}
\ No newline at end of file
+        let text_email_content = match email_model.text_body.trim().is_empty() {
+            true => html_to_text(&email_model.body),
+            false => email_model.text_body.clone(),
+        };
+        let alternative = MultiPart::alternative_plain_html(text_email_content, email_model.body.clone());
+
+        let mut builder = Message::builder()
+            .from(email_model.from_address.parse()?)
+            .to(email_model.to_address.parse()?)
+            .subject(email_model.subject.clone());
+        if !email_model.reply_to.is_empty() {
+            builder = builder.reply_to(email_model.reply_to.parse()?);
+        }
+
+        if attachments.is_empty() {
+            return Ok(builder.multipart(alternative)?);
+        }
+
+        let mut mixed = MultiPart::mixed().multipart(alternative);
+        for (file_name, content_type, content) in attachments {
+            let part: SinglePart = Attachment::new(file_name).body(content, content_type);
+            mixed = mixed.singlepart(part);
+        }
+
+        Ok(builder.multipart(mixed)?)
+    }
+}
+
+/// Rough plain text version of an html email: drops head, style and script,
+/// keeps link targets and puts block elements on their own line.
+fn html_to_text(html: &str) -> String {
+    let mut text = String::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+        let end = match rest[start..].find('>') {
+            Some(end) => start + end,
+            None => {
+                rest = "";
+                break;
+            }
+        };
+        let raw_tag = rest[start + 1..end].trim();
+        let tag = raw_tag.to_ascii_lowercase();
+        rest = &rest[end + 1..];
+
+        let tag_name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_string();
+
+        if !tag.starts_with('/') && matches!(tag_name.as_str(), "head" | "style" | "script" | "title") {
+            let closing = format!("</{tag_name}");
+            rest = match rest.to_ascii_lowercase().find(&closing) {
+                Some(position) => {
+                    let after = &rest[position..];
+                    after.find('>').map(|close| &after[close + 1..]).unwrap_or("")
+                }
+                None => "",
+            };
+            continue;
+        }
+
+        if tag_name == "a" && !tag.starts_with('/') {
+            if let Some(href) = attribute_value(raw_tag, &tag, "href") {
+                text.push_str(&format!("[{href}] "));
+            }
+            continue;
+        }
+
+        if matches!(
+            tag_name.as_str(),
+            "br" | "p" | "div" | "tr" | "li" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "table"
+        ) {
+            text.push('\n');
+        }
+    }
+    text.push_str(rest);
+
+    let text = text
+        .replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#x27;", "'")
+        .replace("&#39;", "'")
+        .replace("&#x3D;", "=")
+        .replace("&#x60;", "`")
+        .replace("&copy;", "©")
+        .replace("&amp;", "&");
+
+    let mut lines: Vec<String> = vec![];
+    for line in text.lines() {
+        let line = line.split_whitespace().collect::<Vec<&str>>().join(" ");
+        if line.is_empty() && lines.last().is_none_or(|last| last.is_empty()) {
+            continue;
+        }
+        lines.push(line);
+    }
+
+    lines.join("\n").trim().to_string()
+}
+
+/// Finds the attribute in the lowercased tag and reads its value from the
+/// original one, so the value keeps its case.
+fn attribute_value(raw_tag: &str, tag: &str, name: &str) -> Option<String> {
+    let position = tag.find(&format!(" {name}="))?;
+    let value = &raw_tag[position + name.len() + 2..];
+    let quote = value.chars().next()?;
+    match quote {
+        '"' | '\'' => value[1..].split(quote).next().map(|value| value.to_string()),
+        _ => value.split_whitespace().next().map(|value| value.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::html_to_text;
+
+    #[test]
+    fn html_to_text_puts_nested_blocks_on_their_own_line() {
+        let html = "<div><p>Hello <b>Jane</b></p><ul><li>One</li><li>Two</li></ul></div>";
+
+        assert_eq!(html_to_text(html), "Hello Jane\n\nOne\n\nTwo");
+    }
+
+    #[test]
+    fn html_to_text_drops_an_unclosed_style_or_tag() {
+        assert_eq!(html_to_text("<p>Hi</p><style>p { color: red; }"), "Hi");
+        assert_eq!(html_to_text("<p>Hi <b"), "Hi");
+    }
+
+    #[test]
+    fn html_to_text_decodes_entities_once() {
+        let html = "Tom &amp; Jerry &lt;3 &quot;cheese&quot;&nbsp;&amp;lt;";
+
+        assert_eq!(html_to_text(html), "Tom & Jerry <3 \"cheese\" &lt;");
+    }
+
+    #[test]
+    fn html_to_text_reads_uppercase_tags() {
+        let html = "<HTML><HEAD><TITLE>Secret</TITLE></HEAD><BODY><P>Hello</P>\
+                    <A HREF=\"https://Example.com/Path\">link</A><BR>Bye</BODY></HTML>";
+
+        assert_eq!(html_to_text(html), "Hello\n[https://Example.com/Path] link\nBye");
+    }
+}
@@ -1,3 +1,5 @@
 pub mod email_message_builder;
 pub mod string_extension;
 pub mod tonic_request;
+pub mod bearer_token;
+pub mod xml_extension;
@@ -1,17 +1,104 @@
+use argon2::password_hash::SaltString;
+use argon2::{Algorithm, Argon2, PasswordHash, PasswordHasher, Version};
+use rand::Rng;
+use crate::providers::avored_config_provider::PasswordHashConfig;
 
 // Usually we don't postfix the extension, 
 // but naming only string might conflict so we use it.
 pub trait StringExtension {
     fn get_password_hash(
         &self,
-        password_salt: &str
+        password_hash_config: &PasswordHashConfig
     ) -> crate::error::Result<String>;
+
+    fn password_hash_needs_rehash(&self, password_hash_config: &PasswordHashConfig) -> bool;
 }
 
 
 impl StringExtension for String {
-    fn get_password_hash(&self, password_salt: &str) -> crate::error::Result<String> {
-        
-        Ok(String::from(password_salt))
+    /// Argon2id hash of the password with a random salt of its own.
+    fn get_password_hash(&self, password_hash_config: &PasswordHashConfig) -> crate::error::Result<String> {
+        let mut salt_bytes = [0u8; 16];
+        rand::rng().fill(&mut salt_bytes);
+        let salt = SaltString::encode_b64(&salt_bytes)?;
+
+        let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, password_hash_config.params.clone());
+
+        Ok(argon2.hash_password(self.as_bytes(), &salt)?.to_string())
+    }
+
+    /// True for a stored hash made with other argon2 parameters than the
+    /// configured ones, or with the salt every password shared before.
+    fn password_hash_needs_rehash(&self, password_hash_config: &PasswordHashConfig) -> bool {
+        let password_hash = match PasswordHash::new(self) {
+            Ok(password_hash) => password_hash,
+            Err(_) => return true,
+        };
+
+        let is_legacy_salt = !password_hash_config.legacy_salt.is_empty()
+            && password_hash
+                .salt
+                .is_some_and(|salt| salt.as_str() == password_hash_config.legacy_salt);
+        let params = &password_hash_config.params;
+        let is_same_params = password_hash.algorithm == Algorithm::Argon2id.ident()
+            && password_hash.version == Some(Version::V0x13.into())
+            && argon2::Params::try_from(&password_hash).is_ok_and(|hash_params| {
+                hash_params.m_cost() == params.m_cost()
+                    && hash_params.t_cost() == params.t_cost()
+                    && hash_params.p_cost() == params.p_cost()
+            });
+
+        is_legacy_salt || !is_same_params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use argon2::password_hash::SaltString;
+    use argon2::{Argon2, PasswordHasher};
+    use crate::providers::avored_config_provider::PasswordHashConfig;
+    use super::StringExtension;
+
+    const LEGACY_SALT: &str = "c29tZXNhbHRzb21lc2FsdA";
+
+    fn password_hash_config() -> PasswordHashConfig {
+        PasswordHashConfig {
+            params: argon2::Params::default(),
+            legacy_salt: String::from(LEGACY_SALT),
+        }
+    }
+
+    #[test]
+    fn hash_with_the_legacy_salt_needs_rehash() {
+        let salt = SaltString::from_b64(LEGACY_SALT).unwrap();
+        let password_hash = Argon2::default()
+            .hash_password(b"secret-password", &salt)
+            .unwrap()
+            .to_string();
+
+        assert!(password_hash.password_hash_needs_rehash(&password_hash_config()));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn hash_with_the_current_params_does_not_need_rehash() {
+        let password_hash = String::from("secret-password")
+            .get_password_hash(&password_hash_config())
+            .unwrap();
+
+        assert!(!password_hash.password_hash_needs_rehash(&password_hash_config()));
+    }
+
+    #[test]
+    fn hash_with_other_params_or_no_hash_needs_rehash() {
+        let weaker_config = PasswordHashConfig {
+            params: argon2::Params::new(8 * 1024, 1, 1, None).unwrap(),
+            legacy_salt: String::from(""),
+        };
+        let password_hash = String::from("secret-password")
+            .get_password_hash(&weaker_config)
+            .unwrap();
+
+        assert!(password_hash.password_hash_needs_rehash(&password_hash_config()));
+        assert!(String::from("not a hash").password_hash_needs_rehash(&password_hash_config()));
+    }
+}
@@ -1,12 +1,21 @@
+use axum::extract::ConnectInfo;
 use tonic::Request;
+use std::net::{IpAddr, SocketAddr};
 use crate::error::Error;
 use crate::models::token_claim_model::TokenClaims;
+use crate::providers::avored_config_provider::ClientIpConfig;
 
 pub trait TonicRequest {
     type Error;
     fn get_token_claim(
         &self
     ) -> crate::error::Result<TokenClaims>;
+
+    fn get_client_ip(&self, client_ip_config: &ClientIpConfig) -> String;
+
+    fn get_user_agent(&self) -> String;
+
+    fn get_bearer_token(&self) -> String;
 }
 
 
@@ -19,4 +28,115 @@ impl<R> TonicRequest for Request<R> {
             None => Err(Error::Unauthenticated(String::from("token is malformed")))
         }
     }
-}
\ No newline at end of file
+
+    /// The peer address, unless the peer is a trusted proxy. Then the proxy
+    /// header is walked from the end and the first address that is not a
+    /// trusted proxy itself is the client, anything before it is client supplied.
+    /// Behind axum the peer address is the `ConnectInfo` the server stores.
+    fn get_client_ip(&self, client_ip_config: &ClientIpConfig) -> String {
+        let peer_addr = self
+            .extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| *addr)
+            .or_else(|| self.remote_addr());
+        let peer_ip = match peer_addr {
+            Some(addr) => addr.ip(),
+            None => return String::from("unknown"),
+        };
+        if !client_ip_config.is_trusted_proxy(&peer_ip) {
+            return peer_ip.to_string();
+        }
+
+        self.metadata()
+            .get(client_ip_config.header.as_str())
+            .and_then(|header_value| header_value.to_str().ok())
+            .and_then(|header_value| {
+                header_value
+                    .rsplit(',')
+                    .filter_map(|ip| ip.trim().parse::<IpAddr>().ok())
+                    .find(|ip| !client_ip_config.is_trusted_proxy(ip))
+            })
+            .unwrap_or(peer_ip)
+            .to_string()
+    }
+
+    /// Browsers can not set `user-agent` on grpc-web calls, the client sends
+    /// `x-user-agent` instead.
+    fn get_user_agent(&self) -> String {
+        ["x-user-agent", "user-agent"]
+            .iter()
+            .filter_map(|header_name| self.metadata().get(*header_name))
+            .filter_map(|header_value| header_value.to_str().ok())
+            .map(|user_agent| user_agent.trim().to_string())
+            .find(|user_agent| !user_agent.is_empty())
+            .unwrap_or_else(|| String::from("unknown"))
+    }
+
+    /// Bearer token of a call the auth interceptor does not run for, empty
+    /// when there is none.
+    fn get_bearer_token(&self) -> String {
+        self.metadata()
+            .get("authorization")
+            .and_then(|header_value| header_value.to_str().ok())
+            .and_then(|header_value| header_value.strip_prefix("Bearer "))
+            .unwrap_or_default()
+            .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+    use axum::extract::ConnectInfo;
+    use axum::routing::get;
+    use axum::Router;
+    use tokio::net::TcpListener;
+    use crate::providers::avored_config_provider::ClientIpConfig;
+    use super::TonicRequest;
+
+    fn client_ip_config(trusted_proxies: &[&str]) -> ClientIpConfig {
+        ClientIpConfig {
+            header: String::from("x-forwarded-for"),
+            trusted_proxies: trusted_proxies.iter().map(|ip| ip.parse().unwrap()).collect(),
+        }
+    }
+
+    async fn client_ip(request: axum::extract::Request) -> String {
+        tonic::Request::from_http(request).get_client_ip(&client_ip_config(&[]))
+    }
+
+    #[tokio::test]
+    async fn client_ip_is_the_peer_address_of_the_connection() {
+        let rest_router = Router::new().route("/client-ip", get(client_ip));
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, crate::make_service(rest_router, Router::new())).await });
+
+        let client_ip = reqwest::Client::new()
+            .get(format!("http://{address}/client-ip"))
+            .header("x-forwarded-for", "203.0.113.7")
+            .send()
+            .await
+            .unwrap()
+            .text()
+            .await
+            .unwrap();
+
+        assert_eq!(client_ip, "127.0.0.1");
+    }
+
+    #[test]
+    fn forwarded_header_is_only_read_behind_a_trusted_proxy() {
+        let mut request = tonic::Request::new(());
+        request
+            .extensions_mut()
+            .insert(ConnectInfo("10.0.0.1:4000".parse::<SocketAddr>().unwrap()));
+        request
+            .metadata_mut()
+            .insert("x-forwarded-for", "198.51.100.1, 203.0.113.7, 10.0.0.2".parse().unwrap());
+
+        assert_eq!(request.get_client_ip(&client_ip_config(&[])), "10.0.0.1");
+        assert_eq!(request.get_client_ip(&client_ip_config(&["10.0.0.1", "10.0.0.2"])), "203.0.113.7");
+        assert_eq!(tonic::Request::new(()).get_client_ip(&client_ip_config(&[])), "unknown");
+    }
+}
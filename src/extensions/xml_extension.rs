@@ -0,0 +1,27 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+
+/// Escaping for the hand written xml of the sitemaps and the feeds.
+pub trait XmlExtension {
+    fn xml_escape(&self) -> String;
+}
+
+impl XmlExtension for str {
+    fn xml_escape(&self) -> String {
+        self.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+            .replace('\'', "&apos;")
+    }
+}
+
+/// W3C datetime, RFC 3339 in UTC with whole seconds, as sitemaps and Atom expect it.
+pub trait W3cDatetimeExtension {
+    fn to_w3c_datetime(&self) -> String;
+}
+
+impl W3cDatetimeExtension for DateTime<Utc> {
+    fn to_w3c_datetime(&self) -> String {
+        self.to_rfc3339_opts(SecondsFormat::Secs, true)
+    }
+}
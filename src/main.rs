@@ -1,7 +1,9 @@
 use std::env;
 use std::fs::File;
+use std::net::SocketAddr;
 use std::path::Path;
 use std::sync::Arc;
+use axum::extract::connect_info::IntoMakeServiceWithConnectInfo;
 use axum::http::{HeaderName, HeaderValue};
 use axum::response::Html;
 use axum::Router;
@@ -16,25 +18,42 @@ use crate::api::admin_user_api::AdminUserApi;
 use crate::api::asset_api::AssetApi;
 use crate::api::auth_api::AuthApi;
 use crate::api::cms_api::CmsApi;
+use crate::api::cms_api_key_api::CmsApiKeyApi;
 use crate::api::content_api::ContentApi;
 use crate::api::dashboard_api::DashboardApi;
+use crate::api::email_api::EmailApi;
+use crate::api::form_api::FormApi;
 use crate::api::general_api::GeneralApi;
 use crate::api::handlers::asset::store_asset_api_handler::store_asset_api_handler;
+use crate::api::handlers::cms::get_cms_content_api_handler::get_cms_content_api_handler;
+use crate::api::handlers::cms::watch_cms_content_api_handler::watch_cms_content_api_handler;
+use crate::api::handlers::feed::collection_feed_handler::collection_feed_handler;
+use crate::api::handlers::graphql::graphql_api_handler::graphql_api_handler;
+use crate::api::handlers::page::render_page_handler::render_page_handler;
+use crate::api::handlers::sitemap::robots_txt_handler::robots_txt_handler;
+use crate::api::handlers::sitemap::sitemap_handler::sitemap_handler;
+use crate::api::handlers::sitemap::sitemap_page_handler::sitemap_page_handler;
 use crate::api::misc_api::MiscApi;
 use crate::api::proto::admin_user::admin_user_server::AdminUserServer;
 use crate::api::proto::asset::asset_server::AssetServer;
 use crate::api::proto::auth::auth_server::AuthServer;
 use crate::api::proto::cms::cms_server::CmsServer;
+use crate::api::proto::cms_api_key::cms_api_key_server::CmsApiKeyServer;
 use crate::api::proto::content::content_server::ContentServer;
 use crate::api::proto::dashboard::dashboard_server::DashboardServer;
 use crate::api::proto::echo::test2_server::Test2Server;
+use crate::api::proto::email::email_server::EmailServer;
+use crate::api::proto::form::form_server::FormServer;
 use crate::api::proto::general::general_service_server::GeneralServiceServer;
 use crate::api::proto::misc::misc_server::MiscServer;
 use crate::api::proto::setting::setting_server::SettingServer;
+use crate::api::proto::webhook::webhook_server::WebhookServer;
 use crate::api::setting_api::SettingApi;
 use crate::api::test_api::Test2Api;
+use crate::api::webhook_api::WebhookApi;
 use crate::avored_state::AvoRedState;
 use crate::error::Error;
+use crate::middleware::conditional_request::conditional_request;
 use crate::middleware::grpc_auth_middleware::check_auth;
 use crate::middleware::require_jwt_authentication::require_jwt_authentication;
 
@@ -48,11 +67,22 @@ mod requests;
 mod repositories;
 mod middleware;
 mod extensions;
+#[cfg(test)]
+mod test_helpers;
 
 const PER_PAGE: u64 = 10;
 
 rust_i18n::i18n!("resources/locales");
 
+/// Splits requests between the rest and grpc routers and stores the peer
+/// address of the connection in the request extensions, where
+/// `get_client_ip` reads it from.
+fn make_service(rest_router: Router, grpc_router: Router) -> IntoMakeServiceWithConnectInfo<Router, SocketAddr> {
+    Router::new()
+        .fallback_service(RestGrpcService::new(rest_router, grpc_router))
+        .into_make_service_with_connect_info::<SocketAddr>()
+}
+
 async fn handler() -> Html<&'static str> {
     Html("<h1>Hello, AvoRed content management system!</h1>")
 }
@@ -63,16 +93,52 @@ async fn main() -> Result<(), Error>{
 
     let state = Arc::new(AvoRedState::new().await?);
 
+    let dispatcher_state = state.clone();
+    tokio::spawn(async move {
+        dispatcher_state
+            .webhook_service
+            .run_dispatcher(&dispatcher_state.db)
+            .await
+    });
+    let delivery_worker_state = state.clone();
+    tokio::spawn(async move {
+        delivery_worker_state
+            .webhook_service
+            .run_delivery_worker(&delivery_worker_state.db)
+            .await
+    });
+    let outbox_worker_state = state.clone();
+    tokio::spawn(async move {
+        outbox_worker_state
+            .email_service
+            .run_outbox_worker(&outbox_worker_state.db, &outbox_worker_state.template)
+            .await
+    });
+    let password_reset_cleanup_state = state.clone();
+    tokio::spawn(async move {
+        password_reset_cleanup_state
+            .auth_service
+            .run_password_reset_cleanup(&password_reset_cleanup_state.db)
+            .await
+    });
+
     let mut origins: Vec<HeaderValue> = vec![];
     for origin in &state.config.cors_allowed_app_url {
         origins.push(HeaderValue::from_str(origin).unwrap());
     }
 
     // const DEFAULT_MAX_AGE: Duration = Duration::from_secs(24 * 60 * 60);
-    const DEFAULT_EXPOSED_HEADERS: [&str; 3] =
-        ["grpc-status", "grpc-message", "grpc-status-details-bin"];
-    const DEFAULT_ALLOW_HEADERS: [&str; 5] =
-        ["x-grpc-web", "content-type", "x-user-agent", "grpc-timeout", "authorization"];
+    const DEFAULT_EXPOSED_HEADERS: [&str; 5] =
+        ["grpc-status", "grpc-message", "grpc-status-details-bin", "etag", "last-modified"];
+    const DEFAULT_ALLOW_HEADERS: [&str; 7] = [
+        "x-grpc-web",
+        "content-type",
+        "x-user-agent",
+        "grpc-timeout",
+        "authorization",
+        "if-none-match",
+        "if-modified-since",
+    ];
 
     let cors = CorsLayer::new()
         .allow_origin(origins)
@@ -103,28 +169,45 @@ async fn main() -> Result<(), Error>{
     let cms_server = CmsServer::new(cms_api);
 
     let dashboard_api = DashboardApi {state: state.clone()};
-    let dashboard_server = DashboardServer::with_interceptor(dashboard_api, check_auth);
+    let dashboard_server = DashboardServer::with_interceptor(dashboard_api, check_auth(state.clone()));
 
     let auth_api = AuthApi {state: state.clone()};
     let auth_server = AuthServer::new(auth_api);
 
     let admin_user_api = AdminUserApi {state: state.clone()};
-    let admin_user_server = AdminUserServer::with_interceptor(admin_user_api, check_auth);
+    let admin_user_server = AdminUserServer::with_interceptor(admin_user_api, check_auth(state.clone()));
 
     let content_api = ContentApi {state: state.clone()};
-    let content_server = ContentServer::with_interceptor(content_api, check_auth);
+    let content_server = ContentServer::with_interceptor(content_api, check_auth(state.clone()));
     
     let setting_api = SettingApi {state: state.clone()};
-    let setting_server = SettingServer::with_interceptor(setting_api, check_auth);
+    let setting_server = SettingServer::with_interceptor(setting_api, check_auth(state.clone()));
     
     let general_api = GeneralApi {state: state.clone()};
-    let general_server = GeneralServiceServer::with_interceptor(general_api, check_auth);
+    let general_server = GeneralServiceServer::with_interceptor(general_api, check_auth(state.clone()));
 
     let asset_api = AssetApi {state: state.clone()};
-    let asset_server = AssetServer::with_interceptor(asset_api, check_auth);
+    let asset_server = AssetServer::with_interceptor(asset_api, check_auth(state.clone()));
+
+    let cms_api_key_api = CmsApiKeyApi {state: state.clone()};
+    let cms_api_key_server = CmsApiKeyServer::with_interceptor(cms_api_key_api, check_auth(state.clone()));
+
+    let webhook_api = WebhookApi {state: state.clone()};
+    let webhook_server = WebhookServer::with_interceptor(webhook_api, check_auth(state.clone()));
+
+    let form_api = FormApi {state: state.clone()};
+    let form_server = FormServer::with_interceptor(form_api, check_auth(state.clone()));
+
+    let email_api = EmailApi {state: state.clone()};
+    let email_server = EmailServer::with_interceptor(email_api, check_auth(state.clone()));
 
 
 
+    // Only the cms reads send validators, so only they answer conditional requests.
+    let cms_grpc_router = Router::new()
+        .nest_tonic(cms_server)
+        .layer(axum::middleware::from_fn(conditional_request));
+
     let grpc_router = Router::new()
         .nest_tonic(test_server)
         .nest_tonic(misc_server)
@@ -135,11 +218,16 @@ async fn main() -> Result<(), Error>{
         .nest_tonic(setting_server)
         .nest_tonic(general_server)
         .nest_tonic(asset_server)
-        .nest_tonic(cms_server)
+        .merge(cms_grpc_router)
+        .nest_tonic(cms_api_key_server)
+        .nest_tonic(webhook_server)
+        .nest_tonic(form_server)
+        .nest_tonic(email_server)
         .layer(cors.clone());
 
 
     let static_routing_service = ServeDir::new("public");
+    let theme_assets_routing_service = ServeDir::new(state.theme.theme_path.join("assets"));
 
     let rest_router = Router::new()
         .route("/", get(handler))
@@ -148,12 +236,25 @@ async fn main() -> Result<(), Error>{
             state.clone(),
             require_jwt_authentication,
         ))
+        .route("/graphql", post(graphql_api_handler))
+        .route(
+            "/cms/content/{content_type}/{content_identifier}",
+            get(get_cms_content_api_handler).layer(axum::middleware::from_fn(conditional_request)),
+        )
+        .route("/cms/events", get(watch_cms_content_api_handler))
+        .route("/sitemap.xml", get(sitemap_handler))
+        .route("/sitemaps/{file_name}", get(sitemap_page_handler))
+        .route("/robots.txt", get(robots_txt_handler))
+        .route("/feeds/{file_name}", get(collection_feed_handler))
+        .route(
+            "/{collection}/{identifier}",
+            get(render_page_handler).layer(axum::middleware::from_fn(conditional_request)),
+        )
         .nest_service("/public", static_routing_service)
+        .nest_service(&state.theme.assets_url(), theme_assets_routing_service)
         .with_state(state)
         .layer(cors);
 
-    let service = RestGrpcService::new(rest_router, grpc_router);
-
     let port = env::var("PORT").unwrap_or("50051".to_string());
 
     let listener = tokio::net::TcpListener::bind(format!("127.0.0.1:{}", port))
@@ -171,7 +272,7 @@ async fn main() -> Result<(), Error>{
 
     println!("Server started: http://0.0.0.0:{}", port);
 
-    axum::serve(listener ,service.into_make_service())
+    axum::serve(listener, make_service(rest_router, grpc_router))
         .await
         .unwrap();
 
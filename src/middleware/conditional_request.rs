@@ -0,0 +1,137 @@
+use axum::body::Body;
+use axum::http::header::{CACHE_CONTROL, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use axum::http::{HeaderMap, HeaderValue, Request, StatusCode};
+use axum::middleware::Next;
+use axum::response::Response;
+use chrono::DateTime;
+
+/// Answers conditional requests with `304 Not Modified` when the handler
+/// responded with an `ETag` / `Last-Modified` the client already holds.
+/// Works for both the REST JSON routes and the gRPC-web services, as tonic
+/// sends response metadata as plain HTTP headers.
+pub async fn conditional_request(req: Request<Body>, next: Next) -> Response {
+    let if_none_match = req.headers().get(IF_NONE_MATCH).cloned();
+    let if_modified_since = req.headers().get(IF_MODIFIED_SINCE).cloned();
+
+    let response = next.run(req).await;
+
+    if response.status() != StatusCode::OK
+        || !is_not_modified(response.headers(), if_none_match, if_modified_since)
+    {
+        return response;
+    }
+
+    let mut not_modified = Response::new(Body::empty());
+    *not_modified.status_mut() = StatusCode::NOT_MODIFIED;
+    for header in [ETAG, LAST_MODIFIED, CACHE_CONTROL] {
+        if let Some(value) = response.headers().get(&header) {
+            not_modified.headers_mut().insert(header, value.clone());
+        }
+    }
+
+    not_modified
+}
+
+fn is_not_modified(
+    response_headers: &HeaderMap,
+    if_none_match: Option<HeaderValue>,
+    if_modified_since: Option<HeaderValue>,
+) -> bool {
+    // If-None-Match takes precedence, If-Modified-Since is only checked without it.
+    if let Some(if_none_match) = if_none_match {
+        let etag = match response_headers.get(ETAG).and_then(|v| v.to_str().ok()) {
+            Some(etag) => etag,
+            None => return false,
+        };
+
+        return if_none_match
+            .to_str()
+            .unwrap_or_default()
+            .split(',')
+            .map(|candidate| candidate.trim())
+            .any(|candidate| candidate == "*" || weak_compare(candidate, etag));
+    }
+
+    if let Some(if_modified_since) = if_modified_since {
+        let last_modified = response_headers
+            .get(LAST_MODIFIED)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| DateTime::parse_from_rfc2822(v).ok());
+        let since = if_modified_since
+            .to_str()
+            .ok()
+            .and_then(|v| DateTime::parse_from_rfc2822(v).ok());
+
+        return match (last_modified, since) {
+            (Some(last_modified), Some(since)) => last_modified <= since,
+            _ => false,
+        };
+    }
+
+    false
+}
+
+fn weak_compare(left: &str, right: &str) -> bool {
+    left.trim_start_matches("W/") == right.trim_start_matches("W/")
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::header::{ETAG, LAST_MODIFIED};
+    use axum::http::{HeaderMap, HeaderValue};
+    use super::{is_not_modified, weak_compare};
+
+    const LAST_MODIFIED_DATE: &str = "Wed, 21 Oct 2026 07:28:00 GMT";
+
+    fn response_headers() -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(ETAG, HeaderValue::from_static("\"content:1-abc\""));
+        headers.insert(LAST_MODIFIED, HeaderValue::from_static(LAST_MODIFIED_DATE));
+
+        headers
+    }
+
+    fn header(value: &'static str) -> Option<HeaderValue> {
+        Some(HeaderValue::from_static(value))
+    }
+
+    #[test]
+    fn weak_compare_ignores_the_weak_prefix() {
+        assert!(weak_compare("W/\"abc\"", "\"abc\""));
+        assert!(weak_compare("\"abc\"", "W/\"abc\""));
+        assert!(!weak_compare("\"abc\"", "\"abd\""));
+    }
+
+    #[test]
+    fn if_none_match_matches_a_list_weak_tags_and_any() {
+        let headers = response_headers();
+
+        assert!(is_not_modified(&headers, header("\"other\", \"content:1-abc\""), None));
+        assert!(is_not_modified(&headers, header("W/\"content:1-abc\""), None));
+        assert!(is_not_modified(&headers, header("*"), None));
+        assert!(!is_not_modified(&headers, header("\"other\", W/\"content:2-abc\""), None));
+        assert!(!is_not_modified(&HeaderMap::new(), header("*"), None));
+    }
+
+    #[test]
+    fn if_modified_since_compares_the_dates() {
+        let headers = response_headers();
+
+        assert!(is_not_modified(&headers, None, header(LAST_MODIFIED_DATE)));
+        assert!(is_not_modified(&headers, None, header("Thu, 22 Oct 2026 07:28:00 GMT")));
+        assert!(!is_not_modified(&headers, None, header("Tue, 20 Oct 2026 07:28:00 GMT")));
+        assert!(!is_not_modified(&headers, None, header("not a date")));
+    }
+
+    #[test]
+    fn if_none_match_takes_precedence_over_if_modified_since() {
+        let headers = response_headers();
+
+        assert!(!is_not_modified(&headers, header("\"other\""), header(LAST_MODIFIED_DATE)));
+        assert!(is_not_modified(
+            &headers,
+            header("\"content:1-abc\""),
+            header("Tue, 20 Oct 2026 07:28:00 GMT")
+        ));
+    }
+}
@@ -1,31 +1,27 @@
-use std::env;
-use jsonwebtoken::{decode, DecodingKey, Validation};
+use std::sync::Arc;
 use tonic::{Request, Status};
-use crate::error::Error;
-use crate::models::token_claim_model::TokenClaims;
+use crate::avored_state::AvoRedState;
 
-pub fn check_auth(mut req: Request<()>) -> Result<Request<()>, Status> {
+/// Interceptor for the admin services: the bearer token has to be a valid
+/// access token of a session that has not been revoked.
+pub fn check_auth(
+    state: Arc<AvoRedState>,
+) -> impl Fn(Request<()>) -> Result<Request<()>, Status> + Clone {
+    move |mut req: Request<()>| {
+        match req.metadata().get("authorization") {
+            Some(t) => {
+                let auth_value = t.to_str()
+                    .map_err(|_e| Status::unavailable("authorization header value is not valid string"))?;
 
-    match req.metadata().get("authorization") {
-        Some(t) => {
-            let auth_value = t.to_str()
-                .map_err(|_e| Status::unavailable("authorization header value is not valid string"))?;
+                let token = auth_value.strip_prefix("Bearer ").unwrap_or_default();
+                let claims = state
+                    .auth_service
+                    .verify_access_token(token, &state.config.jwt_secret_key)?;
+                req.extensions_mut().insert(claims);
 
-            let jwt_token = &env::var("AVORED_JWT_SECRET")
-                    .map_err(|_| Error::ConfigMissing("AVORED_JWT_SECRET".to_string()))?;
-            let token = auth_value.strip_prefix("Bearer ").map(|auth| auth.to_owned());
-            let claims = decode::<TokenClaims>(
-                &token.unwrap_or_default(),
-                &DecodingKey::from_secret(jwt_token.as_ref()),
-                &Validation::default(),
-            ).map_err(|_| {
-                Status::unauthenticated("No valid auth token claims found")
-            })?
-                .claims;
-            req.extensions_mut().insert(claims);
-
-            Ok(req)
-        },
-        _ => Err(Status::unauthenticated("No valid auth token")),
+                Ok(req)
+            },
+            _ => Err(Status::unauthenticated("No valid auth token")),
+        }
     }
 }
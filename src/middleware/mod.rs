@@ -1,4 +1,4 @@
 pub mod require_jwt_authentication;
-// pub mod validate_cms_authentication;
 
 pub mod grpc_auth_middleware;
+pub mod conditional_request;
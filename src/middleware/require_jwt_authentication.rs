@@ -1,13 +1,13 @@
-use std::env;
+use std::sync::Arc;
 use axum::body::Body;
+use axum::extract::State;
 use axum::http::{StatusCode};
 use axum::response::IntoResponse;
 use axum::{http::Request, middleware::Next, Json};
-use crate::models::token_claim_model::{LoggedInUser, TokenClaims};
-use jsonwebtoken::{decode, DecodingKey, Validation};
+use crate::avored_state::AvoRedState;
+use crate::models::admin_user_model::AdminUserModelExtension;
+use crate::models::token_claim_model::LoggedInUser;
 use serde::Serialize;
-use tonic::Status;
-use crate::error::Error;
 
 #[derive(Debug, Serialize, Default)]
 pub struct ErrorResponse {
@@ -16,34 +16,42 @@ pub struct ErrorResponse {
 }
 
 pub async fn require_jwt_authentication(
+    State(state): State<Arc<AvoRedState>>,
     mut req: Request<Body>,
     next: Next,
 ) -> Result<impl IntoResponse, (StatusCode, Json<ErrorResponse>)> {
 
     match req.headers().get("authorization") {
         Some(t) => {
+            let unauthorized = |message: &str| {
+                let json_error = ErrorResponse {
+                    status: false,
+                    message: message.to_string(),
+                };
+                (StatusCode::UNAUTHORIZED, Json(json_error))
+            };
+
             let auth_value = t.to_str()
-                .map_err(|_e| Status::unavailable("authorization header value is not valid string")).unwrap();
+                .map_err(|_e| unauthorized("authorization header value is not valid string"))?;
+
+            let token = auth_value.strip_prefix("Bearer ").unwrap_or_default();
+            let claims = state
+                .auth_service
+                .verify_access_token(token, &state.config.jwt_secret_key)
+                .map_err(|_| unauthorized("Invalid or revoked token"))?;
 
-            let jwt_token = &env::var("AVORED_JWT_SECRET")
-                .map_err(|_| Error::ConfigMissing("AVORED_JWT_SECRET".to_string())).unwrap();
-            let token = auth_value.strip_prefix("Bearer ").map(|auth| auth.to_owned());
-            let claims = decode::<TokenClaims>(
-                &token.unwrap_or_default(),
-                &DecodingKey::from_secret(jwt_token.as_ref()),
-                &Validation::default(),
-            ).map_err(|_| {
-                Status::unauthenticated("No valid auth token claims found")
-            }).unwrap()
-                .claims;
+            let admin_user_model = claims
+                .logged_in_admin_user(&state.admin_user_service, &state.db)
+                .await
+                .map_err(|_| unauthorized("Invalid or revoked token"))?;
 
             let file_exist = true;
             let logged_in_user = LoggedInUser {
-                id: claims.sub,
-                name: claims.name,
-                email: claims.email,
+                id: admin_user_model.id.clone(),
+                name: admin_user_model.full_name.clone(),
+                email: admin_user_model.email.clone(),
                 demo_data_status: file_exist,
-                admin_user_model: claims.admin_user_model,
+                admin_user_model,
             };
             req.extensions_mut().insert(logged_in_user);
 
@@ -2,9 +2,11 @@ use std::time::SystemTime;
 use prost_types::Timestamp;
 use crate::error::{Error, Result};
 use crate::services::admin_user_service::AdminUserService;
+use crate::models::token_claim_model::TokenClaims;
+use crate::providers::avored_cache_provider::AvoRedCacheProvider;
+use crate::providers::avored_database_provider::DB;
 use serde::{Deserialize, Serialize};
 use surrealdb::sql::{Datetime, Object, Value};
-use crate::models::token_claim_model::TokenClaims;
 use super::{BaseModel, Pagination};
 use crate::api::proto::admin_user::{AdminUserModel as GrpcAdminUserModel};
 use crate::models::role_model::RoleModel;
@@ -25,27 +27,12 @@ pub struct AdminUserModel {
     pub roles: Vec<RoleModel>,
 }
 
-// region: impl try_from AdminUserModel
-impl TryFrom<AdminUserModel> for TokenClaims {
-    type Error = Error;
-
-    fn try_from(val: AdminUserModel) -> Result<TokenClaims> {
-        let now = chrono::Utc::now();
-        let iat = now.timestamp() as usize;
-        let exp = (now + chrono::Duration::minutes(60)).timestamp() as usize;
-        let claims: TokenClaims = TokenClaims {
-            sub: val.clone().id,
-            name: val.clone().full_name,
-            email: val.clone().email,
-            admin_user_model: val,
-            exp,
-            iat,
-        };
-
-        Ok(claims)
-    }
-}
+/// Logged in admin users by id, so roles and permissions are not read from
+/// the database for every request. Entries live for a few seconds and are
+/// dropped when the admin user or a role changes.
+pub type AdminUserCache = AvoRedCacheProvider<String, AdminUserModel>;
 
+// region: impl try_from AdminUserModel
 impl TryFrom<AdminUserModel> for  GrpcAdminUserModel {
     type Error = Error;
 
@@ -173,26 +160,42 @@ pub struct AdminUserPagination {
 
 
 pub trait AdminUserModelExtension {
+    /// The admin user of the token as they are now, with their current roles.
+    async fn logged_in_admin_user(
+        &self,
+        admin_user_service: &AdminUserService,
+        db: &DB,
+    ) -> crate::error::Result<AdminUserModel>;
+
+    /// Returns the logged in admin user when one of their roles has the
+    /// permission, roles are resolved fresh rather than taken from the token.
     async fn check_user_has_resouce_access(
         &self,
         admin_user_service: &AdminUserService,
+        db: &DB,
         permission_identifier: String
-    ) -> crate::error::Result<()>;
+    ) -> crate::error::Result<AdminUserModel>;
 }
 
 
 
-impl AdminUserModelExtension for AdminUserModel {
-    async fn check_user_has_resouce_access(&self, admin_user_service: &AdminUserService, permission_identifier: String) -> crate::error::Result<()> {
+impl AdminUserModelExtension for TokenClaims {
+    async fn logged_in_admin_user(&self, admin_user_service: &AdminUserService, db: &DB) -> crate::error::Result<AdminUserModel> {
+        admin_user_service
+            .find_logged_in_admin_user(db, &self.sub)
+            .await
+    }
+
+    async fn check_user_has_resouce_access(&self, admin_user_service: &AdminUserService, db: &DB, permission_identifier: String) -> crate::error::Result<AdminUserModel> {
         
-        let logged_in_user = self.clone();
+        let logged_in_user = self.logged_in_admin_user(admin_user_service, db).await?;
          let has_permission_bool = admin_user_service
-            .has_permission(logged_in_user, permission_identifier.clone())
+            .has_permission(logged_in_user.clone(), permission_identifier.clone())
             .await?;
         if !has_permission_bool {
             return Err(crate::error::Error::Unauthorizeed(permission_identifier));
         }
 
-        Ok(())
+        Ok(logged_in_user)
     }
-}
\ No newline at end of file
+}
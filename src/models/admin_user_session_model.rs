@@ -0,0 +1,197 @@
+use std::time::SystemTime;
+use prost_types::Timestamp;
+use serde::{Deserialize, Serialize};
+use surrealdb::sql::{Datetime, Object};
+use crate::error::{Error, Result};
+use super::BaseModel;
+
+/// A login of an admin user. Every refresh token issued since that login
+/// belongs to it, revoking it ends the whole chain of tokens.
+#[derive(Serialize, Debug, Deserialize, Clone, Default)]
+pub struct AdminUserSessionModel {
+    pub id: String,
+    pub admin_user_id: String,
+    pub status: AdminUserSessionStatus,
+    pub user_agent: String,
+    pub ip_address: String,
+    pub last_activity_at: Datetime,
+    pub expires_at: Datetime,
+    pub created_at: Datetime,
+    pub updated_at: Datetime,
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, Default, PartialEq)]
+pub enum AdminUserSessionStatus {
+    #[default]
+    Active,
+    Revoked,
+}
+
+impl AdminUserSessionModel {
+    pub fn is_active(&self) -> bool {
+        self.status == AdminUserSessionStatus::Active && self.expires_at.to_utc() > chrono::Utc::now()
+    }
+}
+
+impl TryFrom<String> for AdminUserSessionStatus {
+    type Error = Error;
+
+    fn try_from(val: String) -> Result<AdminUserSessionStatus> {
+        let status = match val.as_str() {
+            "Revoked" => AdminUserSessionStatus::Revoked,
+            _ => AdminUserSessionStatus::Active,
+        };
+
+        Ok(status)
+    }
+}
+
+impl TryFrom<Object> for AdminUserSessionModel {
+    type Error = Error;
+
+    fn try_from(val: Object) -> Result<AdminUserSessionModel> {
+        let id = val.get("id").get_id()?;
+        let admin_user_id = val.get("admin_user_id").get_string()?;
+        let status = val.get("status").get_string()?.try_into()?;
+        let user_agent = val.get("user_agent").get_string()?;
+        let ip_address = val.get("ip_address").get_string()?;
+        let last_activity_at = val.get("last_activity_at").get_datetime()?;
+        let expires_at = val.get("expires_at").get_datetime()?;
+        let created_at = val.get("created_at").get_datetime()?;
+        let updated_at = val.get("updated_at").get_datetime()?;
+
+        Ok(AdminUserSessionModel {
+            id,
+            admin_user_id,
+            status,
+            user_agent,
+            ip_address,
+            last_activity_at,
+            expires_at,
+            created_at,
+            updated_at,
+        })
+    }
+}
+
+impl TryFrom<AdminUserSessionModel> for crate::api::proto::admin_user::AdminUserSessionModel {
+    type Error = Error;
+
+    fn try_from(val: AdminUserSessionModel) -> Result<crate::api::proto::admin_user::AdminUserSessionModel> {
+        let last_activity_at = Timestamp::from(SystemTime::from(val.last_activity_at.to_utc()));
+        let expires_at = Timestamp::from(SystemTime::from(val.expires_at.to_utc()));
+        let created_at = Timestamp::from(SystemTime::from(val.created_at.to_utc()));
+
+        let model = crate::api::proto::admin_user::AdminUserSessionModel {
+            id: val.id,
+            admin_user_id: val.admin_user_id,
+            user_agent: val.user_agent,
+            ip_address: val.ip_address,
+            is_current: false,
+            last_activity_at: Some(last_activity_at),
+            expires_at: Some(expires_at),
+            created_at: Some(created_at),
+        };
+
+        Ok(model)
+    }
+}
+
+/// Where a login came from, kept on its session.
+#[derive(Serialize, Debug, Deserialize, Clone, Default)]
+pub struct CreatableAdminUserSessionModel {
+    pub admin_user_id: String,
+    pub user_agent: String,
+    pub ip_address: String,
+    pub expires_at: Datetime,
+}
+
+/// Only the sha256 of a refresh token is stored. A token is `Used` once it
+/// has been swapped for a new one, presenting it again means it leaked.
+/// Tokens of sessions that were ended are `Revoked`.
+#[derive(Serialize, Debug, Deserialize, Clone, Default)]
+pub struct RefreshTokenModel {
+    pub id: String,
+    pub session_id: String,
+    pub token_hash: String,
+    pub status: RefreshTokenStatus,
+    pub expires_at: Datetime,
+    pub created_at: Datetime,
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, Default, PartialEq)]
+pub enum RefreshTokenStatus {
+    #[default]
+    Active,
+    Used,
+    Revoked,
+}
+
+impl TryFrom<String> for RefreshTokenStatus {
+    type Error = Error;
+
+    fn try_from(val: String) -> Result<RefreshTokenStatus> {
+        let status = match val.as_str() {
+            "Used" => RefreshTokenStatus::Used,
+            "Revoked" => RefreshTokenStatus::Revoked,
+            _ => RefreshTokenStatus::Active,
+        };
+
+        Ok(status)
+    }
+}
+
+impl TryFrom<Object> for RefreshTokenModel {
+    type Error = Error;
+
+    fn try_from(val: Object) -> Result<RefreshTokenModel> {
+        let id = val.get("id").get_id()?;
+        let session_id = val.get("session_id").get_string()?;
+        let token_hash = val.get("token_hash").get_string()?;
+        let status = val.get("status").get_string()?.try_into()?;
+        let expires_at = val.get("expires_at").get_datetime()?;
+        let created_at = val.get("created_at").get_datetime()?;
+
+        Ok(RefreshTokenModel {
+            id,
+            session_id,
+            token_hash,
+            status,
+            expires_at,
+            created_at,
+        })
+    }
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, Default)]
+pub struct CreatableRefreshTokenModel {
+    pub session_id: String,
+    pub token_hash: String,
+    pub expires_at: Datetime,
+}
+
+/// What a login or a refresh hands back to the client. A login that still
+/// needs a two factor code only carries the `two_factor_token`.
+#[derive(Serialize, Debug, Deserialize, Clone, Default)]
+pub struct AuthTokenModel {
+    pub access_token: String,
+    pub refresh_token: String,
+    pub expires_in: i64,
+    pub two_factor_required: bool,
+    pub two_factor_setup_required: bool,
+    pub two_factor_token: String,
+}
+
+impl From<AuthTokenModel> for crate::api::proto::auth::LoginResponse {
+    fn from(val: AuthTokenModel) -> Self {
+        crate::api::proto::auth::LoginResponse {
+            status: true,
+            data: val.access_token,
+            refresh_token: val.refresh_token,
+            expires_in: val.expires_in,
+            two_factor_required: val.two_factor_required,
+            two_factor_token: val.two_factor_token,
+            two_factor_setup_required: val.two_factor_setup_required,
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use rand::distr::Alphanumeric;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use surrealdb::sql::{Datetime, Object, Value};
+use totp_rs::{Algorithm, Secret, TOTP};
+use crate::error::{Error, Result};
+use super::BaseModel;
+
+/// Issuer shown next to the account in authenticator apps.
+const TOTP_ISSUER: &str = "AvoRed";
+const TOTP_DIGITS: usize = 6;
+const TOTP_STEP_SECONDS: u64 = 30;
+
+const RECOVERY_CODE_COUNT: usize = 10;
+const RECOVERY_CODE_LENGTH: usize = 10;
+
+/// TOTP (RFC 6238) secret of an admin user, stored under the id of the admin
+/// user. It is only `is_enabled` once a first code has been confirmed. Recovery
+/// codes are kept as sha256 hashes and removed when used.
+#[derive(Serialize, Debug, Deserialize, Clone, Default)]
+pub struct AdminUserTwoFactorModel {
+    pub id: String,
+    pub admin_user_id: String,
+    pub secret: String,
+    pub is_enabled: bool,
+    pub recovery_codes: Vec<String>,
+    pub last_used_step: i64,
+    pub created_at: Datetime,
+    pub updated_at: Datetime,
+}
+
+impl AdminUserTwoFactorModel {
+    pub fn totp(&self, email: &str) -> Result<TOTP> {
+        build_totp(Secret::Encoded(self.secret.clone()).to_bytes()?, email)
+    }
+
+    /// Time step the code is valid for, allowing one step of clock drift either
+    /// way. Steps up to `last_used_step` are refused so a code works only once.
+    pub fn verify_code(&self, email: &str, code: &str) -> Result<Option<i64>> {
+        let totp = self.totp(email)?;
+        let now = chrono::Utc::now().timestamp() as u64;
+
+        for drift in [-1i64, 0, 1] {
+            let time = now.saturating_add_signed(drift * TOTP_STEP_SECONDS as i64);
+            let step = (time / TOTP_STEP_SECONDS) as i64;
+            if step > self.last_used_step && totp.check(code, time) {
+                return Ok(Some(step));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+impl TryFrom<Object> for AdminUserTwoFactorModel {
+    type Error = Error;
+
+    fn try_from(val: Object) -> Result<AdminUserTwoFactorModel> {
+        let id = val.get("id").get_id()?;
+        let admin_user_id = val.get("admin_user_id").get_string()?;
+        let secret = val.get("secret").get_string()?;
+        let is_enabled = val.get("is_enabled").get_bool()?;
+        let recovery_codes = match val.get("recovery_codes") {
+            Some(Value::Array(v)) => v.iter().map(|item| item.clone().as_string()).collect(),
+            _ => Vec::new(),
+        };
+        let last_used_step = val.get("last_used_step").get_int()?;
+        let created_at = val.get("created_at").get_datetime()?;
+        let updated_at = val.get("updated_at").get_datetime()?;
+
+        Ok(AdminUserTwoFactorModel {
+            id,
+            admin_user_id,
+            secret,
+            is_enabled,
+            recovery_codes,
+            last_used_step,
+            created_at,
+            updated_at,
+        })
+    }
+}
+
+/// A new random secret with its provisioning uri for the given email.
+pub fn generate_totp(email: &str) -> Result<TOTP> {
+    build_totp(Secret::generate_secret().to_bytes()?, email)
+}
+
+fn build_totp(secret: Vec<u8>, email: &str) -> Result<TOTP> {
+    // ':' separates the issuer from the account in the provisioning uri
+    let account_name = email.replace(':', "");
+
+    Ok(TOTP::new(
+        Algorithm::SHA1,
+        TOTP_DIGITS,
+        0,
+        TOTP_STEP_SECONDS,
+        secret,
+        Some(TOTP_ISSUER.to_string()),
+        account_name,
+    )?)
+}
+
+pub fn generate_recovery_codes() -> Vec<String> {
+    (0..RECOVERY_CODE_COUNT)
+        .map(|_| {
+            rand::rng()
+                .sample_iter(&Alphanumeric)
+                .take(RECOVERY_CODE_LENGTH)
+                .map(char::from)
+                .collect::<String>()
+                .to_lowercase()
+        })
+        .collect()
+}
+
+/// Recovery codes are compared case insensitive and without separators.
+pub fn hash_recovery_code(recovery_code: &str) -> String {
+    let normalized: String = recovery_code
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect::<String>()
+        .to_lowercase();
+
+    hex::encode(Sha256::digest(normalized.as_bytes()))
+}
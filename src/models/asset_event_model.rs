@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use surrealdb::sql::Datetime;
+use crate::models::asset_model::AssetModel;
+use crate::providers::avored_event_bus_provider::AvoRedEventBusProvider;
+
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum AssetEventType {
+    Uploaded,
+    Deleted,
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone)]
+pub struct AssetEventModel {
+    pub event_type: AssetEventType,
+    pub asset_id: String,
+    pub name: String,
+    pub path: String,
+    pub asset_type: String,
+    pub occurred_at: Datetime,
+}
+
+pub type AssetEventBus = AvoRedEventBusProvider<AssetEventModel>;
+
+impl AssetEventModel {
+    pub fn new(event_type: AssetEventType, asset_model: &AssetModel) -> AssetEventModel {
+        AssetEventModel {
+            event_type,
+            asset_id: asset_model.id.clone(),
+            name: asset_model.name.clone(),
+            path: asset_model.new_path.clone(),
+            asset_type: asset_model.asset_type.clone(),
+            occurred_at: Datetime::default(),
+        }
+    }
+}
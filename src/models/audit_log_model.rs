@@ -0,0 +1,100 @@
+use std::time::SystemTime;
+use prost_types::Timestamp;
+use serde::{Deserialize, Serialize};
+use surrealdb::sql::{Datetime, Object};
+use crate::error::{Error, Result};
+use super::BaseModel;
+
+/// Security relevant event, `email` is the account it is about and
+/// `created_by` who caused it.
+#[derive(Serialize, Debug, Deserialize, Clone, Default)]
+pub struct AuditLogModel {
+    pub id: String,
+    pub action: AuditLogAction,
+    pub email: String,
+    pub ip_address: String,
+    pub detail: String,
+    pub created_by: String,
+    pub created_at: Datetime,
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, Default, PartialEq)]
+pub enum AuditLogAction {
+    #[default]
+    LoginLockout,
+    LoginUnlock,
+}
+
+impl AuditLogAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditLogAction::LoginLockout => "LoginLockout",
+            AuditLogAction::LoginUnlock => "LoginUnlock",
+        }
+    }
+}
+
+impl TryFrom<String> for AuditLogAction {
+    type Error = Error;
+
+    fn try_from(val: String) -> Result<AuditLogAction> {
+        match val.as_str() {
+            "LoginLockout" => Ok(AuditLogAction::LoginLockout),
+            "LoginUnlock" => Ok(AuditLogAction::LoginUnlock),
+            _ => Err(Error::Generic(format!("unknown audit log action: {val}"))),
+        }
+    }
+}
+
+impl TryFrom<Object> for AuditLogModel {
+    type Error = Error;
+
+    fn try_from(val: Object) -> Result<AuditLogModel> {
+        let id = val.get("id").get_id()?;
+        let action = val.get("action").get_string()?.try_into()?;
+        let email = val.get("email").get_string()?;
+        let ip_address = val.get("ip_address").get_string()?;
+        let detail = val.get("detail").get_string()?;
+        let created_by = val.get("created_by").get_string()?;
+        let created_at = val.get("created_at").get_datetime()?;
+
+        Ok(AuditLogModel {
+            id,
+            action,
+            email,
+            ip_address,
+            detail,
+            created_by,
+            created_at,
+        })
+    }
+}
+
+impl TryFrom<AuditLogModel> for crate::api::proto::admin_user::AuditLogModel {
+    type Error = Error;
+
+    fn try_from(val: AuditLogModel) -> Result<crate::api::proto::admin_user::AuditLogModel> {
+        let created_at = Timestamp::from(SystemTime::from(val.created_at.to_utc()));
+
+        let model = crate::api::proto::admin_user::AuditLogModel {
+            id: val.id,
+            action: val.action.as_str().to_string(),
+            email: val.email,
+            ip_address: val.ip_address,
+            detail: val.detail,
+            created_by: val.created_by,
+            created_at: Some(created_at),
+        };
+
+        Ok(model)
+    }
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, Default)]
+pub struct CreatableAuditLogModel {
+    pub action: AuditLogAction,
+    pub email: String,
+    pub ip_address: String,
+    pub detail: String,
+    pub created_by: String,
+}
@@ -0,0 +1,177 @@
+use std::time::SystemTime;
+use prost_types::Timestamp;
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use surrealdb::sql::{Datetime, Object, Value};
+use super::BaseModel;
+
+/// Operations an api key can be scoped to, one per public cms endpoint.
+pub const CMS_API_KEY_OPERATIONS: [&str; 4] = ["get_cms_content", "sent_contact_form", "submit_form", "watch_content"];
+
+#[derive(Serialize, Debug, Deserialize, Clone, Default)]
+pub struct CmsApiKeyModel {
+    pub id: String,
+    pub name: String,
+    pub key_prefix: String,
+    pub key_hash: String,
+    pub collections: Vec<String>,
+    pub operations: Vec<String>,
+    pub status: CmsApiKeyStatus,
+    pub last_used_at: Option<Datetime>,
+    pub created_at: Datetime,
+    pub updated_at: Datetime,
+    pub created_by: String,
+    pub updated_by: String,
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, Default, PartialEq)]
+pub enum CmsApiKeyStatus {
+    #[default]
+    Active,
+    Revoked,
+}
+
+impl CmsApiKeyModel {
+    pub fn has_operation(&self, operation: &str) -> bool {
+        self.operations.iter().any(|o| o == operation)
+    }
+
+    pub fn has_collection(&self, collection: &str) -> bool {
+        self.collections.iter().any(|c| c == collection)
+    }
+
+    /// Collections a change feed follows: the requested ones when the key can read
+    /// all of them, or every collection of the key when none are requested.
+    pub fn resolve_collections(&self, collections: Vec<String>) -> Result<Vec<String>> {
+        if collections.is_empty() {
+            return Ok(self.collections.clone());
+        }
+        if let Some(collection) = collections.iter().find(|c| !self.has_collection(c)) {
+            return Err(Error::Unauthorizeed(collection.to_string()));
+        }
+
+        Ok(collections)
+    }
+}
+
+impl TryFrom<String> for CmsApiKeyStatus {
+    type Error = Error;
+
+    fn try_from(val: String) -> Result<CmsApiKeyStatus> {
+        let status = match val.as_str() {
+            "Revoked" => CmsApiKeyStatus::Revoked,
+            _ => CmsApiKeyStatus::Active,
+        };
+
+        Ok(status)
+    }
+}
+
+impl TryFrom<CmsApiKeyStatus> for String {
+    type Error = Error;
+
+    fn try_from(val: CmsApiKeyStatus) -> Result<String> {
+        let status = match val {
+            CmsApiKeyStatus::Active => String::from("Active"),
+            CmsApiKeyStatus::Revoked => String::from("Revoked"),
+        };
+
+        Ok(status)
+    }
+}
+
+impl TryFrom<Object> for CmsApiKeyModel {
+    type Error = Error;
+    fn try_from(val: Object) -> Result<CmsApiKeyModel> {
+        let id = val.get("id").get_id()?;
+        let name = val.get("name").get_string()?;
+        let key_prefix = val.get("key_prefix").get_string()?;
+        let key_hash = val.get("key_hash").get_string()?;
+        let collections = get_string_array(val.get("collections"));
+        let operations = get_string_array(val.get("operations"));
+        let status = val.get("status").get_string()?.try_into()?;
+        let last_used_at = match val.get("last_used_at") {
+            Some(Value::Datetime(v)) => Some(v.clone()),
+            _ => None,
+        };
+        let created_at = val.get("created_at").get_datetime()?;
+        let updated_at = val.get("updated_at").get_datetime()?;
+        let created_by = val.get("created_by").get_string()?;
+        let updated_by = val.get("updated_by").get_string()?;
+
+        Ok(CmsApiKeyModel {
+            id,
+            name,
+            key_prefix,
+            key_hash,
+            collections,
+            operations,
+            status,
+            last_used_at,
+            created_at,
+            updated_at,
+            created_by,
+            updated_by,
+        })
+    }
+}
+
+fn get_string_array(val: Option<&Value>) -> Vec<String> {
+    match val {
+        Some(Value::Array(v)) => v.iter().map(|item| item.clone().as_string()).collect(),
+        _ => Vec::new(),
+    }
+}
+
+impl TryFrom<CmsApiKeyModel> for crate::api::proto::cms_api_key::CmsApiKeyModel {
+    type Error = Error;
+
+    fn try_from(val: CmsApiKeyModel) -> Result<crate::api::proto::cms_api_key::CmsApiKeyModel> {
+        let chrono_utc_created_at= val.created_at.to_utc();
+        let system_time_created_at = SystemTime::from(chrono_utc_created_at);
+        let created_at = Timestamp::from(system_time_created_at);
+
+        let chrono_utc_updated_at= val.updated_at.to_utc();
+        let system_time_updated_at = SystemTime::from(chrono_utc_updated_at);
+        let updated_at = Timestamp::from(system_time_updated_at);
+
+        let last_used_at = val
+            .last_used_at
+            .map(|last_used_at| Timestamp::from(SystemTime::from(last_used_at.to_utc())));
+
+        let model = crate::api::proto::cms_api_key::CmsApiKeyModel {
+            id: val.id,
+            name: val.name,
+            key_prefix: val.key_prefix,
+            collections: val.collections,
+            operations: val.operations,
+            status: val.status.try_into()?,
+            last_used_at,
+            created_at: Option::from(created_at),
+            updated_at: Option::from(updated_at),
+            created_by: val.created_by,
+            updated_by: val.updated_by,
+        };
+
+        Ok(model)
+    }
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone)]
+pub struct CreatableCmsApiKeyModel {
+    pub name: String,
+    pub key_prefix: String,
+    pub key_hash: String,
+    pub collections: Vec<String>,
+    pub operations: Vec<String>,
+    pub logged_in_username: String,
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone)]
+pub struct UpdatableCmsApiKeyModel {
+    pub id: String,
+    pub name: String,
+    pub collections: Vec<String>,
+    pub operations: Vec<String>,
+    pub logged_in_username: String,
+}
@@ -2,8 +2,28 @@ use std::time::SystemTime;
 use prost_types::Timestamp;
 use crate::error::{Error, Result};
 use crate::models::{BaseModel, Pagination};
+use crate::providers::avored_cache_provider::AvoRedCacheProvider;
 use serde::{Deserialize, Serialize};
-use surrealdb::sql::{Datetime, Object};
+use std::collections::BTreeMap;
+use surrealdb::sql::{Datetime, Object, Value};
+
+/// First path segments taken by the public routes, a routable collection with
+/// one of these identifiers could never be reached at `/{collection}/{identifier}`.
+pub const RESERVED_COLLECTION_IDENTIFIERS: [&str; 5] = ["api", "cms", "sitemaps", "feeds", "themes"];
+
+pub fn is_reserved_collection_identifier(identifier: &str) -> bool {
+    RESERVED_COLLECTION_IDENTIFIERS
+        .iter()
+        .any(|reserved| reserved.eq_ignore_ascii_case(identifier.trim()))
+}
+
+/// GraphQL schemas keyed by the sorted collections an api key can read,
+/// collection writes clear them and the next request builds them again.
+pub type GraphqlSchemaCache = AvoRedCacheProvider<Vec<String>, async_graphql::dynamic::Schema>;
+
+/// Theme menus of the routable collections under a single key, content and
+/// collection writes clear it and the next rendered page loads them again.
+pub type PageMenuCache = AvoRedCacheProvider<(), serde_json::Map<String, serde_json::Value>>;
 
 #[derive(Serialize, Debug, Deserialize, Clone, Default)]
 pub struct CollectionModel {
@@ -14,9 +34,20 @@ pub struct CollectionModel {
     pub updated_at: Datetime,
     pub created_by: String,
     pub updated_by: String,
+    pub is_routable: bool,
+    pub feed_mapping: CollectionFeedMapping,
     // pub collection_fields: Vec<CollectionFieldModel>,
 }
 
+/// Content field identifiers used to build the RSS and Atom feeds of a collection.
+#[derive(Serialize, Debug, Deserialize, Clone, Default)]
+pub struct CollectionFeedMapping {
+    pub title_field: String,
+    pub summary_field: String,
+    pub content_field: String,
+    pub date_field: String,
+}
+
 
 // #[derive(Serialize, Debug, Deserialize, Clone, Default)]
 // pub struct CollectionFieldModel {
@@ -36,6 +67,8 @@ pub struct CollectionPagination {
 pub struct CreatableCollection {
     pub name: String,
     pub identifier: String,
+    pub is_routable: bool,
+    pub feed_mapping: CollectionFeedMapping,
     pub logged_in_username: String,
     // pub collection_fields: Vec<CreatableCollectionField>,
 }
@@ -53,6 +86,8 @@ pub struct UpdatableCollection {
     pub id: String,
     pub name: String,
     pub identifier: String,
+    pub is_routable: bool,
+    pub feed_mapping: Option<CollectionFeedMapping>,
     pub logged_in_username: String,
     // pub collection_fields: Vec<UpdatableCollectionField>,
 }
@@ -111,12 +146,73 @@ impl TryFrom<CollectionModel> for crate::api::proto::content::CollectionModel {
             updated_at: Option::from(updated_at),
             created_by: val.created_by,
             updated_by: val.updated_by,
+            is_routable: val.is_routable,
+            feed_mapping: Some(val.feed_mapping.try_into()?),
         };
 
         Ok(model)
     }
 }
 
+impl TryFrom<CollectionFeedMapping> for crate::api::proto::content::CollectionFeedMapping {
+    type Error = Error;
+
+    fn try_from(val: CollectionFeedMapping) -> Result<crate::api::proto::content::CollectionFeedMapping> {
+        let model = crate::api::proto::content::CollectionFeedMapping {
+            title_field: val.title_field,
+            summary_field: val.summary_field,
+            content_field: val.content_field,
+            date_field: val.date_field,
+        };
+
+        Ok(model)
+    }
+}
+
+impl TryFrom<crate::api::proto::content::CollectionFeedMapping> for CollectionFeedMapping {
+    type Error = Error;
+
+    fn try_from(val: crate::api::proto::content::CollectionFeedMapping) -> Result<CollectionFeedMapping> {
+        let model = CollectionFeedMapping {
+            title_field: val.title_field,
+            summary_field: val.summary_field,
+            content_field: val.content_field,
+            date_field: val.date_field,
+        };
+
+        Ok(model)
+    }
+}
+
+impl TryFrom<CollectionFeedMapping> for Value {
+    type Error = Error;
+
+    fn try_from(val: CollectionFeedMapping) -> Result<Value> {
+        let val_val: BTreeMap<String, Value> = [
+            ("title_field".into(), val.title_field.into()),
+            ("summary_field".into(), val.summary_field.into()),
+            ("content_field".into(), val.content_field.into()),
+            ("date_field".into(), val.date_field.into()),
+        ]
+        .into();
+
+        Ok(val_val.into())
+    }
+}
+
+impl TryFrom<Object> for CollectionFeedMapping {
+    type Error = Error;
+
+    fn try_from(val: Object) -> Result<CollectionFeedMapping> {
+        Ok(CollectionFeedMapping {
+            title_field: val.get("title_field").get_string()?,
+            summary_field: val.get("summary_field").get_string()?,
+            content_field: val.get("content_field").get_string()?,
+            date_field: val.get("date_field").get_string()?,
+        })
+    }
+}
+
 
 impl TryFrom<Object> for CollectionModel {
     type Error = Error;
@@ -128,6 +224,11 @@ impl TryFrom<Object> for CollectionModel {
         let updated_at = val.get("updated_at").get_datetime()?;
         let created_by = val.get("created_by").get_string()?;
         let updated_by = val.get("updated_by").get_string()?;
+        let is_routable = val.get("is_routable").get_bool()?;
+        let feed_mapping = match val.get("feed_mapping") {
+            Some(Value::Object(object)) => object.clone().try_into()?,
+            _ => CollectionFeedMapping::default(),
+        };
 
         // let collection_fields = match val.get("collection_fields") {
         //     Some(val) => match val.clone() {
@@ -159,6 +260,8 @@ impl TryFrom<Object> for CollectionModel {
             updated_at,
             created_by,
             updated_by,
+            is_routable,
+            feed_mapping,
             // collection_fields,
         })
     }
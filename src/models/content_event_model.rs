@@ -0,0 +1,88 @@
+use std::time::SystemTime;
+use prost_types::Timestamp;
+use serde::{Deserialize, Serialize};
+use surrealdb::sql::Datetime;
+use crate::error::{Error, Result};
+use crate::models::content_model::ContentModel;
+use crate::providers::avored_event_bus_provider::AvoRedEventBusProvider;
+
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ContentEventType {
+    Created,
+    Updated,
+    Deleted,
+    Published,
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone)]
+pub struct ContentEventModel {
+    pub event_type: ContentEventType,
+    pub collection: String,
+    pub content_id: String,
+    pub identifier: String,
+    pub occurred_at: Datetime,
+    pub data: Option<ContentModel>,
+}
+
+pub type ContentEventBus = AvoRedEventBusProvider<ContentEventModel>;
+
+impl ContentEventModel {
+    pub fn new(event_type: ContentEventType, collection: &str, content_model: &ContentModel) -> ContentEventModel {
+        // watchers only ever see what the public api would return.
+        let data = match event_type {
+            ContentEventType::Deleted => None,
+            _ if content_model.is_published() => Some(content_model.clone()),
+            _ => None,
+        };
+
+        ContentEventModel {
+            event_type,
+            collection: collection.to_string(),
+            content_id: content_model.id.clone(),
+            identifier: content_model.identifier.clone(),
+            occurred_at: Datetime::default(),
+            data,
+        }
+    }
+}
+
+impl TryFrom<ContentEventType> for String {
+    type Error = Error;
+
+    fn try_from(val: ContentEventType) -> Result<String> {
+        let event_type = match val {
+            ContentEventType::Created => String::from("created"),
+            ContentEventType::Updated => String::from("updated"),
+            ContentEventType::Deleted => String::from("deleted"),
+            ContentEventType::Published => String::from("published"),
+        };
+
+        Ok(event_type)
+    }
+}
+
+impl TryFrom<ContentEventModel> for crate::api::proto::cms::ContentEventModel {
+    type Error = Error;
+
+    fn try_from(val: ContentEventModel) -> Result<crate::api::proto::cms::ContentEventModel> {
+        let system_time_occurred_at = SystemTime::from(val.occurred_at.to_utc());
+        let occurred_at = Timestamp::from(system_time_occurred_at);
+
+        let data = match val.data {
+            Some(content_model) => Some(content_model.try_into()?),
+            None => None,
+        };
+
+        let model = crate::api::proto::cms::ContentEventModel {
+            event_type: val.event_type.try_into()?,
+            collection: val.collection,
+            content_id: val.content_id,
+            identifier: val.identifier,
+            occurred_at: Some(occurred_at),
+            data,
+        };
+
+        Ok(model)
+    }
+}
@@ -2,9 +2,14 @@ use std::collections::BTreeMap;
 use std::time::SystemTime;
 use prost_types::Timestamp;
 use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as JsonValue};
+use sha2::{Digest, Sha256};
 use surrealdb::sql::{Datetime, Object, Value};
+use rust_i18n::t;
 use crate::error::{Error, Result};
+use crate::models::validation_error::{ErrorMessage, ErrorResponse};
 use crate::models::{BaseModel, Pagination};
+use crate::providers::avored_cache_provider::AvoRedCacheProvider;
 
 
 // region: Struct, Enum Initialization
@@ -49,12 +54,23 @@ pub struct ContentModel {
     pub name: String,
     pub identifier: String,
     pub content_fields: Vec<ContentFieldModel>,
+    pub status: ContentStatus,
     pub created_at: Datetime,
     pub updated_at: Datetime,
     pub created_by: String,
     pub updated_by: String,
 }
 
+/// Draft content is only visible to admin users, it is left out of the
+/// public pages and the sitemap. Records saved before the status existed
+/// are treated as published.
+#[derive(Serialize, Debug, Deserialize, Clone, Default, PartialEq)]
+pub enum ContentStatus {
+    Draft,
+    #[default]
+    Published,
+}
+
 #[derive(Serialize, Debug, Deserialize, Clone, Default)]
 pub struct ContentFieldModel {
     pub name: String,
@@ -107,6 +123,7 @@ pub struct CreatableContentModel {
     pub logged_in_username: String,
     pub content_type: String,
     pub content_fields: Vec<CreatableContentField>,
+    pub status: ContentStatus,
 }
 
 #[derive(Serialize, Debug, Deserialize, Clone)]
@@ -129,6 +146,7 @@ pub struct UpdatableContentModel {
     pub updated_at: Datetime,
     pub updated_by: String,
     pub content_fields: Vec<UpdatableContentField>,
+    pub status: Option<ContentStatus>,
 }
 
 #[derive(Serialize, Debug, Deserialize, Clone)]
@@ -163,6 +181,101 @@ impl Default for ContentFieldDataType {
 
 // endregion: struct default implementation
 
+// region: field projection
+
+impl ContentModel {
+    /// Keeps only the content fields whose identifier is listed, an empty list keeps them all.
+    pub fn project_fields(mut self, identifiers: &[String]) -> ContentModel {
+        if !identifiers.is_empty() {
+            self.content_fields
+                .retain(|content_field| identifiers.contains(&content_field.identifier));
+        }
+
+        self
+    }
+
+    /// Content fields as identifier => typed value, without the field metadata.
+    pub fn compact_fields(&self) -> BTreeMap<String, JsonValue> {
+        self.content_fields
+            .iter()
+            .map(|content_field| (content_field.identifier.clone(), content_field.json_value()))
+            .collect()
+    }
+}
+
+impl ContentFieldModel {
+    /// The value matching the field data type.
+    pub fn json_value(&self) -> JsonValue {
+        let field_content = &self.field_content;
+        match self.data_type {
+            ContentFieldDataType::Text => json!(field_content.text_value),
+            ContentFieldDataType::Int => json!(field_content.int_value),
+            ContentFieldDataType::Float => json!(field_content.float_value),
+            ContentFieldDataType::Bool => json!(field_content.bool_value),
+            ContentFieldDataType::Array => json!(field_content.array_value),
+        }
+    }
+}
+
+// endregion: field projection
+
+// region: http cache validators
+
+impl ContentModel {
+    pub fn is_published(&self) -> bool {
+        self.status == ContentStatus::Published
+    }
+
+    /// Strong validator built from the record id and its last update time.
+    pub fn etag(&self) -> String {
+        let id: String = self
+            .id
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric() || *c == ':' || *c == '_' || *c == '-')
+            .collect();
+
+        format!("\"{}-{:x}\"", id, self.updated_at.to_utc().timestamp_micros())
+    }
+
+    /// Validator of a projected response. The field mask and the compact shape
+    /// change the body, so they are hashed into the tag next to the record version.
+    pub fn representation_etag(&self, field_identifiers: &[String], compact: bool) -> String {
+        let mut field_identifiers = field_identifiers.to_vec();
+        field_identifiers.sort();
+        field_identifiers.dedup();
+        if field_identifiers.is_empty() && !compact {
+            return self.etag();
+        }
+
+        let digest = Sha256::digest(format!("{}|{compact}", field_identifiers.join(",")));
+        let etag = self.etag();
+
+        format!("{}-{}\"", etag.trim_end_matches('"'), &hex::encode(digest)[..16])
+    }
+
+    /// `updated_at` formatted as an HTTP date.
+    pub fn last_modified(&self) -> String {
+        self.updated_at
+            .to_utc()
+            .format("%a, %d %b %Y %H:%M:%S GMT")
+            .to_string()
+    }
+}
+
+// endregion: http cache validators
+
+// region: cms content cache
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CmsContentCacheKey {
+    pub collection: String,
+    pub identifier: String,
+}
+
+pub type CmsContentCache = AvoRedCacheProvider<CmsContentCacheKey, ContentModel>;
+
+// endregion: cms content cache
+
 
 
 // region: MODEL => gRPC convert
@@ -195,7 +308,7 @@ impl TryFrom<ContentModel> for crate::api::proto::content::ContentModel {
             created_by: val.created_by,
             updated_by: val.updated_by,
             content_fields,
-
+            status: val.status.try_into()?,
         };
 
         Ok(model)
@@ -583,6 +696,31 @@ impl TryFrom<String> for ContentFieldFieldType {
     }
 }
 
+/// An empty status is a record from before the status existed, or a request
+/// that left it out. Anything else unknown is refused.
+impl TryFrom<String> for ContentStatus {
+    type Error = Error;
+
+    fn try_from(val: String) -> Result<ContentStatus> {
+        let status = match val.as_str() {
+            "Draft" => ContentStatus::Draft,
+            "Published" | "" => ContentStatus::Published,
+            _ => {
+                let error_response = ErrorResponse {
+                    status: false,
+                    errors: vec![ErrorMessage {
+                        key: String::from("status"),
+                        message: t!("content_status_not_valid").to_string(),
+                    }],
+                };
+                return Err(Error::InvalidArgument(serde_json::to_string(&error_response)?));
+            }
+        };
+
+        Ok(status)
+    }
+}
+
 
 
 // endregion: STRING => MODEL convert
@@ -631,6 +769,19 @@ impl TryFrom<ContentFieldFieldType> for String {
     }
 }
 
+impl TryFrom<ContentStatus> for String {
+    type Error = Error;
+
+    fn try_from(val: ContentStatus) -> Result<String> {
+        let string_val = match val {
+            ContentStatus::Draft => String::from("Draft"),
+            ContentStatus::Published => String::from("Published"),
+        };
+
+        Ok(string_val)
+    }
+}
+
 // endregion: MODEL => STRING convert
 
 
@@ -702,6 +853,7 @@ impl TryFrom<Object> for ContentModel {
             None => Vec::new(),
         };
 
+        let status = val.get("status").get_string()?.try_into()?;
         let created_at = val.get("created_at").get_datetime()?;
         let updated_at = val.get("updated_at").get_datetime()?;
         let created_by = val.get("created_by").get_string()?;
@@ -712,6 +864,7 @@ impl TryFrom<Object> for ContentModel {
             name,
             identifier,
             content_fields,
+            status,
             created_at,
             updated_at,
             created_by,
@@ -0,0 +1,238 @@
+use std::time::SystemTime;
+use prost_types::Timestamp;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use surrealdb::sql::{Datetime, Object, Value};
+use crate::error::{Error, Result};
+use super::email_template_model::is_sensitive_email_template;
+use super::BaseModel;
+
+/// Pending emails wait in the outbox, dead ones ran out of attempts or were
+/// refused for good and sit in the dead letter list until resent.
+#[derive(Serialize, Debug, Deserialize, Clone, Default, PartialEq)]
+pub enum EmailStatus {
+    #[default]
+    Pending,
+    Sent,
+    Dead,
+}
+
+impl TryFrom<String> for EmailStatus {
+    type Error = Error;
+
+    fn try_from(val: String) -> Result<EmailStatus> {
+        let status = match val.as_str() {
+            "Sent" => EmailStatus::Sent,
+            "Dead" => EmailStatus::Dead,
+            _ => EmailStatus::Pending,
+        };
+
+        Ok(status)
+    }
+}
+
+impl TryFrom<EmailStatus> for String {
+    type Error = Error;
+
+    fn try_from(val: EmailStatus) -> Result<String> {
+        let status = match val {
+            EmailStatus::Pending => String::from("Pending"),
+            EmailStatus::Sent => String::from("Sent"),
+            EmailStatus::Dead => String::from("Dead"),
+        };
+
+        Ok(status)
+    }
+}
+
+/// A file sent along with the email. The path points to the file on disk,
+/// which is read when the email is sent.
+#[derive(Serialize, Debug, Deserialize, Clone, Default)]
+pub struct EmailAttachmentModel {
+    pub file_name: String,
+    pub content_type: String,
+    pub path: String,
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, Default)]
+pub struct EmailModel {
+    pub id: String,
+    pub from_address: String,
+    pub reply_to: String,
+    pub to_address: String,
+    pub subject: String,
+    pub body: String,
+    pub text_body: String,
+    pub attachments: Vec<EmailAttachmentModel>,
+    pub template: String,
+    pub status: EmailStatus,
+    pub attempts: i64,
+    pub error: String,
+    pub next_attempt_at: Datetime,
+    pub created_at: Datetime,
+    pub updated_at: Datetime,
+}
+
+impl TryFrom<Object> for EmailModel {
+    type Error = Error;
+    fn try_from(val: Object) -> Result<EmailModel> {
+        let id = val.get("id").get_id()?;
+        let from_address = val.get("from_address").get_string()?;
+        let reply_to = val.get("reply_to").get_string()?;
+        let to_address = val.get("to_address").get_string()?;
+        let subject = val.get("subject").get_string()?;
+        let body = val.get("body").get_string()?;
+        let text_body = val.get("text_body").get_string()?;
+        let attachments = match val.get("attachments") {
+            Some(Value::Array(v)) => {
+                let mut attachments = Vec::new();
+                for item in v.iter() {
+                    if let Value::Object(object) = item {
+                        attachments.push(object.clone().try_into()?);
+                    }
+                }
+                attachments
+            }
+            _ => Vec::new(),
+        };
+        let template = val.get("template").get_string()?;
+        let status = val.get("status").get_string()?.try_into()?;
+        let attempts = val.get("attempts").get_int()?;
+        let error = val.get("error").get_string()?;
+        let next_attempt_at = val.get("next_attempt_at").get_datetime()?;
+        let created_at = val.get("created_at").get_datetime()?;
+        let updated_at = val.get("updated_at").get_datetime()?;
+
+        Ok(EmailModel {
+            id,
+            from_address,
+            reply_to,
+            to_address,
+            subject,
+            body,
+            text_body,
+            attachments,
+            template,
+            status,
+            attempts,
+            error,
+            next_attempt_at,
+            created_at,
+            updated_at,
+        })
+    }
+}
+
+impl TryFrom<EmailModel> for crate::api::proto::email::EmailModel {
+    type Error = Error;
+
+    fn try_from(val: EmailModel) -> Result<crate::api::proto::email::EmailModel> {
+        let next_attempt_at = Timestamp::from(SystemTime::from(val.next_attempt_at.to_utc()));
+        let created_at = Timestamp::from(SystemTime::from(val.created_at.to_utc()));
+        let updated_at = Timestamp::from(SystemTime::from(val.updated_at.to_utc()));
+
+        // the admin listing never shows what a sensitive email said.
+        let (body, text_body) = match is_sensitive_email_template(&val.template) {
+            true => (String::from(""), String::from("")),
+            false => (val.body, val.text_body),
+        };
+
+        let mut attachments = vec![];
+        for attachment in val.attachments {
+            attachments.push(attachment.try_into()?);
+        }
+
+        let model = crate::api::proto::email::EmailModel {
+            id: val.id,
+            from_address: val.from_address,
+            reply_to: val.reply_to,
+            attachments,
+            to_address: val.to_address,
+            subject: val.subject,
+            body,
+            text_body,
+            template: val.template,
+            status: val.status.try_into()?,
+            attempts: val.attempts,
+            error: val.error,
+            next_attempt_at: Some(next_attempt_at),
+            created_at: Some(created_at),
+            updated_at: Some(updated_at),
+        };
+
+        Ok(model)
+    }
+}
+
+impl TryFrom<Object> for EmailAttachmentModel {
+    type Error = Error;
+
+    fn try_from(val: Object) -> Result<EmailAttachmentModel> {
+        Ok(EmailAttachmentModel {
+            file_name: val.get("file_name").get_string()?,
+            content_type: val.get("content_type").get_string()?,
+            path: val.get("path").get_string()?,
+        })
+    }
+}
+
+impl TryFrom<EmailAttachmentModel> for Value {
+    type Error = Error;
+
+    fn try_from(val: EmailAttachmentModel) -> Result<Value> {
+        let val_val: BTreeMap<String, Value> = [
+            ("file_name".into(), val.file_name.into()),
+            ("content_type".into(), val.content_type.into()),
+            ("path".into(), val.path.into()),
+        ]
+        .into();
+
+        Ok(val_val.into())
+    }
+}
+
+impl TryFrom<EmailAttachmentModel> for crate::api::proto::email::EmailAttachmentModel {
+    type Error = Error;
+
+    fn try_from(val: EmailAttachmentModel) -> Result<crate::api::proto::email::EmailAttachmentModel> {
+        let model = crate::api::proto::email::EmailAttachmentModel {
+            file_name: val.file_name,
+            content_type: val.content_type,
+            path: val.path,
+        };
+
+        Ok(model)
+    }
+}
+
+/// A rendered email ready to be queued. The sender comes from the settings
+/// when the email is queued.
+#[derive(Serialize, Debug, Deserialize, Clone)]
+pub struct CreatableEmailModel {
+    pub to_address: String,
+    pub subject: String,
+    pub body: String,
+    pub text_body: String,
+    pub attachments: Vec<EmailAttachmentModel>,
+    pub template: String,
+}
+
+/// From and reply-to addresses, read from the `email_sender_name`,
+/// `email_sender_address` and `email_reply_to` settings.
+#[derive(Debug, Clone)]
+pub struct EmailSenderModel {
+    pub from_address: String,
+    pub reply_to: String,
+}
+
+/// Outcome of one send attempt written back to the outbox. `clear_body`
+/// empties the stored bodies, for sensitive emails once they are sent.
+#[derive(Debug, Clone)]
+pub struct UpdatableEmailModel {
+    pub id: String,
+    pub status: EmailStatus,
+    pub attempts: i64,
+    pub error: String,
+    pub next_attempt_at: Datetime,
+    pub clear_body: bool,
+}
@@ -0,0 +1,158 @@
+use std::time::SystemTime;
+use prost_types::Timestamp;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use surrealdb::sql::{Datetime, Object};
+use crate::error::{Error, Result};
+use super::BaseModel;
+
+/// An email the application sends, with the subject used when no stored
+/// template overrides it. The html body falls back to `resources/mail/{identifier}.hbs`.
+/// Bodies of sensitive emails carry secrets such as reset links, they are
+/// never listed and are cleared once the email is sent.
+pub struct EmailTemplateDefinition {
+    pub identifier: &'static str,
+    pub name: &'static str,
+    pub subject: &'static str,
+    pub sensitive: bool,
+}
+
+pub const EMAIL_TEMPLATES: [EmailTemplateDefinition; 2] = [
+    EmailTemplateDefinition {
+        identifier: "forgot-password",
+        name: "Forgot password",
+        subject: "Forgot your password?",
+        sensitive: true,
+    },
+    EmailTemplateDefinition {
+        identifier: "form-submission-email",
+        name: "Form submission",
+        subject: "{{#if email_subject}}{{email_subject}}{{else}}{{form_name}} submission{{/if}}",
+        sensitive: false,
+    },
+];
+
+pub fn email_template_definition(identifier: &str) -> Option<&'static EmailTemplateDefinition> {
+    EMAIL_TEMPLATES
+        .iter()
+        .find(|definition| definition.identifier == identifier)
+}
+
+pub fn is_sensitive_email_template(identifier: &str) -> bool {
+    email_template_definition(identifier).is_some_and(|definition| definition.sensitive)
+}
+
+/// Data the preview renders a template with, shaped like the real payload.
+pub fn email_template_sample_data(identifier: &str) -> serde_json::Value {
+    match identifier {
+        "forgot-password" => json!({
+            "link": "https://example.com/admin/reset-password/sample-token"
+        }),
+        "form-submission-email" => json!({
+            "form_name": "Contact us",
+            "email_subject": "",
+            "fields": [
+                { "name": "Name", "value": "Jane Doe" },
+                { "name": "Email", "value": "jane@example.com" },
+                { "name": "Message", "value": "Hello, I would like to know more." }
+            ]
+        }),
+        _ => json!({}),
+    }
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, Default)]
+pub struct EmailTemplateModel {
+    pub id: String,
+    pub identifier: String,
+    pub subject: String,
+    pub html_body: String,
+    pub text_body: String,
+    pub created_at: Datetime,
+    pub updated_at: Datetime,
+    pub created_by: String,
+    pub updated_by: String,
+}
+
+impl TryFrom<Object> for EmailTemplateModel {
+    type Error = Error;
+
+    fn try_from(val: Object) -> Result<EmailTemplateModel> {
+        let id = val.get("id").get_id()?;
+        let identifier = val.get("identifier").get_string()?;
+        let subject = val.get("subject").get_string()?;
+        let html_body = val.get("html_body").get_string()?;
+        let text_body = val.get("text_body").get_string()?;
+        let created_at = val.get("created_at").get_datetime()?;
+        let updated_at = val.get("updated_at").get_datetime()?;
+        let created_by = val.get("created_by").get_string()?;
+        let updated_by = val.get("updated_by").get_string()?;
+
+        Ok(EmailTemplateModel {
+            id,
+            identifier,
+            subject,
+            html_body,
+            text_body,
+            created_at,
+            updated_at,
+            created_by,
+            updated_by,
+        })
+    }
+}
+
+impl TryFrom<EmailTemplateModel> for crate::api::proto::email::EmailTemplateModel {
+    type Error = Error;
+
+    fn try_from(val: EmailTemplateModel) -> Result<crate::api::proto::email::EmailTemplateModel> {
+        let created_at = Timestamp::from(SystemTime::from(val.created_at.to_utc()));
+        let updated_at = Timestamp::from(SystemTime::from(val.updated_at.to_utc()));
+        let name = email_template_definition(&val.identifier)
+            .map(|definition| definition.name.to_string())
+            .unwrap_or_default();
+
+        let model = crate::api::proto::email::EmailTemplateModel {
+            id: val.id,
+            identifier: val.identifier,
+            name,
+            subject: val.subject,
+            html_body: val.html_body,
+            text_body: val.text_body,
+            is_default: false,
+            created_at: Some(created_at),
+            updated_at: Some(updated_at),
+            created_by: val.created_by,
+            updated_by: val.updated_by,
+        };
+
+        Ok(model)
+    }
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone)]
+pub struct CreatableEmailTemplateModel {
+    pub identifier: String,
+    pub subject: String,
+    pub html_body: String,
+    pub text_body: String,
+    pub logged_in_username: String,
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone)]
+pub struct UpdatableEmailTemplateModel {
+    pub id: String,
+    pub subject: String,
+    pub html_body: String,
+    pub text_body: String,
+    pub logged_in_username: String,
+}
+
+/// Subject and bodies of an email after rendering. An empty text body is
+/// derived from the html body when the email is sent.
+#[derive(Serialize, Debug, Deserialize, Clone, Default)]
+pub struct RenderedEmailModel {
+    pub subject: String,
+    pub html_body: String,
+    pub text_body: String,
+}
@@ -0,0 +1,17 @@
+use chrono::{DateTime, Utc};
+use crate::providers::avored_cache_provider::AvoRedCacheProvider;
+
+/// One feed entry after the collection feed mapping has been applied.
+#[derive(Debug, Clone)]
+pub struct FeedItemModel {
+    pub title: String,
+    pub link: String,
+    pub summary: String,
+    pub content: String,
+    pub date: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Newest entries of each routable collection keyed by its identifier, content
+/// and collection writes clear them and the next request builds them again.
+pub type FeedCache = AvoRedCacheProvider<String, Vec<FeedItemModel>>;
@@ -0,0 +1,422 @@
+use std::collections::{BTreeMap, HashMap};
+use std::time::SystemTime;
+use prost_types::Timestamp;
+use rust_i18n::t;
+use serde::{Deserialize, Serialize};
+use surrealdb::sql::{Datetime, Object, Value};
+use crate::error::{Error, Result};
+use crate::models::validation_error::{ErrorMessage, ErrorResponse, Validate};
+use super::BaseModel;
+
+pub const FORM_FIELD_TYPES: [&str; 8] = ["text", "email", "phone", "number", "textarea", "select", "checkbox", "file"];
+
+/// Built in form the legacy `SentContactForm` rpc submits to.
+pub const CONTACT_US_FORM_IDENTIFIER: &str = "contact-us";
+
+/// Form identifiers name the upload folder of the form, so only lowercase
+/// letters, digits, dashes and underscores are allowed.
+pub fn is_form_identifier(identifier: &str) -> bool {
+    !identifier.is_empty()
+        && identifier
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, Default)]
+pub struct FormFieldModel {
+    pub name: String,
+    pub identifier: String,
+    pub field_type: String,
+    pub is_required: bool,
+    pub min_length: i64,
+    pub max_length: i64,
+    pub options: Vec<String>,
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, Default)]
+pub struct FormModel {
+    pub id: String,
+    pub name: String,
+    pub identifier: String,
+    pub fields: Vec<FormFieldModel>,
+    pub recipients: Vec<String>,
+    pub email_subject: String,
+    pub success_message: String,
+    pub is_active: bool,
+    pub created_at: Datetime,
+    pub updated_at: Datetime,
+    pub created_by: String,
+    pub updated_by: String,
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, Default)]
+pub struct FormSubmissionModel {
+    pub id: String,
+    pub form_id: String,
+    pub form_identifier: String,
+    pub data: BTreeMap<String, String>,
+    pub created_at: Datetime,
+}
+
+/// A public submission as it arrives, before any spam check or validation.
+#[derive(Debug, Clone, Default)]
+pub struct FormSubmissionAttempt {
+    pub form_identifier: String,
+    pub client_ip: String,
+    pub challenge_token: String,
+    pub data: HashMap<String, String>,
+    pub files: Vec<FormSubmissionFile>,
+}
+
+/// A file uploaded for a `file` field of the form.
+#[derive(Debug, Clone, Default)]
+pub struct FormSubmissionFile {
+    pub field_identifier: String,
+    pub file_name: String,
+    pub content_type: String,
+    pub content: Vec<u8>,
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone)]
+pub struct CreatableFormModel {
+    pub name: String,
+    pub identifier: String,
+    pub fields: Vec<FormFieldModel>,
+    pub recipients: Vec<String>,
+    pub email_subject: String,
+    pub success_message: String,
+    pub is_active: bool,
+    pub logged_in_username: String,
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone)]
+pub struct UpdatableFormModel {
+    pub id: String,
+    pub name: String,
+    pub fields: Vec<FormFieldModel>,
+    pub recipients: Vec<String>,
+    pub email_subject: String,
+    pub success_message: String,
+    pub is_active: bool,
+    pub logged_in_username: String,
+}
+
+impl FormModel {
+    /// Checks submitted values against the form fields. Returns one trimmed
+    /// value per field, unknown keys are dropped and checkboxes become `true` or `false`.
+    /// File fields get the uploaded file name, their size limit is `max_length`
+    /// in kilobytes or `max_upload_kb` when the field has none.
+    pub fn validate_submission(
+        &self,
+        data: &HashMap<String, String>,
+        files: &[FormSubmissionFile],
+        max_upload_kb: i64,
+    ) -> Result<BTreeMap<String, String>> {
+        let mut errors: Vec<ErrorMessage> = vec![];
+        let mut values: BTreeMap<String, String> = BTreeMap::new();
+
+        for field in &self.fields {
+            if field.field_type == "file" {
+                let file = files.iter().find(|file| file.field_identifier == field.identifier);
+                let file = match file {
+                    Some(file) if !file.content.is_empty() => file,
+                    _ => {
+                        if field.is_required {
+                            errors.push(field.error("validation_required"));
+                        }
+                        values.insert(field.identifier.clone(), String::from(""));
+                        continue;
+                    }
+                };
+
+                let max_kb = match field.max_length > 0 {
+                    true => field.max_length,
+                    false => max_upload_kb,
+                };
+                if max_kb > 0 && file.content.len() as i64 > max_kb * 1024 {
+                    errors.push(ErrorMessage {
+                        key: field.identifier.clone(),
+                        message: t!("validation_max_file_size", attribute = field.name, max = max_kb).to_string(),
+                    });
+                }
+                let is_allowed_type = file.content_type.parse::<lettre::message::header::ContentType>().is_ok()
+                    && (field.options.is_empty() || field.options.contains(&file.content_type));
+                if !is_allowed_type {
+                    errors.push(field.error("validation_invalid"));
+                }
+
+                values.insert(field.identifier.clone(), file.file_name.clone());
+                continue;
+            }
+
+            let value = data
+                .get(&field.identifier)
+                .map(|value| value.trim().to_string())
+                .unwrap_or_default();
+
+            if field.field_type == "checkbox" {
+                let is_checked = matches!(value.to_lowercase().as_str(), "true" | "on" | "1" | "yes");
+                if field.is_required && !is_checked {
+                    errors.push(field.error("validation_required"));
+                }
+                values.insert(field.identifier.clone(), is_checked.to_string());
+                continue;
+            }
+
+            if !value.required()? {
+                if field.is_required {
+                    errors.push(field.error("validation_required"));
+                }
+                values.insert(field.identifier.clone(), value);
+                continue;
+            }
+
+            let length = value.chars().count() as i64;
+            if field.min_length > 0 && length < field.min_length {
+                errors.push(ErrorMessage {
+                    key: field.identifier.clone(),
+                    message: t!("validation_min_length", attribute = field.name, min = field.min_length).to_string(),
+                });
+            }
+            if field.max_length > 0 && length > field.max_length {
+                errors.push(ErrorMessage {
+                    key: field.identifier.clone(),
+                    message: t!("validation_max_length", attribute = field.name, max = field.max_length).to_string(),
+                });
+            }
+
+            let is_valid = match field.field_type.as_str() {
+                "email" => value.validate_email()?,
+                "number" => value.parse::<f64>().is_ok(),
+                "phone" => value
+                    .chars()
+                    .all(|c| c.is_ascii_digit() || " +-().".contains(c)),
+                "select" => field.options.contains(&value),
+                _ => true,
+            };
+            if !is_valid {
+                errors.push(field.error("validation_invalid"));
+            }
+
+            values.insert(field.identifier.clone(), value);
+        }
+
+        if !errors.is_empty() {
+            let error_response = ErrorResponse {
+                status: false,
+                errors,
+            };
+            let error_string = serde_json::to_string(&error_response)?;
+            return Err(Error::InvalidArgument(error_string));
+        }
+
+        Ok(values)
+    }
+}
+
+impl FormFieldModel {
+    fn error(&self, message_key: &str) -> ErrorMessage {
+        ErrorMessage {
+            key: self.identifier.clone(),
+            message: t!(message_key, attribute = self.name).to_string(),
+        }
+    }
+}
+
+impl TryFrom<Object> for FormFieldModel {
+    type Error = Error;
+
+    fn try_from(val: Object) -> Result<FormFieldModel> {
+        Ok(FormFieldModel {
+            name: val.get("name").get_string()?,
+            identifier: val.get("identifier").get_string()?,
+            field_type: val.get("field_type").get_string()?,
+            is_required: val.get("is_required").get_bool()?,
+            min_length: val.get("min_length").get_int()?,
+            max_length: val.get("max_length").get_int()?,
+            options: get_string_array(val.get("options")),
+        })
+    }
+}
+
+impl TryFrom<FormFieldModel> for Value {
+    type Error = Error;
+
+    fn try_from(val: FormFieldModel) -> Result<Value> {
+        let val_val: BTreeMap<String, Value> = [
+            ("name".into(), val.name.into()),
+            ("identifier".into(), val.identifier.into()),
+            ("field_type".into(), val.field_type.into()),
+            ("is_required".into(), val.is_required.into()),
+            ("min_length".into(), val.min_length.into()),
+            ("max_length".into(), val.max_length.into()),
+            ("options".into(), val.options.into()),
+        ]
+        .into();
+
+        Ok(val_val.into())
+    }
+}
+
+impl TryFrom<FormFieldModel> for crate::api::proto::form::FormFieldModel {
+    type Error = Error;
+
+    fn try_from(val: FormFieldModel) -> Result<crate::api::proto::form::FormFieldModel> {
+        let model = crate::api::proto::form::FormFieldModel {
+            name: val.name,
+            identifier: val.identifier,
+            field_type: val.field_type,
+            is_required: val.is_required,
+            min_length: val.min_length,
+            max_length: val.max_length,
+            options: val.options,
+        };
+
+        Ok(model)
+    }
+}
+
+impl TryFrom<crate::api::proto::form::FormFieldModel> for FormFieldModel {
+    type Error = Error;
+
+    fn try_from(val: crate::api::proto::form::FormFieldModel) -> Result<FormFieldModel> {
+        let model = FormFieldModel {
+            name: val.name,
+            identifier: val.identifier,
+            field_type: val.field_type,
+            is_required: val.is_required,
+            min_length: val.min_length,
+            max_length: val.max_length,
+            options: val.options,
+        };
+
+        Ok(model)
+    }
+}
+
+impl TryFrom<Object> for FormModel {
+    type Error = Error;
+
+    fn try_from(val: Object) -> Result<FormModel> {
+        let id = val.get("id").get_id()?;
+        let name = val.get("name").get_string()?;
+        let identifier = val.get("identifier").get_string()?;
+        let fields = match val.get("fields") {
+            Some(Value::Array(v)) => {
+                let mut fields = Vec::new();
+                for item in v.iter() {
+                    if let Value::Object(object) = item {
+                        fields.push(object.clone().try_into()?);
+                    }
+                }
+                fields
+            }
+            _ => Vec::new(),
+        };
+        let recipients = get_string_array(val.get("recipients"));
+        let email_subject = val.get("email_subject").get_string()?;
+        let success_message = val.get("success_message").get_string()?;
+        let is_active = val.get("is_active").get_bool()?;
+        let created_at = val.get("created_at").get_datetime()?;
+        let updated_at = val.get("updated_at").get_datetime()?;
+        let created_by = val.get("created_by").get_string()?;
+        let updated_by = val.get("updated_by").get_string()?;
+
+        Ok(FormModel {
+            id,
+            name,
+            identifier,
+            fields,
+            recipients,
+            email_subject,
+            success_message,
+            is_active,
+            created_at,
+            updated_at,
+            created_by,
+            updated_by,
+        })
+    }
+}
+
+impl TryFrom<FormModel> for crate::api::proto::form::FormModel {
+    type Error = Error;
+
+    fn try_from(val: FormModel) -> Result<crate::api::proto::form::FormModel> {
+        let created_at = Timestamp::from(SystemTime::from(val.created_at.to_utc()));
+        let updated_at = Timestamp::from(SystemTime::from(val.updated_at.to_utc()));
+
+        let mut fields = vec![];
+        for field in val.fields {
+            fields.push(field.try_into()?);
+        }
+
+        let model = crate::api::proto::form::FormModel {
+            id: val.id,
+            name: val.name,
+            identifier: val.identifier,
+            fields,
+            recipients: val.recipients,
+            email_subject: val.email_subject,
+            success_message: val.success_message,
+            is_active: val.is_active,
+            created_at: Some(created_at),
+            updated_at: Some(updated_at),
+            created_by: val.created_by,
+            updated_by: val.updated_by,
+        };
+
+        Ok(model)
+    }
+}
+
+impl TryFrom<Object> for FormSubmissionModel {
+    type Error = Error;
+
+    fn try_from(val: Object) -> Result<FormSubmissionModel> {
+        let id = val.get("id").get_id()?;
+        let form_id = val.get("form_id").get_string()?;
+        let form_identifier = val.get("form_identifier").get_string()?;
+        let data = match val.get("data") {
+            Some(Value::Object(object)) => object
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone().as_string()))
+                .collect(),
+            _ => BTreeMap::new(),
+        };
+        let created_at = val.get("created_at").get_datetime()?;
+
+        Ok(FormSubmissionModel {
+            id,
+            form_id,
+            form_identifier,
+            data,
+            created_at,
+        })
+    }
+}
+
+impl TryFrom<FormSubmissionModel> for crate::api::proto::form::FormSubmissionModel {
+    type Error = Error;
+
+    fn try_from(val: FormSubmissionModel) -> Result<crate::api::proto::form::FormSubmissionModel> {
+        let created_at = Timestamp::from(SystemTime::from(val.created_at.to_utc()));
+
+        let model = crate::api::proto::form::FormSubmissionModel {
+            id: val.id,
+            form_id: val.form_id,
+            form_identifier: val.form_identifier,
+            data: val.data.into_iter().collect(),
+            created_at: Some(created_at),
+        };
+
+        Ok(model)
+    }
+}
+
+fn get_string_array(val: Option<&Value>) -> Vec<String> {
+    match val {
+        Some(Value::Array(v)) => v.iter().map(|item| item.clone().as_string()).collect(),
+        _ => Vec::new(),
+    }
+}
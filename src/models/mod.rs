@@ -12,6 +12,18 @@ pub mod setting_model;
 pub mod token_claim_model;
 pub mod validation_error;
 pub mod content_model;
+pub mod sitemap_model;
+pub mod feed_model;
+pub mod content_event_model;
+pub mod asset_event_model;
+pub mod webhook_model;
+pub mod form_model;
+pub mod email_model;
+pub mod email_template_model;
+pub mod admin_user_session_model;
+pub mod admin_user_two_factor_model;
+pub mod audit_log_model;
+pub mod password_history_model;
 
 #[derive(Serialize, Debug, Deserialize, Clone, Default)]
 pub struct Pagination {
@@ -158,3 +170,4 @@ impl TryFrom<Object> for ModelCount {
         Ok(ModelCount { total: count })
     }
 }
+pub mod cms_api_key_model;
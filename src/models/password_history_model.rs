@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use surrealdb::sql::{Datetime, Object};
+use crate::error::{Error, Result};
+use super::BaseModel;
+
+/// Hash of a password an admin user had, kept so it is not set again.
+#[derive(Serialize, Debug, Deserialize, Clone, Default)]
+pub struct PasswordHistoryModel {
+    pub id: String,
+    pub admin_user_id: String,
+    pub password_hash: String,
+    pub created_at: Datetime,
+}
+
+impl TryFrom<Object> for PasswordHistoryModel {
+    type Error = Error;
+
+    fn try_from(val: Object) -> Result<PasswordHistoryModel> {
+        let id = val.get("id").get_id()?;
+        let admin_user_id = val.get("admin_user_id").get_string()?;
+        let password_hash = val.get("password_hash").get_string()?;
+        let created_at = val.get("created_at").get_datetime()?;
+
+        Ok(PasswordHistoryModel {
+            id,
+            admin_user_id,
+            password_hash,
+            created_at,
+        })
+    }
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, Default)]
+pub struct CreatablePasswordHistoryModel {
+    pub admin_user_id: String,
+    pub password_hash: String,
+}
@@ -1,14 +1,16 @@
 use crate::error::{Error, Result};
 use crate::models::BaseModel;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use surrealdb::sql::{Datetime, Object};
 
 #[derive(Serialize, Debug, Deserialize, Clone, Default, PartialEq)]
 pub struct PasswordResetModel {
     pub id: String,
     pub email: String,
-    pub token: String,
+    pub token_hash: String,
     pub status: PasswordResetTokenStatus,
+    pub expires_at: Datetime,
     pub created_at: Datetime,
 }
 
@@ -20,6 +22,7 @@ pub struct ForgotPasswordViewModel {
 #[derive(Serialize, Debug, Deserialize, Clone, PartialEq, Default)]
 pub enum PasswordResetTokenStatus {
     Active,
+    Used,
     #[default]
     Expire,
 }
@@ -29,20 +32,22 @@ impl TryFrom<Object> for PasswordResetModel {
     fn try_from(val: Object) -> Result<PasswordResetModel> {
         let id = val.get("id").get_id()?;
         let email = val.get("email").get_string()?;
-        let token = val.get("token").get_string()?;
+        let token_hash = val.get("token_hash").get_string()?;
+        let expires_at = val.get("expires_at").get_datetime()?;
         let created_at = val.get("created_at").get_datetime()?;
         let status = match val.get("status").get_string()?.as_str() {
             "Active" => PasswordResetTokenStatus::Active,
-            "Expire" => PasswordResetTokenStatus::Active,
+            "Used" => PasswordResetTokenStatus::Used,
             _ => PasswordResetTokenStatus::Expire,
         };
 
         Ok(PasswordResetModel {
             id,
             email,
-            token,
-            created_at,
+            token_hash,
             status,
+            expires_at,
+            created_at,
         })
     }
 }
@@ -50,5 +55,11 @@ impl TryFrom<Object> for PasswordResetModel {
 #[derive(Serialize, Debug, Deserialize, Clone, Default)]
 pub struct CreatablePasswordResetModel {
     pub email: String,
-    pub token: String,
+    pub token_hash: String,
+    pub expires_at: Datetime,
+}
+
+/// Only this hash is stored, the token itself is only ever in the email.
+pub fn hash_password_reset_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
 }
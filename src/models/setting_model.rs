@@ -5,6 +5,13 @@ use crate::models::BaseModel;
 use serde::{Deserialize, Serialize};
 use surrealdb::sql::{Datetime, Object};
 
+/// Settings only a super admin may change, through their own RPC rather
+/// than `StoreSetting`.
+pub const SUPER_ADMIN_SETTINGS: [&str; 1] = [TWO_FACTOR_REQUIRED_SETTING];
+
+/// "true" when every admin user has to log in with two factor authentication.
+pub const TWO_FACTOR_REQUIRED_SETTING: &str = "auth_two_factor_required";
+
 #[derive(Serialize, Debug, Deserialize, Clone, Default)]
 pub struct SettingModel {
     pub id: String,
@@ -0,0 +1,12 @@
+use surrealdb::sql::Datetime;
+use crate::providers::avored_cache_provider::AvoRedCacheProvider;
+
+#[derive(Debug, Clone)]
+pub struct SitemapUrlModel {
+    pub loc: String,
+    pub lastmod: Datetime,
+}
+
+/// Every url of the sitemap lives under a single key, content writes clear it
+/// and the next request rebuilds the list.
+pub type SitemapCache = AvoRedCacheProvider<(), Vec<SitemapUrlModel>>;
@@ -1,16 +1,68 @@
 use crate::models::admin_user_model::AdminUserModel;
 use serde::{Deserialize, Serialize};
 
+/// Claims of an access token. Only who and which session it is, the admin
+/// user with their roles is looked up for every request, see
+/// `AdminUserService::find_logged_in_admin_user`.
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct TokenClaims {
     pub sub: String,
-    pub name: String,
-    pub email: String,
-    pub admin_user_model: AdminUserModel,
+    pub sid: String,
     pub iat: usize,
     pub exp: usize,
 }
 
+impl TokenClaims {
+    /// Claims of an access token for the given session, valid for `expires_in` seconds.
+    pub fn new(admin_user_id: &str, session_id: &str, expires_in: i64) -> TokenClaims {
+        let now = chrono::Utc::now();
+        let iat = now.timestamp() as usize;
+        let exp = (now + chrono::Duration::seconds(expires_in)).timestamp() as usize;
+
+        TokenClaims {
+            sub: admin_user_id.to_string(),
+            sid: session_id.to_string(),
+            exp,
+            iat,
+        }
+    }
+}
+
+/// Partial token handed out after the password matched, while the login
+/// still waits for a two factor code. It can not be used as an access token.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct TwoFactorClaims {
+    pub sub: String,
+    pub purpose: TwoFactorPurpose,
+    pub iat: usize,
+    pub exp: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TwoFactorPurpose {
+    /// exchanged with a code for a session by `VerifyTwoFactor`
+    #[default]
+    Login,
+    /// two factor is required but not set up yet, allows enrolling only
+    Setup,
+}
+
+impl TwoFactorClaims {
+    pub fn new(admin_user_id: &str, purpose: TwoFactorPurpose, expires_in: i64) -> TwoFactorClaims {
+        let now = chrono::Utc::now();
+        let iat = now.timestamp() as usize;
+        let exp = (now + chrono::Duration::seconds(expires_in)).timestamp() as usize;
+
+        TwoFactorClaims {
+            sub: admin_user_id.to_string(),
+            purpose,
+            iat,
+            exp,
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct LoggedInUser {
     pub id: String,
@@ -0,0 +1,286 @@
+use std::time::SystemTime;
+use prost_types::Timestamp;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use surrealdb::sql::{Datetime, Object, Value};
+use crate::error::{Error, Result};
+use crate::models::asset_event_model::{AssetEventModel, AssetEventType};
+use crate::models::content_event_model::ContentEventModel;
+use super::BaseModel;
+
+/// Events a webhook can subscribe to.
+pub const WEBHOOK_EVENTS: [&str; 6] = [
+    "content.created",
+    "content.updated",
+    "content.deleted",
+    "content.published",
+    "asset.uploaded",
+    "asset.deleted",
+];
+
+/// Sent by `PingWebhook` only, endpoints never subscribe to it.
+pub const WEBHOOK_PING_EVENT: &str = "ping";
+
+#[derive(Serialize, Debug, Deserialize, Clone, Default)]
+pub struct WebhookModel {
+    pub id: String,
+    pub name: String,
+    pub url: String,
+    pub events: Vec<String>,
+    pub secret: String,
+    pub is_active: bool,
+    pub created_at: Datetime,
+    pub updated_at: Datetime,
+    pub created_by: String,
+    pub updated_by: String,
+}
+
+impl TryFrom<Object> for WebhookModel {
+    type Error = Error;
+    fn try_from(val: Object) -> Result<WebhookModel> {
+        let id = val.get("id").get_id()?;
+        let name = val.get("name").get_string()?;
+        let url = val.get("url").get_string()?;
+        let events = match val.get("events") {
+            Some(Value::Array(v)) => v.iter().map(|item| item.clone().as_string()).collect(),
+            _ => Vec::new(),
+        };
+        let secret = val.get("secret").get_string()?;
+        let is_active = val.get("is_active").get_bool()?;
+        let created_at = val.get("created_at").get_datetime()?;
+        let updated_at = val.get("updated_at").get_datetime()?;
+        let created_by = val.get("created_by").get_string()?;
+        let updated_by = val.get("updated_by").get_string()?;
+
+        Ok(WebhookModel {
+            id,
+            name,
+            url,
+            events,
+            secret,
+            is_active,
+            created_at,
+            updated_at,
+            created_by,
+            updated_by,
+        })
+    }
+}
+
+impl TryFrom<WebhookModel> for crate::api::proto::webhook::WebhookModel {
+    type Error = Error;
+
+    fn try_from(val: WebhookModel) -> Result<crate::api::proto::webhook::WebhookModel> {
+        let created_at = Timestamp::from(SystemTime::from(val.created_at.to_utc()));
+        let updated_at = Timestamp::from(SystemTime::from(val.updated_at.to_utc()));
+
+        let model = crate::api::proto::webhook::WebhookModel {
+            id: val.id,
+            name: val.name,
+            url: val.url,
+            events: val.events,
+            is_active: val.is_active,
+            created_at: Some(created_at),
+            updated_at: Some(updated_at),
+            created_by: val.created_by,
+            updated_by: val.updated_by,
+        };
+
+        Ok(model)
+    }
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone)]
+pub struct CreatableWebhookModel {
+    pub name: String,
+    pub url: String,
+    pub events: Vec<String>,
+    pub secret: String,
+    pub is_active: bool,
+    pub logged_in_username: String,
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone)]
+pub struct UpdatableWebhookModel {
+    pub id: String,
+    pub name: String,
+    pub url: String,
+    pub events: Vec<String>,
+    pub secret: Option<String>,
+    pub is_active: bool,
+    pub logged_in_username: String,
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, Default, PartialEq)]
+pub enum WebhookDeliveryStatus {
+    #[default]
+    Pending,
+    Succeeded,
+    Failed,
+}
+
+impl TryFrom<String> for WebhookDeliveryStatus {
+    type Error = Error;
+
+    fn try_from(val: String) -> Result<WebhookDeliveryStatus> {
+        let status = match val.as_str() {
+            "Succeeded" => WebhookDeliveryStatus::Succeeded,
+            "Failed" => WebhookDeliveryStatus::Failed,
+            _ => WebhookDeliveryStatus::Pending,
+        };
+
+        Ok(status)
+    }
+}
+
+impl TryFrom<WebhookDeliveryStatus> for String {
+    type Error = Error;
+
+    fn try_from(val: WebhookDeliveryStatus) -> Result<String> {
+        let status = match val {
+            WebhookDeliveryStatus::Pending => String::from("Pending"),
+            WebhookDeliveryStatus::Succeeded => String::from("Succeeded"),
+            WebhookDeliveryStatus::Failed => String::from("Failed"),
+        };
+
+        Ok(status)
+    }
+}
+
+/// One attempt log entry: the exact signed body plus the outcome of the latest try.
+#[derive(Serialize, Debug, Deserialize, Clone, Default)]
+pub struct WebhookDeliveryModel {
+    pub id: String,
+    pub webhook_id: String,
+    pub event: String,
+    pub payload: String,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i64,
+    pub response_status: i64,
+    pub response_body: String,
+    pub error: String,
+    pub next_attempt_at: Datetime,
+    pub created_at: Datetime,
+    pub updated_at: Datetime,
+}
+
+impl TryFrom<Object> for WebhookDeliveryModel {
+    type Error = Error;
+    fn try_from(val: Object) -> Result<WebhookDeliveryModel> {
+        let id = val.get("id").get_id()?;
+        let webhook_id = val.get("webhook_id").get_string()?;
+        let event = val.get("event").get_string()?;
+        let payload = val.get("payload").get_string()?;
+        let status = val.get("status").get_string()?.try_into()?;
+        let attempts = val.get("attempts").get_int()?;
+        let response_status = val.get("response_status").get_int()?;
+        let response_body = val.get("response_body").get_string()?;
+        let error = val.get("error").get_string()?;
+        let next_attempt_at = val.get("next_attempt_at").get_datetime()?;
+        let created_at = val.get("created_at").get_datetime()?;
+        let updated_at = val.get("updated_at").get_datetime()?;
+
+        Ok(WebhookDeliveryModel {
+            id,
+            webhook_id,
+            event,
+            payload,
+            status,
+            attempts,
+            response_status,
+            response_body,
+            error,
+            next_attempt_at,
+            created_at,
+            updated_at,
+        })
+    }
+}
+
+impl TryFrom<WebhookDeliveryModel> for crate::api::proto::webhook::WebhookDeliveryModel {
+    type Error = Error;
+
+    fn try_from(val: WebhookDeliveryModel) -> Result<crate::api::proto::webhook::WebhookDeliveryModel> {
+        let next_attempt_at = Timestamp::from(SystemTime::from(val.next_attempt_at.to_utc()));
+        let created_at = Timestamp::from(SystemTime::from(val.created_at.to_utc()));
+        let updated_at = Timestamp::from(SystemTime::from(val.updated_at.to_utc()));
+
+        let model = crate::api::proto::webhook::WebhookDeliveryModel {
+            id: val.id,
+            webhook_id: val.webhook_id,
+            event: val.event,
+            payload: val.payload,
+            status: val.status.try_into()?,
+            attempts: val.attempts,
+            response_status: val.response_status,
+            response_body: val.response_body,
+            error: val.error,
+            next_attempt_at: Some(next_attempt_at),
+            created_at: Some(created_at),
+            updated_at: Some(updated_at),
+        };
+
+        Ok(model)
+    }
+}
+
+/// Outcome of one delivery attempt written back to the log.
+#[derive(Debug, Clone)]
+pub struct UpdatableWebhookDeliveryModel {
+    pub id: String,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i64,
+    pub response_status: i64,
+    pub response_body: String,
+    pub error: String,
+    pub next_attempt_at: Datetime,
+}
+
+/// Body posted to webhook endpoints.
+#[derive(Serialize, Debug, Clone)]
+pub struct WebhookEventModel {
+    pub event: String,
+    pub occurred_at: String,
+    pub data: serde_json::Value,
+}
+
+impl TryFrom<ContentEventModel> for WebhookEventModel {
+    type Error = Error;
+
+    fn try_from(val: ContentEventModel) -> Result<WebhookEventModel> {
+        let event_type: String = val.event_type.try_into()?;
+
+        Ok(WebhookEventModel {
+            event: format!("content.{event_type}"),
+            occurred_at: val.occurred_at.to_utc().to_rfc3339(),
+            data: json!({
+                "collection": val.collection,
+                "content_id": val.content_id,
+                "identifier": val.identifier,
+                "content": val.data,
+            }),
+        })
+    }
+}
+
+impl TryFrom<AssetEventModel> for WebhookEventModel {
+    type Error = Error;
+
+    fn try_from(val: AssetEventModel) -> Result<WebhookEventModel> {
+        let event_type = match val.event_type {
+            AssetEventType::Uploaded => "uploaded",
+            AssetEventType::Deleted => "deleted",
+        };
+
+        Ok(WebhookEventModel {
+            event: format!("asset.{event_type}"),
+            occurred_at: val.occurred_at.to_utc().to_rfc3339(),
+            data: json!({
+                "asset_id": val.asset_id,
+                "name": val.name,
+                "path": val.path,
+                "asset_type": val.asset_type,
+            }),
+        })
+    }
+}
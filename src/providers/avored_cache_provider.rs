@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Small in-process cache with a size bound and a time to live.
+/// When the cache is full, expired entries are dropped first and then the
+/// oldest entry is evicted.
+pub struct AvoRedCacheProvider<K, V> {
+    entries: Mutex<HashMap<K, CacheEntry<V>>>,
+    max_entries: usize,
+    ttl: Duration,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+struct CacheEntry<V> {
+    value: V,
+    inserted_at: Instant,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub entries: u64,
+    pub max_entries: u64,
+    pub ttl_seconds: u64,
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> AvoRedCacheProvider<K, V> {
+    pub fn register(max_entries: usize, ttl: Duration) -> AvoRedCacheProvider<K, V> {
+        AvoRedCacheProvider {
+            entries: Mutex::new(HashMap::new()),
+            max_entries,
+            ttl,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    pub fn get(&self, key: &K) -> Option<V> {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+
+        let value = match entries.get(key) {
+            Some(entry) if entry.inserted_at.elapsed() < self.ttl => Some(entry.value.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        };
+
+        match value {
+            Some(_) => self.hits.fetch_add(1, Ordering::Relaxed),
+            None => self.misses.fetch_add(1, Ordering::Relaxed),
+        };
+
+        value
+    }
+
+    pub fn insert(&self, key: K, value: V) {
+        if self.max_entries == 0 {
+            return;
+        }
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+
+        if entries.len() >= self.max_entries && !entries.contains_key(&key) {
+            let ttl = self.ttl;
+            entries.retain(|_, entry| entry.inserted_at.elapsed() < ttl);
+        }
+        if entries.len() >= self.max_entries && !entries.contains_key(&key) {
+            let oldest_key = entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.inserted_at)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest_key) = oldest_key {
+                entries.remove(&oldest_key);
+            }
+        }
+
+        entries.insert(
+            key,
+            CacheEntry {
+                value,
+                inserted_at: Instant::now(),
+            },
+        );
+    }
+
+    /// Removes every entry for which the predicate returns true.
+    pub fn invalidate<F>(&self, predicate: F)
+    where
+        F: Fn(&K, &V) -> bool,
+    {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.retain(|key, entry| !predicate(key, &entry.value));
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            entries: entries.len() as u64,
+            max_entries: self.max_entries as u64,
+            ttl_seconds: self.ttl.as_secs(),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use std::sync::atomic::{AtomicI64, Ordering};
+use chrono::{DateTime, Utc};
+
+/// Current time of the services that wait between attempts. Tests move it
+/// forward instead of sleeping.
+#[derive(Default)]
+pub struct AvoRedClockProvider {
+    offset_seconds: AtomicI64,
+}
+
+impl AvoRedClockProvider {
+    pub fn register() -> AvoRedClockProvider {
+        AvoRedClockProvider::default()
+    }
+
+    pub fn now(&self) -> DateTime<Utc> {
+        Utc::now() + chrono::Duration::seconds(self.offset_seconds.load(Ordering::Relaxed))
+    }
+
+    pub fn timestamp(&self) -> i64 {
+        self.now().timestamp()
+    }
+
+    #[cfg(test)]
+    pub fn advance(&self, seconds: i64) {
+        self.offset_seconds.fetch_add(seconds, Ordering::Relaxed);
+    }
+}
@@ -1,6 +1,7 @@
 use crate::error::{Error, Result};
 use dotenvy::dotenv;
 use std::env;
+use std::net::IpAddr;
 
 #[derive(Debug, Clone)]
 pub struct AvoRedConfigProvider {
@@ -8,15 +9,46 @@ pub struct AvoRedConfigProvider {
     pub database_namespace: String,
     pub database_name: String,
     pub jwt_secret_key: String,
+    pub jwt_expired_in_seconds: i64,
+    pub refresh_token_expired_in_seconds: i64,
+    pub password_reset_token_expired_in_seconds: i64,
+    pub login_max_attempts: u32,
+    pub login_ip_max_attempts: u32,
+    pub login_lockout_seconds: i64,
     pub react_admin_app_url: String,
     // pub react_frontend_app_url: String,
-    // pub back_end_app_url: String,
+    pub back_end_app_url: String,
     pub cors_allowed_app_url: Vec<String>,
-    pub password_salt: String,
-    pub smtp_host: String,
-    pub smtp_username: String,
-    pub smtp_password: String,
-    pub smtp_port: u16,
+    pub password_hash: PasswordHashConfig,
+    pub password_policy: PasswordPolicyConfig,
+    pub smtp: Option<SmtpConfig>,
+    pub mail_transport: String,
+    pub mail_file_dir: String,
+    pub cms_cache_max_entries: usize,
+    pub cms_cache_ttl_seconds: u64,
+    pub cms_watch_query_api_key: bool,
+    pub admin_user_cache_ttl_seconds: u64,
+    pub themes_dir: String,
+    pub theme: String,
+    pub sitemap_max_urls: usize,
+    pub feed_items: i64,
+    pub webhook_max_attempts: i64,
+    pub webhook_timeout_seconds: u64,
+    pub webhook_retry_base_seconds: i64,
+    pub webhook_allow_private_targets: bool,
+    pub static_export_dir: String,
+    pub static_export_keep: usize,
+    pub form_ip_max_submissions: u32,
+    pub form_max_submissions: u32,
+    pub form_rate_limit_window_seconds: u64,
+    pub form_honeypot_field: String,
+    pub form_challenge_verify_url: String,
+    pub form_challenge_secret: String,
+    pub form_upload_dir: String,
+    pub form_max_upload_kb: i64,
+    pub client_ip: ClientIpConfig,
+    pub email_max_attempts: i64,
+    pub email_retry_base_seconds: i64,
 }
 
 // pub fn config() -> &'static AvoRedConfigProvider {
@@ -45,24 +77,194 @@ impl AvoRedConfigProvider {
         let vec_cors_urls = env_str_allowed_cors.split(',').collect::<Vec<&str>>();
         let cors_urls = vec_cors_urls.iter().map(|url| url.to_string()).collect();
 
+        let mail_transport = get_env_or("AVORED_MAIL_TRANSPORT", "smtp");
+        let smtp = match mail_transport.as_str() {
+            "smtp" => Some(SmtpConfig {
+                host: get_env("SMTP_HOST")?,
+                username: get_env("SMTP_USERNAME")?,
+                password: get_env("SMTP_PASSWORD")?,
+                port: get_env("SMTP_PORT")?.parse::<u16>()?,
+                encryption: get_env_or("SMTP_ENCRYPTION", "starttls"),
+            }),
+            _ => None,
+        };
+
         Ok(AvoRedConfigProvider {
             database_folder_name: get_env("AVORED_DATABASE_FOLDER_NAME")?,
             database_namespace: get_env("AVORED_DATABASE_NAMESPACE")?,
             database_name: get_env("AVORED_DATABASE_NAME")?,
             jwt_secret_key: get_env("AVORED_JWT_SECRET")?,
+            jwt_expired_in_seconds: parse_duration_seconds(&get_env_or("AVORED_JWT_EXPIRED_IN", "15m"))?,
+            refresh_token_expired_in_seconds: parse_duration_seconds(&get_env_or("AVORED_REFRESH_TOKEN_EXPIRED_IN", "14d"))?,
+            password_reset_token_expired_in_seconds: parse_duration_seconds(&get_env_or("AVORED_PASSWORD_RESET_TOKEN_EXPIRED_IN", "1h"))?,
+            login_max_attempts: get_env_or("AVORED_LOGIN_MAX_ATTEMPTS", "5").parse::<u32>()?,
+            login_ip_max_attempts: get_env_or("AVORED_LOGIN_IP_MAX_ATTEMPTS", "20").parse::<u32>()?,
+            login_lockout_seconds: parse_duration_seconds(&get_env_or("AVORED_LOGIN_LOCKOUT", "15m"))?,
             react_admin_app_url: get_env("AVORED_REACT_ADMIN_APP_URL")?,
             // react_frontend_app_url: get_env("AVORED_REACT_FRONTEND_APP_URL")?,
-            // back_end_app_url: get_env("AVORED_BACK_END_APP_URL")?,
+            back_end_app_url: get_env("AVORED_BACK_END_APP_URL")?,
             cors_allowed_app_url: cors_urls,
-            password_salt: get_env("AVORED_PASSWORD_SALT")?,
-            smtp_host: get_env("SMTP_HOST")?,
-            smtp_username: get_env("SMTP_USERNAME")?,
-            smtp_password: get_env("SMTP_PASSWORD")?,
-            smtp_port: get_env("SMTP_PORT")?.parse::<u16>()?,
+            password_hash: PasswordHashConfig {
+                params: argon2::Params::new(
+                    get_env_or("AVORED_ARGON2_MEMORY_KIB", &argon2::Params::DEFAULT_M_COST.to_string()).parse::<u32>()?,
+                    get_env_or("AVORED_ARGON2_ITERATIONS", &argon2::Params::DEFAULT_T_COST.to_string()).parse::<u32>()?,
+                    get_env_or("AVORED_ARGON2_PARALLELISM", &argon2::Params::DEFAULT_P_COST.to_string()).parse::<u32>()?,
+                    None,
+                )?,
+                legacy_salt: get_env_or("AVORED_PASSWORD_SALT", ""),
+            },
+            password_policy: PasswordPolicyConfig::parse(
+                get_env_or("AVORED_PASSWORD_MIN_LENGTH", "10").parse::<usize>()?,
+                get_env_or("AVORED_PASSWORD_MAX_LENGTH", "128").parse::<usize>()?,
+                &get_env_or("AVORED_PASSWORD_REQUIRED_CHARACTERS", "lowercase,uppercase,digit"),
+                get_env_or("AVORED_PASSWORD_HISTORY", "5").parse::<i64>()?,
+            )?,
+            smtp,
+            mail_transport,
+            mail_file_dir: get_env_or("AVORED_MAIL_FILE_DIR", "mail"),
+            cms_cache_max_entries: get_env_or("AVORED_CMS_CACHE_MAX_ENTRIES", "1000").parse::<usize>()?,
+            cms_cache_ttl_seconds: get_env_or("AVORED_CMS_CACHE_TTL_SECONDS", "300").parse::<u64>()?,
+            cms_watch_query_api_key: get_env_or("AVORED_CMS_WATCH_QUERY_API_KEY", "false") == "true",
+            admin_user_cache_ttl_seconds: get_env_or("AVORED_ADMIN_USER_CACHE_TTL_SECONDS", "30").parse::<u64>()?,
+            themes_dir: get_env_or("AVORED_THEMES_DIR", "resources/themes"),
+            theme: get_env_or("AVORED_THEME", "default"),
+            sitemap_max_urls: get_env_or("AVORED_SITEMAP_MAX_URLS", "50000").parse::<usize>()?,
+            feed_items: get_env_or("AVORED_FEED_ITEMS", "20").parse::<i64>()?,
+            webhook_max_attempts: get_env_or("AVORED_WEBHOOK_MAX_ATTEMPTS", "5").parse::<i64>()?,
+            webhook_timeout_seconds: get_env_or("AVORED_WEBHOOK_TIMEOUT_SECONDS", "10").parse::<u64>()?,
+            webhook_retry_base_seconds: get_env_or("AVORED_WEBHOOK_RETRY_BASE_SECONDS", "30").parse::<i64>()?,
+            webhook_allow_private_targets: get_env_or("AVORED_WEBHOOK_ALLOW_PRIVATE_TARGETS", "false") == "true",
+            static_export_dir: get_env_or("AVORED_STATIC_EXPORT_DIR", "exports"),
+            static_export_keep: get_env_or("AVORED_STATIC_EXPORT_KEEP", "5").parse::<usize>()?,
+            form_ip_max_submissions: get_env_or("AVORED_FORM_IP_MAX_SUBMISSIONS", "5").parse::<u32>()?,
+            form_max_submissions: get_env_or("AVORED_FORM_MAX_SUBMISSIONS", "100").parse::<u32>()?,
+            form_rate_limit_window_seconds: get_env_or("AVORED_FORM_RATE_LIMIT_WINDOW_SECONDS", "600").parse::<u64>()?,
+            form_honeypot_field: get_env_or("AVORED_FORM_HONEYPOT_FIELD", "company_website"),
+            form_challenge_verify_url: get_env_or("AVORED_FORM_CHALLENGE_VERIFY_URL", ""),
+            form_challenge_secret: get_env_or("AVORED_FORM_CHALLENGE_SECRET", ""),
+            form_upload_dir: get_env_or("AVORED_FORM_UPLOAD_DIR", "storage/form-uploads"),
+            form_max_upload_kb: get_env_or("AVORED_FORM_MAX_UPLOAD_KB", "5120").parse::<i64>()?,
+            client_ip: ClientIpConfig::parse(
+                &get_env_or("AVORED_CLIENT_IP_HEADER", "x-forwarded-for"),
+                &get_env_or("AVORED_TRUSTED_PROXIES", ""),
+            )?,
+            email_max_attempts: get_env_or("AVORED_EMAIL_MAX_ATTEMPTS", "6").parse::<i64>()?,
+            email_retry_base_seconds: get_env_or("AVORED_EMAIL_RETRY_BASE_SECONDS", "60").parse::<i64>()?,
+        })
+    }
+}
+
+/// Argon2id parameters new password hashes are made with. Every hash has a
+/// random salt of its own, `legacy_salt` is the one all passwords used to share.
+#[derive(Debug, Clone)]
+pub struct PasswordHashConfig {
+    pub params: argon2::Params,
+    pub legacy_salt: String,
+}
+
+/// What a new password has to look like. `history` is how many of the
+/// passwords an admin user had last can not be set again.
+#[derive(Debug, Clone)]
+pub struct PasswordPolicyConfig {
+    pub min_length: usize,
+    pub max_length: usize,
+    pub require_lowercase: bool,
+    pub require_uppercase: bool,
+    pub require_digit: bool,
+    pub require_symbol: bool,
+    pub history: i64,
+}
+
+impl PasswordPolicyConfig {
+    /// `required_characters` is a comma separated list of lowercase,
+    /// uppercase, digit and symbol, it may be empty.
+    fn parse(min_length: usize, max_length: usize, required_characters: &str, history: i64) -> Result<Self> {
+        let mut password_policy = PasswordPolicyConfig {
+            min_length,
+            max_length,
+            require_lowercase: false,
+            require_uppercase: false,
+            require_digit: false,
+            require_symbol: false,
+            history: history.max(0),
+        };
+
+        for character_class in required_characters.split(',').map(str::trim).filter(|c| !c.is_empty()) {
+            match character_class {
+                "lowercase" => password_policy.require_lowercase = true,
+                "uppercase" => password_policy.require_uppercase = true,
+                "digit" => password_policy.require_digit = true,
+                "symbol" => password_policy.require_symbol = true,
+                _ => {
+                    return Err(Error::Generic(format!(
+                        "unknown character class in AVORED_PASSWORD_REQUIRED_CHARACTERS: {character_class}"
+                    )))
+                }
+            }
+        }
+
+        Ok(password_policy)
+    }
+}
+
+/// The smtp relay, only read when `AVORED_MAIL_TRANSPORT` is smtp.
+#[derive(Debug, Clone)]
+pub struct SmtpConfig {
+    pub host: String,
+    pub username: String,
+    pub password: String,
+    pub port: u16,
+    pub encryption: String,
+}
+
+/// Where the client address of a request comes from. The `header` is only
+/// read when the peer is one of the `trusted_proxies`, by default there are
+/// none and the peer address is the client address.
+#[derive(Debug, Clone)]
+pub struct ClientIpConfig {
+    pub header: String,
+    pub trusted_proxies: Vec<IpAddr>,
+}
+
+impl ClientIpConfig {
+    /// `trusted_proxies` is a comma separated list of ip addresses, it may be empty.
+    fn parse(header: &str, trusted_proxies: &str) -> Result<Self> {
+        let trusted_proxies = trusted_proxies
+            .split(',')
+            .map(str::trim)
+            .filter(|ip| !ip.is_empty())
+            .map(|ip| ip.parse::<IpAddr>())
+            .collect::<std::result::Result<Vec<IpAddr>, _>>()?;
+
+        Ok(ClientIpConfig {
+            header: header.trim().to_lowercase(),
+            trusted_proxies,
         })
     }
+
+    pub fn is_trusted_proxy(&self, ip: &IpAddr) -> bool {
+        self.trusted_proxies.contains(ip)
+    }
 }
 
 fn get_env(name: &'static str) -> Result<String> {
     env::var(name).map_err(|_| Error::ConfigMissing(name.to_string()))
 }
+
+fn get_env_or(name: &'static str, default: &str) -> String {
+    env::var(name).unwrap_or_else(|_| default.to_string())
+}
+
+/// Reads durations like `90s`, `15m`, `12h` or `14d`, a bare number is seconds.
+fn parse_duration_seconds(value: &str) -> Result<i64> {
+    let value = value.trim();
+    let (amount, unit_seconds) = match value.chars().last() {
+        Some('s') => (&value[..value.len() - 1], 1),
+        Some('m') => (&value[..value.len() - 1], 60),
+        Some('h') => (&value[..value.len() - 1], 60 * 60),
+        Some('d') => (&value[..value.len() - 1], 24 * 60 * 60),
+        _ => (value, 1),
+    };
+
+    Ok(amount.trim().parse::<i64>()? * unit_seconds)
+}
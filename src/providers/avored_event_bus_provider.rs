@@ -0,0 +1,28 @@
+use tokio::sync::broadcast;
+use tracing::debug;
+
+/// In-process publish/subscribe channel. Every subscriber gets its own copy of
+/// each event published after it subscribed; a subscriber that falls more than
+/// `capacity` events behind skips the oldest ones.
+pub struct AvoRedEventBusProvider<E> {
+    sender: broadcast::Sender<E>,
+}
+
+impl<E: Clone> AvoRedEventBusProvider<E> {
+    pub fn register(capacity: usize) -> AvoRedEventBusProvider<E> {
+        let (sender, _) = broadcast::channel(capacity.max(1));
+
+        AvoRedEventBusProvider { sender }
+    }
+
+    pub fn publish(&self, event: E) {
+        // an error only means nobody is listening right now
+        if self.sender.send(event).is_err() {
+            debug!("event published without subscribers");
+        }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<E> {
+        self.sender.subscribe()
+    }
+}
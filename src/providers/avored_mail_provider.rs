@@ -0,0 +1,123 @@
+use std::fmt;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::transport::stub::AsyncStubTransport;
+use lettre::{AsyncFileTransport, AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use tracing::info;
+use crate::error::{Error, Result};
+use crate::providers::avored_config_provider::{AvoRedConfigProvider, SmtpConfig};
+
+/// Where outgoing email ends up, picked with `AVORED_MAIL_TRANSPORT`.
+pub enum AvoRedMailProvider {
+    /// smtp relay, with STARTTLS, implicit TLS or no encryption
+    Smtp(AsyncSmtpTransport<Tokio1Executor>),
+    /// writes every email as an `.eml` file into a folder
+    File(AsyncFileTransport<Tokio1Executor>),
+    /// prints the headers of every email to the log, bodies carry password
+    /// reset and two factor tokens so they are left out
+    Stdout,
+    /// keeps every email in memory, for tests
+    Memory(AsyncStubTransport),
+}
+
+/// A failed send. Permanent failures are not worth another attempt.
+#[derive(Debug, Clone)]
+pub struct MailSendError {
+    pub is_permanent: bool,
+    pub message: String,
+}
+
+impl fmt::Display for MailSendError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{}", self.message)
+    }
+}
+
+impl AvoRedMailProvider {
+    pub fn register(config: &AvoRedConfigProvider) -> Result<AvoRedMailProvider> {
+        let mail_provider = match config.mail_transport.as_str() {
+            "smtp" => {
+                let smtp_config = config
+                    .smtp
+                    .as_ref()
+                    .ok_or_else(|| Error::ConfigMissing(String::from("SMTP_HOST")))?;
+                AvoRedMailProvider::Smtp(smtp_transport(smtp_config)?)
+            }
+            "file" => {
+                std::fs::create_dir_all(&config.mail_file_dir)?;
+                AvoRedMailProvider::File(AsyncFileTransport::<Tokio1Executor>::new(&config.mail_file_dir))
+            }
+            "stdout" => AvoRedMailProvider::Stdout,
+            "memory" => AvoRedMailProvider::Memory(AsyncStubTransport::new_ok()),
+            transport => {
+                return Err(Error::Generic(format!(
+                    "unknown mail transport {transport}, use smtp, file, stdout or memory"
+                )))
+            }
+        };
+
+        Ok(mail_provider)
+    }
+
+    pub async fn send(&self, email_message: Message) -> core::result::Result<(), MailSendError> {
+        match self {
+            AvoRedMailProvider::Smtp(mailer) => mailer
+                .send(email_message)
+                .await
+                .map(|_| ())
+                .map_err(|e| MailSendError {
+                    is_permanent: e.is_permanent(),
+                    message: e.to_string(),
+                }),
+            AvoRedMailProvider::File(mailer) => mailer
+                .send(email_message)
+                .await
+                .map(|_| ())
+                .map_err(|e| MailSendError {
+                    is_permanent: false,
+                    message: e.to_string(),
+                }),
+            AvoRedMailProvider::Stdout => {
+                let recipients = email_message
+                    .envelope()
+                    .to()
+                    .iter()
+                    .map(|address| address.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                info!("email to {recipients}\n{}(body not printed)", email_message.headers());
+                Ok(())
+            }
+            AvoRedMailProvider::Memory(mailer) => mailer
+                .send(email_message)
+                .await
+                .map_err(|e| MailSendError {
+                    is_permanent: false,
+                    message: e.to_string(),
+                }),
+        }
+    }
+}
+
+fn smtp_transport(smtp_config: &SmtpConfig) -> Result<AsyncSmtpTransport<Tokio1Executor>> {
+    let builder = match smtp_config.encryption.as_str() {
+        "starttls" => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&smtp_config.host)?,
+        "tls" => AsyncSmtpTransport::<Tokio1Executor>::relay(&smtp_config.host)?,
+        "none" => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&smtp_config.host),
+        encryption => {
+            return Err(Error::Generic(format!(
+                "unknown smtp encryption {encryption}, use starttls, tls or none"
+            )))
+        }
+    };
+
+    let builder = builder.port(smtp_config.port);
+    let builder = match smtp_config.username.is_empty() {
+        true => builder,
+        false => builder.credentials(Credentials::new(
+            smtp_config.username.clone(),
+            smtp_config.password.clone(),
+        )),
+    };
+
+    Ok(builder.build())
+}
@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Windows kept before expired ones are swept on the next hit.
+const SWEEP_THRESHOLD: usize = 10_000;
+
+/// Fixed window rate limiter kept in process memory.
+/// A `max_attempts` of 0 turns the limiter off.
+pub struct AvoRedRateLimitProvider<K> {
+    windows: Mutex<HashMap<K, RateLimitWindow>>,
+    max_attempts: u32,
+    window: Duration,
+}
+
+struct RateLimitWindow {
+    attempts: u32,
+    started_at: Instant,
+}
+
+impl<K: Eq + Hash> AvoRedRateLimitProvider<K> {
+    pub fn register(max_attempts: u32, window: Duration) -> AvoRedRateLimitProvider<K> {
+        AvoRedRateLimitProvider {
+            windows: Mutex::new(HashMap::new()),
+            max_attempts,
+            window,
+        }
+    }
+
+    /// Counts an attempt for the key and returns false once the key has used
+    /// up its attempts in the current window.
+    pub fn hit(&self, key: K) -> bool {
+        if self.max_attempts == 0 {
+            return true;
+        }
+        let mut windows = self.windows.lock().unwrap_or_else(|e| e.into_inner());
+
+        if windows.len() >= SWEEP_THRESHOLD {
+            let window = self.window;
+            windows.retain(|_, rate_limit_window| rate_limit_window.started_at.elapsed() < window);
+        }
+
+        let rate_limit_window = windows.entry(key).or_insert(RateLimitWindow {
+            attempts: 0,
+            started_at: Instant::now(),
+        });
+        if rate_limit_window.started_at.elapsed() >= self.window {
+            rate_limit_window.attempts = 0;
+            rate_limit_window.started_at = Instant::now();
+        }
+
+        if rate_limit_window.attempts >= self.max_attempts {
+            return false;
+        }
+        rate_limit_window.attempts += 1;
+
+        true
+    }
+
+    /// True when the key has used up its attempts in the current window,
+    /// without counting an attempt.
+    pub fn is_limited(&self, key: &K) -> bool {
+        if self.max_attempts == 0 {
+            return false;
+        }
+        let windows = self.windows.lock().unwrap_or_else(|e| e.into_inner());
+
+        windows.get(key).is_some_and(|rate_limit_window| {
+            rate_limit_window.started_at.elapsed() < self.window
+                && rate_limit_window.attempts >= self.max_attempts
+        })
+    }
+
+    /// Seconds until the window of the key starts over.
+    pub fn retry_after(&self, key: &K) -> u64 {
+        let windows = self.windows.lock().unwrap_or_else(|e| e.into_inner());
+
+        windows
+            .get(key)
+            .map(|rate_limit_window| {
+                self.window
+                    .saturating_sub(rate_limit_window.started_at.elapsed())
+                    .as_secs()
+            })
+            .unwrap_or_default()
+    }
+}
@@ -1,33 +1,140 @@
-use crate::error::Result;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::RwLock;
+use handlebars::{no_escape, Handlebars};
+use serde::Serialize;
+use crate::error::{Error, Result};
+use crate::models::email_template_model::{RenderedEmailModel, EMAIL_TEMPLATES};
 use crate::providers::avored_config_provider::AvoRedConfigProvider;
-use handlebars::Handlebars;
-use lettre::transport::smtp::authentication::Credentials;
-use lettre::{AsyncSmtpTransport, Tokio1Executor};
+use crate::providers::avored_mail_provider::AvoRedMailProvider;
 
+const EMAIL_TEMPLATE_DIR: &str = "./resources/mail";
+
+/// Email templates are registered under three names: `{identifier}` for the
+/// html body, `{identifier}.subject` and `{identifier}.text`. Stored templates
+/// replace them at runtime, the files on disk are the fallback.
 pub struct AvoRedTemplateProvider {
-    pub handlebars: Handlebars<'static>,
-    pub mailer: AsyncSmtpTransport<Tokio1Executor>,
+    registry: RwLock<EmailTemplateRegistry>,
+    defaults: BTreeMap<String, RenderedEmailModel>,
+    pub mailer: AvoRedMailProvider,
 }
 
 impl AvoRedTemplateProvider {
     pub async fn register(config: AvoRedConfigProvider) -> Result<AvoRedTemplateProvider> {
-        let mut reg = Handlebars::new();
-        reg.register_template_file("forgot-password", "./resources/mail/forgot-password.hbs")?;
-        reg.register_template_file("contact-us-email", "./resources/mail/contact-us-email.hbs")?;
+        let mut defaults = BTreeMap::new();
+        for definition in EMAIL_TEMPLATES.iter() {
+            let html_path = format!("{EMAIL_TEMPLATE_DIR}/{}.hbs", definition.identifier);
+            let text_path = format!("{EMAIL_TEMPLATE_DIR}/{}.txt.hbs", definition.identifier);
+            let text_body = match Path::new(&text_path).exists() {
+                true => std::fs::read_to_string(&text_path)?,
+                false => String::from(""),
+            };
+
+            let default_template = RenderedEmailModel {
+                subject: definition.subject.to_string(),
+                html_body: std::fs::read_to_string(&html_path)?,
+                text_body,
+            };
+            defaults.insert(definition.identifier.to_string(), default_template);
+        }
 
-        println!("{:?}", config.smtp_username);
-        let creds = Credentials::new(config.smtp_username, config.smtp_password);
+        let mut registry = EmailTemplateRegistry::new();
+        for (identifier, default_template) in &defaults {
+            registry.register(identifier, default_template)?;
+        }
 
-        let mailer: AsyncSmtpTransport<Tokio1Executor> =
-            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.smtp_host)
-                .unwrap()
-                .port(config.smtp_port)
-                .credentials(creds)
-                .build();
+        let mailer = AvoRedMailProvider::register(&config)?;
 
         Ok(AvoRedTemplateProvider {
-            handlebars: reg,
+            registry: RwLock::new(registry),
+            defaults,
             mailer,
         })
     }
+
+    /// Subject, html and text body of the email template as it is on disk.
+    pub fn default_email_template(&self, identifier: &str) -> Option<&RenderedEmailModel> {
+        self.defaults.get(identifier)
+    }
+
+    pub fn render_email<T: Serialize>(&self, identifier: &str, data: &T) -> Result<RenderedEmailModel> {
+        self.registry
+            .read()
+            .map_err(|_| Error::Generic(String::from("email template lock poisoned")))?
+            .render(identifier, data)
+    }
+
+    /// Renders unsaved template sources, without touching the registered ones.
+    pub fn preview_email<T: Serialize>(&self, source: &RenderedEmailModel, data: &T) -> Result<RenderedEmailModel> {
+        let mut registry = EmailTemplateRegistry::new();
+        registry.register("preview", source)?;
+
+        registry.render("preview", data)
+    }
+
+    /// Swaps in a stored template, called when one is saved.
+    pub fn register_email_template(&self, identifier: &str, source: &RenderedEmailModel) -> Result<()> {
+        self.registry
+            .write()
+            .map_err(|_| Error::Generic(String::from("email template lock poisoned")))?
+            .register(identifier, source)
+    }
+
+    /// Goes back to the template on disk, called when a stored one is deleted.
+    pub fn restore_email_template(&self, identifier: &str) -> Result<()> {
+        match self.defaults.get(identifier) {
+            Some(default_template) => self.register_email_template(identifier, default_template),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Html bodies are escaped, subjects and text bodies are not.
+struct EmailTemplateRegistry {
+    html: Handlebars<'static>,
+    plain: Handlebars<'static>,
+}
+
+impl EmailTemplateRegistry {
+    fn new() -> Self {
+        let mut plain = Handlebars::new();
+        plain.register_escape_fn(no_escape);
+
+        EmailTemplateRegistry {
+            html: Handlebars::new(),
+            plain,
+        }
+    }
+
+    fn register(&mut self, identifier: &str, source: &RenderedEmailModel) -> Result<()> {
+        self.html.register_template_string(identifier, &source.html_body)?;
+        self.plain
+            .register_template_string(&format!("{identifier}.subject"), &source.subject)?;
+
+        let text_name = format!("{identifier}.text");
+        match source.text_body.is_empty() {
+            true => self.plain.unregister_template(&text_name),
+            false => self.plain.register_template_string(&text_name, &source.text_body)?,
+        }
+
+        Ok(())
+    }
+
+    fn render<T: Serialize>(&self, identifier: &str, data: &T) -> Result<RenderedEmailModel> {
+        let text_name = format!("{identifier}.text");
+        let text_body = match self.plain.has_template(&text_name) {
+            true => self.plain.render(&text_name, data)?,
+            false => String::from(""),
+        };
+
+        Ok(RenderedEmailModel {
+            subject: self
+                .plain
+                .render(&format!("{identifier}.subject"), data)?
+                .trim()
+                .to_string(),
+            html_body: self.html.render(identifier, data)?,
+            text_body,
+        })
+    }
 }
@@ -0,0 +1,144 @@
+use std::path::{Path, PathBuf};
+use crate::error::Result;
+use crate::providers::avored_config_provider::AvoRedConfigProvider;
+use handlebars::{
+    Context, DirectorySourceOptions, Handlebars, Helper, HelperDef, RenderContext, RenderError,
+    ScopedJson,
+};
+use serde_json::Value as JsonValue;
+use tracing::warn;
+
+const DEFAULT_THEME: &str = "default";
+
+/// Handlebars registry for the public theme. Every `.hbs` file below the theme
+/// folder is registered by its relative path, so `templates/pages`,
+/// `layouts/default` and `partials/header` can be rendered or used as partials.
+pub struct AvoRedThemeProvider {
+    pub handlebars: Handlebars<'static>,
+    pub theme: String,
+    pub theme_path: PathBuf,
+}
+
+impl AvoRedThemeProvider {
+    /// A missing theme folder falls back to the `default` theme, and without that
+    /// one the site starts with no templates so every page renders as not found.
+    pub fn register(config: AvoRedConfigProvider) -> Result<AvoRedThemeProvider> {
+        let mut theme = config.theme;
+        let mut theme_path = Path::new(&config.themes_dir).join(&theme);
+        if !theme_path.is_dir() && theme != DEFAULT_THEME {
+            warn!("theme folder {} not found, falling back to the {DEFAULT_THEME} theme", theme_path.display());
+            theme = String::from(DEFAULT_THEME);
+            theme_path = Path::new(&config.themes_dir).join(&theme);
+        }
+
+        let mut reg = Handlebars::new();
+        if theme_path.is_dir() {
+            reg.register_templates_directory(&theme_path, DirectorySourceOptions::default())?;
+        } else {
+            warn!("theme folder {} not found, pages will not be rendered", theme_path.display());
+        }
+        reg.register_helper("field", Box::new(FieldHelper));
+        reg.register_helper("menu", Box::new(MenuHelper));
+        reg.register_helper("asset", Box::new(AssetHelper { theme: theme.clone() }));
+
+        Ok(AvoRedThemeProvider {
+            handlebars: reg,
+            theme,
+            theme_path,
+        })
+    }
+
+    /// Url path the theme `assets` folder is served from.
+    pub fn assets_url(&self) -> String {
+        format!("/themes/{}/assets", self.theme)
+    }
+
+    pub fn has_template(&self, name: &str) -> bool {
+        self.handlebars.has_template(name)
+    }
+}
+
+// region: theme helpers
+
+/// `{{field "title"}}` prints a field of the current content, `{{field "title" "Untitled"}}`
+/// falls back to a default and `{{field "title" of=item}}` reads another content object.
+struct FieldHelper;
+
+impl HelperDef for FieldHelper {
+    fn call_inner<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        _: &'reg Handlebars<'reg>,
+        ctx: &'rc Context,
+        _: &mut RenderContext<'reg, 'rc>,
+    ) -> std::result::Result<ScopedJson<'rc>, RenderError> {
+        let identifier = h.param(0).and_then(|p| p.value().as_str()).unwrap_or_default();
+        let content = match h.hash_get("of") {
+            Some(of) => of.value().clone(),
+            None => ctx.data().get("content").cloned().unwrap_or_default(),
+        };
+
+        let value = match content.get("fields").and_then(|fields| fields.get(identifier)) {
+            Some(JsonValue::Null) | None => h
+                .param(1)
+                .map(|default| default.value().clone())
+                .unwrap_or(JsonValue::String(String::new())),
+            Some(JsonValue::Array(values)) => JsonValue::String(
+                values
+                    .iter()
+                    .map(|v| v.as_str().map(str::to_string).unwrap_or_else(|| v.to_string()))
+                    .collect::<Vec<String>>()
+                    .join(", "),
+            ),
+            Some(value) => value.clone(),
+        };
+
+        Ok(ScopedJson::Derived(value))
+    }
+}
+
+/// `{{#each (menu "pages")}}<a href="{{url}}">{{name}}</a>{{/each}}` lists the
+/// content of a routable collection.
+struct MenuHelper;
+
+impl HelperDef for MenuHelper {
+    fn call_inner<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        _: &'reg Handlebars<'reg>,
+        ctx: &'rc Context,
+        _: &mut RenderContext<'reg, 'rc>,
+    ) -> std::result::Result<ScopedJson<'rc>, RenderError> {
+        let collection = h.param(0).and_then(|p| p.value().as_str()).unwrap_or_default();
+        let items = ctx
+            .data()
+            .get("menus")
+            .and_then(|menus| menus.get(collection))
+            .cloned()
+            .unwrap_or(JsonValue::Array(vec![]));
+
+        Ok(ScopedJson::Derived(items))
+    }
+}
+
+/// `{{asset "css/site.css"}}` resolves a file of the theme `assets` folder.
+struct AssetHelper {
+    theme: String,
+}
+
+impl HelperDef for AssetHelper {
+    fn call_inner<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        _: &'reg Handlebars<'reg>,
+        _: &'rc Context,
+        _: &mut RenderContext<'reg, 'rc>,
+    ) -> std::result::Result<ScopedJson<'rc>, RenderError> {
+        let path = h.param(0).and_then(|p| p.value().as_str()).unwrap_or_default();
+        let url = format!("/themes/{}/assets/{}", self.theme, path.trim_start_matches('/'));
+
+        Ok(ScopedJson::Derived(JsonValue::String(url)))
+    }
+}
+
+// endregion: theme helpers
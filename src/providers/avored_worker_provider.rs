@@ -0,0 +1,114 @@
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use surrealdb::sql::Datetime;
+use tokio::sync::Notify;
+use tracing::error;
+use crate::error::Result;
+use crate::providers::avored_clock_provider::AvoRedClockProvider;
+
+/// Wake ups and retry schedule of a background queue, shared by the email
+/// outbox and the webhook deliveries. The worker runs when work is queued and
+/// on the poll interval, to pick up retries that became due. Failed attempts
+/// wait base, 2 * base, 4 * base ... up to `max_retry_delay_seconds`.
+pub struct AvoRedWorkerProvider {
+    notify: Notify,
+    poll_interval: Duration,
+    max_attempts: i64,
+    retry_base_seconds: i64,
+    max_retry_delay_seconds: i64,
+    clock: Arc<AvoRedClockProvider>,
+}
+
+impl AvoRedWorkerProvider {
+    pub fn register(
+        poll_interval: Duration,
+        max_attempts: i64,
+        retry_base_seconds: i64,
+        max_retry_delay_seconds: i64,
+        clock: Arc<AvoRedClockProvider>,
+    ) -> AvoRedWorkerProvider {
+        AvoRedWorkerProvider {
+            notify: Notify::new(),
+            poll_interval,
+            max_attempts: max_attempts.max(1),
+            retry_base_seconds: retry_base_seconds.max(1),
+            max_retry_delay_seconds,
+            clock,
+        }
+    }
+
+    /// Wakes the worker up for work that was just queued.
+    pub fn notify(&self) {
+        self.notify.notify_one();
+    }
+
+    /// Calls `work` on every wake up. Runs for the lifetime of the server.
+    pub async fn run<F, Fut>(&self, name: &str, mut work: F)
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<()>>,
+    {
+        let mut interval = tokio::time::interval(self.poll_interval);
+
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {},
+                _ = self.notify.notified() => {},
+            }
+
+            if let Err(e) = work().await {
+                error!("{name} worker error: {e:?}");
+            }
+        }
+    }
+
+    /// True when attempt number `attempts` was the last one allowed.
+    pub fn is_last_attempt(&self, attempts: i64) -> bool {
+        attempts >= self.max_attempts
+    }
+
+    /// Work whose next attempt is at or before this time is due.
+    pub fn now(&self) -> Datetime {
+        Datetime::from(self.clock.now())
+    }
+
+    /// When to try again after the failed attempt number `attempts`.
+    pub fn next_attempt_at(&self, attempts: i64) -> Datetime {
+        Datetime::from(self.clock.now() + chrono::Duration::seconds(self.retry_delay_seconds(attempts)))
+    }
+
+    fn retry_delay_seconds(&self, attempts: i64) -> i64 {
+        let exponent = (attempts - 1).clamp(0, 20) as u32;
+
+        self.retry_base_seconds
+            .saturating_mul(2_i64.pow(exponent))
+            .min(self.max_retry_delay_seconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+    use crate::providers::avored_clock_provider::AvoRedClockProvider;
+    use super::AvoRedWorkerProvider;
+
+    #[test]
+    fn retry_delay_doubles_up_to_the_longest_delay() {
+        let worker = AvoRedWorkerProvider::register(
+            Duration::from_secs(5),
+            3,
+            30,
+            100,
+            Arc::new(AvoRedClockProvider::register()),
+        );
+
+        assert_eq!(worker.retry_delay_seconds(1), 30);
+        assert_eq!(worker.retry_delay_seconds(2), 60);
+        assert_eq!(worker.retry_delay_seconds(3), 100);
+        assert_eq!(worker.retry_delay_seconds(1000), 100);
+        assert!(!worker.is_last_attempt(2));
+        assert!(worker.is_last_attempt(3));
+    }
+}
@@ -1,3 +1,10 @@
 pub mod avored_config_provider;
 pub mod avored_database_provider;
 pub mod avored_template_provider;
+pub mod avored_cache_provider;
+pub mod avored_theme_provider;
+pub mod avored_event_bus_provider;
+pub mod avored_rate_limit_provider;
+pub mod avored_mail_provider;
+pub mod avored_worker_provider;
+pub mod avored_clock_provider;
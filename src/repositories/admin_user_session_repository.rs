@@ -0,0 +1,205 @@
+use std::collections::BTreeMap;
+use surrealdb::dbs::Session;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::{Datetime, Value};
+use crate::error::{Error, Result};
+use crate::models::admin_user_session_model::{AdminUserSessionModel, CreatableAdminUserSessionModel};
+use crate::repositories::into_iter_objects;
+
+const ADMIN_USER_SESSION_TABLE: &str = "admin_user_sessions";
+
+#[derive(Clone)]
+pub struct AdminUserSessionRepository {}
+
+impl AdminUserSessionRepository {
+    pub fn new() -> Self {
+        AdminUserSessionRepository {}
+    }
+
+    pub async fn find_by_id(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        id: &str,
+    ) -> Result<AdminUserSessionModel> {
+        let sql = "SELECT * FROM type::thing($table, $id);";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), ADMIN_USER_SESSION_TABLE.into()),
+            ("id".into(), id.into()),
+        ]
+        .into();
+
+        self.execute_single(datastore, database_session, sql, vars).await
+    }
+
+    /// Sessions that are neither revoked nor expired, most recently used first.
+    pub async fn active_by_admin_user_id(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        admin_user_id: &str,
+    ) -> Result<Vec<AdminUserSessionModel>> {
+        let sql = "SELECT * FROM type::table($table)
+                    WHERE admin_user_id = $admin_user_id AND status = 'Active' AND expires_at > time::now()
+                    ORDER BY last_activity_at DESC;";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), ADMIN_USER_SESSION_TABLE.into()),
+            ("admin_user_id".into(), admin_user_id.into()),
+        ]
+        .into();
+
+        self.execute_many(datastore, database_session, sql, vars).await
+    }
+
+    /// Sessions revoked after the given time. Access tokens issued for them
+    /// before that may not have expired yet.
+    pub async fn revoked_since(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        since: Datetime,
+    ) -> Result<Vec<AdminUserSessionModel>> {
+        let sql = "SELECT * FROM type::table($table) WHERE status = 'Revoked' AND updated_at > $since;";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), ADMIN_USER_SESSION_TABLE.into()),
+            ("since".into(), since.into()),
+        ]
+        .into();
+
+        self.execute_many(datastore, database_session, sql, vars).await
+    }
+
+    pub async fn create_admin_user_session(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        creatable_admin_user_session: CreatableAdminUserSessionModel,
+    ) -> Result<AdminUserSessionModel> {
+        let sql = "CREATE type::table($table) CONTENT $data";
+
+        let data: BTreeMap<String, Value> = [
+            ("admin_user_id".into(), creatable_admin_user_session.admin_user_id.into()),
+            ("status".into(), "Active".into()),
+            ("user_agent".into(), creatable_admin_user_session.user_agent.into()),
+            ("ip_address".into(), creatable_admin_user_session.ip_address.into()),
+            ("last_activity_at".into(), Datetime::default().into()),
+            ("expires_at".into(), creatable_admin_user_session.expires_at.into()),
+            ("created_at".into(), Datetime::default().into()),
+            ("updated_at".into(), Datetime::default().into()),
+        ]
+        .into();
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), ADMIN_USER_SESSION_TABLE.into()),
+            ("data".into(), data.into()),
+        ]
+        .into();
+
+        self.execute_single(datastore, database_session, sql, vars).await
+    }
+
+    /// Moves the end of an active session and records where it was used
+    /// from, called when its refresh token rotates.
+    pub async fn extend_admin_user_session(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        id: &str,
+        ip_address: &str,
+        expires_at: Datetime,
+    ) -> Result<AdminUserSessionModel> {
+        let sql = "UPDATE type::thing($table, $id) MERGE $data;";
+
+        let data: BTreeMap<String, Value> = [
+            ("ip_address".into(), ip_address.into()),
+            ("last_activity_at".into(), Datetime::default().into()),
+            ("expires_at".into(), expires_at.into()),
+            ("updated_at".into(), Datetime::default().into()),
+        ]
+        .into();
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), ADMIN_USER_SESSION_TABLE.into()),
+            ("id".into(), id.into()),
+            ("data".into(), data.into()),
+        ]
+        .into();
+
+        self.execute_single(datastore, database_session, sql, vars).await
+    }
+
+    pub async fn revoke_admin_user_session(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        id: &str,
+    ) -> Result<AdminUserSessionModel> {
+        let sql = "UPDATE type::thing($table, $id) MERGE $data;";
+
+        let data: BTreeMap<String, Value> = [
+            ("status".into(), "Revoked".into()),
+            ("updated_at".into(), Datetime::default().into()),
+        ]
+        .into();
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), ADMIN_USER_SESSION_TABLE.into()),
+            ("id".into(), id.into()),
+            ("data".into(), data.into()),
+        ]
+        .into();
+
+        self.execute_single(datastore, database_session, sql, vars).await
+    }
+
+    /// Revokes every active session of the admin user and returns them.
+    pub async fn revoke_by_admin_user_id(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        admin_user_id: &str,
+    ) -> Result<Vec<AdminUserSessionModel>> {
+        let sql = "UPDATE type::table($table) SET status = 'Revoked', updated_at = time::now()
+                    WHERE admin_user_id = $admin_user_id AND status = 'Active';";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), ADMIN_USER_SESSION_TABLE.into()),
+            ("admin_user_id".into(), admin_user_id.into()),
+        ]
+        .into();
+
+        self.execute_many(datastore, database_session, sql, vars).await
+    }
+
+    async fn execute_single(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        sql: &str,
+        vars: BTreeMap<String, Value>,
+    ) -> Result<AdminUserSessionModel> {
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        let result_object_option = into_iter_objects(responses)?.next();
+        let result_object = match result_object_option {
+            Some(object) => object,
+            None => Err(Error::Generic("no record found".to_string())),
+        };
+
+        result_object?.try_into()
+    }
+
+    async fn execute_many(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        sql: &str,
+        vars: BTreeMap<String, Value>,
+    ) -> Result<Vec<AdminUserSessionModel>> {
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        let mut admin_user_sessions: Vec<AdminUserSessionModel> = Vec::new();
+        for object in into_iter_objects(responses)? {
+            let admin_user_session_model: Result<AdminUserSessionModel> = object?.try_into();
+            admin_user_sessions.push(admin_user_session_model?);
+        }
+
+        Ok(admin_user_sessions)
+    }
+}
@@ -0,0 +1,185 @@
+use std::collections::BTreeMap;
+use surrealdb::dbs::Session;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::Value;
+use crate::error::{Error, Result};
+use crate::models::admin_user_two_factor_model::AdminUserTwoFactorModel;
+use crate::repositories::into_iter_objects;
+
+const ADMIN_USER_TWO_FACTOR_TABLE: &str = "admin_user_two_factors";
+
+#[derive(Clone)]
+pub struct AdminUserTwoFactorRepository {}
+
+impl AdminUserTwoFactorRepository {
+    pub fn new() -> Self {
+        AdminUserTwoFactorRepository {}
+    }
+
+    pub async fn find_by_admin_user_id(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        admin_user_id: &str,
+    ) -> Result<Option<AdminUserTwoFactorModel>> {
+        let sql = "SELECT * FROM type::thing($table, $admin_user_id);";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), ADMIN_USER_TWO_FACTOR_TABLE.into()),
+            ("admin_user_id".into(), admin_user_id.into()),
+        ]
+        .into();
+
+        self.execute_optional(datastore, database_session, sql, vars).await
+    }
+
+    /// Stores a new secret that is not enabled until a code of it is
+    /// confirmed. Replaces an earlier secret that was never confirmed.
+    pub async fn save_pending_secret(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        admin_user_id: &str,
+        secret: &str,
+    ) -> Result<AdminUserTwoFactorModel> {
+        let sql = "UPSERT type::thing($table, $admin_user_id) CONTENT {
+                        admin_user_id: $admin_user_id,
+                        secret: $secret,
+                        is_enabled: false,
+                        recovery_codes: [],
+                        last_used_step: 0,
+                        created_at: time::now(),
+                        updated_at: time::now()
+                    };";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), ADMIN_USER_TWO_FACTOR_TABLE.into()),
+            ("admin_user_id".into(), admin_user_id.into()),
+            ("secret".into(), secret.into()),
+        ]
+        .into();
+
+        self.execute_single(datastore, database_session, sql, vars).await
+    }
+
+    pub async fn enable(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        admin_user_id: &str,
+        recovery_code_hashes: Vec<String>,
+        step: i64,
+    ) -> Result<AdminUserTwoFactorModel> {
+        let sql = "UPDATE type::thing($table, $admin_user_id) MERGE {
+                        is_enabled: true,
+                        recovery_codes: $recovery_codes,
+                        last_used_step: $step,
+                        updated_at: time::now()
+                    };";
+        let recovery_codes: Vec<Value> = recovery_code_hashes.into_iter().map(Value::from).collect();
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), ADMIN_USER_TWO_FACTOR_TABLE.into()),
+            ("admin_user_id".into(), admin_user_id.into()),
+            ("recovery_codes".into(), recovery_codes.into()),
+            ("step".into(), step.into()),
+        ]
+        .into();
+
+        self.execute_single(datastore, database_session, sql, vars).await
+    }
+
+    /// Records the time step of a used code. Only succeeds when no code of the
+    /// same or a later step was used, so a code can not be replayed by racing it.
+    pub async fn use_step(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        admin_user_id: &str,
+        step: i64,
+    ) -> Result<bool> {
+        let sql = "UPDATE type::thing($table, $admin_user_id)
+                    SET last_used_step = $step, updated_at = time::now()
+                    WHERE last_used_step < $step;";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), ADMIN_USER_TWO_FACTOR_TABLE.into()),
+            ("admin_user_id".into(), admin_user_id.into()),
+            ("step".into(), step.into()),
+        ]
+        .into();
+
+        let model = self.execute_optional(datastore, database_session, sql, vars).await?;
+
+        Ok(model.is_some())
+    }
+
+    /// Removes the recovery code in a single statement, returns false when
+    /// it was not one of the remaining codes.
+    pub async fn use_recovery_code(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        admin_user_id: &str,
+        recovery_code_hash: &str,
+    ) -> Result<bool> {
+        let sql = "UPDATE type::thing($table, $admin_user_id)
+                    SET recovery_codes -= $recovery_code_hash, updated_at = time::now()
+                    WHERE is_enabled = true AND $recovery_code_hash INSIDE recovery_codes;";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), ADMIN_USER_TWO_FACTOR_TABLE.into()),
+            ("admin_user_id".into(), admin_user_id.into()),
+            ("recovery_code_hash".into(), recovery_code_hash.into()),
+        ]
+        .into();
+
+        let model = self.execute_optional(datastore, database_session, sql, vars).await?;
+
+        Ok(model.is_some())
+    }
+
+    pub async fn delete_by_admin_user_id(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        admin_user_id: &str,
+    ) -> Result<bool> {
+        let sql = "DELETE type::thing($table, $admin_user_id);";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), ADMIN_USER_TWO_FACTOR_TABLE.into()),
+            ("admin_user_id".into(), admin_user_id.into()),
+        ]
+        .into();
+
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+        for response in responses {
+            response.result?;
+        }
+
+        Ok(true)
+    }
+
+    async fn execute_single(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        sql: &str,
+        vars: BTreeMap<String, Value>,
+    ) -> Result<AdminUserTwoFactorModel> {
+        match self.execute_optional(datastore, database_session, sql, vars).await? {
+            Some(model) => Ok(model),
+            None => Err(Error::Generic("no record found".to_string())),
+        }
+    }
+
+    async fn execute_optional(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        sql: &str,
+        vars: BTreeMap<String, Value>,
+    ) -> Result<Option<AdminUserTwoFactorModel>> {
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        match into_iter_objects(responses)?.next() {
+            Some(object) => Ok(Some(object?.try_into()?)),
+            None => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use std::collections::BTreeMap;
+use surrealdb::dbs::Session;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::{Datetime, Value};
+use crate::error::{Error, Result};
+use crate::models::audit_log_model::{AuditLogModel, CreatableAuditLogModel};
+use crate::repositories::into_iter_objects;
+
+const AUDIT_LOG_TABLE: &str = "audit_logs";
+
+#[derive(Clone)]
+pub struct AuditLogRepository {}
+
+impl AuditLogRepository {
+    pub fn new() -> Self {
+        AuditLogRepository {}
+    }
+
+    /// Most recent entries first.
+    pub async fn latest(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        limit: i64,
+    ) -> Result<Vec<AuditLogModel>> {
+        let sql = "SELECT * FROM type::table($table) ORDER BY created_at DESC LIMIT $limit;";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), AUDIT_LOG_TABLE.into()),
+            ("limit".into(), limit.into()),
+        ]
+        .into();
+
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        let mut audit_logs: Vec<AuditLogModel> = Vec::new();
+        for object in into_iter_objects(responses)? {
+            let audit_log_model: Result<AuditLogModel> = object?.try_into();
+            audit_logs.push(audit_log_model?);
+        }
+
+        Ok(audit_logs)
+    }
+
+    pub async fn create_audit_log(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        creatable_audit_log: CreatableAuditLogModel,
+    ) -> Result<AuditLogModel> {
+        let sql = "CREATE type::table($table) CONTENT $data";
+
+        let data: BTreeMap<String, Value> = [
+            ("action".into(), creatable_audit_log.action.as_str().into()),
+            ("email".into(), creatable_audit_log.email.into()),
+            ("ip_address".into(), creatable_audit_log.ip_address.into()),
+            ("detail".into(), creatable_audit_log.detail.into()),
+            ("created_by".into(), creatable_audit_log.created_by.into()),
+            ("created_at".into(), Datetime::default().into()),
+        ]
+        .into();
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), AUDIT_LOG_TABLE.into()),
+            ("data".into(), data.into()),
+        ]
+        .into();
+
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        let result_object_option = into_iter_objects(responses)?.next();
+        let result_object = match result_object_option {
+            Some(object) => object,
+            None => Err(Error::Generic("no record found".to_string())),
+        };
+
+        result_object?.try_into()
+    }
+}
@@ -0,0 +1,208 @@
+use std::collections::BTreeMap;
+use surrealdb::dbs::Session;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::{Datetime, Value};
+use crate::error::{Error, Result};
+use crate::models::cms_api_key_model::{CmsApiKeyModel, CreatableCmsApiKeyModel, UpdatableCmsApiKeyModel};
+use crate::repositories::into_iter_objects;
+
+const CMS_API_KEY_TABLE: &str = "cms_api_keys";
+
+#[derive(Clone)]
+pub struct CmsApiKeyRepository {}
+
+impl CmsApiKeyRepository {
+    pub fn new() -> Self {
+        CmsApiKeyRepository {}
+    }
+
+    pub async fn all(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+    ) -> Result<Vec<CmsApiKeyModel>> {
+        let sql = "SELECT * FROM type::table($table) ORDER BY created_at DESC;";
+        let vars: BTreeMap<String, Value> = [("table".into(), CMS_API_KEY_TABLE.into())].into();
+
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        let mut cms_api_keys: Vec<CmsApiKeyModel> = Vec::new();
+        for object in into_iter_objects(responses)? {
+            let cms_api_key_model: Result<CmsApiKeyModel> = object?.try_into();
+            cms_api_keys.push(cms_api_key_model?);
+        }
+
+        Ok(cms_api_keys)
+    }
+
+    pub async fn find_by_key_hash(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        key_hash: &str,
+    ) -> Result<CmsApiKeyModel> {
+        let sql = "SELECT * FROM type::table($table) WHERE key_hash=$key_hash;";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), CMS_API_KEY_TABLE.into()),
+            ("key_hash".into(), key_hash.into()),
+        ]
+        .into();
+
+        self.execute_single(datastore, database_session, sql, vars).await
+    }
+
+    pub async fn create_cms_api_key(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        creatable_cms_api_key: CreatableCmsApiKeyModel,
+    ) -> Result<CmsApiKeyModel> {
+        let sql = "CREATE type::table($table) CONTENT $data";
+
+        let data: BTreeMap<String, Value> = [
+            ("name".into(), creatable_cms_api_key.name.into()),
+            ("key_prefix".into(), creatable_cms_api_key.key_prefix.into()),
+            ("key_hash".into(), creatable_cms_api_key.key_hash.into()),
+            ("collections".into(), creatable_cms_api_key.collections.into()),
+            ("operations".into(), creatable_cms_api_key.operations.into()),
+            ("status".into(), "Active".into()),
+            (
+                "created_by".into(),
+                creatable_cms_api_key.logged_in_username.clone().into(),
+            ),
+            (
+                "updated_by".into(),
+                creatable_cms_api_key.logged_in_username.into(),
+            ),
+            ("created_at".into(), Datetime::default().into()),
+            ("updated_at".into(), Datetime::default().into()),
+        ]
+        .into();
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), CMS_API_KEY_TABLE.into()),
+            ("data".into(), data.into()),
+        ]
+        .into();
+
+        self.execute_single(datastore, database_session, sql, vars).await
+    }
+
+    pub async fn update_cms_api_key(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        updatable_cms_api_key: UpdatableCmsApiKeyModel,
+    ) -> Result<CmsApiKeyModel> {
+        let sql = "UPDATE type::thing($table, $id) MERGE $data;";
+
+        let data: BTreeMap<String, Value> = [
+            ("name".into(), updatable_cms_api_key.name.into()),
+            ("collections".into(), updatable_cms_api_key.collections.into()),
+            ("operations".into(), updatable_cms_api_key.operations.into()),
+            (
+                "updated_by".into(),
+                updatable_cms_api_key.logged_in_username.into(),
+            ),
+            ("updated_at".into(), Datetime::default().into()),
+        ]
+        .into();
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), CMS_API_KEY_TABLE.into()),
+            ("id".into(), updatable_cms_api_key.id.into()),
+            ("data".into(), data.into()),
+        ]
+        .into();
+
+        self.execute_single(datastore, database_session, sql, vars).await
+    }
+
+    pub async fn update_key_hash(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        id: &str,
+        key_prefix: &str,
+        key_hash: &str,
+        logged_in_username: &str,
+    ) -> Result<CmsApiKeyModel> {
+        let sql = "UPDATE type::thing($table, $id)
+                    SET
+                        key_prefix = $key_prefix,
+                        key_hash = $key_hash,
+                        last_used_at = NONE,
+                        updated_at = time::now(),
+                        updated_by = $updated_by
+                    ;";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), CMS_API_KEY_TABLE.into()),
+            ("id".into(), id.into()),
+            ("key_prefix".into(), key_prefix.into()),
+            ("key_hash".into(), key_hash.into()),
+            ("updated_by".into(), logged_in_username.into()),
+        ]
+        .into();
+
+        self.execute_single(datastore, database_session, sql, vars).await
+    }
+
+    pub async fn update_status(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        id: &str,
+        status: String,
+        logged_in_username: &str,
+    ) -> Result<CmsApiKeyModel> {
+        let sql = "UPDATE type::thing($table, $id)
+                    SET
+                        status = $status,
+                        updated_at = time::now(),
+                        updated_by = $updated_by
+                    ;";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), CMS_API_KEY_TABLE.into()),
+            ("id".into(), id.into()),
+            ("status".into(), status.into()),
+            ("updated_by".into(), logged_in_username.into()),
+        ]
+        .into();
+
+        self.execute_single(datastore, database_session, sql, vars).await
+    }
+
+    pub async fn touch_last_used(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        id: &str,
+    ) -> Result<()> {
+        let sql = "UPDATE type::thing($table, $id) SET last_used_at = time::now();";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), CMS_API_KEY_TABLE.into()),
+            ("id".into(), id.into()),
+        ]
+        .into();
+
+        datastore.execute(sql, database_session, Some(vars)).await?;
+
+        Ok(())
+    }
+
+    async fn execute_single(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        sql: &str,
+        vars: BTreeMap<String, Value>,
+    ) -> Result<CmsApiKeyModel> {
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        let result_object_option = into_iter_objects(responses)?.next();
+        let result_object = match result_object_option {
+            Some(object) => object,
+            None => Err(Error::Generic("no record found".to_string())),
+        };
+
+        result_object?.try_into()
+    }
+}
@@ -30,6 +30,26 @@ impl CollectionRepository {
         }
         Ok(collection_list)
     }
+
+    pub(crate) async fn find_by_identifier(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        identifier: &str,
+    ) -> Result<CollectionModel> {
+        let sql = "SELECT * FROM collections WHERE identifier=$identifier;";
+        let vars: BTreeMap<String, Value> = [("identifier".into(), identifier.into())].into();
+
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        let result_object_option = into_iter_objects(responses)?.next();
+        let result_object = match result_object_option {
+            Some(object) => object,
+            None => Err(Error::NotFound(format!("collection {identifier}"))),
+        };
+
+        result_object?.try_into()
+    }
 }
 
 impl CollectionRepository {
@@ -206,9 +226,10 @@ impl CollectionRepository {
     ) -> Result<CollectionModel> {
         let sql = "UPDATE type::thing($table, $id) MERGE $data";
         
-        let data: BTreeMap<String, Value> = [
+        let mut data: BTreeMap<String, Value> = [
             ("name".into(), updatable_model.name.into()),
             ("identifier".into(), updatable_model.identifier.into()),
+            ("is_routable".into(), updatable_model.is_routable.into()),
             (
                 "updated_by".into(),
                 updatable_model.logged_in_username.clone().into(),
@@ -216,6 +237,9 @@ impl CollectionRepository {
             ("updated_at".into(), Datetime::default().into()),
         ]
             .into();
+        if let Some(feed_mapping) = updatable_model.feed_mapping {
+            data.insert("feed_mapping".into(), feed_mapping.try_into()?);
+        }
     
         let vars: BTreeMap<String, Value> = [
             ("data".into(), data.into()),
@@ -248,6 +272,8 @@ impl CollectionRepository {
         let data: BTreeMap<String, Value> = [
             ("name".into(), creatable_model.name.into()),
             ("identifier".into(), creatable_model.identifier.into()),
+            ("is_routable".into(), creatable_model.is_routable.into()),
+            ("feed_mapping".into(), creatable_model.feed_mapping.try_into()?),
             (
                 "created_by".into(),
                 creatable_model.logged_in_username.clone().into(),
@@ -68,6 +68,29 @@ impl ContentRepository {
         model
     }
 
+    /// `None` when there is no such content or it is a draft.
+    pub(crate) async fn find_published_by_identifier(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        content_type: &str,
+        identifier: &str,
+    ) -> Result<Option<ContentModel>> {
+        let sql = "SELECT * FROM type::table($table) WHERE identifier=$identifier AND status != 'Draft';";
+        let vars: BTreeMap<String, Value> = [
+            ("identifier".into(), identifier.into()),
+            ("table".into(), content_type.into()),
+        ]
+            .into();
+
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        match into_iter_objects(responses)?.next() {
+            Some(object) => Ok(Some(object?.try_into()?)),
+            None => Ok(None),
+        }
+    }
+
     pub(crate) async fn paginate(
         &self,
         datastore: &Datastore,
@@ -106,6 +129,137 @@ impl ContentRepository {
         }
         Ok(content_list)
     }
+
+    /// Like `paginate`, leaving out drafts.
+    pub(crate) async fn paginate_published(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        content_type: &str,
+        start: i64,
+        order_column: String,
+        order_type: String,
+    ) -> Result<Vec<ContentModel>> {
+        let sql = format!(
+            "\
+            SELECT * \
+            FROM type::table($table) \
+            WHERE status != 'Draft' \
+            ORDER {} {}
+            LIMIT $limit \
+            START $start;\
+        ",
+            order_column, order_type
+        );
+        let vars = BTreeMap::from([
+            ("limit".into(), PER_PAGE.into()),
+            ("start".into(), start.into()),
+            ("table".into(), content_type.into()),
+        ]);
+        let responses = datastore
+            .execute(&sql, database_session, Some(vars))
+            .await?;
+
+        let mut content_list: Vec<ContentModel> = Vec::new();
+        for object in into_iter_objects(responses)? {
+            let content_model: Result<ContentModel> = object?.try_into();
+            content_list.push(content_model?);
+        }
+
+        Ok(content_list)
+    }
+
+    /// Published content of a collection up to `limit`, ordered by name; used to build theme menus.
+    pub(crate) async fn all_by_collection(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        content_type: &str,
+        limit: i64,
+    ) -> Result<Vec<ContentModel>> {
+        let sql = "SELECT * FROM type::table($table) WHERE status != 'Draft' ORDER BY name ASC LIMIT $limit;";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), content_type.into()),
+            ("limit".into(), limit.into()),
+        ]
+        .into();
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        let mut content_list: Vec<ContentModel> = Vec::new();
+        for object in into_iter_objects(responses)? {
+            let content_model: Result<ContentModel> = object?.try_into();
+            content_list.push(content_model?);
+        }
+
+        Ok(content_list)
+    }
+
+    /// Most recently created published content of a collection, used by the feeds.
+    pub(crate) async fn latest_published(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        content_type: &str,
+        limit: i64,
+    ) -> Result<Vec<ContentModel>> {
+        let sql = "SELECT * FROM type::table($table) WHERE status != 'Draft' ORDER BY created_at DESC LIMIT $limit;";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), content_type.into()),
+            ("limit".into(), limit.into()),
+        ]
+        .into();
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        let mut content_list: Vec<ContentModel> = Vec::new();
+        for object in into_iter_objects(responses)? {
+            let content_model: Result<ContentModel> = object?.try_into();
+            content_list.push(content_model?);
+        }
+
+        Ok(content_list)
+    }
+
+    /// Every published content of a collection, used by the feeds when the
+    /// entries are ordered by a mapped date field instead of `created_at`.
+    pub(crate) async fn all_published_content(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        content_type: &str,
+    ) -> Result<Vec<ContentModel>> {
+        let sql = "SELECT * FROM type::table($table) WHERE status != 'Draft';";
+        let vars: BTreeMap<String, Value> = [("table".into(), content_type.into())].into();
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        let mut content_list: Vec<ContentModel> = Vec::new();
+        for object in into_iter_objects(responses)? {
+            let content_model: Result<ContentModel> = object?.try_into();
+            content_list.push(content_model?);
+        }
+
+        Ok(content_list)
+    }
+
+    /// Identifier and update time of every published content, enough to build the sitemap.
+    pub(crate) async fn all_published(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        content_type: &str,
+    ) -> Result<Vec<ContentModel>> {
+        let sql = "SELECT id, identifier, updated_at FROM type::table($table) WHERE status != 'Draft' ORDER BY identifier ASC;";
+        let vars: BTreeMap<String, Value> = [("table".into(), content_type.into())].into();
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        let mut content_list: Vec<ContentModel> = Vec::new();
+        for object in into_iter_objects(responses)? {
+            let content_model: Result<ContentModel> = object?.try_into();
+            content_list.push(content_model?);
+        }
+
+        Ok(content_list)
+    }
+
     pub(crate) async fn get_total_count(
         &self,
         datastore: &Datastore,
@@ -192,6 +346,7 @@ impl ContentRepository {
         let data: BTreeMap<String, Value> = [
             ("name".into(), creatable_content_model.name.into()),
             ("identifier".into(), creatable_content_model.identifier.into()),
+            ("status".into(), String::try_from(creatable_content_model.status)?.into()),
             (
                 "created_by".into(),
                 creatable_content_model.logged_in_username.clone().into(),
@@ -306,12 +461,15 @@ impl ContentRepository {
 
         // println!("final value {:?}", content_fields);
 
-        let data: BTreeMap<String, Value> = [
+        let mut data: BTreeMap<String, Value> = [
             ("name".into(), updatable_model.name.into()),
             ("updated_by".into(), updatable_model.logged_in_username.clone().into()),
             ("content_fields".into(), content_fields.into()),
             ("updated_at".into(), Datetime::default().into()),
         ].into();
+        if let Some(status) = updatable_model.status {
+            data.insert("status".into(), String::try_from(status)?.into());
+        }
 
         let vars: BTreeMap<String, Value> = [
             ("data".into(), data.into()),
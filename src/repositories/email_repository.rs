@@ -0,0 +1,208 @@
+use std::collections::BTreeMap;
+use surrealdb::dbs::Session;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::{Datetime, Value};
+use crate::error::{Error, Result};
+use crate::models::email_model::{CreatableEmailModel, EmailModel, EmailSenderModel, UpdatableEmailModel};
+use crate::models::ModelCount;
+use crate::repositories::into_iter_objects;
+use crate::PER_PAGE;
+
+const EMAIL_TABLE: &str = "emails";
+
+#[derive(Clone)]
+pub struct EmailRepository {}
+
+impl EmailRepository {
+    pub fn new() -> Self {
+        EmailRepository {}
+    }
+
+    /// An empty status lists every email.
+    pub async fn paginate(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        status: &str,
+        start: i64,
+    ) -> Result<Vec<EmailModel>> {
+        let sql = "SELECT * FROM type::table($table) WHERE $status = '' OR status = $status
+                    ORDER BY created_at DESC LIMIT $limit START $start;";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), EMAIL_TABLE.into()),
+            ("status".into(), status.into()),
+            ("limit".into(), PER_PAGE.into()),
+            ("start".into(), start.into()),
+        ]
+        .into();
+
+        self.execute_many(datastore, database_session, sql, vars).await
+    }
+
+    pub async fn get_total_count(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        status: &str,
+    ) -> Result<ModelCount> {
+        let sql = "SELECT count() FROM type::table($table) WHERE $status = '' OR status = $status GROUP ALL;";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), EMAIL_TABLE.into()),
+            ("status".into(), status.into()),
+        ]
+        .into();
+
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        let result_object_option = into_iter_objects(responses)?.next();
+        match result_object_option {
+            Some(object) => object?.try_into(),
+            None => Ok(ModelCount::default()),
+        }
+    }
+
+    /// Pending emails whose next attempt is due, oldest first.
+    pub async fn due(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        now: Datetime,
+        limit: i64,
+    ) -> Result<Vec<EmailModel>> {
+        let sql = "SELECT * FROM type::table($table)
+                    WHERE status = 'Pending' AND next_attempt_at <= $now
+                    ORDER BY next_attempt_at ASC LIMIT $limit;";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), EMAIL_TABLE.into()),
+            ("now".into(), now.into()),
+            ("limit".into(), limit.into()),
+        ]
+        .into();
+
+        self.execute_many(datastore, database_session, sql, vars).await
+    }
+
+    pub async fn find_by_id(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        id: &str,
+    ) -> Result<EmailModel> {
+        let sql = "SELECT * FROM type::thing($table, $id);";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), EMAIL_TABLE.into()),
+            ("id".into(), id.into()),
+        ]
+        .into();
+
+        self.execute_single(datastore, database_session, sql, vars).await
+    }
+
+    /// Queues an email that is due straight away.
+    pub async fn create_email(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        creatable_email: CreatableEmailModel,
+        email_sender: EmailSenderModel,
+    ) -> Result<EmailModel> {
+        let sql = "CREATE type::table($table) CONTENT $data";
+
+        let mut attachments: Vec<Value> = vec![];
+        for attachment in creatable_email.attachments {
+            attachments.push(attachment.try_into()?);
+        }
+
+        let data: BTreeMap<String, Value> = [
+            ("from_address".into(), email_sender.from_address.into()),
+            ("reply_to".into(), email_sender.reply_to.into()),
+            ("to_address".into(), creatable_email.to_address.into()),
+            ("subject".into(), creatable_email.subject.into()),
+            ("body".into(), creatable_email.body.into()),
+            ("text_body".into(), creatable_email.text_body.into()),
+            ("attachments".into(), attachments.into()),
+            ("template".into(), creatable_email.template.into()),
+            ("status".into(), "Pending".into()),
+            ("attempts".into(), 0.into()),
+            ("error".into(), "".into()),
+            ("next_attempt_at".into(), Datetime::default().into()),
+            ("created_at".into(), Datetime::default().into()),
+            ("updated_at".into(), Datetime::default().into()),
+        ]
+        .into();
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), EMAIL_TABLE.into()),
+            ("data".into(), data.into()),
+        ]
+        .into();
+
+        self.execute_single(datastore, database_session, sql, vars).await
+    }
+
+    pub async fn update_attempt(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        updatable_email: UpdatableEmailModel,
+    ) -> Result<EmailModel> {
+        let sql = "UPDATE type::thing($table, $id)
+                    SET
+                        status = $status,
+                        attempts = $attempts,
+                        error = $error,
+                        next_attempt_at = $next_attempt_at,
+                        body = IF $clear_body THEN '' ELSE body END,
+                        text_body = IF $clear_body THEN '' ELSE text_body END,
+                        updated_at = time::now()
+                    ;";
+        let status: String = updatable_email.status.try_into()?;
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), EMAIL_TABLE.into()),
+            ("id".into(), updatable_email.id.into()),
+            ("status".into(), status.into()),
+            ("attempts".into(), updatable_email.attempts.into()),
+            ("error".into(), updatable_email.error.into()),
+            ("next_attempt_at".into(), updatable_email.next_attempt_at.into()),
+            ("clear_body".into(), updatable_email.clear_body.into()),
+        ]
+        .into();
+
+        self.execute_single(datastore, database_session, sql, vars).await
+    }
+
+    async fn execute_many(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        sql: &str,
+        vars: BTreeMap<String, Value>,
+    ) -> Result<Vec<EmailModel>> {
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        let mut emails: Vec<EmailModel> = Vec::new();
+        for object in into_iter_objects(responses)? {
+            let email_model: Result<EmailModel> = object?.try_into();
+            emails.push(email_model?);
+        }
+
+        Ok(emails)
+    }
+
+    async fn execute_single(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        sql: &str,
+        vars: BTreeMap<String, Value>,
+    ) -> Result<EmailModel> {
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        let result_object_option = into_iter_objects(responses)?.next();
+        let result_object = match result_object_option {
+            Some(object) => object,
+            None => Err(Error::Generic("no record found".to_string())),
+        };
+
+        result_object?.try_into()
+    }
+}
@@ -0,0 +1,179 @@
+use std::collections::BTreeMap;
+use surrealdb::dbs::Session;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::{Datetime, Value};
+use crate::error::{Error, Result};
+use crate::models::email_template_model::{
+    CreatableEmailTemplateModel, EmailTemplateModel, UpdatableEmailTemplateModel,
+};
+use crate::models::ModelCount;
+use crate::repositories::into_iter_objects;
+
+const EMAIL_TEMPLATE_TABLE: &str = "email_templates";
+
+#[derive(Clone)]
+pub struct EmailTemplateRepository {}
+
+impl EmailTemplateRepository {
+    pub fn new() -> Self {
+        EmailTemplateRepository {}
+    }
+
+    pub async fn all(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+    ) -> Result<Vec<EmailTemplateModel>> {
+        let sql = "SELECT * FROM type::table($table) ORDER BY identifier ASC;";
+        let vars: BTreeMap<String, Value> = [("table".into(), EMAIL_TEMPLATE_TABLE.into())].into();
+
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        let mut email_templates: Vec<EmailTemplateModel> = Vec::new();
+        for object in into_iter_objects(responses)? {
+            let email_template_model: Result<EmailTemplateModel> = object?.try_into();
+            email_templates.push(email_template_model?);
+        }
+
+        Ok(email_templates)
+    }
+
+    pub async fn find_by_id(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        id: &str,
+    ) -> Result<EmailTemplateModel> {
+        let sql = "SELECT * FROM type::thing($table, $id);";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), EMAIL_TEMPLATE_TABLE.into()),
+            ("id".into(), id.into()),
+        ]
+        .into();
+
+        self.execute_single(datastore, database_session, sql, vars).await
+    }
+
+    pub async fn count_of_identifier(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        identifier: &str,
+    ) -> Result<ModelCount> {
+        let sql = "SELECT count() FROM type::table($table) WHERE identifier = $identifier GROUP ALL;";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), EMAIL_TEMPLATE_TABLE.into()),
+            ("identifier".into(), identifier.into()),
+        ]
+        .into();
+
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        match into_iter_objects(responses)?.next() {
+            Some(object) => object?.try_into(),
+            None => Ok(ModelCount::default()),
+        }
+    }
+
+    pub async fn create_email_template(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        creatable_email_template: CreatableEmailTemplateModel,
+    ) -> Result<EmailTemplateModel> {
+        let sql = "CREATE type::table($table) CONTENT $data";
+
+        let data: BTreeMap<String, Value> = [
+            ("identifier".into(), creatable_email_template.identifier.into()),
+            ("subject".into(), creatable_email_template.subject.into()),
+            ("html_body".into(), creatable_email_template.html_body.into()),
+            ("text_body".into(), creatable_email_template.text_body.into()),
+            (
+                "created_by".into(),
+                creatable_email_template.logged_in_username.clone().into(),
+            ),
+            (
+                "updated_by".into(),
+                creatable_email_template.logged_in_username.into(),
+            ),
+            ("created_at".into(), Datetime::default().into()),
+            ("updated_at".into(), Datetime::default().into()),
+        ]
+        .into();
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), EMAIL_TEMPLATE_TABLE.into()),
+            ("data".into(), data.into()),
+        ]
+        .into();
+
+        self.execute_single(datastore, database_session, sql, vars).await
+    }
+
+    pub async fn update_email_template(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        updatable_email_template: UpdatableEmailTemplateModel,
+    ) -> Result<EmailTemplateModel> {
+        let sql = "UPDATE type::thing($table, $id) MERGE $data;";
+
+        let data: BTreeMap<String, Value> = [
+            ("subject".into(), updatable_email_template.subject.into()),
+            ("html_body".into(), updatable_email_template.html_body.into()),
+            ("text_body".into(), updatable_email_template.text_body.into()),
+            (
+                "updated_by".into(),
+                updatable_email_template.logged_in_username.into(),
+            ),
+            ("updated_at".into(), Datetime::default().into()),
+        ]
+        .into();
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), EMAIL_TEMPLATE_TABLE.into()),
+            ("id".into(), updatable_email_template.id.into()),
+            ("data".into(), data.into()),
+        ]
+        .into();
+
+        self.execute_single(datastore, database_session, sql, vars).await
+    }
+
+    pub async fn delete_by_id(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        id: &str,
+    ) -> Result<bool> {
+        let sql = "DELETE type::thing($table, $id);";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), EMAIL_TEMPLATE_TABLE.into()),
+            ("id".into(), id.into()),
+        ]
+        .into();
+
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+        for response in responses {
+            response.result?;
+        }
+
+        Ok(true)
+    }
+
+    async fn execute_single(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        sql: &str,
+        vars: BTreeMap<String, Value>,
+    ) -> Result<EmailTemplateModel> {
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        let result_object_option = into_iter_objects(responses)?.next();
+        let result_object = match result_object_option {
+            Some(object) => object,
+            None => Err(Error::Generic("no record found".to_string())),
+        };
+
+        result_object?.try_into()
+    }
+}
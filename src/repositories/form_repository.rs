@@ -0,0 +1,220 @@
+use std::collections::BTreeMap;
+use surrealdb::dbs::Session;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::{Datetime, Value};
+use crate::error::{Error, Result};
+use crate::models::form_model::{CreatableFormModel, FormFieldModel, FormModel, UpdatableFormModel};
+use crate::models::ModelCount;
+use crate::repositories::into_iter_objects;
+
+const FORM_TABLE: &str = "forms";
+
+#[derive(Clone)]
+pub struct FormRepository {}
+
+impl FormRepository {
+    pub fn new() -> Self {
+        FormRepository {}
+    }
+
+    pub async fn all(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+    ) -> Result<Vec<FormModel>> {
+        let sql = "SELECT * FROM type::table($table) ORDER BY created_at DESC;";
+        let vars: BTreeMap<String, Value> = [("table".into(), FORM_TABLE.into())].into();
+
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        let mut forms: Vec<FormModel> = Vec::new();
+        for object in into_iter_objects(responses)? {
+            let form_model: Result<FormModel> = object?.try_into();
+            forms.push(form_model?);
+        }
+
+        Ok(forms)
+    }
+
+    pub async fn find_by_id(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        id: &str,
+    ) -> Result<FormModel> {
+        let sql = "SELECT * FROM type::thing($table, $id);";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), FORM_TABLE.into()),
+            ("id".into(), id.into()),
+        ]
+        .into();
+
+        self.execute_single(datastore, database_session, sql, vars).await
+    }
+
+    pub async fn find_by_identifier(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        identifier: &str,
+    ) -> Result<FormModel> {
+        let sql = "SELECT * FROM type::table($table) WHERE identifier = $identifier;";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), FORM_TABLE.into()),
+            ("identifier".into(), identifier.into()),
+        ]
+        .into();
+
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        let result_object_option = into_iter_objects(responses)?.next();
+        let result_object = match result_object_option {
+            Some(object) => object,
+            None => Err(Error::NotFound(format!("form {identifier}"))),
+        };
+
+        result_object?.try_into()
+    }
+
+    pub async fn count_of_identifier(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        identifier: &str,
+    ) -> Result<ModelCount> {
+        let sql = "SELECT count() FROM type::table($table) WHERE identifier = $identifier GROUP ALL;";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), FORM_TABLE.into()),
+            ("identifier".into(), identifier.into()),
+        ]
+        .into();
+
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        match into_iter_objects(responses)?.next() {
+            Some(object) => object?.try_into(),
+            None => Ok(ModelCount::default()),
+        }
+    }
+
+    pub async fn create_form(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        creatable_form: CreatableFormModel,
+    ) -> Result<FormModel> {
+        let sql = "CREATE type::table($table) CONTENT $data";
+
+        let data: BTreeMap<String, Value> = [
+            ("name".into(), creatable_form.name.into()),
+            ("identifier".into(), creatable_form.identifier.into()),
+            ("fields".into(), fields_value(creatable_form.fields)?),
+            ("recipients".into(), creatable_form.recipients.into()),
+            ("email_subject".into(), creatable_form.email_subject.into()),
+            ("success_message".into(), creatable_form.success_message.into()),
+            ("is_active".into(), creatable_form.is_active.into()),
+            (
+                "created_by".into(),
+                creatable_form.logged_in_username.clone().into(),
+            ),
+            (
+                "updated_by".into(),
+                creatable_form.logged_in_username.into(),
+            ),
+            ("created_at".into(), Datetime::default().into()),
+            ("updated_at".into(), Datetime::default().into()),
+        ]
+        .into();
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), FORM_TABLE.into()),
+            ("data".into(), data.into()),
+        ]
+        .into();
+
+        self.execute_single(datastore, database_session, sql, vars).await
+    }
+
+    pub async fn update_form(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        updatable_form: UpdatableFormModel,
+    ) -> Result<FormModel> {
+        let sql = "UPDATE type::thing($table, $id) MERGE $data;";
+
+        let data: BTreeMap<String, Value> = [
+            ("name".into(), updatable_form.name.into()),
+            ("fields".into(), fields_value(updatable_form.fields)?),
+            ("recipients".into(), updatable_form.recipients.into()),
+            ("email_subject".into(), updatable_form.email_subject.into()),
+            ("success_message".into(), updatable_form.success_message.into()),
+            ("is_active".into(), updatable_form.is_active.into()),
+            (
+                "updated_by".into(),
+                updatable_form.logged_in_username.into(),
+            ),
+            ("updated_at".into(), Datetime::default().into()),
+        ]
+        .into();
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), FORM_TABLE.into()),
+            ("id".into(), updatable_form.id.into()),
+            ("data".into(), data.into()),
+        ]
+        .into();
+
+        self.execute_single(datastore, database_session, sql, vars).await
+    }
+
+    /// Removes the form together with its submissions.
+    pub async fn delete_by_id(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        id: &str,
+    ) -> Result<bool> {
+        let sql = "
+            DELETE type::table('form_submissions') WHERE form_id = $id;
+            DELETE type::thing($table, $id);
+        ";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), FORM_TABLE.into()),
+            ("id".into(), id.into()),
+        ]
+        .into();
+
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+        for response in responses {
+            response.result?;
+        }
+
+        Ok(true)
+    }
+
+    async fn execute_single(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        sql: &str,
+        vars: BTreeMap<String, Value>,
+    ) -> Result<FormModel> {
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        let result_object_option = into_iter_objects(responses)?.next();
+        let result_object = match result_object_option {
+            Some(object) => object,
+            None => Err(Error::Generic("no record found".to_string())),
+        };
+
+        result_object?.try_into()
+    }
+}
+
+fn fields_value(fields: Vec<FormFieldModel>) -> Result<Value> {
+    let mut values: Vec<Value> = vec![];
+    for field in fields {
+        values.push(field.try_into()?);
+    }
+
+    Ok(values.into())
+}
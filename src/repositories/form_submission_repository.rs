@@ -0,0 +1,133 @@
+use std::collections::BTreeMap;
+use surrealdb::dbs::Session;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::{Datetime, Value};
+use crate::error::{Error, Result};
+use crate::models::form_model::{FormModel, FormSubmissionModel};
+use crate::models::ModelCount;
+use crate::repositories::into_iter_objects;
+use crate::PER_PAGE;
+
+const FORM_SUBMISSION_TABLE: &str = "form_submissions";
+
+#[derive(Clone)]
+pub struct FormSubmissionRepository {}
+
+impl FormSubmissionRepository {
+    pub fn new() -> Self {
+        FormSubmissionRepository {}
+    }
+
+    pub async fn paginate(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        form_id: &str,
+        start: i64,
+    ) -> Result<Vec<FormSubmissionModel>> {
+        let sql = "SELECT * FROM type::table($table) WHERE form_id = $form_id
+                    ORDER BY created_at DESC LIMIT $limit START $start;";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), FORM_SUBMISSION_TABLE.into()),
+            ("form_id".into(), form_id.into()),
+            ("limit".into(), PER_PAGE.into()),
+            ("start".into(), start.into()),
+        ]
+        .into();
+
+        self.execute_many(datastore, database_session, sql, vars).await
+    }
+
+    /// Every submission of a form, oldest first.
+    pub async fn all_by_form(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        form_id: &str,
+    ) -> Result<Vec<FormSubmissionModel>> {
+        let sql = "SELECT * FROM type::table($table) WHERE form_id = $form_id ORDER BY created_at ASC;";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), FORM_SUBMISSION_TABLE.into()),
+            ("form_id".into(), form_id.into()),
+        ]
+        .into();
+
+        self.execute_many(datastore, database_session, sql, vars).await
+    }
+
+    pub async fn get_total_count(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        form_id: &str,
+    ) -> Result<ModelCount> {
+        let sql = "SELECT count() FROM type::table($table) WHERE form_id = $form_id GROUP ALL;";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), FORM_SUBMISSION_TABLE.into()),
+            ("form_id".into(), form_id.into()),
+        ]
+        .into();
+
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        match into_iter_objects(responses)?.next() {
+            Some(object) => object?.try_into(),
+            None => Ok(ModelCount::default()),
+        }
+    }
+
+    pub async fn create_form_submission(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        form_model: &FormModel,
+        data: BTreeMap<String, String>,
+    ) -> Result<FormSubmissionModel> {
+        let sql = "CREATE type::table($table) CONTENT $data";
+
+        let data: BTreeMap<String, Value> = data
+            .into_iter()
+            .map(|(key, value)| (key, value.into()))
+            .collect();
+        let data: BTreeMap<String, Value> = [
+            ("form_id".into(), form_model.id.clone().into()),
+            ("form_identifier".into(), form_model.identifier.clone().into()),
+            ("data".into(), data.into()),
+            ("created_at".into(), Datetime::default().into()),
+        ]
+        .into();
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), FORM_SUBMISSION_TABLE.into()),
+            ("data".into(), data.into()),
+        ]
+        .into();
+
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        let result_object_option = into_iter_objects(responses)?.next();
+        let result_object = match result_object_option {
+            Some(object) => object,
+            None => Err(Error::Generic("no record found".to_string())),
+        };
+
+        result_object?.try_into()
+    }
+
+    async fn execute_many(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        sql: &str,
+        vars: BTreeMap<String, Value>,
+    ) -> Result<Vec<FormSubmissionModel>> {
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        let mut form_submissions: Vec<FormSubmissionModel> = Vec::new();
+        for object in into_iter_objects(responses)? {
+            let form_submission_model: Result<FormSubmissionModel> = object?.try_into();
+            form_submissions.push(form_submission_model?);
+        }
+
+        Ok(form_submissions)
+    }
+}
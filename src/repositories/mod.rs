@@ -10,6 +10,18 @@ pub mod asset_repository;
 pub mod collection_repository;
 pub mod setting_repository;
 pub mod content_repository;
+pub mod cms_api_key_repository;
+pub mod webhook_repository;
+pub mod webhook_delivery_repository;
+pub mod form_repository;
+pub mod form_submission_repository;
+pub mod email_repository;
+pub mod email_template_repository;
+pub mod admin_user_session_repository;
+pub mod refresh_token_repository;
+pub mod admin_user_two_factor_repository;
+pub mod audit_log_repository;
+pub mod password_history_repository;
 
 pub fn into_iter_objects(responses: Vec<Response>) -> Result<impl Iterator<Item = Result<Object>>> {
     let response = responses
@@ -0,0 +1,100 @@
+use std::collections::BTreeMap;
+use surrealdb::dbs::Session;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::{Datetime, Value};
+use crate::error::{Error, Result};
+use crate::models::password_history_model::{CreatablePasswordHistoryModel, PasswordHistoryModel};
+use crate::repositories::into_iter_objects;
+
+const PASSWORD_HISTORY_TABLE: &str = "password_histories";
+
+#[derive(Clone)]
+pub struct PasswordHistoryRepository {}
+
+impl PasswordHistoryRepository {
+    pub fn new() -> Self {
+        PasswordHistoryRepository {}
+    }
+
+    /// Most recent passwords of the admin user first.
+    pub async fn latest_by_admin_user_id(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        admin_user_id: &str,
+        limit: i64,
+    ) -> Result<Vec<PasswordHistoryModel>> {
+        let sql = "SELECT * FROM type::table($table) WHERE admin_user_id = $admin_user_id
+                    ORDER BY created_at DESC LIMIT $limit;";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), PASSWORD_HISTORY_TABLE.into()),
+            ("admin_user_id".into(), admin_user_id.into()),
+            ("limit".into(), limit.into()),
+        ]
+        .into();
+
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        let mut password_histories: Vec<PasswordHistoryModel> = Vec::new();
+        for object in into_iter_objects(responses)? {
+            let password_history_model: Result<PasswordHistoryModel> = object?.try_into();
+            password_histories.push(password_history_model?);
+        }
+
+        Ok(password_histories)
+    }
+
+    pub async fn create_password_history(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        creatable_password_history: CreatablePasswordHistoryModel,
+    ) -> Result<PasswordHistoryModel> {
+        let sql = "CREATE type::table($table) CONTENT $data";
+
+        let data: BTreeMap<String, Value> = [
+            ("admin_user_id".into(), creatable_password_history.admin_user_id.into()),
+            ("password_hash".into(), creatable_password_history.password_hash.into()),
+            ("created_at".into(), Datetime::default().into()),
+        ]
+        .into();
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), PASSWORD_HISTORY_TABLE.into()),
+            ("data".into(), data.into()),
+        ]
+        .into();
+
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        let result_object_option = into_iter_objects(responses)?.next();
+        let result_object = match result_object_option {
+            Some(object) => object,
+            None => Err(Error::Generic("no record found".to_string())),
+        };
+
+        result_object?.try_into()
+    }
+
+    pub async fn delete_older_than(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        admin_user_id: &str,
+        created_at: Datetime,
+    ) -> Result<bool> {
+        let sql = "DELETE type::table($table) WHERE admin_user_id = $admin_user_id AND created_at < $created_at;";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), PASSWORD_HISTORY_TABLE.into()),
+            ("admin_user_id".into(), admin_user_id.into()),
+            ("created_at".into(), created_at.into()),
+        ]
+        .into();
+
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+        for response in responses {
+            response.result?;
+        }
+
+        Ok(true)
+    }
+}
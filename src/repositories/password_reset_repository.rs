@@ -26,8 +26,9 @@ impl PasswordResetRepository {
 
         let data: BTreeMap<String, Value> = [
             ("email".into(), creatable_password_reset_model.email.into()),
-            ("token".into(), creatable_password_reset_model.token.into()),
+            ("token_hash".into(), creatable_password_reset_model.token_hash.into()),
             ("status".into(), "Active".into()),
+            ("expires_at".into(), creatable_password_reset_model.expires_at.into()),
             ("created_at".into(), Datetime::default().into()),
             ("table".into(), PASSWORD_RESET_TABLE.into()),
         ]
@@ -48,18 +49,20 @@ impl PasswordResetRepository {
         password_reset_model
     }
 
-    pub async fn get_password_reset_by_email_and_token(
+    /// The reset an email was sent for, as long as it was neither used nor
+    /// replaced by a newer one and has not expired.
+    pub async fn find_active_by_email_and_token_hash(
         &self,
         datastore: &Datastore,
         database_session: &Session,
         email: &str,
-        token: &str,
+        token_hash: &str,
     ) -> crate::error::Result<PasswordResetModel> {
-        let sql = "SELECT * FROM type::table($table) WHERE email=$email and token=$password_token";
+        let sql = "SELECT * FROM type::table($table)
+                    WHERE email = $email AND token_hash = $token_hash AND status = 'Active' AND expires_at > time::now();";
         let vars: BTreeMap<String, Value> = [
-            ("password_token".into(), token.into()),
+            ("token_hash".into(), token_hash.into()),
             ("email".into(), email.into()),
-            ("status".into(), "Active".into()),
             ("table".into(), PASSWORD_RESET_TABLE.into()),
         ]
         .into();
@@ -80,6 +83,7 @@ impl PasswordResetRepository {
         password_reset_model
     }
 
+    /// Expires every reset still active for the email, a newer one replaces them.
     pub async fn expire_password_token_by_email (
         &self,
         datastore: &Datastore,
@@ -87,62 +91,64 @@ impl PasswordResetRepository {
         email: &str,
     ) -> crate::error::Result<bool> {
         let sql = "
-            UPDATE type::table($table) SET status=$status WHERE email=$email";
+            UPDATE type::table($table) SET status=$status WHERE email=$email AND status = 'Active'";
 
         let vars = BTreeMap::from([
-            ("status".into(), "Expired".into()),
+            ("status".into(), "Expire".into()),
             ("email".into(), email.into()),
             ("table".into(), PASSWORD_RESET_TABLE.into()),
         ]);
 
         let responses = datastore.execute(sql, database_session, Some(vars)).await?;
-
-        let result_object_option = into_iter_objects(responses)?.next();
-        let result_object = match result_object_option {
-            Some(object) => object,
-            None => Err(Error::Generic("no record found".to_string())),
-        };
-
-        if result_object.is_ok() {
-            return Ok(true);
+        for response in responses {
+            response.result?;
         }
 
-        Err(Error::Generic(format!(
-            "issue while expiring the password token: {email}"
-        )))
+        Ok(true)
     }
 
-    pub async fn expire_password_token_by_email_and_token(
+    /// Marks an active, unexpired reset as used in a single statement, so the
+    /// same token can not reset the password twice. Returns `false` when
+    /// there was no such reset.
+    pub async fn use_password_token(
         &self,
         datastore: &Datastore,
         database_session: &Session,
         email: &str,
-        token: &str,
+        token_hash: &str,
     ) -> crate::error::Result<bool> {
-        let sql = "
-            UPDATE type::table($table) SET status=$status WHERE email=$email and token=$password_token";
+        let sql = "UPDATE type::table($table) SET status = 'Used'
+                    WHERE email = $email AND token_hash = $token_hash AND status = 'Active' AND expires_at > time::now();";
     
         let vars = BTreeMap::from([
-            ("status".into(), "Expired".into()),
             ("email".into(), email.into()),
-            ("password_token".into(), token.into()),
+            ("token_hash".into(), token_hash.into()),
             ("table".into(), PASSWORD_RESET_TABLE.into()),
         ]);
     
         let responses = datastore.execute(sql, database_session, Some(vars)).await?;
-    
-        let result_object_option = into_iter_objects(responses)?.next();
-        let result_object = match result_object_option {
-            Some(object) => object,
-            None => Err(Error::Generic("no record found".to_string())),
-        };
-    
-        if result_object.is_ok() {
-            return Ok(true);
+
+        match into_iter_objects(responses)?.next() {
+            Some(object) => object.map(|_| true),
+            None => Ok(false),
         }
-    
-        Err(Error::Generic(format!(
-            "issue while updating password by email: {email}"
-        )))
+    }
+
+    /// Resets past their expiry can not be used any more, whatever their status.
+    /// Resets without an expiry still hold a plain text token and go as well.
+    pub async fn delete_expired(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+    ) -> crate::error::Result<bool> {
+        let sql = "DELETE type::table($table) WHERE expires_at = NONE OR expires_at <= time::now();";
+        let vars: BTreeMap<String, Value> = [("table".into(), PASSWORD_RESET_TABLE.into())].into();
+
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+        for response in responses {
+            response.result?;
+        }
+
+        Ok(true)
     }
 }
@@ -0,0 +1,143 @@
+use std::collections::BTreeMap;
+use surrealdb::dbs::Session;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::{Datetime, Value};
+use crate::error::{Error, Result};
+use crate::models::admin_user_session_model::{CreatableRefreshTokenModel, RefreshTokenModel};
+use crate::repositories::into_iter_objects;
+
+const REFRESH_TOKEN_TABLE: &str = "refresh_tokens";
+
+#[derive(Clone)]
+pub struct RefreshTokenRepository {}
+
+impl RefreshTokenRepository {
+    pub fn new() -> Self {
+        RefreshTokenRepository {}
+    }
+
+    pub async fn find_by_token_hash(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        token_hash: &str,
+    ) -> Result<RefreshTokenModel> {
+        let sql = "SELECT * FROM type::table($table) WHERE token_hash = $token_hash;";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), REFRESH_TOKEN_TABLE.into()),
+            ("token_hash".into(), token_hash.into()),
+        ]
+        .into();
+
+        self.execute_single(datastore, database_session, sql, vars).await
+    }
+
+    pub async fn create_refresh_token(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        creatable_refresh_token: CreatableRefreshTokenModel,
+    ) -> Result<RefreshTokenModel> {
+        let sql = "CREATE type::table($table) CONTENT $data";
+
+        let data: BTreeMap<String, Value> = [
+            ("session_id".into(), creatable_refresh_token.session_id.into()),
+            ("token_hash".into(), creatable_refresh_token.token_hash.into()),
+            ("status".into(), "Active".into()),
+            ("expires_at".into(), creatable_refresh_token.expires_at.into()),
+            ("created_at".into(), Datetime::default().into()),
+        ]
+        .into();
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), REFRESH_TOKEN_TABLE.into()),
+            ("data".into(), data.into()),
+        ]
+        .into();
+
+        self.execute_single(datastore, database_session, sql, vars).await
+    }
+
+    /// Marks an active, unexpired token as used in a single statement, so two
+    /// requests racing with the same token cannot both rotate it. Returns
+    /// `None` when there was no such token.
+    pub async fn use_refresh_token(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        token_hash: &str,
+    ) -> Result<Option<RefreshTokenModel>> {
+        let sql = "UPDATE type::table($table) SET status = 'Used'
+                    WHERE token_hash = $token_hash AND status = 'Active' AND expires_at > time::now();";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), REFRESH_TOKEN_TABLE.into()),
+            ("token_hash".into(), token_hash.into()),
+        ]
+        .into();
+
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        match into_iter_objects(responses)?.next() {
+            Some(object) => Ok(Some(object?.try_into()?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Active tokens of the given sessions can no longer be swapped.
+    pub async fn revoke_by_session_ids(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        session_ids: Vec<String>,
+    ) -> Result<bool> {
+        let sql = "UPDATE type::table($table) SET status = 'Revoked'
+                    WHERE session_id IN $session_ids AND status = 'Active';";
+        let session_ids: Vec<Value> = session_ids.into_iter().map(Value::from).collect();
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), REFRESH_TOKEN_TABLE.into()),
+            ("session_ids".into(), session_ids.into()),
+        ]
+        .into();
+
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+        for response in responses {
+            response.result?;
+        }
+
+        Ok(true)
+    }
+
+    /// Tokens past their expiry can not be used or reused any more.
+    pub async fn delete_expired(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+    ) -> Result<bool> {
+        let sql = "DELETE type::table($table) WHERE expires_at <= time::now();";
+        let vars: BTreeMap<String, Value> = [("table".into(), REFRESH_TOKEN_TABLE.into())].into();
+
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+        for response in responses {
+            response.result?;
+        }
+
+        Ok(true)
+    }
+
+    async fn execute_single(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        sql: &str,
+        vars: BTreeMap<String, Value>,
+    ) -> Result<RefreshTokenModel> {
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        let result_object_option = into_iter_objects(responses)?.next();
+        let result_object = match result_object_option {
+            Some(object) => object,
+            None => Err(Error::Generic("no record found".to_string())),
+        };
+
+        result_object?.try_into()
+    }
+}
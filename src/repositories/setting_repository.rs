@@ -1,9 +1,10 @@
 use crate::error::Error;
-use crate::models::setting_model::{SettingModel, UpdatableSettingModel};
+use crate::models::setting_model::{SettingModel, UpdatableSettingModel, SUPER_ADMIN_SETTINGS};
 use crate::repositories::into_iter_objects;
 use std::collections::BTreeMap;
 use surrealdb::dbs::Session;
 use surrealdb::kvs::Datastore;
+use surrealdb::sql::Value;
 
 #[derive(Clone)]
 pub struct SettingRepository {}
@@ -44,10 +45,12 @@ impl SettingRepository {
                 value: $value,
                 updated_by: $logged_in_user_name,
                 updated_at: time::now(),
-            };";
+            } WHERE identifier NOTINSIDE $super_admin_settings;";
 
+        let super_admin_settings: Vec<Value> = SUPER_ADMIN_SETTINGS.iter().map(|identifier| (*identifier).into()).collect();
         let vars = BTreeMap::from([
             ("table".into(), "settings".into()),
+            ("super_admin_settings".into(), super_admin_settings.into()),
             ("value".into(), updatable_setting.value.into()),
             ("id".into(), updatable_setting.id.into()),
             (
@@ -69,6 +72,74 @@ impl SettingRepository {
         Ok(false)
     }
 
+    /// Writes the value of a setting, including the ones `update_setting`
+    /// leaves alone. Creates the setting when it does not exist yet.
+    pub async fn update_setting_by_identifier(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        identifier: &str,
+        value: &str,
+        logged_in_username: &str,
+    ) -> crate::error::Result<SettingModel> {
+        let vars: BTreeMap<String, Value> = [
+            ("identifier".into(), identifier.into()),
+            ("value".into(), value.into()),
+            ("logged_in_user_name".into(), logged_in_username.into()),
+        ]
+        .into();
+
+        let sql = "
+            UPDATE settings MERGE {
+                value: $value,
+                updated_by: $logged_in_user_name,
+                updated_at: time::now(),
+            } WHERE identifier = $identifier;";
+        let responses = datastore.execute(sql, database_session, Some(vars.clone())).await?;
+        if let Some(object) = into_iter_objects(responses)?.next() {
+            return object?.try_into();
+        }
+
+        let sql = "
+            CREATE settings CONTENT {
+                identifier: $identifier,
+                value: $value,
+                created_by: $logged_in_user_name,
+                updated_by: $logged_in_user_name,
+                created_at: time::now(),
+                updated_at: time::now(),
+            };";
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        let result_object_option = into_iter_objects(responses)?.next();
+        let result_object = match result_object_option {
+            Some(object) => object,
+            None => Err(Error::Generic("no record found".to_string())),
+        };
+
+        result_object?.try_into()
+    }
+
+    pub async fn find_by_identifier(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        identifier: &str,
+    ) -> crate::error::Result<SettingModel> {
+        let sql = "SELECT * FROM settings WHERE identifier=$data;";
+        let data: BTreeMap<String, Value> = [("data".into(), identifier.into())].into();
+
+        let responses = datastore.execute(sql, database_session, Some(data)).await?;
+
+        let result_object_option = into_iter_objects(responses)?.next();
+        let result_object = match result_object_option {
+            Some(object) => object,
+            None => Err(Error::NotFound(format!("setting {identifier}"))),
+        };
+
+        result_object?.try_into()
+    }
+
     // pub async fn find_by_identifier(
     //     &self,
     //     datastore: &Datastore,
@@ -0,0 +1,201 @@
+use std::collections::BTreeMap;
+use surrealdb::dbs::Session;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::{Datetime, Value};
+use crate::error::{Error, Result};
+use crate::models::ModelCount;
+use crate::models::webhook_model::{UpdatableWebhookDeliveryModel, WebhookDeliveryModel};
+use crate::repositories::into_iter_objects;
+use crate::PER_PAGE;
+
+const WEBHOOK_DELIVERY_TABLE: &str = "webhook_deliveries";
+
+#[derive(Clone)]
+pub struct WebhookDeliveryRepository {}
+
+impl WebhookDeliveryRepository {
+    pub fn new() -> Self {
+        WebhookDeliveryRepository {}
+    }
+
+    pub async fn paginate(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        webhook_id: &str,
+        start: i64,
+    ) -> Result<Vec<WebhookDeliveryModel>> {
+        let sql = "SELECT * FROM type::table($table) WHERE webhook_id = $webhook_id
+                    ORDER BY created_at DESC LIMIT $limit START $start;";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), WEBHOOK_DELIVERY_TABLE.into()),
+            ("webhook_id".into(), webhook_id.into()),
+            ("limit".into(), PER_PAGE.into()),
+            ("start".into(), start.into()),
+        ]
+        .into();
+
+        self.execute_many(datastore, database_session, sql, vars).await
+    }
+
+    pub async fn get_total_count(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        webhook_id: &str,
+    ) -> Result<ModelCount> {
+        let sql = "SELECT count() FROM type::table($table) WHERE webhook_id = $webhook_id GROUP ALL;";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), WEBHOOK_DELIVERY_TABLE.into()),
+            ("webhook_id".into(), webhook_id.into()),
+        ]
+        .into();
+
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        let result_object_option = into_iter_objects(responses)?.next();
+        match result_object_option {
+            Some(object) => object?.try_into(),
+            None => Ok(ModelCount::default()),
+        }
+    }
+
+    /// Pending deliveries whose next attempt is due, oldest first.
+    pub async fn due(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        now: Datetime,
+        limit: i64,
+    ) -> Result<Vec<WebhookDeliveryModel>> {
+        let sql = "SELECT * FROM type::table($table)
+                    WHERE status = 'Pending' AND next_attempt_at <= $now
+                    ORDER BY next_attempt_at ASC LIMIT $limit;";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), WEBHOOK_DELIVERY_TABLE.into()),
+            ("now".into(), now.into()),
+            ("limit".into(), limit.into()),
+        ]
+        .into();
+
+        self.execute_many(datastore, database_session, sql, vars).await
+    }
+
+    pub async fn find_by_id(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        id: &str,
+    ) -> Result<WebhookDeliveryModel> {
+        let sql = "SELECT * FROM type::thing($table, $id);";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), WEBHOOK_DELIVERY_TABLE.into()),
+            ("id".into(), id.into()),
+        ]
+        .into();
+
+        self.execute_single(datastore, database_session, sql, vars).await
+    }
+
+    /// Queues a delivery that is due straight away.
+    pub async fn create_webhook_delivery(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        webhook_id: &str,
+        event: &str,
+        payload: &str,
+    ) -> Result<WebhookDeliveryModel> {
+        let sql = "CREATE type::table($table) CONTENT $data";
+
+        let data: BTreeMap<String, Value> = [
+            ("webhook_id".into(), webhook_id.into()),
+            ("event".into(), event.into()),
+            ("payload".into(), payload.into()),
+            ("status".into(), "Pending".into()),
+            ("attempts".into(), 0.into()),
+            ("response_status".into(), 0.into()),
+            ("response_body".into(), "".into()),
+            ("error".into(), "".into()),
+            ("next_attempt_at".into(), Datetime::default().into()),
+            ("created_at".into(), Datetime::default().into()),
+            ("updated_at".into(), Datetime::default().into()),
+        ]
+        .into();
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), WEBHOOK_DELIVERY_TABLE.into()),
+            ("data".into(), data.into()),
+        ]
+        .into();
+
+        self.execute_single(datastore, database_session, sql, vars).await
+    }
+
+    pub async fn update_attempt(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        updatable_webhook_delivery: UpdatableWebhookDeliveryModel,
+    ) -> Result<WebhookDeliveryModel> {
+        let sql = "UPDATE type::thing($table, $id)
+                    SET
+                        status = $status,
+                        attempts = $attempts,
+                        response_status = $response_status,
+                        response_body = $response_body,
+                        error = $error,
+                        next_attempt_at = $next_attempt_at,
+                        updated_at = time::now()
+                    ;";
+        let status: String = updatable_webhook_delivery.status.try_into()?;
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), WEBHOOK_DELIVERY_TABLE.into()),
+            ("id".into(), updatable_webhook_delivery.id.into()),
+            ("status".into(), status.into()),
+            ("attempts".into(), updatable_webhook_delivery.attempts.into()),
+            ("response_status".into(), updatable_webhook_delivery.response_status.into()),
+            ("response_body".into(), updatable_webhook_delivery.response_body.into()),
+            ("error".into(), updatable_webhook_delivery.error.into()),
+            ("next_attempt_at".into(), updatable_webhook_delivery.next_attempt_at.into()),
+        ]
+        .into();
+
+        self.execute_single(datastore, database_session, sql, vars).await
+    }
+
+    async fn execute_many(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        sql: &str,
+        vars: BTreeMap<String, Value>,
+    ) -> Result<Vec<WebhookDeliveryModel>> {
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        let mut webhook_deliveries: Vec<WebhookDeliveryModel> = Vec::new();
+        for object in into_iter_objects(responses)? {
+            let webhook_delivery_model: Result<WebhookDeliveryModel> = object?.try_into();
+            webhook_deliveries.push(webhook_delivery_model?);
+        }
+
+        Ok(webhook_deliveries)
+    }
+
+    async fn execute_single(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        sql: &str,
+        vars: BTreeMap<String, Value>,
+    ) -> Result<WebhookDeliveryModel> {
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        let result_object_option = into_iter_objects(responses)?.next();
+        let result_object = match result_object_option {
+            Some(object) => object,
+            None => Err(Error::Generic("no record found".to_string())),
+        };
+
+        result_object?.try_into()
+    }
+}
@@ -0,0 +1,191 @@
+use std::collections::BTreeMap;
+use surrealdb::dbs::Session;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::{Datetime, Value};
+use crate::error::{Error, Result};
+use crate::models::webhook_model::{CreatableWebhookModel, UpdatableWebhookModel, WebhookModel};
+use crate::repositories::into_iter_objects;
+
+const WEBHOOK_TABLE: &str = "webhooks";
+
+#[derive(Clone)]
+pub struct WebhookRepository {}
+
+impl WebhookRepository {
+    pub fn new() -> Self {
+        WebhookRepository {}
+    }
+
+    pub async fn all(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+    ) -> Result<Vec<WebhookModel>> {
+        let sql = "SELECT * FROM type::table($table) ORDER BY created_at DESC;";
+        let vars: BTreeMap<String, Value> = [("table".into(), WEBHOOK_TABLE.into())].into();
+
+        self.execute_many(datastore, database_session, sql, vars).await
+    }
+
+    /// Active webhooks subscribed to the given event.
+    pub async fn active_for_event(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        event: &str,
+    ) -> Result<Vec<WebhookModel>> {
+        let sql = "SELECT * FROM type::table($table) WHERE is_active = true AND events CONTAINS $event;";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), WEBHOOK_TABLE.into()),
+            ("event".into(), event.into()),
+        ]
+        .into();
+
+        self.execute_many(datastore, database_session, sql, vars).await
+    }
+
+    pub async fn find_by_id(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        id: &str,
+    ) -> Result<WebhookModel> {
+        let sql = "SELECT * FROM type::thing($table, $id);";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), WEBHOOK_TABLE.into()),
+            ("id".into(), id.into()),
+        ]
+        .into();
+
+        self.execute_single(datastore, database_session, sql, vars).await
+    }
+
+    pub async fn create_webhook(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        creatable_webhook: CreatableWebhookModel,
+    ) -> Result<WebhookModel> {
+        let sql = "CREATE type::table($table) CONTENT $data";
+
+        let data: BTreeMap<String, Value> = [
+            ("name".into(), creatable_webhook.name.into()),
+            ("url".into(), creatable_webhook.url.into()),
+            ("events".into(), creatable_webhook.events.into()),
+            ("secret".into(), creatable_webhook.secret.into()),
+            ("is_active".into(), creatable_webhook.is_active.into()),
+            (
+                "created_by".into(),
+                creatable_webhook.logged_in_username.clone().into(),
+            ),
+            (
+                "updated_by".into(),
+                creatable_webhook.logged_in_username.into(),
+            ),
+            ("created_at".into(), Datetime::default().into()),
+            ("updated_at".into(), Datetime::default().into()),
+        ]
+        .into();
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), WEBHOOK_TABLE.into()),
+            ("data".into(), data.into()),
+        ]
+        .into();
+
+        self.execute_single(datastore, database_session, sql, vars).await
+    }
+
+    pub async fn update_webhook(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        updatable_webhook: UpdatableWebhookModel,
+    ) -> Result<WebhookModel> {
+        let sql = "UPDATE type::thing($table, $id) MERGE $data;";
+
+        let mut data: BTreeMap<String, Value> = [
+            ("name".into(), updatable_webhook.name.into()),
+            ("url".into(), updatable_webhook.url.into()),
+            ("events".into(), updatable_webhook.events.into()),
+            ("is_active".into(), updatable_webhook.is_active.into()),
+            (
+                "updated_by".into(),
+                updatable_webhook.logged_in_username.into(),
+            ),
+            ("updated_at".into(), Datetime::default().into()),
+        ]
+        .into();
+        if let Some(secret) = updatable_webhook.secret {
+            data.insert("secret".into(), secret.into());
+        }
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), WEBHOOK_TABLE.into()),
+            ("id".into(), updatable_webhook.id.into()),
+            ("data".into(), data.into()),
+        ]
+        .into();
+
+        self.execute_single(datastore, database_session, sql, vars).await
+    }
+
+    /// Removes the webhook together with its delivery log.
+    pub async fn delete_by_id(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        id: &str,
+    ) -> Result<bool> {
+        let sql = "
+            DELETE type::table('webhook_deliveries') WHERE webhook_id = $id;
+            DELETE type::thing($table, $id);
+        ";
+        let vars: BTreeMap<String, Value> = [
+            ("table".into(), WEBHOOK_TABLE.into()),
+            ("id".into(), id.into()),
+        ]
+        .into();
+
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+        for response in responses {
+            response.result?;
+        }
+
+        Ok(true)
+    }
+
+    async fn execute_many(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        sql: &str,
+        vars: BTreeMap<String, Value>,
+    ) -> Result<Vec<WebhookModel>> {
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        let mut webhooks: Vec<WebhookModel> = Vec::new();
+        for object in into_iter_objects(responses)? {
+            let webhook_model: Result<WebhookModel> = object?.try_into();
+            webhooks.push(webhook_model?);
+        }
+
+        Ok(webhooks)
+    }
+
+    async fn execute_single(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        sql: &str,
+        vars: BTreeMap<String, Value>,
+    ) -> Result<WebhookModel> {
+        let responses = datastore.execute(sql, database_session, Some(vars)).await?;
+
+        let result_object_option = into_iter_objects(responses)?.next();
+        let result_object = match result_object_option {
+            Some(object) => object,
+            None => Err(Error::Generic("no record found".to_string())),
+        };
+
+        result_object?.try_into()
+    }
+}
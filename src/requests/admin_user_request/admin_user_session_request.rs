@@ -0,0 +1,48 @@
+use crate::{
+    api::proto::admin_user::{TerminateAdminUserSessionRequest, TerminateAllAdminUserSessionsRequest, UnlockAdminUserRequest},
+    models::validation_error::{ErrorMessage, ErrorResponse, Validate},
+};
+use rust_i18n::t;
+
+impl TerminateAdminUserSessionRequest {
+    pub fn validate(&self) -> crate::error::Result<()> {
+        required_id_errors("session_id", &self.session_id)
+    }
+}
+
+impl TerminateAllAdminUserSessionsRequest {
+    pub fn validate(&self) -> crate::error::Result<()> {
+        required_id_errors("admin_user_id", &self.admin_user_id)
+    }
+}
+
+impl UnlockAdminUserRequest {
+    pub fn validate(&self) -> crate::error::Result<()> {
+        required_id_errors("admin_user_id", &self.admin_user_id)
+    }
+}
+
+fn required_id_errors(key: &str, value: &str) -> crate::error::Result<()> {
+    let mut errors: Vec<ErrorMessage> = vec![];
+    let mut valid = true;
+
+    if !value.to_string().required()? {
+        let error_message = ErrorMessage {
+            key: key.to_string(),
+            message: t!("validation_required", attribute = t!(key)).to_string(),
+        };
+        valid = false;
+        errors.push(error_message);
+    }
+
+    if !valid {
+        let error_response = ErrorResponse {
+            status: valid,
+            errors,
+        };
+        let error_string = serde_json::to_string(&error_response)?;
+        return Err(crate::error::Error::InvalidArgument(error_string));
+    }
+
+    Ok(())
+}
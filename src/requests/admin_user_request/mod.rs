@@ -2,4 +2,6 @@ pub mod store_admin_user_request;
 pub mod store_role_request;
 pub mod update_role_request;
 pub mod update_admin_user_request;
-pub mod put_role_identifier_request;
\ No newline at end of file
+pub mod put_role_identifier_request;
+pub mod admin_user_session_request;
+pub mod two_factor_request;
\ No newline at end of file
@@ -52,6 +52,17 @@ impl StoreAdminUserRequest {
             errors.push(error_message);
         }
 
+        if self.password.required()? {
+            let password_errors = state
+                .password_policy_service
+                .validate(&state.db, &self.password, &self.email)
+                .await?;
+            if !password_errors.is_empty() {
+                valid = false;
+                errors.extend(password_errors);
+            }
+        }
+
         if self.password != self.confirm_password {
             let error_message = ErrorMessage {
                 key: String::from("password"),
@@ -0,0 +1,32 @@
+use crate::{
+    api::proto::admin_user::DisableTwoFactorRequest,
+    models::validation_error::{ErrorMessage, ErrorResponse, Validate},
+};
+use rust_i18n::t;
+
+impl DisableTwoFactorRequest {
+    pub fn validate(&self) -> crate::error::Result<()> {
+        let mut errors: Vec<ErrorMessage> = vec![];
+        let mut valid = true;
+
+        if !self.code.required()? {
+            let error_message = ErrorMessage {
+                key: String::from("code"),
+                message: t!("validation_required", attribute = t!("code")).to_string(),
+            };
+            valid = false;
+            errors.push(error_message);
+        }
+
+        if !valid {
+            let error_response = ErrorResponse {
+                status: valid,
+                errors,
+            };
+            let error_string = serde_json::to_string(&error_response)?;
+            return Err(crate::error::Error::InvalidArgument(error_string));
+        }
+
+        Ok(())
+    }
+}
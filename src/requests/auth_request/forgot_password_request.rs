@@ -1,10 +1,9 @@
 use rust_i18n::t;
 use crate::api::proto::auth::ForgotPasswordRequest;
-use crate::avored_state::AvoRedState;
 use crate::models::validation_error::{ErrorMessage, ErrorResponse, Validate};
 
 impl ForgotPasswordRequest {
-    pub async fn validate(&self, state: &AvoRedState) -> crate::error::Result<(bool, String)> {
+    pub fn validate(&self) -> crate::error::Result<(bool, String)> {
         let mut errors: Vec<ErrorMessage> = vec![];
         let mut valid = true;
 
@@ -27,21 +26,6 @@ impl ForgotPasswordRequest {
             errors.push(error_message);
         }
 
-        let admin_user_model = state
-            .admin_user_service
-            .count_of_email(&state.db, self.email.clone())
-            .await?;
-
-        
-        if admin_user_model.total != 1 {
-            let error_message = ErrorMessage {
-                key: String::from("email"),
-                message: t!("not_found", attribute = t!("email")).to_string(),
-            };
-            valid = false;
-            errors.push(error_message);
-        }
-
         let error_response = ErrorResponse {
             status: false,
             errors,
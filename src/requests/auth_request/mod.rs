@@ -1,4 +1,6 @@
 pub mod login_request;
 pub mod forgot_password_request;
 pub mod reset_password_request;
-pub mod delete_role_request;
\ No newline at end of file
+pub mod delete_role_request;
+pub mod refresh_token_request;
+pub mod two_factor_request;
\ No newline at end of file
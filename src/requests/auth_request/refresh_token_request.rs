@@ -0,0 +1,39 @@
+use rust_i18n::t;
+use crate::models::validation_error::{ErrorMessage, ErrorResponse, Validate};
+use crate::api::proto::auth::{LogoutRequest, RefreshTokenRequest};
+
+impl RefreshTokenRequest {
+    pub fn validate(&self) -> crate::error::Result<(bool, String)> {
+        refresh_token_errors(&self.refresh_token)
+    }
+}
+
+impl LogoutRequest {
+    pub fn validate(&self) -> crate::error::Result<(bool, String)> {
+        refresh_token_errors(&self.refresh_token)
+    }
+}
+
+fn refresh_token_errors(refresh_token: &str) -> crate::error::Result<(bool, String)> {
+    let mut errors: Vec<ErrorMessage> = vec![];
+    let mut valid = true;
+
+    if !refresh_token.to_string().required()? {
+        let error_message = ErrorMessage {
+            key: String::from("refresh_token"),
+            message: t!("validation_required", attribute = t!("refresh_token")).to_string(),
+        };
+
+        valid = false;
+        errors.push(error_message);
+    }
+
+    let error_response = ErrorResponse {
+        status: false,
+        errors,
+    };
+
+    let error_string = serde_json::to_string(&error_response)?;
+
+    Ok((valid, error_string))
+}
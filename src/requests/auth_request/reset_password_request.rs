@@ -37,16 +37,8 @@ impl ResetPasswordRequest {
             errors.push(error_message);
         }
 
-        if self.password != self.confirm_password {
-            let error_message = ErrorMessage {
-                key: String::from("password"),
-                message: t!("password_match_error").to_string(),
-            };
-
-            valid = false;
-            errors.push(error_message);
-        }
-        
+        // the token goes first, without it the password is not looked at, as
+        // the history check would tell whether a guess is the current password.
         let validated_token_result = state
             .auth_service
             .validate_token(
@@ -56,14 +48,38 @@ impl ResetPasswordRequest {
             ).await?;
 
         if !validated_token_result {
+            let error_response = ErrorResponse {
+                status: false,
+                errors: vec![ErrorMessage {
+                    key: String::from("email"),
+                    message: t!("not_valid_password_reset_token").to_string(),
+                }],
+            };
+
+            return Ok((false, serde_json::to_string(&error_response)?));
+        }
+
+        if self.password.required()? {
+            let password_errors = state
+                .password_policy_service
+                .validate(&state.db, &self.password, &self.email)
+                .await?;
+            if !password_errors.is_empty() {
+                valid = false;
+                errors.extend(password_errors);
+            }
+        }
+
+        if self.password != self.confirm_password {
             let error_message = ErrorMessage {
-                key: String::from("email"),
-                message: t!("not_valid_password_reset_token").to_string(),
+                key: String::from("password"),
+                message: t!("password_match_error").to_string(),
             };
 
             valid = false;
             errors.push(error_message);
         }
+        
 
         let error_response = ErrorResponse {
             status: false,
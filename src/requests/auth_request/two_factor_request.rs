@@ -0,0 +1,44 @@
+use rust_i18n::t;
+use crate::models::validation_error::{ErrorMessage, ErrorResponse, Validate};
+use crate::api::proto::auth::{ConfirmTwoFactorRequest, VerifyTwoFactorRequest};
+
+impl VerifyTwoFactorRequest {
+    pub fn validate(&self) -> crate::error::Result<(bool, String)> {
+        required_errors(&[
+            ("two_factor_token", &self.two_factor_token),
+            ("code", &self.code),
+        ])
+    }
+}
+
+impl ConfirmTwoFactorRequest {
+    pub fn validate(&self) -> crate::error::Result<(bool, String)> {
+        required_errors(&[("code", &self.code)])
+    }
+}
+
+fn required_errors(fields: &[(&str, &String)]) -> crate::error::Result<(bool, String)> {
+    let mut errors: Vec<ErrorMessage> = vec![];
+    let mut valid = true;
+
+    for (key, value) in fields {
+        if !value.required()? {
+            let error_message = ErrorMessage {
+                key: key.to_string(),
+                message: t!("validation_required", attribute = t!(*key)).to_string(),
+            };
+
+            valid = false;
+            errors.push(error_message);
+        }
+    }
+
+    let error_response = ErrorResponse {
+        status: false,
+        errors,
+    };
+
+    let error_string = serde_json::to_string(&error_response)?;
+
+    Ok((valid, error_string))
+}
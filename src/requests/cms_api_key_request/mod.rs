@@ -0,0 +1,4 @@
+pub mod store_cms_api_key_request;
+pub mod update_cms_api_key_request;
+pub mod rotate_cms_api_key_request;
+pub mod revoke_cms_api_key_request;
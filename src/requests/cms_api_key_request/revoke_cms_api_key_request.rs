@@ -0,0 +1,30 @@
+use crate::api::proto::cms_api_key::RevokeCmsApiKeyRequest;
+use crate::models::validation_error::{ErrorMessage, ErrorResponse, Validate};
+use rust_i18n::t;
+
+impl RevokeCmsApiKeyRequest {
+    pub fn validate(&self) -> crate::error::Result<()> {
+        let mut errors: Vec<ErrorMessage> = vec![];
+        let mut valid = true;
+
+        if !self.cms_api_key_id.required()? {
+            let error_message = ErrorMessage {
+                key: String::from("cms_api_key_id"),
+                message: t!("validation_required", attribute = t!("cms_api_key_id")).to_string(),
+            };
+            valid = false;
+            errors.push(error_message);
+        }
+
+        if !valid {
+            let error_response = ErrorResponse {
+                status: valid,
+                errors,
+            };
+            let error_string = serde_json::to_string(&error_response)?;
+            return Err(crate::error::Error::InvalidArgument(error_string));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use crate::api::proto::cms_api_key::UpdateCmsApiKeyRequest;
+use crate::models::cms_api_key_model::CMS_API_KEY_OPERATIONS;
+use crate::models::validation_error::{ErrorMessage, ErrorResponse, Validate};
+use rust_i18n::t;
+
+impl UpdateCmsApiKeyRequest {
+    pub fn validate(&self) -> crate::error::Result<()> {
+        let mut errors: Vec<ErrorMessage> = vec![];
+        let mut valid = true;
+
+        if !self.cms_api_key_id.required()? {
+            let error_message = ErrorMessage {
+                key: String::from("cms_api_key_id"),
+                message: t!("validation_required", attribute = t!("cms_api_key_id")).to_string(),
+            };
+            valid = false;
+            errors.push(error_message);
+        }
+
+        if !self.name.required()? {
+            let error_message = ErrorMessage {
+                key: String::from("name"),
+                message: t!("validation_required", attribute = t!("name")).to_string(),
+            };
+            valid = false;
+            errors.push(error_message);
+        }
+
+        if self.operations.is_empty() {
+            let error_message = ErrorMessage {
+                key: String::from("operations"),
+                message: t!("validation_required", attribute = t!("operations")).to_string(),
+            };
+            valid = false;
+            errors.push(error_message);
+        }
+
+        if self
+            .operations
+            .iter()
+            .any(|operation| !CMS_API_KEY_OPERATIONS.contains(&operation.as_str()))
+        {
+            let error_message = ErrorMessage {
+                key: String::from("operations"),
+                message: t!("validation_invalid", attribute = t!("operations")).to_string(),
+            };
+            valid = false;
+            errors.push(error_message);
+        }
+
+        if !valid {
+            let error_response = ErrorResponse {
+                status: valid,
+                errors,
+            };
+            let error_string = serde_json::to_string(&error_response)?;
+            return Err(crate::error::Error::InvalidArgument(error_string));
+        }
+
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 use crate::api::proto::content::UpdateCollectionRequest;
+use crate::models::collection_model::is_reserved_collection_identifier;
 use crate::models::validation_error::{ErrorMessage, ErrorResponse, Validate};
 use rust_i18n::t;
 
@@ -15,6 +16,15 @@ impl UpdateCollectionRequest {
             valid = false;
             errors.push(error_message);
         }
+
+        if is_reserved_collection_identifier(&self.identifier) {
+            let error_message = ErrorMessage {
+                key: String::from("identifier"),
+                message: t!("collection_identifier_reserved").to_string(),
+            };
+            valid = false;
+            errors.push(error_message);
+        }
        
         if !valid {
             let error_response = ErrorResponse {
@@ -0,0 +1,30 @@
+use crate::api::proto::email::DeleteEmailTemplateRequest;
+use crate::models::validation_error::{ErrorMessage, ErrorResponse, Validate};
+use rust_i18n::t;
+
+impl DeleteEmailTemplateRequest {
+    pub fn validate(&self) -> crate::error::Result<()> {
+        let mut errors: Vec<ErrorMessage> = vec![];
+        let mut valid = true;
+
+        if !self.email_template_id.required()? {
+            let error_message = ErrorMessage {
+                key: String::from("email_template_id"),
+                message: t!("validation_required", attribute = t!("email_template_id")).to_string(),
+            };
+            valid = false;
+            errors.push(error_message);
+        }
+
+        if !valid {
+            let error_response = ErrorResponse {
+                status: valid,
+                errors,
+            };
+            let error_string = serde_json::to_string(&error_response)?;
+            return Err(crate::error::Error::InvalidArgument(error_string));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+use crate::api::proto::email::EmailPaginateRequest;
+use crate::models::validation_error::{ErrorMessage, ErrorResponse};
+use rust_i18n::t;
+
+const EMAIL_STATUSES: [&str; 3] = ["Pending", "Sent", "Dead"];
+
+impl EmailPaginateRequest {
+    pub fn validate(&self) -> crate::error::Result<()> {
+        let mut errors: Vec<ErrorMessage> = vec![];
+        let mut valid = true;
+
+        if let Some(status) = &self.status {
+            if !status.is_empty() && !EMAIL_STATUSES.contains(&status.as_str()) {
+                let error_message = ErrorMessage {
+                    key: String::from("status"),
+                    message: t!("validation_invalid", attribute = t!("status")).to_string(),
+                };
+                valid = false;
+                errors.push(error_message);
+            }
+        }
+
+        if !valid {
+            let error_response = ErrorResponse {
+                status: valid,
+                errors,
+            };
+            let error_string = serde_json::to_string(&error_response)?;
+            return Err(crate::error::Error::InvalidArgument(error_string));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+pub mod email_paginate_request;
+pub mod resend_email_request;
+pub mod get_email_template_request;
+pub mod store_email_template_request;
+pub mod update_email_template_request;
+pub mod delete_email_template_request;
+pub mod preview_email_template_request;
+
+use handlebars::Template;
+use crate::models::validation_error::{ErrorMessage, Validate};
+use rust_i18n::t;
+
+/// Subject and html body are required and every source has to compile.
+/// Shared by the store, update and preview requests.
+fn email_template_errors(subject: &str, html_body: &str, text_body: &str) -> crate::error::Result<Vec<ErrorMessage>> {
+    let mut errors: Vec<ErrorMessage> = vec![];
+
+    let sources = [("subject", subject, true), ("html_body", html_body, true), ("text_body", text_body, false)];
+    for (key, source, is_required) in sources {
+        if is_required && !source.to_string().required()? {
+            errors.push(ErrorMessage {
+                key: String::from(key),
+                message: t!("validation_required", attribute = t!(key)).to_string(),
+            });
+        } else if Template::compile(source).is_err() {
+            errors.push(ErrorMessage {
+                key: String::from(key),
+                message: t!("validation_invalid", attribute = t!(key)).to_string(),
+            });
+        }
+    }
+
+    Ok(errors)
+}
@@ -0,0 +1,46 @@
+use crate::api::proto::email::PreviewEmailTemplateRequest;
+use crate::models::email_template_model::email_template_definition;
+use crate::models::validation_error::{ErrorMessage, ErrorResponse};
+use rust_i18n::t;
+use super::email_template_errors;
+
+impl PreviewEmailTemplateRequest {
+    pub fn validate(&self) -> crate::error::Result<()> {
+        let mut errors: Vec<ErrorMessage> = vec![];
+
+        if email_template_definition(&self.identifier).is_none() {
+            let error_message = ErrorMessage {
+                key: String::from("identifier"),
+                message: t!("validation_invalid", attribute = t!("identifier")).to_string(),
+            };
+            errors.push(error_message);
+        }
+
+        errors.extend(email_template_errors(&self.subject, &self.html_body, &self.text_body)?);
+
+        let is_valid_data = match &self.data {
+            Some(data) if !data.trim().is_empty() => {
+                serde_json::from_str::<serde_json::Value>(data).is_ok_and(|value| value.is_object())
+            }
+            _ => true,
+        };
+        if !is_valid_data {
+            let error_message = ErrorMessage {
+                key: String::from("data"),
+                message: t!("validation_invalid", attribute = t!("data")).to_string(),
+            };
+            errors.push(error_message);
+        }
+
+        if !errors.is_empty() {
+            let error_response = ErrorResponse {
+                status: false,
+                errors,
+            };
+            let error_string = serde_json::to_string(&error_response)?;
+            return Err(crate::error::Error::InvalidArgument(error_string));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use crate::api::proto::email::StoreEmailTemplateRequest;
+use crate::avored_state::AvoRedState;
+use crate::models::email_template_model::email_template_definition;
+use crate::models::validation_error::{ErrorMessage, ErrorResponse};
+use rust_i18n::t;
+use super::email_template_errors;
+
+impl StoreEmailTemplateRequest {
+    pub async fn validate(&self, state: &AvoRedState) -> crate::error::Result<()> {
+        let mut errors: Vec<ErrorMessage> = vec![];
+
+        if email_template_definition(&self.identifier).is_none() {
+            let error_message = ErrorMessage {
+                key: String::from("identifier"),
+                message: t!("validation_invalid", attribute = t!("identifier")).to_string(),
+            };
+            errors.push(error_message);
+        } else {
+            let email_template_count = state
+                .email_template_service
+                .count_of_identifier(&state.db, &self.identifier)
+                .await?;
+
+            if email_template_count.total > 0 {
+                let error_message = ErrorMessage {
+                    key: String::from("identifier"),
+                    message: t!("validation_count", attribute = t!("identifier")).to_string(),
+                };
+                errors.push(error_message);
+            }
+        }
+
+        errors.extend(email_template_errors(&self.subject, &self.html_body, &self.text_body)?);
+
+        if !errors.is_empty() {
+            let error_response = ErrorResponse {
+                status: false,
+                errors,
+            };
+            let error_string = serde_json::to_string(&error_response)?;
+            return Err(crate::error::Error::InvalidArgument(error_string));
+        }
+
+        Ok(())
+    }
+}
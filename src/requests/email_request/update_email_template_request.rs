@@ -0,0 +1,31 @@
+use crate::api::proto::email::UpdateEmailTemplateRequest;
+use crate::models::validation_error::{ErrorMessage, ErrorResponse, Validate};
+use rust_i18n::t;
+use super::email_template_errors;
+
+impl UpdateEmailTemplateRequest {
+    pub fn validate(&self) -> crate::error::Result<()> {
+        let mut errors: Vec<ErrorMessage> = vec![];
+
+        if !self.email_template_id.required()? {
+            let error_message = ErrorMessage {
+                key: String::from("email_template_id"),
+                message: t!("validation_required", attribute = t!("email_template_id")).to_string(),
+            };
+            errors.push(error_message);
+        }
+
+        errors.extend(email_template_errors(&self.subject, &self.html_body, &self.text_body)?);
+
+        if !errors.is_empty() {
+            let error_response = ErrorResponse {
+                status: false,
+                errors,
+            };
+            let error_string = serde_json::to_string(&error_response)?;
+            return Err(crate::error::Error::InvalidArgument(error_string));
+        }
+
+        Ok(())
+    }
+}
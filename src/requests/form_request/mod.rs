@@ -0,0 +1,56 @@
+pub mod store_form_request;
+pub mod update_form_request;
+pub mod get_form_request;
+pub mod delete_form_request;
+pub mod form_submission_paginate_request;
+pub mod export_form_submissions_request;
+
+use std::collections::HashSet;
+use crate::api::proto::form::FormFieldModel;
+use crate::models::form_model::FORM_FIELD_TYPES;
+use crate::models::validation_error::{ErrorMessage, Validate};
+use rust_i18n::t;
+
+/// Field and recipient checks shared by the store and update requests.
+fn form_errors(fields: &[FormFieldModel], recipients: &[String]) -> crate::error::Result<Vec<ErrorMessage>> {
+    let mut errors: Vec<ErrorMessage> = vec![];
+
+    if fields.is_empty() {
+        errors.push(ErrorMessage {
+            key: String::from("fields"),
+            message: t!("validation_required", attribute = t!("fields")).to_string(),
+        });
+    }
+
+    let mut identifiers: HashSet<&str> = HashSet::new();
+    for field in fields {
+        let is_valid = field.name.required()?
+            && field.identifier.required()?
+            && identifiers.insert(field.identifier.as_str())
+            && FORM_FIELD_TYPES.contains(&field.field_type.as_str())
+            && (field.field_type != "select" || !field.options.is_empty())
+            && field.min_length >= 0
+            && field.max_length >= 0
+            && (field.max_length == 0 || field.max_length >= field.min_length);
+
+        if !is_valid {
+            errors.push(ErrorMessage {
+                key: String::from("fields"),
+                message: t!("validation_invalid", attribute = t!("fields")).to_string(),
+            });
+            break;
+        }
+    }
+
+    for recipient in recipients {
+        if !recipient.validate_email()? {
+            errors.push(ErrorMessage {
+                key: String::from("recipients"),
+                message: t!("validation_invalid", attribute = t!("recipients")).to_string(),
+            });
+            break;
+        }
+    }
+
+    Ok(errors)
+}
@@ -0,0 +1,60 @@
+use crate::api::proto::form::StoreFormRequest;
+use crate::avored_state::AvoRedState;
+use crate::models::form_model::is_form_identifier;
+use crate::models::validation_error::{ErrorMessage, ErrorResponse, Validate};
+use rust_i18n::t;
+use super::form_errors;
+
+impl StoreFormRequest {
+    pub async fn validate(&self, state: &AvoRedState) -> crate::error::Result<()> {
+        let mut errors: Vec<ErrorMessage> = vec![];
+
+        if !self.name.required()? {
+            let error_message = ErrorMessage {
+                key: String::from("name"),
+                message: t!("validation_required", attribute = t!("name")).to_string(),
+            };
+            errors.push(error_message);
+        }
+
+        if !self.identifier.required()? {
+            let error_message = ErrorMessage {
+                key: String::from("identifier"),
+                message: t!("validation_required", attribute = t!("identifier")).to_string(),
+            };
+            errors.push(error_message);
+        } else if !is_form_identifier(&self.identifier) {
+            let error_message = ErrorMessage {
+                key: String::from("identifier"),
+                message: t!("validation_invalid", attribute = t!("identifier")).to_string(),
+            };
+            errors.push(error_message);
+        } else {
+            let form_count = state
+                .form_service
+                .count_of_identifier(&state.db, &self.identifier)
+                .await?;
+
+            if form_count.total > 0 {
+                let error_message = ErrorMessage {
+                    key: String::from("identifier"),
+                    message: t!("validation_count", attribute = t!("identifier")).to_string(),
+                };
+                errors.push(error_message);
+            }
+        }
+
+        errors.extend(form_errors(&self.fields, &self.recipients)?);
+
+        if !errors.is_empty() {
+            let error_response = ErrorResponse {
+                status: false,
+                errors,
+            };
+            let error_string = serde_json::to_string(&error_response)?;
+            return Err(crate::error::Error::InvalidArgument(error_string));
+        }
+
+        Ok(())
+    }
+}
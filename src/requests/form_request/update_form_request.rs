@@ -0,0 +1,39 @@
+use crate::api::proto::form::UpdateFormRequest;
+use crate::models::validation_error::{ErrorMessage, ErrorResponse, Validate};
+use rust_i18n::t;
+use super::form_errors;
+
+impl UpdateFormRequest {
+    pub fn validate(&self) -> crate::error::Result<()> {
+        let mut errors: Vec<ErrorMessage> = vec![];
+
+        if !self.form_id.required()? {
+            let error_message = ErrorMessage {
+                key: String::from("form_id"),
+                message: t!("validation_required", attribute = t!("form_id")).to_string(),
+            };
+            errors.push(error_message);
+        }
+
+        if !self.name.required()? {
+            let error_message = ErrorMessage {
+                key: String::from("name"),
+                message: t!("validation_required", attribute = t!("name")).to_string(),
+            };
+            errors.push(error_message);
+        }
+
+        errors.extend(form_errors(&self.fields, &self.recipients)?);
+
+        if !errors.is_empty() {
+            let error_response = ErrorResponse {
+                status: false,
+                errors,
+            };
+            let error_string = serde_json::to_string(&error_response)?;
+            return Err(crate::error::Error::InvalidArgument(error_string));
+        }
+
+        Ok(())
+    }
+}
@@ -1,4 +1,7 @@
 pub mod misc_request;
 pub mod auth_request;
 pub mod admin_user_request;
-pub mod content_request;
\ No newline at end of file
+pub mod content_request;pub mod cms_api_key_request;
+pub mod webhook_request;
+pub mod form_request;
+pub mod email_request;
@@ -0,0 +1,45 @@
+pub mod store_webhook_request;
+pub mod update_webhook_request;
+pub mod delete_webhook_request;
+pub mod webhook_delivery_paginate_request;
+pub mod redeliver_webhook_request;
+pub mod ping_webhook_request;
+
+use crate::models::validation_error::ErrorMessage;
+use crate::models::webhook_model::WEBHOOK_EVENTS;
+use rust_i18n::t;
+
+/// Url and event checks shared by the store and update requests.
+fn webhook_errors(url: &str, events: &[String]) -> Vec<ErrorMessage> {
+    let mut errors: Vec<ErrorMessage> = vec![];
+
+    let url = url.trim();
+    if url.is_empty() {
+        errors.push(ErrorMessage {
+            key: String::from("url"),
+            message: t!("validation_required", attribute = t!("url")).to_string(),
+        });
+    } else if !(url.starts_with("http://") || url.starts_with("https://")) {
+        errors.push(ErrorMessage {
+            key: String::from("url"),
+            message: t!("validation_invalid", attribute = t!("url")).to_string(),
+        });
+    }
+
+    if events.is_empty() {
+        errors.push(ErrorMessage {
+            key: String::from("events"),
+            message: t!("validation_required", attribute = t!("events")).to_string(),
+        });
+    } else if events
+        .iter()
+        .any(|event| !WEBHOOK_EVENTS.contains(&event.as_str()))
+    {
+        errors.push(ErrorMessage {
+            key: String::from("events"),
+            message: t!("validation_invalid", attribute = t!("events")).to_string(),
+        });
+    }
+
+    errors
+}
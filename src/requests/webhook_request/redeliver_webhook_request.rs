@@ -0,0 +1,30 @@
+use crate::api::proto::webhook::RedeliverWebhookRequest;
+use crate::models::validation_error::{ErrorMessage, ErrorResponse, Validate};
+use rust_i18n::t;
+
+impl RedeliverWebhookRequest {
+    pub fn validate(&self) -> crate::error::Result<()> {
+        let mut errors: Vec<ErrorMessage> = vec![];
+        let mut valid = true;
+
+        if !self.webhook_delivery_id.required()? {
+            let error_message = ErrorMessage {
+                key: String::from("webhook_delivery_id"),
+                message: t!("validation_required", attribute = t!("webhook_delivery_id")).to_string(),
+            };
+            valid = false;
+            errors.push(error_message);
+        }
+
+        if !valid {
+            let error_response = ErrorResponse {
+                status: valid,
+                errors,
+            };
+            let error_string = serde_json::to_string(&error_response)?;
+            return Err(crate::error::Error::InvalidArgument(error_string));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+use crate::api::proto::webhook::UpdateWebhookRequest;
+use crate::models::validation_error::{ErrorMessage, ErrorResponse, Validate};
+use rust_i18n::t;
+use super::webhook_errors;
+
+impl UpdateWebhookRequest {
+    pub fn validate(&self) -> crate::error::Result<()> {
+        let mut errors: Vec<ErrorMessage> = vec![];
+
+        if !self.webhook_id.required()? {
+            let error_message = ErrorMessage {
+                key: String::from("webhook_id"),
+                message: t!("validation_required", attribute = t!("webhook_id")).to_string(),
+            };
+            errors.push(error_message);
+        }
+
+        if !self.name.required()? {
+            let error_message = ErrorMessage {
+                key: String::from("name"),
+                message: t!("validation_required", attribute = t!("name")).to_string(),
+            };
+            errors.push(error_message);
+        }
+
+        errors.extend(webhook_errors(&self.url, &self.events));
+
+        if !errors.is_empty() {
+            let error_response = ErrorResponse {
+                status: false,
+                errors,
+            };
+            let error_string = serde_json::to_string(&error_response)?;
+            return Err(crate::error::Error::InvalidArgument(error_string));
+        }
+
+        Ok(())
+    }
+}
@@ -2,34 +2,58 @@ use crate::api::proto::admin_user::role_paginate_response::{RolePaginateData, Ro
 use crate::api::proto::admin_user::{
     DeleteRoleRequest, DeleteRoleResponse, GetRoleRequest, GetRoleResponse, PutRoleIdentifierRequest, PutRoleIdentifierResponse, RoleModel, RoleOptionModel, RoleOptionResponse, RolePaginateRequest, RolePaginateResponse, StoreAdminUserRequest, StoreAdminUserResponse, StoreRoleResponse, UpdateAdminUserRequest, UpdateAdminUserResponse, UpdateRoleRequest, UpdateRoleResponse
 };
-use crate::models::admin_user_model::{CreatableAdminUserModel, UpdatableAdminUserModel};
+use crate::models::admin_user_model::{AdminUserCache, AdminUserModel, CreatableAdminUserModel, UpdatableAdminUserModel};
 use crate::models::role_model::{CreatableRole, PutRoleIdentifierModel, UpdatableRoleModel};
 use crate::models::ModelCount;
+use crate::extensions::string_extension::StringExtension;
+use crate::providers::avored_config_provider::PasswordHashConfig;
 use crate::repositories::role_repository::RoleRepository;
 use crate::{
-    error::Result, providers::avored_database_provider::DB,
+    error::{Error, Result}, providers::avored_database_provider::DB,
     repositories::admin_user_repository::AdminUserRepository, PER_PAGE,
 };
-use argon2::password_hash::SaltString;
-use argon2::{Argon2, PasswordHasher};
 use std::path::Path;
 
 pub struct AdminUserService {
     admin_user_repository: AdminUserRepository,
     role_repository: RoleRepository,
+    admin_user_cache: AdminUserCache,
 }
 
 impl AdminUserService {
     pub fn new(
         admin_user_repository: AdminUserRepository,
         role_repository: RoleRepository,
+        admin_user_cache: AdminUserCache,
     ) -> Result<Self> {
         Ok(AdminUserService {
             admin_user_repository,
             role_repository,
+            admin_user_cache,
         })
     }
 
+    /// The admin user an access token belongs to, with their current roles.
+    pub async fn find_logged_in_admin_user(
+        &self,
+        (datastore, database_session): &DB,
+        admin_user_id: &str,
+    ) -> Result<AdminUserModel> {
+        if let Some(admin_user_model) = self.admin_user_cache.get(&admin_user_id.to_string()) {
+            return Ok(admin_user_model);
+        }
+
+        let admin_user_model = self
+            .admin_user_repository
+            .find_by_id(datastore, database_session, admin_user_id)
+            .await
+            .map_err(|_| Error::Unauthenticated(String::from("admin user of the token not found")))?;
+        self.admin_user_cache
+            .insert(admin_user_id.to_string(), admin_user_model.clone());
+
+        Ok(admin_user_model)
+    }
+
     pub async fn paginate(
         &self,
         page: i64,
@@ -83,11 +107,11 @@ impl AdminUserService {
         &self,
         req: StoreAdminUserRequest,
         logged_in_username: String,
-        password_salt: &str,
+        password_hash_config: &PasswordHashConfig,
         (datastore, database_session): &DB,
     ) -> Result<StoreAdminUserResponse> {
         let password_hash =
-            self.get_password_hash_from_raw_password(&req.password, password_salt)?;
+            self.get_password_hash_from_raw_password(&req.password, password_hash_config)?;
 
         let mut created_admin_user_model = CreatableAdminUserModel {
             full_name: req.full_name,
@@ -210,6 +234,8 @@ impl AdminUserService {
 
             model.roles.push(grpc_role_model);
         }
+        self.admin_user_cache
+            .invalidate(|admin_user_id, _| *admin_user_id == admin_user_model.id);
 
         let res = UpdateAdminUserResponse {
             status: true,
@@ -358,6 +384,7 @@ impl AdminUserService {
             .role_repository
             .update_role(datastore, database_session, updatable_role_model.clone())
             .await?;
+        self.forget_logged_in_admin_users();
 
         let model: RoleModel = role_model.clone().try_into().unwrap();
 
@@ -384,6 +411,7 @@ impl AdminUserService {
             .role_repository
             .update_role_identifier(datastore, database_session, updatable_role_model.clone())
             .await?;
+        self.forget_logged_in_admin_users();
 
         let model: RoleModel = role_model.clone().try_into().unwrap();
 
@@ -726,15 +754,9 @@ impl AdminUserService {
     pub fn get_password_hash_from_raw_password(
         &self,
         raw_password: &str,
-        password_salt: &str,
+        password_hash_config: &PasswordHashConfig,
     ) -> Result<String> {
-        let password = raw_password.as_bytes();
-        let salt = SaltString::from_b64(password_salt)?;
-
-        let argon2 = Argon2::default();
-        let password_hash = argon2.hash_password(password, &salt)?.to_string();
-
-        Ok(password_hash)
+        raw_password.to_string().get_password_hash(password_hash_config)
     }
     //
     pub async fn count_of_email(
@@ -766,6 +788,7 @@ impl AdminUserService {
         let delete_status = self.admin_user_repository
             .delete_role(datastore, database_session, &request.role_id)
             .await?;
+        self.forget_logged_in_admin_users();
 
         let response = DeleteRoleResponse {
             status: delete_status
@@ -775,6 +798,12 @@ impl AdminUserService {
     }
 
 
+    /// A role can belong to any number of admin users, so a role change
+    /// drops every cached admin user.
+    fn forget_logged_in_admin_users(&self) {
+        self.admin_user_cache.invalidate(|_, _| true);
+    }
+
     //count_of_identifier
 
     //
@@ -790,3 +819,141 @@ impl AdminUserService {
     //     Ok(())
     // }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use crate::api::proto::admin_user::{DeleteRoleRequest, UpdateAdminUserRequest, UpdateRoleRequest};
+    use crate::models::admin_user_model::{AdminUserModel, CreatableAdminUserModel};
+    use crate::models::role_model::CreatableRole;
+    use crate::providers::avored_cache_provider::AvoRedCacheProvider;
+    use crate::providers::avored_database_provider::DB;
+    use crate::repositories::admin_user_repository::AdminUserRepository;
+    use crate::repositories::role_repository::RoleRepository;
+    use crate::test_helpers::memory_db;
+    use super::AdminUserService;
+
+    /// An hour, so only the invalidation can make a change visible.
+    const CACHE_TTL_SECONDS: u64 = 60 * 60;
+
+    async fn setup(database: &str) -> (DB, AdminUserService, AdminUserModel, String) {
+        let db = memory_db(database).await;
+        let admin_user_service = AdminUserService::new(
+            AdminUserRepository::new(),
+            RoleRepository::new(),
+            AvoRedCacheProvider::register(10, Duration::from_secs(CACHE_TTL_SECONDS)),
+        )
+        .unwrap();
+
+        let (datastore, database_session) = &db;
+        let creatable_admin_user = CreatableAdminUserModel {
+            full_name: String::from("Jane Doe"),
+            email: String::from("jane@example.com"),
+            password: String::from(""),
+            profile_image: String::from(""),
+            is_super_admin: false,
+            logged_in_username: String::from("admin@example.com"),
+        };
+        let admin_user_model = AdminUserRepository::new()
+            .create_admin_user(datastore, database_session, creatable_admin_user)
+            .await
+            .unwrap();
+        let creatable_role = CreatableRole {
+            name: String::from("Editor"),
+            identifier: String::from("editor"),
+            logged_in_username: String::from("admin@example.com"),
+            permissions: vec![String::from("content_table")],
+        };
+        let role_id = admin_user_service
+            .store_role(creatable_role, &db)
+            .await
+            .unwrap()
+            .data
+            .unwrap()
+            .id;
+
+        let update_admin_user_request = UpdateAdminUserRequest {
+            admin_user_id: admin_user_model.id.clone(),
+            full_name: admin_user_model.full_name.clone(),
+            role_ids: vec![role_id.clone()],
+            ..Default::default()
+        };
+        admin_user_service
+            .update_admin_user(update_admin_user_request, String::from("admin@example.com"), &db)
+            .await
+            .unwrap();
+
+        (db, admin_user_service, admin_user_model, role_id)
+    }
+
+    async fn permissions(db: &DB, admin_user_service: &AdminUserService, admin_user_id: &str) -> Vec<String> {
+        admin_user_service
+            .find_logged_in_admin_user(db, admin_user_id)
+            .await
+            .unwrap()
+            .roles
+            .into_iter()
+            .flat_map(|role| role.permissions)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn role_permission_change_reaches_cached_admin_users() {
+        let (db, admin_user_service, admin_user_model, role_id) = setup("admin_user_role_update_test").await;
+        assert_eq!(
+            permissions(&db, &admin_user_service, &admin_user_model.id).await,
+            vec![String::from("content_table")]
+        );
+
+        let update_role_request = UpdateRoleRequest {
+            role_id,
+            name: String::from("Editor"),
+            permissions: vec![String::from("asset_table")],
+        };
+        admin_user_service
+            .update_role(update_role_request, String::from("admin@example.com"), &db)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            permissions(&db, &admin_user_service, &admin_user_model.id).await,
+            vec![String::from("asset_table")]
+        );
+    }
+
+    #[tokio::test]
+    async fn role_assignment_change_reaches_the_cached_admin_user() {
+        let (db, admin_user_service, admin_user_model, _) = setup("admin_user_update_test").await;
+        assert_eq!(permissions(&db, &admin_user_service, &admin_user_model.id).await.len(), 1);
+
+        let update_admin_user_request = UpdateAdminUserRequest {
+            admin_user_id: admin_user_model.id.clone(),
+            full_name: admin_user_model.full_name.clone(),
+            is_super_admin: true,
+            ..Default::default()
+        };
+        admin_user_service
+            .update_admin_user(update_admin_user_request, String::from("admin@example.com"), &db)
+            .await
+            .unwrap();
+
+        let cached_admin_user = admin_user_service
+            .find_logged_in_admin_user(&db, &admin_user_model.id)
+            .await
+            .unwrap();
+        assert!(cached_admin_user.is_super_admin);
+    }
+
+    #[tokio::test]
+    async fn role_delete_reaches_cached_admin_users() {
+        let (db, admin_user_service, admin_user_model, role_id) = setup("admin_user_role_delete_test").await;
+        assert_eq!(permissions(&db, &admin_user_service, &admin_user_model.id).await.len(), 1);
+
+        admin_user_service
+            .delete_role(DeleteRoleRequest { role_id }, &db)
+            .await
+            .unwrap();
+
+        assert!(permissions(&db, &admin_user_service, &admin_user_model.id).await.is_empty());
+    }
+}
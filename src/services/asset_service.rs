@@ -1,3 +1,4 @@
+use std::sync::Arc;
 use tokio::fs;
 use tonic::Status;
 use crate::models::asset_model::{AssetModel, CreatableAssetModel, FolderTypeMetaData, MetaDataType};
@@ -5,14 +6,16 @@ use crate::{error::Result, providers::avored_database_provider::DB, repositories
 use crate::api::proto::asset::{AssetPaginateRequest, AssetPaginateResponse, CreateFolderRequest, CreateFolderResponse, DeleteAssetRequest, DeleteAssetResponse, DeleteFolderRequest, DeleteFolderResponse, RenameAssetRequest, RenameAssetResponse};
 use crate::api::proto::asset::asset_paginate_response::{AssetPaginateData, AssetPagination};
 use crate::error::Error;
+use crate::models::asset_event_model::{AssetEventBus, AssetEventModel, AssetEventType};
 
 pub struct AssetService {
     asset_repository: AssetRepository,
+    asset_event_bus: Arc<AssetEventBus>,
 }
 
 impl AssetService {
-    pub fn new(asset_repository: AssetRepository) -> Result<Self> {
-        Ok(AssetService { asset_repository })
+    pub fn new(asset_repository: AssetRepository, asset_event_bus: Arc<AssetEventBus>) -> Result<Self> {
+        Ok(AssetService { asset_repository, asset_event_bus })
     }
 }
 impl AssetService {
@@ -92,9 +95,14 @@ impl AssetService {
         (datastore, database_session): &DB,
         creatable_asset_model: CreatableAssetModel,
     ) -> Result<AssetModel> {
-        self.asset_repository
+        let asset_model = self.asset_repository
             .create_asset(datastore, database_session, creatable_asset_model)
-            .await
+            .await?;
+
+        self.asset_event_bus
+            .publish(AssetEventModel::new(AssetEventType::Uploaded, &asset_model));
+
+        Ok(asset_model)
     }
 
     pub async fn find_by_id(
@@ -176,6 +184,10 @@ impl AssetService {
             let result =self.asset_repository
                 .delete_by_id(datastore, database_session, &request.asset_id)
                 .await?;
+            if result {
+                self.asset_event_bus
+                    .publish(AssetEventModel::new(AssetEventType::Deleted, &asset_model));
+            }
             let res = DeleteAssetResponse {
                 status: result
             };
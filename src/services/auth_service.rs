@@ -1,54 +1,118 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, RwLock};
+use std::time::Duration;
 use argon2::{Argon2, PasswordHash, PasswordVerifier};
 use crate::error::{Error, Result};
-use crate::models::password_rest_model::{CreatablePasswordResetModel, ForgotPasswordViewModel};
+use crate::models::admin_user_model::AdminUserModel;
+use crate::api::proto::admin_user::{
+    AdminUserSessionAllResponse, AdminUserSessionModel as AdminUserSessionModelGrpc,
+    AuditLogAllResponse, AuditLogModel as AuditLogModelGrpc,
+};
+use crate::api::proto::auth::{ConfirmTwoFactorResponse, EnrollTwoFactorResponse};
+use crate::models::admin_user_session_model::{AuthTokenModel, CreatableAdminUserSessionModel, CreatableRefreshTokenModel, RefreshTokenStatus};
+use crate::models::admin_user_two_factor_model::{generate_recovery_codes, generate_totp, hash_recovery_code, AdminUserTwoFactorModel};
+use crate::models::email_model::CreatableEmailModel;
+use crate::models::password_rest_model::{hash_password_reset_token, CreatablePasswordResetModel, ForgotPasswordViewModel};
 use crate::models::validation_error::{ErrorMessage, ErrorResponse};
 use crate::Error::TonicError;
+use crate::providers::avored_config_provider::PasswordHashConfig;
 use crate::providers::avored_database_provider::DB;
 use crate::providers::avored_template_provider::AvoRedTemplateProvider;
 use crate::repositories::admin_user_repository::AdminUserRepository;
-use crate::models::token_claim_model::TokenClaims;
+use crate::repositories::admin_user_session_repository::AdminUserSessionRepository;
+use crate::models::setting_model::TWO_FACTOR_REQUIRED_SETTING;
+use crate::models::token_claim_model::{TokenClaims, TwoFactorClaims, TwoFactorPurpose};
+use crate::repositories::admin_user_two_factor_repository::AdminUserTwoFactorRepository;
 use crate::repositories::password_reset_repository::PasswordResetRepository;
-use jsonwebtoken::{encode, EncodingKey, Header};
-use lettre::{AsyncTransport, Message};
+use crate::repositories::refresh_token_repository::RefreshTokenRepository;
+use crate::repositories::setting_repository::SettingRepository;
+use crate::services::email_service::EmailService;
+use crate::services::login_throttle_service::LoginThrottleService;
+use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
 use rand::distr::Alphanumeric;
 use rand::Rng;
 use rust_i18n::t;
+use sha2::{Digest, Sha256};
+use surrealdb::sql::Datetime;
 use tonic::Status;
-use tracing::error;
-use crate::extensions::email_message_builder::EmailMessageBuilder;
+use tracing::{error, warn};
 use crate::extensions::string_extension::StringExtension;
 
+const REFRESH_TOKEN_LENGTH: usize = 64;
+const PASSWORD_RESET_TOKEN_LENGTH: usize = 48;
+
+/// How often expired password resets are deleted.
+const PASSWORD_RESET_CLEANUP_SECONDS: u64 = 60 * 60;
+
+/// Entries of the audit log returned, most recent first.
+const AUDIT_LOG_LIMIT: i64 = 100;
+
+/// How long the partial token of a login waiting for a two factor code lives.
+const TWO_FACTOR_TOKEN_EXPIRED_IN_SECONDS: i64 = 300;
+
+/// Sessions tracked in memory before the ones idle for longer than a
+/// refresh token lives are swept.
+const SESSION_ACTIVITY_SWEEP_THRESHOLD: usize = 10_000;
+
 pub struct AuthService {
     admin_user_repository: AdminUserRepository,
     password_reset_repository: PasswordResetRepository,
+    admin_user_session_repository: AdminUserSessionRepository,
+    refresh_token_repository: RefreshTokenRepository,
+    admin_user_two_factor_repository: AdminUserTwoFactorRepository,
+    setting_repository: SettingRepository,
+    login_throttle_service: LoginThrottleService,
+    password_hash_config: PasswordHashConfig,
+    jwt_expired_in_seconds: i64,
+    refresh_token_expired_in_seconds: i64,
+    password_reset_token_expired_in_seconds: i64,
+
+    /// Revoked session ids, with the time after which no access token of the
+    /// session can still be valid. Kept in memory as the interceptors are sync;
+    /// this process is the only writer of the embedded database.
+    revoked_sessions: RwLock<HashMap<String, i64>>,
+
+    /// Last request seen per session. Written to the database when the
+    /// refresh token rotates, so it is not a write per request.
+    session_activity: Mutex<HashMap<String, i64>>,
 }
 
 impl AuthService {
+    /// Emails a reset link when the address belongs to an admin user. Unknown
+    /// addresses get the same answer, so the form does not tell which exist.
     pub async fn forgot_password(
         &self,
-        (datastore, database_session): &DB,
+        db: &DB,
         template: &AvoRedTemplateProvider,
+        email_service: &EmailService,
         react_admin_url: &str,
         to_address: &str,
     ) -> Result<bool> {
+        let (datastore, database_session) = db;
+        let admin_user_count = self
+            .admin_user_repository
+            .count_of_email(datastore, database_session, to_address.to_string())
+            .await?;
+        if admin_user_count.total != 1 {
+            return Ok(true);
+        }
         let admin_user_model = self
             .admin_user_repository
             .find_by_email(datastore, database_session, to_address)
             .await?;
 
-        // is it ok to move this email as part of configuration on admin?
-        // or may be moved this as part of the setup process?
-        let from_address = String::from("info@avored.com");
-        let email_subject = "Forgot your password?";
-        let token = rand::rng()
+        let token: String = rand::rng()
             .sample_iter(&Alphanumeric)
-            .take(22)
+            .take(PASSWORD_RESET_TOKEN_LENGTH)
             .map(char::from)
             .collect();
 
         let creatable_password_reset_model = CreatablePasswordResetModel {
             email: admin_user_model.clone().email,
-            token,
+            token_hash: hash_password_reset_token(&token),
+            expires_at: Datetime::from(
+                chrono::Utc::now() + chrono::Duration::seconds(self.password_reset_token_expired_in_seconds),
+            ),
         };
 
         // expiring any old token that might have been an active. 
@@ -58,53 +122,64 @@ impl AuthService {
             expire_password_token_by_email(datastore, database_session, &admin_user_model.email)
             .await?;
 
-        let password_reset_model = self
-            .password_reset_repository
+        self.password_reset_repository
             .create_password_reset(datastore, database_session, creatable_password_reset_model)
             .await?;
 
-        let link = format!(
-            "{react_admin_url}/admin/reset-password/{}",
-            password_reset_model.token
-        );
+        let link = format!("{react_admin_url}/admin/reset-password/{token}");
         let data = ForgotPasswordViewModel { link };
 
-        let forgot_password_email_content = template.handlebars.render("forgot-password", &data)?;
-        let email_message = Message::builder()
-            .build_email_message(
-                &from_address, 
-                &to_address, 
-                &email_subject, 
-                forgot_password_email_content
-            )?;
-        
-        // Send the email
-        match template.mailer.send(email_message).await {
-            Ok(_) => {
-                Ok(true)
-            }
-            Err(er) => {
-                error!("email sent error: {:?}", er);
-                Err(Error::Generic(String::from("error while sending an email")))
-            }
-        }
+        let rendered_email = template.render_email("forgot-password", &data)?;
+        let creatable_email = CreatableEmailModel {
+            to_address: to_address.to_string(),
+            subject: rendered_email.subject,
+            body: rendered_email.html_body,
+            text_body: rendered_email.text_body,
+            attachments: vec![],
+            template: String::from("forgot-password"),
+        };
+        email_service
+            .queue_email(db, creatable_email)
+            .await?;
+
+        Ok(true)
     }
     pub(crate) async fn auth_user(
         &self,
         email: &str,
         password: &str,
-        (datastore, database_session): &DB,
+        db: &DB,
+        user_agent: &str,
+        ip_address: &str,
         jwt_secret_key: &str,
-    ) -> Result<String> {
-        let admin_user_model = self
+    ) -> Result<AuthTokenModel> {
+        let (datastore, database_session) = db;
+        self.login_throttle_service.check(email, ip_address)?;
+
+        let admin_user_model = match self
             .admin_user_repository
             .find_by_email(datastore, database_session, email)
-            .await?;
+            .await
+        {
+            Ok(admin_user_model) => admin_user_model,
+            Err(e) => {
+                if let TonicError(_) = e {
+                    self.login_throttle_service
+                        .record_failure(db, email, ip_address)
+                        .await?;
+                }
+                return Err(e);
+            }
+        };
 
         let is_password_match: bool =
             self.compare_password(password, admin_user_model.password.clone())?;
 
         if !is_password_match {
+            self.login_throttle_service
+                .record_failure(db, email, ip_address)
+                .await?;
+
             let mut errors: Vec<ErrorMessage> = vec![];
             let error_message = ErrorMessage {
                 key: String::from("email"),
@@ -121,15 +196,738 @@ impl AuthService {
             return Err(TonicError(Status::invalid_argument(error_string)));
         }
 
-        let claims: TokenClaims = admin_user_model.clone().try_into()?;
+        if admin_user_model.password.password_hash_needs_rehash(&self.password_hash_config) {
+            self.rehash_password(db, &admin_user_model.email, password).await;
+        }
+
+        if let Some(auth_token) = self
+            .two_factor_challenge(db, &admin_user_model, jwt_secret_key)
+            .await?
+        {
+            return Ok(auth_token);
+        }
+
+        self.start_session(db, admin_user_model, user_agent, ip_address, jwt_secret_key)
+            .await
+    }
+
+    /// Second step of a login with two factor authentication, exchanges the
+    /// partial token and a code of the authenticator app or a recovery code
+    /// for a session.
+    pub(crate) async fn verify_two_factor(
+        &self,
+        db: &DB,
+        two_factor_token: &str,
+        code: &str,
+        user_agent: &str,
+        ip_address: &str,
+        jwt_secret_key: &str,
+    ) -> Result<AuthTokenModel> {
+        let (datastore, database_session) = db;
+        let claims = decode_two_factor_token(two_factor_token, jwt_secret_key)
+            .filter(|claims| claims.purpose == TwoFactorPurpose::Login)
+            .ok_or_else(|| TonicError(Status::unauthenticated("two factor token is not valid")))?;
+
+        let admin_user_model = self
+            .admin_user_repository
+            .find_by_id(datastore, database_session, &claims.sub)
+            .await?;
+        self.login_throttle_service
+            .check(&admin_user_model.email, ip_address)?;
+        let admin_user_two_factor_model = self
+            .admin_user_two_factor_repository
+            .find_by_admin_user_id(datastore, database_session, &claims.sub)
+            .await?
+            .filter(|admin_user_two_factor_model| admin_user_two_factor_model.is_enabled)
+            .ok_or_else(|| TonicError(Status::unauthenticated("two factor token is not valid")))?;
+
+        if !self
+            .use_two_factor_code(db, &admin_user_model, &admin_user_two_factor_model, code)
+            .await?
+        {
+            self.login_throttle_service
+                .record_failure(db, &admin_user_model.email, ip_address)
+                .await?;
+            return Err(invalid_two_factor_code()?);
+        }
+
+        self.start_session(db, admin_user_model, user_agent, ip_address, jwt_secret_key)
+            .await
+    }
+
+    /// Creates a new secret for the admin user the bearer token belongs to,
+    /// it is enabled once a code of it is confirmed. The bearer token is an
+    /// access token, or the partial token of a login that has to set up two
+    /// factor authentication first.
+    pub(crate) async fn enroll_two_factor(
+        &self,
+        (datastore, database_session): &DB,
+        bearer_token: &str,
+        jwt_secret_key: &str,
+    ) -> Result<EnrollTwoFactorResponse> {
+        let (admin_user_id, _) = self.two_factor_admin_user_id(bearer_token, jwt_secret_key)?;
+
+        let admin_user_two_factor_model = self
+            .admin_user_two_factor_repository
+            .find_by_admin_user_id(datastore, database_session, &admin_user_id)
+            .await?;
+        if admin_user_two_factor_model.is_some_and(|admin_user_two_factor_model| admin_user_two_factor_model.is_enabled) {
+            return Err(TonicError(Status::failed_precondition(
+                "two factor authentication is already enabled",
+            )));
+        }
+
+        let admin_user_model = self
+            .admin_user_repository
+            .find_by_id(datastore, database_session, &admin_user_id)
+            .await?;
+        let totp = generate_totp(&admin_user_model.email)?;
+        let secret = totp.get_secret_base32();
+        self.admin_user_two_factor_repository
+            .save_pending_secret(datastore, database_session, &admin_user_id, &secret)
+            .await?;
+
+        Ok(EnrollTwoFactorResponse {
+            status: true,
+            provisioning_uri: totp.get_url(),
+            secret,
+        })
+    }
+
+    /// Enables two factor authentication with the first code of the enrolled
+    /// secret and hands out the recovery codes, the only time they are shown.
+    /// When enrolling as part of a login the session is started as well.
+    pub(crate) async fn confirm_two_factor(
+        &self,
+        db: &DB,
+        bearer_token: &str,
+        code: &str,
+        user_agent: &str,
+        ip_address: &str,
+        jwt_secret_key: &str,
+    ) -> Result<ConfirmTwoFactorResponse> {
+        let (datastore, database_session) = db;
+        let (admin_user_id, is_login) = self.two_factor_admin_user_id(bearer_token, jwt_secret_key)?;
+
+        let admin_user_two_factor_model = self
+            .admin_user_two_factor_repository
+            .find_by_admin_user_id(datastore, database_session, &admin_user_id)
+            .await?
+            .filter(|admin_user_two_factor_model| !admin_user_two_factor_model.is_enabled)
+            .ok_or_else(|| TonicError(Status::failed_precondition(
+                "there is no two factor enrollment waiting for confirmation",
+            )))?;
+
+        let admin_user_model = self
+            .admin_user_repository
+            .find_by_id(datastore, database_session, &admin_user_id)
+            .await?;
+        if is_login {
+            self.login_throttle_service
+                .check(&admin_user_model.email, ip_address)?;
+        }
+        let step = match admin_user_two_factor_model.verify_code(&admin_user_model.email, code)? {
+            Some(step) => step,
+            None => {
+                if is_login {
+                    self.login_throttle_service
+                        .record_failure(db, &admin_user_model.email, ip_address)
+                        .await?;
+                }
+                return Err(invalid_two_factor_code()?);
+            }
+        };
+
+        let recovery_codes = generate_recovery_codes();
+        let recovery_code_hashes = recovery_codes
+            .iter()
+            .map(|recovery_code| hash_recovery_code(recovery_code))
+            .collect();
+        self.admin_user_two_factor_repository
+            .enable(datastore, database_session, &admin_user_id, recovery_code_hashes, step)
+            .await?;
+
+        let auth_token = match is_login {
+            true => {
+                self.start_session(db, admin_user_model, user_agent, ip_address, jwt_secret_key)
+                    .await?
+            }
+            false => AuthTokenModel::default(),
+        };
+
+        Ok(ConfirmTwoFactorResponse {
+            status: true,
+            recovery_codes,
+            data: auth_token.access_token,
+            refresh_token: auth_token.refresh_token,
+            expires_in: auth_token.expires_in,
+        })
+    }
+
+    /// Turns two factor authentication off for the logged in admin user, which
+    /// takes a valid code. Not possible while it is required for everyone.
+    /// Wrong codes count towards the login throttle like those of a login.
+    pub async fn disable_two_factor(
+        &self,
+        db: &DB,
+        logged_in_user: &AdminUserModel,
+        code: &str,
+        ip_address: &str,
+    ) -> Result<bool> {
+        let (datastore, database_session) = db;
+        if self.is_two_factor_required(db).await {
+            return Err(TonicError(Status::failed_precondition(
+                "two factor authentication is required for every admin user",
+            )));
+        }
+
+        let admin_user_two_factor_model = self
+            .admin_user_two_factor_repository
+            .find_by_admin_user_id(datastore, database_session, &logged_in_user.id)
+            .await?
+            .filter(|admin_user_two_factor_model| admin_user_two_factor_model.is_enabled)
+            .ok_or_else(|| TonicError(Status::failed_precondition(
+                "two factor authentication is not enabled",
+            )))?;
+
+        self.login_throttle_service
+            .check(&logged_in_user.email, ip_address)?;
+        if !self
+            .use_two_factor_code(db, logged_in_user, &admin_user_two_factor_model, code)
+            .await?
+        {
+            self.login_throttle_service
+                .record_failure(db, &logged_in_user.email, ip_address)
+                .await?;
+            return Err(invalid_two_factor_code()?);
+        }
+
+        self.admin_user_two_factor_repository
+            .delete_by_admin_user_id(datastore, database_session, &logged_in_user.id)
+            .await
+    }
+
+    /// Makes every admin user log in with two factor authentication. Admin
+    /// users without it have to enroll during their next login.
+    pub async fn require_two_factor(
+        &self,
+        (datastore, database_session): &DB,
+        logged_in_user: &AdminUserModel,
+        required: bool,
+    ) -> Result<bool> {
+        if !logged_in_user.is_super_admin {
+            return Err(TonicError(Status::permission_denied(
+                "only a super admin can require two factor authentication",
+            )));
+        }
+
+        self.setting_repository
+            .update_setting_by_identifier(
+                datastore,
+                database_session,
+                TWO_FACTOR_REQUIRED_SETTING,
+                &required.to_string(),
+                &logged_in_user.email,
+            )
+            .await?;
+
+        Ok(true)
+    }
+
+    /// Lifts the login lockout of an admin user.
+    pub async fn unlock_admin_user(
+        &self,
+        db: &DB,
+        logged_in_user: &AdminUserModel,
+        admin_user_id: &str,
+        ip_address: &str,
+    ) -> Result<bool> {
+        let (datastore, database_session) = db;
+        if !logged_in_user.is_super_admin {
+            return Err(TonicError(Status::permission_denied(
+                "only a super admin can unlock an admin user",
+            )));
+        }
+
+        let admin_user_model = self
+            .admin_user_repository
+            .find_by_id(datastore, database_session, admin_user_id)
+            .await
+            .map_err(|_| TonicError(Status::not_found("admin user not found")))?;
+
+        self.login_throttle_service
+            .unlock(db, &admin_user_model.email, ip_address, &logged_in_user.email)
+            .await
+    }
+
+    pub async fn audit_log_all(&self, db: &DB, logged_in_user: &AdminUserModel) -> Result<AuditLogAllResponse> {
+        if !logged_in_user.is_super_admin {
+            return Err(TonicError(Status::permission_denied(
+                "only a super admin can see the audit log",
+            )));
+        }
+
+        let audit_log_models = self
+            .login_throttle_service
+            .audit_log_all(db, AUDIT_LOG_LIMIT)
+            .await?;
+        let mut audit_log_grpc_models: Vec<AuditLogModelGrpc> = vec![];
+        for audit_log_model in audit_log_models {
+            audit_log_grpc_models.push(audit_log_model.try_into()?);
+        }
+
+        Ok(AuditLogAllResponse {
+            status: true,
+            data: audit_log_grpc_models,
+        })
+    }
+
+    /// A partial token when the login still needs a two factor code, or has
+    /// to set two factor authentication up because it is required.
+    async fn two_factor_challenge(
+        &self,
+        db: &DB,
+        admin_user_model: &AdminUserModel,
+        jwt_secret_key: &str,
+    ) -> Result<Option<AuthTokenModel>> {
+        let (datastore, database_session) = db;
+        let is_enabled = self
+            .admin_user_two_factor_repository
+            .find_by_admin_user_id(datastore, database_session, &admin_user_model.id)
+            .await?
+            .is_some_and(|admin_user_two_factor_model| admin_user_two_factor_model.is_enabled);
+
+        let purpose = match is_enabled {
+            true => TwoFactorPurpose::Login,
+            false if self.is_two_factor_required(db).await => TwoFactorPurpose::Setup,
+            false => return Ok(None),
+        };
+
+        let claims = TwoFactorClaims::new(&admin_user_model.id, purpose.clone(), TWO_FACTOR_TOKEN_EXPIRED_IN_SECONDS);
+        let two_factor_token = encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(jwt_secret_key.as_bytes()),
+        )?;
+
+        Ok(Some(AuthTokenModel {
+            two_factor_required: purpose == TwoFactorPurpose::Login,
+            two_factor_setup_required: purpose == TwoFactorPurpose::Setup,
+            two_factor_token,
+            ..Default::default()
+        }))
+    }
+
+    async fn is_two_factor_required(&self, (datastore, database_session): &DB) -> bool {
+        self.setting_repository
+            .find_by_identifier(datastore, database_session, TWO_FACTOR_REQUIRED_SETTING)
+            .await
+            .is_ok_and(|setting_model| setting_model.value == "true")
+    }
+
+    /// Admin user of an access token, or of the partial token of a login that
+    /// has to enroll. The flag tells which one it was.
+    fn two_factor_admin_user_id(&self, bearer_token: &str, jwt_secret_key: &str) -> Result<(String, bool)> {
+        if let Ok(claims) = self.verify_access_token(bearer_token, jwt_secret_key) {
+            return Ok((claims.sub, false));
+        }
+
+        match decode_two_factor_token(bearer_token, jwt_secret_key) {
+            Some(claims) if claims.purpose == TwoFactorPurpose::Setup => Ok((claims.sub, true)),
+            _ => Err(TonicError(Status::unauthenticated("No valid auth token"))),
+        }
+    }
+
+    /// Checks a code of the authenticator app or a recovery code and marks it
+    /// as used.
+    async fn use_two_factor_code(
+        &self,
+        (datastore, database_session): &DB,
+        admin_user_model: &AdminUserModel,
+        admin_user_two_factor_model: &AdminUserTwoFactorModel,
+        code: &str,
+    ) -> Result<bool> {
+        if let Some(step) = admin_user_two_factor_model.verify_code(&admin_user_model.email, code.trim())? {
+            return self
+                .admin_user_two_factor_repository
+                .use_step(datastore, database_session, &admin_user_model.id, step)
+                .await;
+        }
+
+        self.admin_user_two_factor_repository
+            .use_recovery_code(datastore, database_session, &admin_user_model.id, &hash_recovery_code(code))
+            .await
+    }
+
+    async fn start_session(
+        &self,
+        db: &DB,
+        admin_user_model: AdminUserModel,
+        user_agent: &str,
+        ip_address: &str,
+        jwt_secret_key: &str,
+    ) -> Result<AuthTokenModel> {
+        let (datastore, database_session) = db;
+        self.login_throttle_service.record_success(&admin_user_model.email);
+
+        let creatable_admin_user_session = CreatableAdminUserSessionModel {
+            admin_user_id: admin_user_model.id.clone(),
+            user_agent: user_agent.to_string(),
+            ip_address: ip_address.to_string(),
+            expires_at: self.refresh_token_expires_at(),
+        };
+        let admin_user_session_model = self
+            .admin_user_session_repository
+            .create_admin_user_session(datastore, database_session, creatable_admin_user_session)
+            .await?;
+
+        self.issue_tokens(
+            db,
+            admin_user_model,
+            &admin_user_session_model.id,
+            jwt_secret_key,
+        )
+        .await
+    }
+
+    /// Swaps a refresh token for a new access and refresh token. A refresh
+    /// token that was already swapped is treated as stolen and ends its session.
+    pub(crate) async fn refresh_token(
+        &self,
+        db: &DB,
+        refresh_token: &str,
+        ip_address: &str,
+        jwt_secret_key: &str,
+    ) -> Result<AuthTokenModel> {
+        let (datastore, database_session) = db;
+        let token_hash = hash_refresh_token(refresh_token);
+
+        let refresh_token_model = match self
+            .refresh_token_repository
+            .use_refresh_token(datastore, database_session, &token_hash)
+            .await?
+        {
+            Some(refresh_token_model) => refresh_token_model,
+            None => {
+                if let Ok(refresh_token_model) = self
+                    .refresh_token_repository
+                    .find_by_token_hash(datastore, database_session, &token_hash)
+                    .await
+                {
+                    if refresh_token_model.status == RefreshTokenStatus::Used {
+                        warn!(
+                            "refresh token reused, revoking session: {}",
+                            refresh_token_model.session_id
+                        );
+                        self.revoke_session(db, &refresh_token_model.session_id)
+                            .await?;
+                    }
+                }
+
+                return Err(TonicError(Status::unauthenticated("refresh token is not valid")));
+            }
+        };
+
+        let admin_user_session_model = self
+            .admin_user_session_repository
+            .find_by_id(datastore, database_session, &refresh_token_model.session_id)
+            .await?;
+        if !admin_user_session_model.is_active() {
+            return Err(TonicError(Status::unauthenticated("session has ended, please login again")));
+        }
+
+        self.admin_user_session_repository
+            .extend_admin_user_session(
+                datastore,
+                database_session,
+                &admin_user_session_model.id,
+                ip_address,
+                self.refresh_token_expires_at(),
+            )
+            .await?;
+
+        let admin_user_model = self
+            .admin_user_repository
+            .find_by_id(datastore, database_session, &admin_user_session_model.admin_user_id)
+            .await?;
+
+        self.issue_tokens(
+            db,
+            admin_user_model,
+            &admin_user_session_model.id,
+            jwt_secret_key,
+        )
+        .await
+    }
+
+    /// Ends the session the refresh token belongs to, access tokens issued
+    /// for it stop working straight away.
+    pub(crate) async fn logout(
+        &self,
+        db: &DB,
+        refresh_token: &str,
+    ) -> Result<bool> {
+        let (datastore, database_session) = db;
+        let refresh_token_model = self
+            .refresh_token_repository
+            .find_by_token_hash(datastore, database_session, &hash_refresh_token(refresh_token))
+            .await
+            .map_err(|_| TonicError(Status::unauthenticated("refresh token is not valid")))?;
+
+        self.revoke_session(db, &refresh_token_model.session_id)
+            .await?;
+
+        Ok(true)
+    }
+
+    /// Decodes an access token and checks its session has not been revoked,
+    /// then records the request as activity of the session.
+    pub fn verify_access_token(&self, token: &str, jwt_secret_key: &str) -> Result<TokenClaims> {
+        let claims = decode::<TokenClaims>(
+            token,
+            &DecodingKey::from_secret(jwt_secret_key.as_ref()),
+            &Validation::default(),
+        )
+        .map_err(|_| Error::Unauthenticated(String::from("No valid auth token claims found")))?
+        .claims;
+
+        let revoked_sessions = self.revoked_sessions.read().unwrap_or_else(|e| e.into_inner());
+        if revoked_sessions.contains_key(&claims.sid) {
+            return Err(Error::Unauthenticated(String::from("auth token has been revoked")));
+        }
+        drop(revoked_sessions);
+
+        let now = chrono::Utc::now().timestamp();
+        let mut session_activity = self.session_activity.lock().unwrap_or_else(|e| e.into_inner());
+        if session_activity.len() >= SESSION_ACTIVITY_SWEEP_THRESHOLD {
+            let refresh_token_expired_in_seconds = self.refresh_token_expired_in_seconds;
+            session_activity.retain(|_, activity_at| now - *activity_at < refresh_token_expired_in_seconds);
+        }
+        session_activity.insert(claims.sid.clone(), now);
+        drop(session_activity);
+
+        Ok(claims)
+    }
+
+    /// Active sessions of the logged in admin user, or of another admin user
+    /// when the logged in one is a super admin.
+    pub async fn admin_user_session_all(
+        &self,
+        (datastore, database_session): &DB,
+        claims: &TokenClaims,
+        logged_in_user: &AdminUserModel,
+        admin_user_id: &str,
+    ) -> Result<AdminUserSessionAllResponse> {
+        let admin_user_id = match admin_user_id.is_empty() {
+            true => claims.sub.as_str(),
+            false => admin_user_id,
+        };
+        if admin_user_id != claims.sub && !logged_in_user.is_super_admin {
+            return Err(TonicError(Status::permission_denied(
+                "only a super admin can see the sessions of another admin user",
+            )));
+        }
+
+        let admin_user_session_models = self
+            .admin_user_session_repository
+            .active_by_admin_user_id(datastore, database_session, admin_user_id)
+            .await?;
+
+        let session_activity = self.session_activity.lock().unwrap_or_else(|e| e.into_inner()).clone();
+        let mut admin_user_session_grpc_models: Vec<AdminUserSessionModelGrpc> = vec![];
+        for admin_user_session_model in admin_user_session_models {
+            let session_id = admin_user_session_model.id.clone();
+            let stored_activity_at = admin_user_session_model.last_activity_at.to_utc().timestamp();
+            let mut admin_user_session_grpc_model: AdminUserSessionModelGrpc = admin_user_session_model.try_into()?;
+
+            if let Some(activity_at) = session_activity.get(&session_id) {
+                if *activity_at > stored_activity_at {
+                    admin_user_session_grpc_model.last_activity_at = Some(prost_types::Timestamp {
+                        seconds: *activity_at,
+                        nanos: 0,
+                    });
+                }
+            }
+            admin_user_session_grpc_model.is_current = session_id == claims.sid;
+            admin_user_session_grpc_models.push(admin_user_session_grpc_model);
+        }
+
+        let response = AdminUserSessionAllResponse {
+            status: true,
+            data: admin_user_session_grpc_models,
+        };
+
+        Ok(response)
+    }
+
+    /// Signs a session out remotely. Admin users can end their own sessions,
+    /// super admins any session.
+    pub async fn terminate_admin_user_session(
+        &self,
+        db: &DB,
+        logged_in_user: &AdminUserModel,
+        session_id: &str,
+    ) -> Result<bool> {
+        let (datastore, database_session) = db;
+        let admin_user_session_model = self
+            .admin_user_session_repository
+            .find_by_id(datastore, database_session, session_id)
+            .await
+            .map_err(|_| TonicError(Status::not_found("session not found")))?;
+
+        if admin_user_session_model.admin_user_id != logged_in_user.id && !logged_in_user.is_super_admin {
+            return Err(TonicError(Status::permission_denied(
+                "only a super admin can end the sessions of another admin user",
+            )));
+        }
+
+        self.revoke_session(db, &admin_user_session_model.id).await?;
+
+        Ok(true)
+    }
+
+    /// Signs an admin user out everywhere, returns how many sessions ended.
+    pub async fn terminate_all_admin_user_sessions(
+        &self,
+        db: &DB,
+        logged_in_user: &AdminUserModel,
+        admin_user_id: &str,
+    ) -> Result<i64> {
+        if !logged_in_user.is_super_admin {
+            return Err(TonicError(Status::permission_denied(
+                "only a super admin can end all sessions of an admin user",
+            )));
+        }
+
+        self.revoke_admin_user_sessions(db, admin_user_id).await
+    }
+
+    /// Loads the sessions revoked recently enough that their access tokens
+    /// may still be valid and drops expired refresh tokens, called once at startup.
+    pub async fn load_revoked_sessions(&self, (datastore, database_session): &DB) -> Result<()> {
+        let since = Datetime::from(chrono::Utc::now() - chrono::Duration::seconds(self.jwt_expired_in_seconds));
+        let admin_user_session_models = self
+            .admin_user_session_repository
+            .revoked_since(datastore, database_session, since)
+            .await?;
+
+        self.refresh_token_repository
+            .delete_expired(datastore, database_session)
+            .await?;
+
+        let mut revoked_sessions = self.revoked_sessions.write().unwrap_or_else(|e| e.into_inner());
+        for admin_user_session_model in admin_user_session_models {
+            let forget_at = admin_user_session_model.updated_at.to_utc().timestamp() + self.jwt_expired_in_seconds;
+            revoked_sessions.insert(admin_user_session_model.id, forget_at);
+        }
+
+        Ok(())
+    }
+
+    /// Ends every session of the admin user along with their refresh tokens,
+    /// returns how many sessions ended.
+    async fn revoke_admin_user_sessions(&self, (datastore, database_session): &DB, admin_user_id: &str) -> Result<i64> {
+        let admin_user_session_models = self
+            .admin_user_session_repository
+            .revoke_by_admin_user_id(datastore, database_session, admin_user_id)
+            .await?;
+
+        let session_ids: Vec<String> = admin_user_session_models
+            .into_iter()
+            .map(|admin_user_session_model| admin_user_session_model.id)
+            .collect();
+        self.refresh_token_repository
+            .revoke_by_session_ids(datastore, database_session, session_ids.clone())
+            .await?;
+        self.forget_sessions(&session_ids);
 
-        let token = encode(
+        Ok(session_ids.len() as i64)
+    }
+
+    async fn revoke_session(&self, (datastore, database_session): &DB, session_id: &str) -> Result<()> {
+        self.admin_user_session_repository
+            .revoke_admin_user_session(datastore, database_session, session_id)
+            .await?;
+        self.forget_sessions(&[session_id.to_string()]);
+
+        Ok(())
+    }
+
+    /// Puts revoked sessions on the deny list checked for every access token.
+    fn forget_sessions(&self, session_ids: &[String]) {
+        let now = chrono::Utc::now().timestamp();
+        let mut revoked_sessions = self.revoked_sessions.write().unwrap_or_else(|e| e.into_inner());
+        revoked_sessions.retain(|_, forget_at| *forget_at > now);
+        for session_id in session_ids {
+            revoked_sessions.insert(session_id.clone(), now + self.jwt_expired_in_seconds);
+        }
+        drop(revoked_sessions);
+
+        let mut session_activity = self.session_activity.lock().unwrap_or_else(|e| e.into_inner());
+        for session_id in session_ids {
+            session_activity.remove(session_id);
+        }
+    }
+
+    async fn issue_tokens(
+        &self,
+        (datastore, database_session): &DB,
+        admin_user_model: AdminUserModel,
+        session_id: &str,
+        jwt_secret_key: &str,
+    ) -> Result<AuthTokenModel> {
+        let claims = TokenClaims::new(&admin_user_model.id, session_id, self.jwt_expired_in_seconds);
+        let access_token = encode(
             &Header::default(),
             &claims,
             &EncodingKey::from_secret(jwt_secret_key.as_bytes()),
         )?;
 
-        Ok(token)
+        let refresh_token: String = rand::rng()
+            .sample_iter(&Alphanumeric)
+            .take(REFRESH_TOKEN_LENGTH)
+            .map(char::from)
+            .collect();
+        let creatable_refresh_token = CreatableRefreshTokenModel {
+            session_id: session_id.to_string(),
+            token_hash: hash_refresh_token(&refresh_token),
+            expires_at: self.refresh_token_expires_at(),
+        };
+        self.refresh_token_repository
+            .create_refresh_token(datastore, database_session, creatable_refresh_token)
+            .await?;
+
+        Ok(AuthTokenModel {
+            access_token,
+            refresh_token,
+            expires_in: self.jwt_expired_in_seconds,
+            ..Default::default()
+        })
+    }
+
+    fn refresh_token_expires_at(&self) -> Datetime {
+        Datetime::from(chrono::Utc::now() + chrono::Duration::seconds(self.refresh_token_expired_in_seconds))
+    }
+
+    /// Stores a new hash of a password that just matched, so old hashes
+    /// move to a salt of their own and the configured argon2 parameters.
+    /// A failure only means the upgrade is tried again on the next login.
+    async fn rehash_password(&self, (datastore, database_session): &DB, email: &str, password: &str) {
+        let password_hash = match password.to_string().get_password_hash(&self.password_hash_config) {
+            Ok(password_hash) => password_hash,
+            Err(e) => {
+                warn!("unable to rehash the password of {email}: {e:?}");
+                return;
+            }
+        };
+
+        if let Err(e) = self
+            .admin_user_repository
+            .update_password_by_email(datastore, database_session, email, password_hash)
+            .await
+        {
+            warn!("unable to store the rehashed password of {email}: {e:?}");
+        }
     }
 
     pub fn compare_password(
@@ -147,14 +945,32 @@ impl AuthService {
 
     pub(crate) async fn reset_password(
         &self,
-        (datastore, database_session): &DB,
+        db: &DB,
         email: &str,
         password: String,
-        password_salt: &str,
         token: &str,
     ) -> Result<bool> {
-        
-        let password_hash = password.get_password_hash(password_salt)?;
+        let (datastore, database_session) = db;
+        let password_hash = password.get_password_hash(&self.password_hash_config)?;
+
+        // the token is used up before the password changes, so two requests
+        // racing with the same link can not both set a password.
+        let is_token_used = self
+            .password_reset_repository
+            .use_password_token(datastore, database_session, email, &hash_password_reset_token(token))
+            .await?;
+        if !is_token_used {
+            let error_response = ErrorResponse {
+                status: false,
+                errors: vec![ErrorMessage {
+                    key: String::from("email"),
+                    message: t!("not_valid_password_reset_token").to_string(),
+                }],
+            };
+            let error_string = serde_json::to_string(&error_response)?;
+
+            return Err(TonicError(Status::invalid_argument(error_string)));
+        }
         
         let status = self
             .admin_user_repository
@@ -164,13 +980,32 @@ impl AuthService {
         if !status {
             return Err(Error::Generic(String::from("there is an issue while updating password.")));
         }
-        
-        let expire_token_status = self
-            .password_reset_repository
-            .expire_password_token_by_email_and_token(datastore, database_session, email, token)
+
+        // whoever had the old password is signed out everywhere.
+        let admin_user_model = self
+            .admin_user_repository
+            .find_by_email(datastore, database_session, email)
             .await?;
+        self.revoke_admin_user_sessions(db, &admin_user_model.id).await?;
 
-        Ok(expire_token_status)
+        Ok(status)
+    }
+
+    /// Deletes expired password resets now and then. Runs for the lifetime of the server.
+    pub async fn run_password_reset_cleanup(&self, (datastore, database_session): &DB) {
+        let mut interval = tokio::time::interval(Duration::from_secs(PASSWORD_RESET_CLEANUP_SECONDS));
+
+        loop {
+            interval.tick().await;
+
+            if let Err(e) = self
+                .password_reset_repository
+                .delete_expired(datastore, database_session)
+                .await
+            {
+                error!("password reset cleanup error: {e:?}");
+            }
+        }
     }
 
     pub(crate) async fn validate_token(
@@ -181,7 +1016,7 @@ impl AuthService {
     ) -> Result<bool> {
         match self
             .password_reset_repository
-            .get_password_reset_by_email_and_token(datastore, database_session, email, token)
+            .find_active_by_email_and_token_hash(datastore, database_session, email, &hash_password_reset_token(token))
             .await
         {
             Ok(_model) => Ok(true),
@@ -191,13 +1026,328 @@ impl AuthService {
 }
 
 impl AuthService {
+    #[allow(clippy::too_many_arguments)]
     pub async fn new(
         admin_user_repository: AdminUserRepository,
         password_reset_repository: PasswordResetRepository,
+        admin_user_session_repository: AdminUserSessionRepository,
+        refresh_token_repository: RefreshTokenRepository,
+        admin_user_two_factor_repository: AdminUserTwoFactorRepository,
+        setting_repository: SettingRepository,
+        login_throttle_service: LoginThrottleService,
+        password_hash_config: PasswordHashConfig,
+        jwt_expired_in_seconds: i64,
+        refresh_token_expired_in_seconds: i64,
+        password_reset_token_expired_in_seconds: i64,
     ) -> Result<AuthService> {
         Ok(AuthService {
             admin_user_repository,
             password_reset_repository,
+            admin_user_session_repository,
+            refresh_token_repository,
+            admin_user_two_factor_repository,
+            setting_repository,
+            login_throttle_service,
+            password_hash_config,
+            jwt_expired_in_seconds,
+            refresh_token_expired_in_seconds,
+            password_reset_token_expired_in_seconds,
+            revoked_sessions: RwLock::new(HashMap::new()),
+            session_activity: Mutex::new(HashMap::new()),
         })
     }
 }
+
+fn decode_two_factor_token(two_factor_token: &str, jwt_secret_key: &str) -> Option<TwoFactorClaims> {
+    decode::<TwoFactorClaims>(
+        two_factor_token,
+        &DecodingKey::from_secret(jwt_secret_key.as_ref()),
+        &Validation::default(),
+    )
+    .ok()
+    .map(|token_data| token_data.claims)
+}
+
+fn invalid_two_factor_code() -> Result<Error> {
+    let error_response = ErrorResponse {
+        status: false,
+        errors: vec![ErrorMessage {
+            key: String::from("code"),
+            message: t!("two_factor_code_not_valid").to_string(),
+        }],
+    };
+    let error_string = serde_json::to_string(&error_response)?;
+
+    Ok(TonicError(Status::invalid_argument(error_string)))
+}
+
+fn hash_refresh_token(refresh_token: &str) -> String {
+    hex::encode(Sha256::digest(refresh_token.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use crate::models::admin_user_model::{AdminUserModel, CreatableAdminUserModel};
+    use tonic::Code;
+    use crate::error::Error::TonicError;
+    use crate::extensions::string_extension::StringExtension;
+    use crate::models::admin_user_session_model::AuthTokenModel;
+    use crate::providers::avored_clock_provider::AvoRedClockProvider;
+    use crate::providers::avored_config_provider::PasswordHashConfig;
+    use crate::providers::avored_database_provider::DB;
+    use crate::repositories::admin_user_repository::AdminUserRepository;
+    use crate::repositories::admin_user_session_repository::AdminUserSessionRepository;
+    use crate::repositories::admin_user_two_factor_repository::AdminUserTwoFactorRepository;
+    use crate::repositories::audit_log_repository::AuditLogRepository;
+    use crate::repositories::password_reset_repository::PasswordResetRepository;
+    use crate::repositories::refresh_token_repository::RefreshTokenRepository;
+    use crate::repositories::setting_repository::SettingRepository;
+    use crate::services::login_throttle_service::LoginThrottleService;
+    use crate::test_helpers::memory_db;
+    use super::AuthService;
+
+    const JWT_SECRET_KEY: &str = "auth_service_test_secret";
+
+    async fn setup(database: &str) -> (DB, AuthService, AdminUserModel) {
+        let (db, auth_service, admin_user_model, _) = setup_with_clock(database).await;
+
+        (db, auth_service, admin_user_model)
+    }
+
+    async fn setup_with_clock(database: &str) -> (DB, AuthService, AdminUserModel, Arc<AvoRedClockProvider>) {
+        let db = memory_db(database).await;
+        let clock = Arc::new(AvoRedClockProvider::register());
+        let login_throttle_service = LoginThrottleService::new(
+            AuditLogRepository::new(),
+            5,
+            50,
+            900,
+            clock.clone(),
+        )
+        .unwrap();
+        let password_hash_config = PasswordHashConfig {
+            params: argon2::Params::default(),
+            legacy_salt: String::from(""),
+        };
+        let auth_service = AuthService::new(
+            AdminUserRepository::new(),
+            PasswordResetRepository::new(),
+            AdminUserSessionRepository::new(),
+            RefreshTokenRepository::new(),
+            AdminUserTwoFactorRepository::new(),
+            SettingRepository::new(),
+            login_throttle_service,
+            password_hash_config,
+            300,
+            3600,
+            3600,
+        )
+        .await
+        .unwrap();
+
+        let (datastore, database_session) = &db;
+        let creatable_admin_user = CreatableAdminUserModel {
+            full_name: String::from("Jane Doe"),
+            email: String::from("jane@example.com"),
+            password: String::from(""),
+            profile_image: String::from(""),
+            is_super_admin: true,
+            logged_in_username: String::from("jane@example.com"),
+        };
+        let admin_user_model = AdminUserRepository::new()
+            .create_admin_user(datastore, database_session, creatable_admin_user)
+            .await
+            .unwrap();
+
+        (db, auth_service, admin_user_model, clock)
+    }
+
+    async fn login(db: &DB, auth_service: &AuthService, admin_user_model: &AdminUserModel) -> AuthTokenModel {
+        auth_service
+            .start_session(db, admin_user_model.clone(), "test", "127.0.0.1", JWT_SECRET_KEY)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn refresh_token_rotates() {
+        let (db, auth_service, admin_user_model) = setup("refresh_token_rotates_test").await;
+        let auth_token = login(&db, &auth_service, &admin_user_model).await;
+
+        let refreshed = auth_service
+            .refresh_token(&db, &auth_token.refresh_token, "127.0.0.1", JWT_SECRET_KEY)
+            .await
+            .unwrap();
+        assert_ne!(refreshed.refresh_token, auth_token.refresh_token);
+        assert!(auth_service.verify_access_token(&refreshed.access_token, JWT_SECRET_KEY).is_ok());
+
+        let refreshed_again = auth_service
+            .refresh_token(&db, &refreshed.refresh_token, "127.0.0.1", JWT_SECRET_KEY)
+            .await;
+        assert!(refreshed_again.is_ok());
+    }
+
+    #[tokio::test]
+    async fn reused_refresh_token_revokes_the_session() {
+        let (db, auth_service, admin_user_model) = setup("reused_refresh_token_test").await;
+        let auth_token = login(&db, &auth_service, &admin_user_model).await;
+        let other_auth_token = login(&db, &auth_service, &admin_user_model).await;
+
+        let refreshed = auth_service
+            .refresh_token(&db, &auth_token.refresh_token, "127.0.0.1", JWT_SECRET_KEY)
+            .await
+            .unwrap();
+
+        let reused = auth_service
+            .refresh_token(&db, &auth_token.refresh_token, "127.0.0.1", JWT_SECRET_KEY)
+            .await;
+        assert!(reused.is_err());
+
+        // the token the legitimate client rotated to belongs to the revoked session
+        assert!(auth_service.verify_access_token(&refreshed.access_token, JWT_SECRET_KEY).is_err());
+        let after_reuse = auth_service
+            .refresh_token(&db, &refreshed.refresh_token, "127.0.0.1", JWT_SECRET_KEY)
+            .await;
+        assert!(after_reuse.is_err());
+
+        // other sessions of the admin user are left alone
+        assert!(auth_service.verify_access_token(&other_auth_token.access_token, JWT_SECRET_KEY).is_ok());
+        let other_refreshed = auth_service
+            .refresh_token(&db, &other_auth_token.refresh_token, "127.0.0.1", JWT_SECRET_KEY)
+            .await;
+        assert!(other_refreshed.is_ok());
+    }
+
+    #[tokio::test]
+    async fn logout_revokes_the_access_and_refresh_token() {
+        let (db, auth_service, admin_user_model) = setup("logout_test").await;
+        let auth_token = login(&db, &auth_service, &admin_user_model).await;
+
+        assert!(auth_service.logout(&db, &auth_token.refresh_token).await.unwrap());
+
+        assert!(auth_service.verify_access_token(&auth_token.access_token, JWT_SECRET_KEY).is_err());
+        let refreshed = auth_service
+            .refresh_token(&db, &auth_token.refresh_token, "127.0.0.1", JWT_SECRET_KEY)
+            .await;
+        assert!(refreshed.is_err());
+    }
+
+    #[tokio::test]
+    async fn two_factor_code_can_not_be_replayed() {
+        let (db, auth_service, admin_user_model) = setup("two_factor_replay_test").await;
+        let (datastore, database_session) = &db;
+        let admin_user_two_factor_repository = AdminUserTwoFactorRepository::new();
+        let totp = super::generate_totp(&admin_user_model.email).unwrap();
+        admin_user_two_factor_repository
+            .save_pending_secret(datastore, database_session, &admin_user_model.id, &totp.get_secret_base32())
+            .await
+            .unwrap();
+        let admin_user_two_factor_model = admin_user_two_factor_repository
+            .enable(datastore, database_session, &admin_user_model.id, vec![], 0)
+            .await
+            .unwrap();
+        let code = totp.generate_current().unwrap();
+
+        let used = auth_service
+            .use_two_factor_code(&db, &admin_user_model, &admin_user_two_factor_model, &code)
+            .await
+            .unwrap();
+        assert!(used);
+
+        // a request racing with the first one still holds the old last used step
+        let raced = auth_service
+            .use_two_factor_code(&db, &admin_user_model, &admin_user_two_factor_model, &code)
+            .await
+            .unwrap();
+        assert!(!raced);
+
+        let admin_user_two_factor_model = admin_user_two_factor_repository
+            .find_by_admin_user_id(datastore, database_session, &admin_user_model.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(admin_user_two_factor_model
+            .verify_code(&admin_user_model.email, &code)
+            .unwrap()
+            .is_none());
+        let replayed = auth_service
+            .use_two_factor_code(&db, &admin_user_model, &admin_user_two_factor_model, &code)
+            .await
+            .unwrap();
+        assert!(!replayed);
+    }
+
+    #[tokio::test]
+    async fn login_rehashes_a_password_made_with_other_params() {
+        let (db, auth_service, admin_user_model) = setup("login_rehash_test").await;
+        let (datastore, database_session) = &db;
+        let weaker_config = PasswordHashConfig {
+            params: argon2::Params::new(8 * 1024, 1, 1, None).unwrap(),
+            legacy_salt: String::from(""),
+        };
+        let weak_password_hash = String::from("secret-password")
+            .get_password_hash(&weaker_config)
+            .unwrap();
+        AdminUserRepository::new()
+            .update_password_by_email(datastore, database_session, &admin_user_model.email, weak_password_hash.clone())
+            .await
+            .unwrap();
+
+        auth_service
+            .auth_user(&admin_user_model.email, "secret-password", &db, "test", "127.0.0.1", JWT_SECRET_KEY)
+            .await
+            .unwrap();
+
+        let password_hash = AdminUserRepository::new()
+            .find_by_email(datastore, database_session, &admin_user_model.email)
+            .await
+            .unwrap()
+            .password;
+        assert_ne!(password_hash, weak_password_hash);
+        assert!(!password_hash.password_hash_needs_rehash(&PasswordHashConfig {
+            params: argon2::Params::default(),
+            legacy_salt: String::from(""),
+        }));
+        assert!(auth_service
+            .compare_password("secret-password", password_hash)
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn disable_two_factor_codes_are_throttled() {
+        let (db, auth_service, admin_user_model, clock) = setup_with_clock("disable_two_factor_throttle_test").await;
+        let (datastore, database_session) = &db;
+        let admin_user_two_factor_repository = AdminUserTwoFactorRepository::new();
+        let totp = super::generate_totp(&admin_user_model.email).unwrap();
+        admin_user_two_factor_repository
+            .save_pending_secret(datastore, database_session, &admin_user_model.id, &totp.get_secret_base32())
+            .await
+            .unwrap();
+        admin_user_two_factor_repository
+            .enable(datastore, database_session, &admin_user_model.id, vec![], 0)
+            .await
+            .unwrap();
+
+        for attempt in 0..3 {
+            if attempt > 0 {
+                clock.advance(60);
+            }
+            let result = auth_service
+                .disable_two_factor(&db, &admin_user_model, "not-a-code", "203.0.113.7")
+                .await;
+            assert!(matches!(result, Err(TonicError(status)) if status.code() == Code::InvalidArgument));
+        }
+
+        // right after a failure the next attempt has to wait, even with the right code.
+        let result = auth_service
+            .disable_two_factor(&db, &admin_user_model, &totp.generate_current().unwrap(), "203.0.113.7")
+            .await;
+        assert!(matches!(result, Err(TonicError(status)) if status.code() == Code::ResourceExhausted));
+        assert!(admin_user_two_factor_repository
+            .find_by_admin_user_id(datastore, database_session, &admin_user_model.id)
+            .await
+            .unwrap()
+            .is_some_and(|admin_user_two_factor_model| admin_user_two_factor_model.is_enabled));
+    }
+}
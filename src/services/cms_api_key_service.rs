@@ -0,0 +1,322 @@
+use rand::distr::Alphanumeric;
+use rand::Rng;
+use sha2::{Digest, Sha256};
+use tracing::error;
+use crate::api::proto::cms_api_key::{CmsApiKeyAllResponse, CmsApiKeyModel as CmsApiKeyModelGrpc, RevokeCmsApiKeyResponse, RotateCmsApiKeyResponse, StoreCmsApiKeyRequest, StoreCmsApiKeyResponse, UpdateCmsApiKeyRequest, UpdateCmsApiKeyResponse};
+use crate::error::{Error, Result};
+use crate::models::cms_api_key_model::{CmsApiKeyModel, CmsApiKeyStatus, CreatableCmsApiKeyModel, UpdatableCmsApiKeyModel};
+use crate::providers::avored_database_provider::DB;
+use crate::repositories::cms_api_key_repository::CmsApiKeyRepository;
+
+const CMS_API_KEY_PREFIX: &str = "avored_";
+const CMS_API_KEY_LENGTH: usize = 40;
+
+/// last_used_at is only written again once it is older than this, so busy
+/// sites do not turn every read into a write.
+const LAST_USED_RESOLUTION_SECONDS: i64 = 60;
+
+pub struct CmsApiKeyService {
+    cms_api_key_repository: CmsApiKeyRepository,
+}
+
+impl CmsApiKeyService {
+    pub fn new(cms_api_key_repository: CmsApiKeyRepository) -> Result<Self> {
+        Ok(CmsApiKeyService {
+            cms_api_key_repository,
+        })
+    }
+}
+
+impl CmsApiKeyService {
+    /// Resolves the api key sent by a site and checks it is allowed to run
+    /// the given operation, and to read the given collection when one is passed.
+    pub async fn authorize(
+        &self,
+        (datastore, database_session): &DB,
+        api_key: &str,
+        operation: &str,
+        collection: Option<&str>,
+    ) -> Result<CmsApiKeyModel> {
+        if api_key.is_empty() {
+            return Err(Error::Unauthenticated(String::from("cms api key is missing")));
+        }
+
+        let cms_api_key_model = self
+            .cms_api_key_repository
+            .find_by_key_hash(datastore, database_session, &hash_api_key(api_key))
+            .await
+            .map_err(|_| Error::Unauthenticated(String::from("cms api key is not valid")))?;
+
+        if cms_api_key_model.status != CmsApiKeyStatus::Active {
+            return Err(Error::Unauthenticated(String::from("cms api key has been revoked")));
+        }
+        if !cms_api_key_model.has_operation(operation) {
+            return Err(Error::Unauthorizeed(operation.to_string()));
+        }
+        if let Some(collection) = collection {
+            if !cms_api_key_model.has_collection(collection) {
+                return Err(Error::Unauthorizeed(collection.to_string()));
+            }
+        }
+
+        let is_stale = match &cms_api_key_model.last_used_at {
+            Some(last_used_at) => {
+                (chrono::Utc::now() - last_used_at.to_utc()).num_seconds() > LAST_USED_RESOLUTION_SECONDS
+            }
+            None => true,
+        };
+        if is_stale {
+            if let Err(e) = self
+                .cms_api_key_repository
+                .touch_last_used(datastore, database_session, &cms_api_key_model.id)
+                .await
+            {
+                error!("unable to update cms api key last used at: {e:?}");
+            }
+        }
+
+        Ok(cms_api_key_model)
+    }
+
+    /// Whether the api key may still watch the collections, asked now and
+    /// then by open content watches so a revoked key stops receiving events.
+    pub async fn can_watch(&self, db: &DB, api_key: &str, collections: &[String]) -> bool {
+        match self.authorize(db, api_key, "watch_content", None).await {
+            Ok(cms_api_key_model) => collections
+                .iter()
+                .all(|collection| cms_api_key_model.has_collection(collection)),
+            Err(_) => false,
+        }
+    }
+
+    pub async fn cms_api_key_all(&self, (datastore, database_session): &DB) -> Result<CmsApiKeyAllResponse> {
+        let cms_api_key_models = self
+            .cms_api_key_repository
+            .all(datastore, database_session)
+            .await?;
+
+        let mut cms_api_key_grpc_models = vec![];
+        for cms_api_key_model in cms_api_key_models {
+            let cms_api_key_grpc_model: CmsApiKeyModelGrpc = cms_api_key_model.try_into()?;
+            cms_api_key_grpc_models.push(cms_api_key_grpc_model);
+        }
+
+        let response = CmsApiKeyAllResponse {
+            status: true,
+            data: cms_api_key_grpc_models,
+        };
+
+        Ok(response)
+    }
+
+    pub async fn store_cms_api_key(
+        &self,
+        (datastore, database_session): &DB,
+        request: StoreCmsApiKeyRequest,
+        logged_in_username: String,
+    ) -> Result<StoreCmsApiKeyResponse> {
+        let api_key = generate_api_key();
+        let creatable_cms_api_key = CreatableCmsApiKeyModel {
+            name: request.name,
+            key_prefix: api_key_prefix(&api_key),
+            key_hash: hash_api_key(&api_key),
+            collections: request.collections,
+            operations: request.operations,
+            logged_in_username,
+        };
+
+        let cms_api_key_model = self
+            .cms_api_key_repository
+            .create_cms_api_key(datastore, database_session, creatable_cms_api_key)
+            .await?;
+
+        let response = StoreCmsApiKeyResponse {
+            status: true,
+            data: Some(cms_api_key_model.try_into()?),
+            api_key,
+        };
+
+        Ok(response)
+    }
+
+    pub async fn update_cms_api_key(
+        &self,
+        (datastore, database_session): &DB,
+        request: UpdateCmsApiKeyRequest,
+        logged_in_username: String,
+    ) -> Result<UpdateCmsApiKeyResponse> {
+        let updatable_cms_api_key = UpdatableCmsApiKeyModel {
+            id: request.cms_api_key_id,
+            name: request.name,
+            collections: request.collections,
+            operations: request.operations,
+            logged_in_username,
+        };
+
+        let cms_api_key_model = self
+            .cms_api_key_repository
+            .update_cms_api_key(datastore, database_session, updatable_cms_api_key)
+            .await?;
+
+        let response = UpdateCmsApiKeyResponse {
+            status: true,
+            data: Some(cms_api_key_model.try_into()?),
+        };
+
+        Ok(response)
+    }
+
+    /// Replaces the secret of an existing key, the previous value stops working immediately.
+    pub async fn rotate_cms_api_key(
+        &self,
+        (datastore, database_session): &DB,
+        cms_api_key_id: &str,
+        logged_in_username: &str,
+    ) -> Result<RotateCmsApiKeyResponse> {
+        let api_key = generate_api_key();
+
+        let cms_api_key_model = self
+            .cms_api_key_repository
+            .update_key_hash(
+                datastore,
+                database_session,
+                cms_api_key_id,
+                &api_key_prefix(&api_key),
+                &hash_api_key(&api_key),
+                logged_in_username,
+            )
+            .await?;
+
+        let response = RotateCmsApiKeyResponse {
+            status: true,
+            data: Some(cms_api_key_model.try_into()?),
+            api_key,
+        };
+
+        Ok(response)
+    }
+
+    pub async fn revoke_cms_api_key(
+        &self,
+        (datastore, database_session): &DB,
+        cms_api_key_id: &str,
+        logged_in_username: &str,
+    ) -> Result<RevokeCmsApiKeyResponse> {
+        let cms_api_key_model = self
+            .cms_api_key_repository
+            .update_status(
+                datastore,
+                database_session,
+                cms_api_key_id,
+                CmsApiKeyStatus::Revoked.try_into()?,
+                logged_in_username,
+            )
+            .await?;
+
+        let response = RevokeCmsApiKeyResponse {
+            status: true,
+            data: Some(cms_api_key_model.try_into()?),
+        };
+
+        Ok(response)
+    }
+}
+
+fn generate_api_key() -> String {
+    let secret: String = rand::rng()
+        .sample_iter(&Alphanumeric)
+        .take(CMS_API_KEY_LENGTH)
+        .map(char::from)
+        .collect();
+
+    format!("{CMS_API_KEY_PREFIX}{secret}")
+}
+
+fn api_key_prefix(api_key: &str) -> String {
+    api_key.chars().take(CMS_API_KEY_PREFIX.len() + 6).collect()
+}
+
+/// Keys are random and long, a plain sha256 is enough to avoid storing them.
+fn hash_api_key(api_key: &str) -> String {
+    hex::encode(Sha256::digest(api_key.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::proto::cms_api_key::StoreCmsApiKeyRequest;
+    use crate::error::Error;
+    use crate::models::cms_api_key_model::CmsApiKeyModel;
+    use crate::repositories::cms_api_key_repository::CmsApiKeyRepository;
+    use crate::test_helpers::memory_db;
+    use super::CmsApiKeyService;
+
+    #[test]
+    fn scope_matches_exact_operations_and_collections() {
+        let cms_api_key_model = CmsApiKeyModel {
+            collections: vec![String::from("pages")],
+            operations: vec![String::from("get_cms_content")],
+            ..Default::default()
+        };
+
+        assert!(cms_api_key_model.has_operation("get_cms_content"));
+        assert!(!cms_api_key_model.has_operation("submit_form"));
+        assert!(!cms_api_key_model.has_operation("get_cms"));
+        assert!(cms_api_key_model.has_collection("pages"));
+        assert!(!cms_api_key_model.has_collection("page"));
+        assert!(!cms_api_key_model.has_collection("posts"));
+
+        assert_eq!(cms_api_key_model.resolve_collections(vec![]).unwrap(), vec![String::from("pages")]);
+        assert!(matches!(
+            cms_api_key_model.resolve_collections(vec![String::from("posts")]),
+            Err(Error::Unauthorizeed(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn authorize_checks_status_operation_and_collection() {
+        let db = memory_db("cms_api_key_test").await;
+        let cms_api_key_service = CmsApiKeyService::new(CmsApiKeyRepository::new()).unwrap();
+        let request = StoreCmsApiKeyRequest {
+            name: String::from("site"),
+            collections: vec![String::from("pages")],
+            operations: vec![String::from("get_cms_content")],
+        };
+        let stored = cms_api_key_service
+            .store_cms_api_key(&db, request, String::from("admin"))
+            .await
+            .unwrap();
+        let api_key = stored.api_key;
+
+        let cms_api_key_model = cms_api_key_service
+            .authorize(&db, &api_key, "get_cms_content", Some("pages"))
+            .await
+            .unwrap();
+        assert_eq!(cms_api_key_model.collections, vec![String::from("pages")]);
+
+        assert!(matches!(
+            cms_api_key_service.authorize(&db, &api_key, "submit_form", None).await,
+            Err(Error::Unauthorizeed(_))
+        ));
+        assert!(matches!(
+            cms_api_key_service
+                .authorize(&db, &api_key, "get_cms_content", Some("posts"))
+                .await,
+            Err(Error::Unauthorizeed(_))
+        ));
+        assert!(matches!(
+            cms_api_key_service.authorize(&db, "avored_not_a_key", "get_cms_content", None).await,
+            Err(Error::Unauthenticated(_))
+        ));
+
+        cms_api_key_service
+            .revoke_cms_api_key(&db, &stored.data.unwrap().id, "admin")
+            .await
+            .unwrap();
+        assert!(matches!(
+            cms_api_key_service
+                .authorize(&db, &api_key, "get_cms_content", Some("pages"))
+                .await,
+            Err(Error::Unauthenticated(_))
+        ));
+    }
+}
@@ -1,86 +1,154 @@
-use lettre::{AsyncTransport, Message};
-use serde::{Deserialize, Serialize};
-use tracing::log::error;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use prost_types::value::Kind;
+use prost_types::{ListValue, Struct};
+use serde_json::Value as JsonValue;
+use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::{Stream, StreamExt};
+use tracing::log::warn;
 use crate::error::{Error, Result};
-use crate::api::proto::cms::{GetCmsContentRequest, GetCmsContentResponse, SentContactFormRequest, SentContactFormResponse};
-use crate::api::proto::content::ContentModel;
-use crate::extensions::email_message_builder::EmailMessageBuilder;
+use crate::api::proto::cms::{CmsContentShape, GetCmsContentRequest, GetCmsContentResponse};
+use crate::api::proto::content::ContentModel as GrpcContentModel;
+use crate::models::content_event_model::{ContentEventBus, ContentEventModel};
+use crate::models::content_model::{CmsContentCache, CmsContentCacheKey, ContentModel};
+use crate::providers::avored_cache_provider::CacheStats;
 use crate::providers::avored_database_provider::DB;
-use crate::providers::avored_template_provider::AvoRedTemplateProvider;
 use crate::repositories::content_repository::ContentRepository;
 
+/// How often an open content watch checks that its api key may still watch.
+const WATCH_REAUTHORIZE_SECONDS: u64 = 30;
+
 pub struct CmsService {
     content_repository: ContentRepository,
+    content_cache: Arc<CmsContentCache>,
+    content_event_bus: Arc<ContentEventBus>,
 }
 
 impl CmsService {
-    pub fn new(content_repository: ContentRepository) -> Result<Self> {
+    pub fn new(
+        content_repository: ContentRepository,
+        content_cache: Arc<CmsContentCache>,
+        content_event_bus: Arc<ContentEventBus>,
+    ) -> Result<Self> {
         Ok(CmsService {
-            content_repository
+            content_repository,
+            content_cache,
+            content_event_bus,
         })
     }
 }
 
 impl CmsService {
-    pub async fn sent_contact_form(
+    /// Published content only, a draft is not found.
+    pub async fn find_cms_content(
         &self,
-        template: &AvoRedTemplateProvider,
-        request: SentContactFormRequest,
-    ) -> Result<SentContactFormResponse> {
-        let from_address = String::from("info@avored.com");
-        let to_address = String::from("ind.purvesh@gmail.com");
-        let email_subject = String::from("Contact us message");
-        
-        let payload = SentContactUsEmailRequest {
-            email: request.email,
-            first_name: request.first_name,
-            last_name: request.last_name,
-            message: request.message,
-            phone: request.phone,
+        (datastore, database_session): &DB,
+        content_type: &str,
+        content_identifier: &str,
+    ) -> Result<ContentModel> {
+        let cache_key = CmsContentCacheKey {
+            collection: content_type.to_string(),
+            identifier: content_identifier.to_string(),
         };
-        
-        let sent_contact_email_message_body =
-            template.handlebars.render("contact-us-email", &payload)?;
-    
-        let email_message = Message::builder()
-            .build_email_message(
-                &from_address,
-                &to_address,
-                &email_subject,
-                sent_contact_email_message_body
-            )?;
-    
-        // Send the email
-        match template.mailer.send(email_message).await {
-            Ok(_) =>  {
-                let response = SentContactFormResponse {
-                    status: true
-                };
-                
-                Ok(response)
-            },
-            Err(err) => {
-                error!("there is an issue with sending an email via smtp: {err:?}");
-                Err(Error::Generic(String::from("error while sending an email")))
-            }
+        if let Some(content_model) = self.content_cache.get(&cache_key) {
+            return Ok(content_model);
+        }
+
+        let content_model = self
+            .content_repository
+            .find_by_identifier(datastore, database_session, content_type, content_identifier)
+            .await?;
+        if !content_model.is_published() {
+            return Err(Error::NotFound(content_identifier.to_string()));
         }
+        self.content_cache.insert(cache_key, content_model.clone());
+
+        Ok(content_model)
     }
 
-    pub async fn get_cms_content(
+    /// Content events of the given collections from now on, used by the
+    /// WatchContent stream and the server sent events endpoint.
+    pub fn watch_content(
+        &self,
+        collections: Vec<String>,
+    ) -> impl Stream<Item = ContentEventModel> + Send + 'static {
+        BroadcastStream::new(self.content_event_bus.subscribe()).filter_map(move |event| match event {
+            Ok(event) if collections.contains(&event.collection) => Some(event),
+            Ok(_) => None,
+            Err(BroadcastStreamRecvError::Lagged(skipped)) => {
+                warn!("content watcher is too slow, {skipped} events were skipped");
+                None
+            }
+        })
+    }
+
+    /// Like `watch_content`, but the stream ends once `still_authorized`
+    /// resolves to false, for instance when the api key was revoked.
+    pub fn watch_content_while_authorized<F, Fut>(
+        &self,
+        collections: Vec<String>,
+        still_authorized: F,
+    ) -> impl Stream<Item = ContentEventModel> + Send + 'static
+    where
+        F: FnMut() -> Fut + Send + 'static,
+        Fut: Future<Output = bool> + Send,
+    {
+        let events = Box::pin(self.watch_content(collections));
+        let period = Duration::from_secs(WATCH_REAUTHORIZE_SECONDS);
+        let reauthorize = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+
+        futures::stream::unfold(
+            (events, reauthorize, still_authorized),
+            |(mut events, mut reauthorize, mut still_authorized)| async move {
+                loop {
+                    tokio::select! {
+                        event = events.next() => {
+                            return event.map(|event| (event, (events, reauthorize, still_authorized)));
+                        }
+                        _ = reauthorize.tick() => {
+                            if !still_authorized().await {
+                                return None;
+                            }
+                        }
+                    }
+                }
+            },
+        )
+    }
+
+    pub fn content_cache_stats(&self) -> CacheStats {
+        self.content_cache.stats()
+    }
+
+    /// Applies the request field mask and shape to the (cached) content before it is sent.
+    pub fn get_cms_content(
         &self,
         request: GetCmsContentRequest,
-        (datastore, database_session): &DB
+        content_model: ContentModel,
     ) -> Result<GetCmsContentResponse> {
+        let shape = request.shape();
+        let field_identifiers = request.field_mask.map(|field_mask| field_mask.paths).unwrap_or_default();
+        let mut content_model = content_model.project_fields(&field_identifiers);
+
+        let mut fields = HashMap::new();
+        if shape == CmsContentShape::Compact {
+            fields = content_model
+                .compact_fields()
+                .into_iter()
+                .map(|(identifier, value)| (identifier, grpc_value(value)))
+                .collect();
+            content_model.content_fields = vec![];
+        }
+
+        let grpc_model: GrpcContentModel = content_model.try_into()?;
 
-        let content_model = self
-            .content_repository
-            .find_by_identifier(datastore, database_session, &request.content_type, &request.content_identifier)
-            .await?;
-        let grpc_model: ContentModel = content_model.try_into()?;
-        
         let response = GetCmsContentResponse {
             status: true,
             data: Some(grpc_model),
+            fields,
         };
 
         Ok(response)
@@ -88,11 +156,23 @@ impl CmsService {
 }
 
 
-#[derive(Deserialize, Debug, Clone, Serialize)]
-pub struct SentContactUsEmailRequest {
-    pub email: String,
-    pub first_name: String,
-    pub last_name: String,
-    pub message: String,
-    pub phone: String,
+fn grpc_value(value: JsonValue) -> prost_types::Value {
+    let kind = match value {
+        JsonValue::Null => Kind::NullValue(0),
+        JsonValue::Bool(value) => Kind::BoolValue(value),
+        JsonValue::Number(value) => Kind::NumberValue(value.as_f64().unwrap_or_default()),
+        JsonValue::String(value) => Kind::StringValue(value),
+        JsonValue::Array(values) => Kind::ListValue(ListValue {
+            values: values.into_iter().map(grpc_value).collect(),
+        }),
+        JsonValue::Object(object) => Kind::StructValue(Struct {
+            fields: object
+                .into_iter()
+                .map(|(key, value)| (key, grpc_value(value)))
+                .collect(),
+        }),
+    };
+
+    prost_types::Value { kind: Some(kind) }
 }
+
@@ -1,17 +1,27 @@
+use std::sync::Arc;
 use crate::api::proto::content::{CollectionAllResponse, CollectionModel, ContentModel as ContentModelGrpc, ContentPaginateRequest, ContentPaginateResponse, DeleteContentResponse, GetCollectionRequest, GetCollectionResponse, GetContentRequest, GetContentResponse, PutContentIdentifierRequest, PutContentIdentifierResponse, StoreCollectionRequest, StoreCollectionResponse, StoreContentRequest, StoreContentResponse, UpdateCollectionRequest, UpdateCollectionResponse, UpdateContentRequest, UpdateContentResponse};
 use crate::api::proto::content::content_paginate_response::{ContentPaginateData, ContentPagination as ContentPaginationGrpc};
 use crate::models::ModelCount;
 use crate::providers::avored_database_provider::DB;
 use crate::repositories::content_repository::ContentRepository;
 use crate::error::Result;
-use crate::models::collection_model::{CreatableCollection, UpdatableCollection};
-use crate::models::content_model::{CreatableContentField, CreatableContentModel, PutContentIdentifierModel, UpdatableContentField, UpdatableContentModel};
+use crate::models::collection_model::{CollectionFeedMapping, CreatableCollection, GraphqlSchemaCache, PageMenuCache, UpdatableCollection};
+use crate::models::feed_model::FeedCache;
+use crate::models::sitemap_model::SitemapCache;
+use crate::models::content_event_model::{ContentEventBus, ContentEventModel, ContentEventType};
+use crate::models::content_model::{CmsContentCache, ContentModel, ContentStatus, CreatableContentField, CreatableContentModel, PutContentIdentifierModel, UpdatableContentField, UpdatableContentModel};
 use crate::PER_PAGE;
 use crate::repositories::collection_repository::CollectionRepository;
 
 pub struct ContentService {
     content_repository: ContentRepository,
     collection_repository: CollectionRepository,
+    cms_content_cache: Arc<CmsContentCache>,
+    sitemap_cache: Arc<SitemapCache>,
+    feed_cache: Arc<FeedCache>,
+    page_menu_cache: Arc<PageMenuCache>,
+    graphql_schema_cache: Arc<GraphqlSchemaCache>,
+    content_event_bus: Arc<ContentEventBus>,
 }
 
 impl ContentService {
@@ -130,12 +140,19 @@ impl ContentService {
             name: request.name,
             identifier: request.identifier,
             logged_in_username: logged_in_username.to_string(),
-            content_type: request.content_type,
+            content_type: request.content_type.clone(),
             content_fields: content_field_model,
+            status: request.status.unwrap_or_default().try_into()?,
         };
+        let request_content_type = request.content_type;
         let content_db_model = self.content_repository
             .create_content(datastore, database_session, creatable_page_model)
             .await?;
+        self.invalidate_cms_content(&request_content_type, &content_db_model.id, &content_db_model.identifier);
+        self.publish_content_event(ContentEventType::Created, &request_content_type, &content_db_model);
+        if content_db_model.is_published() {
+            self.publish_content_event(ContentEventType::Published, &request_content_type, &content_db_model);
+        }
         let content_grpc_model: ContentModelGrpc = content_db_model.try_into()?;
         
         let response = StoreContentResponse {
@@ -193,16 +210,29 @@ impl ContentService {
             name: request.name,
             logged_in_username: logged_in_username.to_string(),
             updated_at: Default::default(),
-            content_type: request.content_type,
+            content_type: request.content_type.clone(),
             updated_by: "".to_string(),
             content_fields: content_field_models,
+            status: request.status.map(ContentStatus::try_from).transpose()?,
         };
         
         
+        let was_published = self
+            .content_repository
+            .find_by_id(datastore, database_session, &request.content_type, &updatable_content_model.id)
+            .await
+            .map(|content_model| content_model.is_published())
+            .unwrap_or(false);
+
         let content_db_model = self
             .content_repository
             .update_content(datastore, database_session, updatable_content_model)
             .await?;
+        self.invalidate_cms_content(&request.content_type, &content_db_model.id, &content_db_model.identifier);
+        self.publish_content_event(ContentEventType::Updated, &request.content_type, &content_db_model);
+        if !was_published && content_db_model.is_published() {
+            self.publish_content_event(ContentEventType::Published, &request.content_type, &content_db_model);
+        }
         let content_grpc_model: ContentModelGrpc = content_db_model.try_into()?;
 
         let response = UpdateContentResponse {
@@ -223,11 +253,13 @@ impl ContentService {
             id: request.content_id,
             logged_in_username: logged_in_username.to_string(),
             identifier: request.identifier,
-            content_type: request.content_type,
+            content_type: request.content_type.clone(),
         };
         let content_db_model = self.content_repository
             .update_content_identifier(datastore, database_session, updatable_content_model)
             .await?;
+        self.invalidate_cms_content(&request.content_type, &content_db_model.id, &content_db_model.identifier);
+        self.publish_content_event(ContentEventType::Updated, &request.content_type, &content_db_model);
         let content_grpc_model: ContentModelGrpc = content_db_model.try_into()?;
 
         let response = PutContentIdentifierResponse {
@@ -266,12 +298,16 @@ impl ContentService {
         let creatable_collection = CreatableCollection {
             name: request.name,
             identifier: request.identifier,
+            is_routable: request.is_routable,
+            feed_mapping: request.feed_mapping.map(CollectionFeedMapping::try_from).transpose()?.unwrap_or_default(),
             logged_in_username: logged_in_user_email.to_string(),
         };
         
         let collection_db_model = self.collection_repository
             .create_collection(datastore, database_session, creatable_collection)
             .await?;
+        self.invalidate_sitemap();
+        self.invalidate_graphql_schemas();
         let collection_grpc_model: CollectionModel = collection_db_model.try_into()?;
 
         let response = StoreCollectionResponse {
@@ -293,12 +329,16 @@ impl ContentService {
             id: request.id,
             name: request.name,
             identifier: request.identifier,
+            is_routable: request.is_routable,
+            feed_mapping: request.feed_mapping.map(CollectionFeedMapping::try_from).transpose()?,
             logged_in_username: logged_in_user_email.to_string(),
         };
 
         let collection_db_model = self.collection_repository
             .update_collection(datastore, database_session, updatable_collection)
             .await?;
+        self.invalidate_sitemap();
+        self.invalidate_graphql_schemas();
         let collection_grpc_model: CollectionModel = collection_db_model.try_into()?;
 
         let response = UpdateCollectionResponse {
@@ -336,9 +376,17 @@ impl ContentService {
         content_id: &str,
         content_type: &str
     ) -> Result<DeleteContentResponse> {
+        let content_db_model = self.content_repository
+            .find_by_id(datastore, database_session, content_type, content_id)
+            .await
+            .unwrap_or_else(|_| ContentModel { id: content_id.to_string(), ..Default::default() });
         let delete_status = self.content_repository
             .delete_content(datastore, database_session, content_id, content_type)
             .await?;
+        self.invalidate_cms_content(content_type, content_id, "");
+        if delete_status {
+            self.publish_content_event(ContentEventType::Deleted, content_type, &content_db_model);
+        }
 
         let contemessage = DeleteContentResponse {
             status: delete_status
@@ -431,8 +479,45 @@ impl ContentService {
     //         })
     //     }
 
-    pub fn new(content_repository: ContentRepository, collection_repository: CollectionRepository) -> Result<Self> {
-        Ok(ContentService { content_repository, collection_repository })
+    /// Drops the cached cms reads of a content record, matched by id so a
+    /// renamed identifier is evicted as well as the new one.
+    fn invalidate_cms_content(&self, content_type: &str, content_id: &str, identifier: &str) {
+        self.cms_content_cache.invalidate(|key, content_model| {
+            key.collection == content_type
+                && (content_model.id == content_id || key.identifier == identifier)
+        });
+        self.invalidate_sitemap();
+    }
+
+    fn publish_content_event(&self, event_type: ContentEventType, content_type: &str, content_model: &ContentModel) {
+        self.content_event_bus
+            .publish(ContentEventModel::new(event_type, content_type, content_model));
+    }
+
+    /// The sitemap, the feeds and the theme menus are rebuilt on the next request
+    /// after any content or collection change.
+    fn invalidate_sitemap(&self) {
+        self.sitemap_cache.invalidate(|_, _| true);
+        self.feed_cache.invalidate(|_, _| true);
+        self.page_menu_cache.invalidate(|_, _| true);
+    }
+
+    /// The schemas have a type per collection, any collection change rebuilds them.
+    fn invalidate_graphql_schemas(&self) {
+        self.graphql_schema_cache.invalidate(|_, _| true);
+    }
+
+    pub fn new(
+        content_repository: ContentRepository,
+        collection_repository: CollectionRepository,
+        cms_content_cache: Arc<CmsContentCache>,
+        sitemap_cache: Arc<SitemapCache>,
+        feed_cache: Arc<FeedCache>,
+        page_menu_cache: Arc<PageMenuCache>,
+        graphql_schema_cache: Arc<GraphqlSchemaCache>,
+        content_event_bus: Arc<ContentEventBus>,
+    ) -> Result<Self> {
+        Ok(ContentService { content_repository, collection_repository, cms_content_cache, sitemap_cache, feed_cache, page_menu_cache, graphql_schema_cache, content_event_bus })
     }
 }
 
@@ -0,0 +1,405 @@
+use std::sync::Arc;
+use std::time::Duration;
+use lettre::message::header::ContentType;
+use lettre::message::Mailbox;
+use lettre::Message;
+use surrealdb::dbs::Session;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::Datetime;
+use tracing::{error, info, warn};
+use crate::api::proto::email::email_paginate_response::{EmailPaginateData, EmailPagination};
+use crate::api::proto::email::{EmailModel as EmailModelGrpc, EmailPaginateResponse, ResendEmailResponse};
+use rust_i18n::t;
+use crate::error::{Error, Result};
+use crate::extensions::email_message_builder::EmailMessageBuilder;
+use crate::models::email_model::{
+    CreatableEmailModel, EmailModel, EmailSenderModel, EmailStatus, UpdatableEmailModel,
+};
+use crate::models::email_template_model::is_sensitive_email_template;
+use crate::models::validation_error::{ErrorMessage, ErrorResponse};
+use crate::providers::avored_clock_provider::AvoRedClockProvider;
+use crate::providers::avored_database_provider::DB;
+use crate::providers::avored_mail_provider::AvoRedMailProvider;
+use crate::providers::avored_template_provider::AvoRedTemplateProvider;
+use crate::providers::avored_worker_provider::AvoRedWorkerProvider;
+use crate::repositories::email_repository::EmailRepository;
+use crate::repositories::setting_repository::SettingRepository;
+use crate::PER_PAGE;
+
+const SENDER_NAME_SETTING: &str = "email_sender_name";
+const SENDER_ADDRESS_SETTING: &str = "email_sender_address";
+const REPLY_TO_SETTING: &str = "email_reply_to";
+/// Content type of an attachment whose stored content type does not parse.
+const ATTACHMENT_FALLBACK_CONTENT_TYPE: &str = "application/octet-stream";
+
+/// The worker also wakes up on this interval to pick up retries that became due.
+const OUTBOX_POLL_SECONDS: u64 = 5;
+const OUTBOX_BATCH_SIZE: i64 = 20;
+
+/// Longest wait between two attempts, whatever the retry base is.
+const MAX_RETRY_DELAY_SECONDS: i64 = 6 * 60 * 60;
+
+pub struct EmailService {
+    email_repository: EmailRepository,
+    setting_repository: SettingRepository,
+    outbox_worker: AvoRedWorkerProvider,
+    attachment_fallback_content_type: ContentType,
+}
+
+impl EmailService {
+    pub fn new(
+        email_repository: EmailRepository,
+        setting_repository: SettingRepository,
+        max_attempts: i64,
+        retry_base_seconds: i64,
+        clock: Arc<AvoRedClockProvider>,
+    ) -> Result<Self> {
+        Ok(EmailService {
+            email_repository,
+            setting_repository,
+            outbox_worker: AvoRedWorkerProvider::register(
+                Duration::from_secs(OUTBOX_POLL_SECONDS),
+                max_attempts,
+                retry_base_seconds,
+                MAX_RETRY_DELAY_SECONDS,
+                clock,
+            ),
+            attachment_fallback_content_type: ContentType::parse(ATTACHMENT_FALLBACK_CONTENT_TYPE)
+                .map_err(|e| Error::Generic(e.to_string()))?,
+        })
+    }
+}
+
+impl EmailService {
+    /// Stores the email in the outbox, sent from the current sender settings,
+    /// and wakes up the worker. The caller does not wait for the mail server.
+    /// Nothing is stored while the sender address setting is missing.
+    pub async fn queue_email(
+        &self,
+        (datastore, database_session): &DB,
+        creatable_email: CreatableEmailModel,
+    ) -> Result<EmailModel> {
+        let email_sender = self.email_sender(datastore, database_session).await?;
+        let email_model = self
+            .email_repository
+            .create_email(datastore, database_session, creatable_email, email_sender)
+            .await?;
+        self.outbox_worker.notify();
+
+        Ok(email_model)
+    }
+
+    pub async fn email_paginate(
+        &self,
+        (datastore, database_session): &DB,
+        status: &str,
+        current_page: i64,
+    ) -> Result<EmailPaginateResponse> {
+        let total_count = self
+            .email_repository
+            .get_total_count(datastore, database_session, status)
+            .await?;
+
+        let start = current_page * PER_PAGE as i64;
+        let email_models = self
+            .email_repository
+            .paginate(datastore, database_session, status, start)
+            .await?;
+
+        let mut email_grpc_models = vec![];
+        for email_model in email_models {
+            let email_grpc_model: EmailModelGrpc = email_model.try_into()?;
+            email_grpc_models.push(email_grpc_model);
+        }
+
+        let pagination = EmailPagination {
+            total: total_count.total,
+        };
+        let paginate_data = EmailPaginateData {
+            pagination: Some(pagination),
+            data: email_grpc_models,
+        };
+
+        let response = EmailPaginateResponse {
+            status: true,
+            data: Some(paginate_data),
+        };
+
+        Ok(response)
+    }
+
+    /// Puts the email back in the outbox with a fresh set of attempts.
+    pub async fn resend_email(&self, (datastore, database_session): &DB, email_id: &str) -> Result<ResendEmailResponse> {
+        let email_model = self
+            .email_repository
+            .find_by_id(datastore, database_session, email_id)
+            .await?;
+
+        // a sent sensitive email no longer has a body to send.
+        if is_sensitive_email_template(&email_model.template) && email_model.body.is_empty() {
+            let error_response = ErrorResponse {
+                status: false,
+                errors: vec![ErrorMessage {
+                    key: String::from("email_id"),
+                    message: t!("email_not_resendable").to_string(),
+                }],
+            };
+            return Err(Error::InvalidArgument(serde_json::to_string(&error_response)?));
+        }
+
+        let updatable_email = UpdatableEmailModel {
+            id: email_model.id,
+            status: EmailStatus::Pending,
+            attempts: 0,
+            error: String::from(""),
+            next_attempt_at: Datetime::default(),
+            clear_body: false,
+        };
+        let email_model = self
+            .email_repository
+            .update_attempt(datastore, database_session, updatable_email)
+            .await?;
+        self.outbox_worker.notify();
+
+        let response = ResendEmailResponse {
+            status: true,
+            data: Some(email_model.try_into()?),
+        };
+
+        Ok(response)
+    }
+
+    /// Sends due emails one after the other. Runs for the lifetime of the server.
+    pub async fn run_outbox_worker(&self, db: &DB, template: &AvoRedTemplateProvider) {
+        self.outbox_worker
+            .run("email outbox", || self.send_due(db, &template.mailer))
+            .await
+    }
+
+    async fn send_due(&self, (datastore, database_session): &DB, mailer: &AvoRedMailProvider) -> Result<()> {
+        let email_models = self
+            .email_repository
+            .due(datastore, database_session, self.outbox_worker.now(), OUTBOX_BATCH_SIZE)
+            .await?;
+
+        for email_model in email_models {
+            let updatable_email = self.attempt(mailer, &email_model).await;
+
+            self.email_repository
+                .update_attempt(datastore, database_session, updatable_email)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Sends the email once and works out when, if ever, to try again. Bad
+    /// addresses, missing attachments and permanent smtp replies go straight
+    /// to the dead letter list.
+    async fn attempt(&self, mailer: &AvoRedMailProvider, email_model: &EmailModel) -> UpdatableEmailModel {
+        let attempts = email_model.attempts + 1;
+
+        let mut attachments = vec![];
+        let mut attachment_error = None;
+        for attachment in &email_model.attachments {
+            match tokio::fs::read(&attachment.path).await {
+                Ok(content) => {
+                    let content_type = ContentType::parse(&attachment.content_type)
+                        .unwrap_or_else(|_| self.attachment_fallback_content_type.clone());
+                    attachments.push((attachment.file_name.clone(), content_type, content));
+                }
+                Err(e) => {
+                    attachment_error = Some(format!("unable to read the attachment {}: {e}", attachment.path));
+                    break;
+                }
+            }
+        }
+
+        let email_message = match attachment_error {
+            Some(error) => Err(error),
+            None => Message::builder()
+                .build_email_message(email_model, attachments)
+                .map_err(|e| e.to_string()),
+        };
+        let result = match email_message {
+            Ok(email_message) => mailer
+                .send(email_message)
+                .await
+                .map_err(|e| (e.is_permanent, e.message)),
+            Err(error) => Err((true, error)),
+        };
+
+        let (status, error, next_attempt_at) = match result {
+            Ok(()) => (EmailStatus::Sent, String::from(""), email_model.next_attempt_at.clone()),
+            Err((is_permanent, error)) if is_permanent || self.outbox_worker.is_last_attempt(attempts) => {
+                (EmailStatus::Dead, error, email_model.next_attempt_at.clone())
+            }
+            Err((_, error)) => (EmailStatus::Pending, error, self.outbox_worker.next_attempt_at(attempts)),
+        };
+
+        match status {
+            EmailStatus::Dead => warn!(
+                "email {} to {} moved to the dead letter list after attempt {attempts}: {error}",
+                email_model.id, email_model.to_address
+            ),
+            _ => info!(
+                "email {} to {} attempt {attempts}: {status:?}",
+                email_model.id, email_model.to_address
+            ),
+        }
+
+        let clear_body = status == EmailStatus::Sent && is_sensitive_email_template(&email_model.template);
+
+        UpdatableEmailModel {
+            id: email_model.id.clone(),
+            status,
+            attempts,
+            error,
+            next_attempt_at,
+            clear_body,
+        }
+    }
+
+    /// `Name <address>` from the sender settings. The sender address is
+    /// required, an invalid reply-to is left out.
+    async fn email_sender(&self, datastore: &Datastore, database_session: &Session) -> Result<EmailSenderModel> {
+        let sender_name = self.setting_value(datastore, database_session, SENDER_NAME_SETTING).await;
+        let sender_address = self.setting_value(datastore, database_session, SENDER_ADDRESS_SETTING).await;
+        let reply_to = self.setting_value(datastore, database_session, REPLY_TO_SETTING).await;
+
+        let sender_address = match sender_address.parse::<lettre::Address>() {
+            Ok(address) => address,
+            Err(_) => {
+                error!(
+                    "the {SENDER_ADDRESS_SETTING} setting is missing or not a valid email address, \
+                     no email is sent until it is set"
+                );
+                return Err(Error::ConfigMissing(String::from(SENDER_ADDRESS_SETTING)));
+            }
+        };
+        let sender_name = match sender_name.is_empty() {
+            true => None,
+            false => Some(sender_name),
+        };
+        let reply_to = match reply_to.parse::<Mailbox>() {
+            Ok(mailbox) => mailbox.to_string(),
+            Err(_) => {
+                if !reply_to.is_empty() {
+                    warn!("the {REPLY_TO_SETTING} setting is not a valid email address, leaving it out");
+                }
+                String::from("")
+            }
+        };
+
+        Ok(EmailSenderModel {
+            from_address: Mailbox::new(sender_name, sender_address).to_string(),
+            reply_to,
+        })
+    }
+
+    async fn setting_value(&self, datastore: &Datastore, database_session: &Session, identifier: &str) -> String {
+        self.setting_repository
+            .find_by_identifier(datastore, database_session, identifier)
+            .await
+            .map(|setting_model| setting_model.value.trim().to_string())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use lettre::transport::stub::AsyncStubTransport;
+    use crate::error::Error;
+    use crate::models::email_model::{CreatableEmailModel, EmailModel, EmailStatus};
+    use crate::providers::avored_clock_provider::AvoRedClockProvider;
+    use crate::providers::avored_database_provider::DB;
+    use crate::providers::avored_mail_provider::AvoRedMailProvider;
+    use crate::repositories::email_repository::EmailRepository;
+    use crate::repositories::setting_repository::SettingRepository;
+    use crate::test_helpers::memory_db;
+    use super::EmailService;
+
+    async fn queue(email_service: &EmailService, db: &DB, template: &str) -> EmailModel {
+        let creatable_email = CreatableEmailModel {
+            to_address: String::from("jane@example.com"),
+            subject: String::from("Hello"),
+            body: String::from("<p>Hello <b>Jane</b></p>"),
+            text_body: String::from(""),
+            attachments: vec![],
+            template: template.to_string(),
+        };
+
+        email_service.queue_email(db, creatable_email).await.unwrap()
+    }
+
+    async fn reload((datastore, database_session): &DB, email_model: &EmailModel) -> EmailModel {
+        EmailRepository::new()
+            .find_by_id(datastore, database_session, &email_model.id)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn outbox_sends_retries_and_dead_letters() {
+        let db = memory_db("email_test").await;
+        let clock = Arc::new(AvoRedClockProvider::register());
+        let email_service =
+            EmailService::new(EmailRepository::new(), SettingRepository::new(), 2, 1, clock.clone()).unwrap();
+
+        // nothing is queued, or sent from a made up address, without a sender.
+        let creatable_email = CreatableEmailModel {
+            to_address: String::from("jane@example.com"),
+            subject: String::from("Hello"),
+            body: String::from("<p>Hello</p>"),
+            text_body: String::from(""),
+            attachments: vec![],
+            template: String::from("form-submission-email"),
+        };
+        assert!(matches!(
+            email_service.queue_email(&db, creatable_email).await,
+            Err(Error::ConfigMissing(_))
+        ));
+
+        let (datastore, database_session) = &db;
+        SettingRepository::new()
+            .update_setting_by_identifier(datastore, database_session, "email_sender_address", "cms@example.com", "admin")
+            .await
+            .unwrap();
+
+        // a sent sensitive email keeps no body.
+        let sent_email = queue(&email_service, &db, "forgot-password").await;
+        let transport = AsyncStubTransport::new_ok();
+        let mailer = AvoRedMailProvider::Memory(transport.clone());
+        email_service.send_due(&db, &mailer).await.unwrap();
+
+        let sent_email = reload(&db, &sent_email).await;
+        assert_eq!(sent_email.status, EmailStatus::Sent);
+        assert_eq!(sent_email.attempts, 1);
+        assert_eq!(sent_email.from_address, "cms@example.com");
+        assert!(sent_email.body.is_empty());
+        assert_eq!(transport.messages().await.len(), 1);
+
+        // a failed send waits for its retry, then moves to the dead letter list.
+        let failing_email = queue(&email_service, &db, "form-submission-email").await;
+        let mailer = AvoRedMailProvider::Memory(AsyncStubTransport::new_error());
+        email_service.send_due(&db, &mailer).await.unwrap();
+
+        let failing_email = reload(&db, &failing_email).await;
+        assert_eq!(failing_email.status, EmailStatus::Pending);
+        assert_eq!(failing_email.attempts, 1);
+        assert!(!failing_email.error.is_empty());
+        assert!(failing_email.next_attempt_at.to_utc() > clock.now());
+
+        email_service.send_due(&db, &mailer).await.unwrap();
+        assert_eq!(reload(&db, &failing_email).await.attempts, 1);
+
+        clock.advance(2);
+        email_service.send_due(&db, &mailer).await.unwrap();
+
+        let failing_email = reload(&db, &failing_email).await;
+        assert_eq!(failing_email.status, EmailStatus::Dead);
+        assert_eq!(failing_email.attempts, 2);
+        assert!(!failing_email.body.is_empty());
+    }
+}
@@ -0,0 +1,249 @@
+use std::time::SystemTime;
+use prost_types::Timestamp;
+use tracing::{error, info};
+use crate::api::proto::email::{
+    EmailTemplateAllResponse, EmailTemplateModel as EmailTemplateModelGrpc, GetEmailTemplateResponse,
+    PreviewEmailTemplateRequest, PreviewEmailTemplateResponse, StoreEmailTemplateRequest,
+    StoreEmailTemplateResponse, UpdateEmailTemplateRequest, UpdateEmailTemplateResponse,
+};
+use crate::error::Result;
+use crate::models::email_template_model::{
+    email_template_sample_data, CreatableEmailTemplateModel, EmailTemplateModel, RenderedEmailModel,
+    UpdatableEmailTemplateModel, EMAIL_TEMPLATES,
+};
+use crate::models::ModelCount;
+use crate::providers::avored_database_provider::DB;
+use crate::providers::avored_template_provider::AvoRedTemplateProvider;
+use crate::repositories::email_template_repository::EmailTemplateRepository;
+
+pub struct EmailTemplateService {
+    email_template_repository: EmailTemplateRepository,
+}
+
+impl EmailTemplateService {
+    pub fn new(email_template_repository: EmailTemplateRepository) -> Result<Self> {
+        Ok(EmailTemplateService {
+            email_template_repository,
+        })
+    }
+}
+
+impl EmailTemplateService {
+    /// Registers every stored template over the files on disk. A template that
+    /// no longer compiles is logged and the file on disk stays in use.
+    pub async fn register_stored_templates(
+        &self,
+        (datastore, database_session): &DB,
+        template: &AvoRedTemplateProvider,
+    ) -> Result<()> {
+        let email_template_models = self
+            .email_template_repository
+            .all(datastore, database_session)
+            .await?;
+
+        for email_template_model in email_template_models {
+            match template.register_email_template(&email_template_model.identifier, &source(&email_template_model)) {
+                Ok(()) => info!("using the stored {} email template", email_template_model.identifier),
+                Err(e) => error!(
+                    "unable to register the stored {} email template: {e:?}",
+                    email_template_model.identifier
+                ),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Lists every email the application sends, with the stored template when
+    /// there is one and the file on disk otherwise.
+    pub async fn email_template_all(
+        &self,
+        (datastore, database_session): &DB,
+        template: &AvoRedTemplateProvider,
+    ) -> Result<EmailTemplateAllResponse> {
+        let email_template_models = self
+            .email_template_repository
+            .all(datastore, database_session)
+            .await?;
+
+        let mut email_template_grpc_models = vec![];
+        for definition in EMAIL_TEMPLATES.iter() {
+            let stored_model = email_template_models
+                .iter()
+                .find(|email_template_model| email_template_model.identifier == definition.identifier);
+
+            let email_template_grpc_model: EmailTemplateModelGrpc = match stored_model {
+                Some(email_template_model) => email_template_model.clone().try_into()?,
+                None => {
+                    let default_template = template
+                        .default_email_template(definition.identifier)
+                        .cloned()
+                        .unwrap_or_default();
+                    let now = Timestamp::from(SystemTime::now());
+
+                    EmailTemplateModelGrpc {
+                        id: String::from(""),
+                        identifier: definition.identifier.to_string(),
+                        name: definition.name.to_string(),
+                        subject: default_template.subject,
+                        html_body: default_template.html_body,
+                        text_body: default_template.text_body,
+                        is_default: true,
+                        created_at: Some(now),
+                        updated_at: Some(now),
+                        created_by: String::from(""),
+                        updated_by: String::from(""),
+                    }
+                }
+            };
+            email_template_grpc_models.push(email_template_grpc_model);
+        }
+
+        let response = EmailTemplateAllResponse {
+            status: true,
+            data: email_template_grpc_models,
+        };
+
+        Ok(response)
+    }
+
+    pub async fn get_email_template(
+        &self,
+        (datastore, database_session): &DB,
+        email_template_id: &str,
+    ) -> Result<GetEmailTemplateResponse> {
+        let email_template_model = self
+            .email_template_repository
+            .find_by_id(datastore, database_session, email_template_id)
+            .await?;
+
+        let response = GetEmailTemplateResponse {
+            status: true,
+            data: Some(email_template_model.try_into()?),
+        };
+
+        Ok(response)
+    }
+
+    pub async fn count_of_identifier(&self, (datastore, database_session): &DB, identifier: &str) -> Result<ModelCount> {
+        self.email_template_repository
+            .count_of_identifier(datastore, database_session, identifier)
+            .await
+    }
+
+    /// Stores the template and starts using it for new emails straight away.
+    pub async fn store_email_template(
+        &self,
+        (datastore, database_session): &DB,
+        template: &AvoRedTemplateProvider,
+        request: StoreEmailTemplateRequest,
+        logged_in_username: String,
+    ) -> Result<StoreEmailTemplateResponse> {
+        let creatable_email_template = CreatableEmailTemplateModel {
+            identifier: request.identifier,
+            subject: request.subject,
+            html_body: request.html_body,
+            text_body: request.text_body,
+            logged_in_username,
+        };
+
+        let email_template_model = self
+            .email_template_repository
+            .create_email_template(datastore, database_session, creatable_email_template)
+            .await?;
+        template.register_email_template(&email_template_model.identifier, &source(&email_template_model))?;
+
+        let response = StoreEmailTemplateResponse {
+            status: true,
+            data: Some(email_template_model.try_into()?),
+        };
+
+        Ok(response)
+    }
+
+    pub async fn update_email_template(
+        &self,
+        (datastore, database_session): &DB,
+        template: &AvoRedTemplateProvider,
+        request: UpdateEmailTemplateRequest,
+        logged_in_username: String,
+    ) -> Result<UpdateEmailTemplateResponse> {
+        let updatable_email_template = UpdatableEmailTemplateModel {
+            id: request.email_template_id,
+            subject: request.subject,
+            html_body: request.html_body,
+            text_body: request.text_body,
+            logged_in_username,
+        };
+
+        let email_template_model = self
+            .email_template_repository
+            .update_email_template(datastore, database_session, updatable_email_template)
+            .await?;
+        template.register_email_template(&email_template_model.identifier, &source(&email_template_model))?;
+
+        let response = UpdateEmailTemplateResponse {
+            status: true,
+            data: Some(email_template_model.try_into()?),
+        };
+
+        Ok(response)
+    }
+
+    /// Removes the stored template, new emails use the file on disk again.
+    pub async fn delete_email_template(
+        &self,
+        (datastore, database_session): &DB,
+        template: &AvoRedTemplateProvider,
+        email_template_id: &str,
+    ) -> Result<bool> {
+        let email_template_model = self
+            .email_template_repository
+            .find_by_id(datastore, database_session, email_template_id)
+            .await?;
+
+        self.email_template_repository
+            .delete_by_id(datastore, database_session, email_template_id)
+            .await?;
+        template.restore_email_template(&email_template_model.identifier)?;
+
+        Ok(true)
+    }
+
+    /// Renders the posted sources with the given JSON data, or with sample
+    /// data shaped like the real email when none is given.
+    pub fn preview_email_template(
+        &self,
+        template: &AvoRedTemplateProvider,
+        request: PreviewEmailTemplateRequest,
+    ) -> Result<PreviewEmailTemplateResponse> {
+        let data = match request.data.filter(|data| !data.trim().is_empty()) {
+            Some(data) => serde_json::from_str(&data)?,
+            None => email_template_sample_data(&request.identifier),
+        };
+        let preview_source = RenderedEmailModel {
+            subject: request.subject,
+            html_body: request.html_body,
+            text_body: request.text_body,
+        };
+
+        let rendered_email = template.preview_email(&preview_source, &data)?;
+
+        let response = PreviewEmailTemplateResponse {
+            status: true,
+            subject: rendered_email.subject,
+            html_body: rendered_email.html_body,
+            text_body: rendered_email.text_body,
+        };
+
+        Ok(response)
+    }
+}
+
+fn source(email_template_model: &EmailTemplateModel) -> RenderedEmailModel {
+    RenderedEmailModel {
+        subject: email_template_model.subject.clone(),
+        html_body: email_template_model.html_body.clone(),
+        text_body: email_template_model.text_body.clone(),
+    }
+}
@@ -0,0 +1,281 @@
+use std::sync::Arc;
+use chrono::{DateTime, NaiveDate, Utc};
+use surrealdb::dbs::Session;
+use surrealdb::kvs::Datastore;
+use crate::error::{Error, Result};
+use crate::extensions::xml_extension::{W3cDatetimeExtension, XmlExtension};
+use crate::models::collection_model::CollectionModel;
+use crate::models::content_model::{ContentFieldDataType, ContentModel};
+use crate::models::feed_model::{FeedCache, FeedItemModel};
+use crate::providers::avored_database_provider::DB;
+use crate::repositories::collection_repository::CollectionRepository;
+use crate::repositories::content_repository::ContentRepository;
+use crate::repositories::setting_repository::SettingRepository;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FeedFormat {
+    Rss,
+    Atom,
+}
+
+pub struct FeedService {
+    content_repository: ContentRepository,
+    collection_repository: CollectionRepository,
+    setting_repository: SettingRepository,
+    feed_cache: Arc<FeedCache>,
+    base_url: String,
+    feed_items: i64,
+}
+
+impl FeedService {
+    pub fn new(
+        content_repository: ContentRepository,
+        collection_repository: CollectionRepository,
+        setting_repository: SettingRepository,
+        feed_cache: Arc<FeedCache>,
+        base_url: String,
+        feed_items: i64,
+    ) -> Result<Self> {
+        Ok(FeedService {
+            content_repository,
+            collection_repository,
+            setting_repository,
+            feed_cache,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            feed_items,
+        })
+    }
+}
+
+impl FeedService {
+    /// Latest published entries of a routable collection as RSS 2.0 or Atom.
+    pub async fn collection_feed(
+        &self,
+        (datastore, database_session): &DB,
+        collection: &str,
+        feed_format: FeedFormat,
+    ) -> Result<String> {
+        let collection_model = self
+            .collection_repository
+            .find_by_identifier(datastore, database_session, collection)
+            .await
+            .map_err(|_| Error::NotFound(format!("collection {collection}")))?;
+        if !collection_model.is_routable {
+            return Err(Error::NotFound(format!("collection {collection}")));
+        }
+
+        let site_name = self
+            .setting_repository
+            .find_by_identifier(datastore, database_session, "general_site_name")
+            .await
+            .map(|setting_model| setting_model.value)
+            .unwrap_or_default();
+        let feed_items = self.feed_items(datastore, database_session, &collection_model).await?;
+
+        let title = match site_name.is_empty() {
+            true => collection_model.name.clone(),
+            false => format!("{} - {}", site_name, collection_model.name),
+        };
+
+        let xml = match feed_format {
+            FeedFormat::Rss => self.rss_xml(&collection_model, &title, &feed_items),
+            FeedFormat::Atom => self.atom_xml(&collection_model, &title, &site_name, &feed_items),
+        };
+
+        Ok(xml)
+    }
+
+    /// Entries of the feed, newest first, cached until the next content or collection write.
+    async fn feed_items(
+        &self,
+        datastore: &Datastore,
+        database_session: &Session,
+        collection_model: &CollectionModel,
+    ) -> Result<Vec<FeedItemModel>> {
+        if let Some(feed_items) = self.feed_cache.get(&collection_model.identifier) {
+            return Ok(feed_items);
+        }
+
+        // The mapped date lives inside the content fields, so those entries are
+        // ordered here, newest first, before the feed is cut to its size.
+        let content_models = match collection_model.feed_mapping.date_field.is_empty() {
+            true => {
+                self.content_repository
+                    .latest_published(datastore, database_session, &collection_model.identifier, self.feed_items)
+                    .await?
+            }
+            false => {
+                self.content_repository
+                    .all_published_content(datastore, database_session, &collection_model.identifier)
+                    .await?
+            }
+        };
+
+        let mut feed_items = content_models
+            .iter()
+            .map(|content_model| self.feed_item(collection_model, content_model))
+            .collect::<Vec<FeedItemModel>>();
+        feed_items.sort_by(|a, b| b.date.cmp(&a.date));
+        feed_items.truncate(usize::try_from(self.feed_items).unwrap_or_default());
+
+        self.feed_cache
+            .insert(collection_model.identifier.clone(), feed_items.clone());
+
+        Ok(feed_items)
+    }
+
+    fn feed_item(&self, collection_model: &CollectionModel, content_model: &ContentModel) -> FeedItemModel {
+        let feed_mapping = &collection_model.feed_mapping;
+
+        let title = field_text(content_model, &feed_mapping.title_field)
+            .unwrap_or_else(|| content_model.name.clone());
+        let summary = field_text(content_model, &feed_mapping.summary_field).unwrap_or_default();
+        let content = field_text(content_model, &feed_mapping.content_field).unwrap_or_default();
+        let date = field_text(content_model, &feed_mapping.date_field)
+            .and_then(|value| parse_date(&value))
+            .unwrap_or_else(|| content_model.created_at.to_utc());
+
+        FeedItemModel {
+            title,
+            link: format!(
+                "{}/{}/{}",
+                self.base_url, collection_model.identifier, content_model.identifier
+            ),
+            summary,
+            content,
+            date,
+            updated_at: content_model.updated_at.to_utc(),
+        }
+    }
+
+    fn rss_xml(&self, collection_model: &CollectionModel, title: &str, feed_items: &[FeedItemModel]) -> String {
+        let feed_url = format!("{}/feeds/{}.xml", self.base_url, collection_model.identifier);
+        let last_build_date = feed_items
+            .iter()
+            .map(|item| item.updated_at)
+            .max()
+            .unwrap_or_else(Utc::now);
+
+        let mut xml = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <rss version=\"2.0\" xmlns:atom=\"http://www.w3.org/2005/Atom\" \
+             xmlns:content=\"http://purl.org/rss/1.0/modules/content/\">\n  <channel>\n",
+        );
+        xml.push_str(&format!("    <title>{}</title>\n", title.xml_escape()));
+        xml.push_str(&format!("    <link>{}/</link>\n", self.base_url.xml_escape()));
+        xml.push_str(&format!("    <description>{}</description>\n", collection_model.name.xml_escape()));
+        xml.push_str(&format!(
+            "    <atom:link href=\"{}\" rel=\"self\" type=\"application/rss+xml\"/>\n",
+            feed_url.xml_escape()
+        ));
+        xml.push_str(&format!("    <lastBuildDate>{}</lastBuildDate>\n", last_build_date.to_rfc2822()));
+
+        for item in feed_items {
+            xml.push_str("    <item>\n");
+            xml.push_str(&format!("      <title>{}</title>\n", item.title.xml_escape()));
+            xml.push_str(&format!("      <link>{}</link>\n", item.link.xml_escape()));
+            xml.push_str(&format!("      <guid isPermaLink=\"true\">{}</guid>\n", item.link.xml_escape()));
+            xml.push_str(&format!("      <pubDate>{}</pubDate>\n", item.date.to_rfc2822()));
+            if !item.summary.is_empty() {
+                xml.push_str(&format!("      <description>{}</description>\n", item.summary.xml_escape()));
+            }
+            if !item.content.is_empty() {
+                xml.push_str(&format!(
+                    "      <content:encoded>{}</content:encoded>\n",
+                    item.content.xml_escape()
+                ));
+            }
+            xml.push_str("    </item>\n");
+        }
+        xml.push_str("  </channel>\n</rss>\n");
+
+        xml
+    }
+
+    fn atom_xml(
+        &self,
+        collection_model: &CollectionModel,
+        title: &str,
+        site_name: &str,
+        feed_items: &[FeedItemModel],
+    ) -> String {
+        let feed_url = format!("{}/feeds/{}.atom", self.base_url, collection_model.identifier);
+        let updated = feed_items
+            .iter()
+            .map(|item| item.updated_at)
+            .max()
+            .unwrap_or_else(Utc::now);
+        let author = match site_name.is_empty() {
+            true => collection_model.name.as_str(),
+            false => site_name,
+        };
+
+        let mut xml = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<feed xmlns=\"http://www.w3.org/2005/Atom\">\n",
+        );
+        xml.push_str(&format!("  <title>{}</title>\n", title.xml_escape()));
+        xml.push_str(&format!("  <id>{}</id>\n", feed_url.xml_escape()));
+        xml.push_str(&format!("  <link rel=\"self\" href=\"{}\"/>\n", feed_url.xml_escape()));
+        xml.push_str(&format!("  <link href=\"{}/\"/>\n", self.base_url.xml_escape()));
+        xml.push_str(&format!("  <updated>{}</updated>\n", updated.to_w3c_datetime()));
+        xml.push_str(&format!("  <author><name>{}</name></author>\n", author.xml_escape()));
+
+        for item in feed_items {
+            xml.push_str("  <entry>\n");
+            xml.push_str(&format!("    <title>{}</title>\n", item.title.xml_escape()));
+            xml.push_str(&format!("    <id>{}</id>\n", item.link.xml_escape()));
+            xml.push_str(&format!("    <link href=\"{}\"/>\n", item.link.xml_escape()));
+            xml.push_str(&format!("    <published>{}</published>\n", item.date.to_w3c_datetime()));
+            xml.push_str(&format!("    <updated>{}</updated>\n", item.updated_at.to_w3c_datetime()));
+            if !item.summary.is_empty() {
+                xml.push_str(&format!("    <summary>{}</summary>\n", item.summary.xml_escape()));
+            }
+            if !item.content.is_empty() {
+                xml.push_str(&format!("    <content type=\"html\">{}</content>\n", item.content.xml_escape()));
+            }
+            xml.push_str("  </entry>\n");
+        }
+        xml.push_str("</feed>\n");
+
+        xml
+    }
+}
+
+/// Text of a content field, numbers are printed and arrays joined.
+/// Returns `None` when the field is not mapped, missing or empty.
+fn field_text(content_model: &ContentModel, identifier: &str) -> Option<String> {
+    if identifier.is_empty() {
+        return None;
+    }
+    let content_field = content_model
+        .content_fields
+        .iter()
+        .find(|content_field| content_field.identifier == identifier)?;
+    let field_content = &content_field.field_content;
+
+    let text = match content_field.data_type {
+        ContentFieldDataType::Text => field_content.text_value.clone().unwrap_or_default(),
+        ContentFieldDataType::Int => field_content.int_value.map(|v| v.to_string()).unwrap_or_default(),
+        ContentFieldDataType::Float => field_content.float_value.map(|v| v.to_string()).unwrap_or_default(),
+        ContentFieldDataType::Bool => field_content.bool_value.map(|v| v.to_string()).unwrap_or_default(),
+        ContentFieldDataType::Array => field_content.array_value.join(", "),
+    };
+
+    match text.trim().is_empty() {
+        true => None,
+        false => Some(text),
+    }
+}
+
+/// Date fields are stored as text, either a full RFC 3339 timestamp or a `YYYY-MM-DD` date.
+fn parse_date(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    if let Ok(date_time) = DateTime::parse_from_rfc3339(value) {
+        return Some(date_time.with_timezone(&Utc));
+    }
+
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|date_time| date_time.and_utc())
+}
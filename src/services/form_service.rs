@@ -0,0 +1,518 @@
+use std::collections::BTreeMap;
+use std::path::{Component, Path, PathBuf};
+use rand::distr::Alphanumeric;
+use rand::Rng;
+use rust_i18n::t;
+use serde::Serialize;
+use tracing::error;
+use crate::api::proto::form::form_submission_paginate_response::{FormSubmissionPaginateData, FormSubmissionPagination};
+use crate::api::proto::form::{
+    ExportFormSubmissionsResponse, FormAllResponse, FormModel as FormModelGrpc,
+    FormSubmissionModel as FormSubmissionModelGrpc, FormSubmissionPaginateResponse, GetFormResponse,
+    StoreFormRequest, StoreFormResponse, UpdateFormRequest, UpdateFormResponse,
+};
+use crate::api::proto::cms::SubmitFormResponse;
+use crate::error::{Error, Result};
+use crate::models::email_model::{CreatableEmailModel, EmailAttachmentModel};
+use crate::models::form_model::{
+    CreatableFormModel, FormFieldModel, FormModel, FormSubmissionAttempt, FormSubmissionFile, UpdatableFormModel,
+};
+use crate::models::ModelCount;
+use crate::providers::avored_database_provider::DB;
+use crate::providers::avored_template_provider::AvoRedTemplateProvider;
+use crate::repositories::form_repository::FormRepository;
+use crate::repositories::form_submission_repository::FormSubmissionRepository;
+use crate::services::email_service::EmailService;
+use crate::services::form_spam_service::{FormSpamService, FormSpamStats};
+use crate::PER_PAGE;
+
+#[derive(Serialize)]
+struct FormSubmissionEmailField {
+    name: String,
+    value: String,
+}
+
+#[derive(Serialize)]
+struct FormSubmissionEmail {
+    form_name: String,
+    email_subject: String,
+    fields: Vec<FormSubmissionEmailField>,
+}
+
+pub struct FormService {
+    form_repository: FormRepository,
+    form_submission_repository: FormSubmissionRepository,
+    form_spam_service: FormSpamService,
+    upload_dir: String,
+    max_upload_kb: i64,
+}
+
+impl FormService {
+    pub fn new(
+        form_repository: FormRepository,
+        form_submission_repository: FormSubmissionRepository,
+        form_spam_service: FormSpamService,
+        upload_dir: String,
+        max_upload_kb: i64,
+    ) -> Result<Self> {
+        Ok(FormService {
+            form_repository,
+            form_submission_repository,
+            form_spam_service,
+            upload_dir,
+            max_upload_kb,
+        })
+    }
+}
+
+impl FormService {
+    pub async fn form_all(&self, (datastore, database_session): &DB) -> Result<FormAllResponse> {
+        let form_models = self.form_repository.all(datastore, database_session).await?;
+
+        let mut form_grpc_models = vec![];
+        for form_model in form_models {
+            let form_grpc_model: FormModelGrpc = form_model.try_into()?;
+            form_grpc_models.push(form_grpc_model);
+        }
+
+        let response = FormAllResponse {
+            status: true,
+            data: form_grpc_models,
+        };
+
+        Ok(response)
+    }
+
+    pub async fn get_form(&self, (datastore, database_session): &DB, form_id: &str) -> Result<GetFormResponse> {
+        let form_model = self
+            .form_repository
+            .find_by_id(datastore, database_session, form_id)
+            .await?;
+
+        let response = GetFormResponse {
+            status: true,
+            data: Some(form_model.try_into()?),
+        };
+
+        Ok(response)
+    }
+
+    pub async fn count_of_identifier(&self, (datastore, database_session): &DB, identifier: &str) -> Result<ModelCount> {
+        self.form_repository
+            .count_of_identifier(datastore, database_session, identifier)
+            .await
+    }
+
+    pub async fn store_form(
+        &self,
+        (datastore, database_session): &DB,
+        request: StoreFormRequest,
+        logged_in_username: String,
+    ) -> Result<StoreFormResponse> {
+        let creatable_form = CreatableFormModel {
+            name: request.name,
+            identifier: request.identifier,
+            fields: form_fields(request.fields)?,
+            recipients: request.recipients,
+            email_subject: request.email_subject,
+            success_message: request.success_message,
+            is_active: request.is_active,
+            logged_in_username,
+        };
+
+        let form_model = self
+            .form_repository
+            .create_form(datastore, database_session, creatable_form)
+            .await?;
+
+        let response = StoreFormResponse {
+            status: true,
+            data: Some(form_model.try_into()?),
+        };
+
+        Ok(response)
+    }
+
+    pub async fn update_form(
+        &self,
+        (datastore, database_session): &DB,
+        request: UpdateFormRequest,
+        logged_in_username: String,
+    ) -> Result<UpdateFormResponse> {
+        let updatable_form = UpdatableFormModel {
+            id: request.form_id,
+            name: request.name,
+            fields: form_fields(request.fields)?,
+            recipients: request.recipients,
+            email_subject: request.email_subject,
+            success_message: request.success_message,
+            is_active: request.is_active,
+            logged_in_username,
+        };
+
+        let form_model = self
+            .form_repository
+            .update_form(datastore, database_session, updatable_form)
+            .await?;
+
+        let response = UpdateFormResponse {
+            status: true,
+            data: Some(form_model.try_into()?),
+        };
+
+        Ok(response)
+    }
+
+    /// Removes the form, its submissions and the files uploaded with them.
+    pub async fn delete_form(&self, (datastore, database_session): &DB, form_id: &str) -> Result<bool> {
+        let form_model = self
+            .form_repository
+            .find_by_id(datastore, database_session, form_id)
+            .await?;
+
+        let is_deleted = self
+            .form_repository
+            .delete_by_id(datastore, database_session, form_id)
+            .await?;
+
+        let form_upload_dir = match form_upload_dir(&self.upload_dir, &form_model.identifier) {
+            Some(form_upload_dir) => form_upload_dir,
+            None => {
+                error!("refusing to remove the uploads of the {} form: invalid identifier", form_model.identifier);
+                return Ok(is_deleted);
+            }
+        };
+        if let Err(e) = tokio::fs::remove_dir_all(&form_upload_dir).await {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                error!("unable to remove the uploads of the {} form: {e:?}", form_model.identifier);
+            }
+        }
+
+        Ok(is_deleted)
+    }
+
+    pub async fn form_submission_paginate(
+        &self,
+        (datastore, database_session): &DB,
+        form_id: &str,
+        current_page: i64,
+    ) -> Result<FormSubmissionPaginateResponse> {
+        let total_count = self
+            .form_submission_repository
+            .get_total_count(datastore, database_session, form_id)
+            .await?;
+
+        let start = current_page * PER_PAGE as i64;
+        let form_submission_models = self
+            .form_submission_repository
+            .paginate(datastore, database_session, form_id, start)
+            .await?;
+
+        let mut form_submission_grpc_models = vec![];
+        for form_submission_model in form_submission_models {
+            let form_submission_grpc_model: FormSubmissionModelGrpc = form_submission_model.try_into()?;
+            form_submission_grpc_models.push(form_submission_grpc_model);
+        }
+
+        let pagination = FormSubmissionPagination {
+            total: total_count.total,
+        };
+        let paginate_data = FormSubmissionPaginateData {
+            pagination: Some(pagination),
+            data: form_submission_grpc_models,
+        };
+
+        let response = FormSubmissionPaginateResponse {
+            status: true,
+            data: Some(paginate_data),
+        };
+
+        Ok(response)
+    }
+
+    /// CSV with a `Submitted at` column followed by one column per current form field.
+    pub async fn export_form_submissions(
+        &self,
+        (datastore, database_session): &DB,
+        form_id: &str,
+    ) -> Result<ExportFormSubmissionsResponse> {
+        let form_model = self
+            .form_repository
+            .find_by_id(datastore, database_session, form_id)
+            .await?;
+        let form_submission_models = self
+            .form_submission_repository
+            .all_by_form(datastore, database_session, form_id)
+            .await?;
+
+        let mut header = vec![String::from("Submitted at")];
+        header.extend(form_model.fields.iter().map(|field| field.name.clone()));
+
+        let mut content = csv_row(&header);
+        for form_submission_model in form_submission_models {
+            let mut row = vec![form_submission_model.created_at.to_utc().to_rfc3339()];
+            row.extend(form_model.fields.iter().map(|field| {
+                form_submission_model
+                    .data
+                    .get(&field.identifier)
+                    .cloned()
+                    .unwrap_or_default()
+            }));
+            content.push_str(&csv_row(&row));
+        }
+
+        let response = ExportFormSubmissionsResponse {
+            status: true,
+            file_name: format!("{}-submissions.csv", form_model.identifier),
+            content,
+        };
+
+        Ok(response)
+    }
+
+    /// Runs the spam checks, then validates and stores a submission of an active
+    /// form and queues an email to the form recipients, with the uploaded files
+    /// attached. A filled honeypot gets the usual answer, but nothing is stored or sent.
+    pub async fn submit_form(
+        &self,
+        db: &DB,
+        template: &AvoRedTemplateProvider,
+        email_service: &EmailService,
+        mut attempt: FormSubmissionAttempt,
+    ) -> Result<SubmitFormResponse> {
+        let (datastore, database_session) = db;
+        self.form_spam_service.check_rate_limits(&attempt)?;
+
+        let form_identifier = attempt.form_identifier.clone();
+        let form_model = self
+            .form_repository
+            .find_by_identifier(datastore, database_session, &form_identifier)
+            .await?;
+        if !form_model.is_active {
+            return Err(Error::NotFound(format!("form {form_identifier}")));
+        }
+
+        if self.form_spam_service.take_honeypot(&mut attempt) {
+            return Ok(submitted_response(form_model));
+        }
+
+        let mut values = form_model.validate_submission(&attempt.data, &attempt.files, self.max_upload_kb)?;
+        self.form_spam_service.verify(&attempt).await?;
+
+        let uploads = self.store_submission_files(&form_model, &attempt.files).await?;
+        let mut file_names = BTreeMap::new();
+        for (field_identifier, attachment) in &uploads {
+            values.insert(field_identifier.clone(), attachment.path.clone());
+            file_names.insert(field_identifier.clone(), attachment.file_name.clone());
+        }
+
+        self.form_submission_repository
+            .create_form_submission(datastore, database_session, &form_model, values.clone())
+            .await?;
+        self.form_spam_service.record_accepted(&attempt);
+
+        if !form_model.recipients.is_empty() {
+            let payload = FormSubmissionEmail {
+                form_name: form_model.name.clone(),
+                email_subject: form_model.email_subject.clone(),
+                fields: form_model
+                    .fields
+                    .iter()
+                    .map(|field| FormSubmissionEmailField {
+                        name: field.name.clone(),
+                        value: file_names
+                            .get(&field.identifier)
+                            .or(values.get(&field.identifier))
+                            .cloned()
+                            .unwrap_or_default(),
+                    })
+                    .collect(),
+            };
+            let attachments = uploads.into_iter().map(|(_, attachment)| attachment).collect();
+            if let Err(e) = self
+                .queue_submission_email(db, template, email_service, &form_model, &payload, attachments)
+                .await
+            {
+                error!("unable to queue the {} form submission email: {e:?}", form_model.identifier);
+            }
+        }
+
+        Ok(submitted_response(form_model))
+    }
+
+    pub fn form_spam_stats(&self) -> FormSpamStats {
+        self.form_spam_service.stats()
+    }
+
+    async fn queue_submission_email(
+        &self,
+        db: &DB,
+        template: &AvoRedTemplateProvider,
+        email_service: &EmailService,
+        form_model: &FormModel,
+        payload: &FormSubmissionEmail,
+        attachments: Vec<EmailAttachmentModel>,
+    ) -> Result<()> {
+        let rendered_email = template.render_email("form-submission-email", payload)?;
+
+        for recipient in &form_model.recipients {
+            let creatable_email = CreatableEmailModel {
+                to_address: recipient.clone(),
+                subject: rendered_email.subject.clone(),
+                body: rendered_email.html_body.clone(),
+                text_body: rendered_email.text_body.clone(),
+                attachments: attachments.clone(),
+                template: String::from("form-submission-email"),
+            };
+            email_service.queue_email(db, creatable_email).await?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FormService {
+    /// Writes the files of the file fields below the upload folder of the form,
+    /// under a random prefix. Returns the field identifier with each stored file.
+    async fn store_submission_files(
+        &self,
+        form_model: &FormModel,
+        files: &[FormSubmissionFile],
+    ) -> Result<Vec<(String, EmailAttachmentModel)>> {
+        let mut uploads = vec![];
+        let form_upload_dir = form_upload_dir(&self.upload_dir, &form_model.identifier)
+            .ok_or_else(|| Error::Generic(format!("invalid upload folder for the {} form", form_model.identifier)))?;
+
+        for field in form_model.fields.iter().filter(|field| field.field_type == "file") {
+            let file = match files
+                .iter()
+                .find(|file| file.field_identifier == field.identifier && !file.content.is_empty())
+            {
+                Some(file) => file,
+                None => continue,
+            };
+
+            tokio::fs::create_dir_all(&form_upload_dir).await?;
+            let prefix: String = rand::rng()
+                .sample_iter(&Alphanumeric)
+                .take(16)
+                .map(char::from)
+                .collect();
+            let file_name = safe_file_name(&file.file_name);
+            let path = form_upload_dir.join(format!("{prefix}-{file_name}"));
+            tokio::fs::write(&path, &file.content).await?;
+
+            let attachment = EmailAttachmentModel {
+                file_name,
+                content_type: file.content_type.clone(),
+                path: path.to_string_lossy().to_string(),
+            };
+            uploads.push((field.identifier.clone(), attachment));
+        }
+
+        Ok(uploads)
+    }
+}
+
+/// The upload folder of a form. Identifiers stored before they were validated
+/// could climb out of the upload dir, so anything but a plain name is refused.
+fn form_upload_dir(upload_dir: &str, identifier: &str) -> Option<PathBuf> {
+    let mut components = Path::new(identifier).components();
+    match (components.next(), components.next()) {
+        (Some(Component::Normal(_)), None) => Some(Path::new(upload_dir).join(identifier)),
+        _ => None,
+    }
+}
+
+/// Keeps letters, digits, dots, dashes and underscores of an uploaded file name.
+fn safe_file_name(file_name: &str) -> String {
+    let file_name = Path::new(file_name)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let file_name: String = file_name
+        .chars()
+        .map(|c| match c.is_ascii_alphanumeric() || ".-_".contains(c) {
+            true => c,
+            false => '_',
+        })
+        .take(100)
+        .collect();
+
+    match file_name.trim_matches('.').is_empty() {
+        true => String::from("file"),
+        false => file_name,
+    }
+}
+
+fn submitted_response(form_model: FormModel) -> SubmitFormResponse {
+    let message = match form_model.success_message.is_empty() {
+        true => t!("form_submitted").to_string(),
+        false => form_model.success_message,
+    };
+
+    SubmitFormResponse {
+        status: true,
+        message,
+    }
+}
+
+fn form_fields(fields: Vec<crate::api::proto::form::FormFieldModel>) -> Result<Vec<FormFieldModel>> {
+    let mut form_fields = vec![];
+    for field in fields {
+        form_fields.push(field.try_into()?);
+    }
+
+    Ok(form_fields)
+}
+
+fn csv_row(values: &[String]) -> String {
+    let mut row = values
+        .iter()
+        .map(|value| csv_value(value))
+        .collect::<Vec<String>>()
+        .join(",");
+    row.push_str("\r\n");
+
+    row
+}
+
+/// Quotes a CSV value and defuses values a spreadsheet would run as a formula.
+/// Phone numbers and negative numbers are left alone.
+fn csv_value(value: &str) -> String {
+    let looks_numeric = value
+        .chars()
+        .all(|c| c.is_ascii_digit() || " +-().".contains(c));
+    let value = match value.starts_with(['=', '+', '-', '@', '\t', '\r']) && !looks_numeric {
+        true => format!("'{value}"),
+        false => value.to_string(),
+    };
+
+    match value.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", value.replace('"', "\"\"")),
+        false => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use crate::models::form_model::is_form_identifier;
+    use super::form_upload_dir;
+
+    #[test]
+    fn upload_dir_stays_below_the_storage_folder() {
+        assert_eq!(
+            form_upload_dir("public/upload/forms", "contact-us"),
+            Some(Path::new("public/upload/forms").join("contact-us"))
+        );
+        for identifier in ["", "..", ".", "../x", "a/b", "/etc"] {
+            assert_eq!(form_upload_dir("public/upload/forms", identifier), None, "{identifier}");
+        }
+
+        assert!(is_form_identifier("contact_us-2"));
+        for identifier in ["", "..", "../x", "Contact", "a b", "a/b"] {
+            assert!(!is_form_identifier(identifier), "{identifier}");
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use rust_i18n::t;
+use serde::Deserialize;
+use tonic::async_trait;
+use tracing::{info, warn};
+use crate::error::{Error, Result};
+use crate::models::form_model::FormSubmissionAttempt;
+use crate::models::validation_error::{ErrorMessage, ErrorResponse};
+use crate::providers::avored_rate_limit_provider::AvoRedRateLimitProvider;
+
+const CHALLENGE_TIMEOUT_SECONDS: u64 = 10;
+
+/// Runs before a public form submission is stored or emailed. Implement it to
+/// plug in a captcha, a proof of work or an external spam filter.
+#[async_trait]
+pub trait FormSubmissionVerifier: Send + Sync {
+    /// Returns false to reject the submission.
+    async fn verify(&self, attempt: &FormSubmissionAttempt) -> Result<bool>;
+}
+
+/// Checks the challenge token against a siteverify style endpoint, as used by
+/// turnstile, hcaptcha and recaptcha.
+pub struct ChallengeTokenVerifier {
+    http_client: reqwest::Client,
+    verify_url: String,
+    secret: String,
+}
+
+#[derive(Deserialize)]
+struct ChallengeTokenVerifyResponse {
+    success: bool,
+}
+
+impl ChallengeTokenVerifier {
+    pub fn new(verify_url: String, secret: String) -> Result<Self> {
+        let http_client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(CHALLENGE_TIMEOUT_SECONDS))
+            .build()?;
+
+        Ok(ChallengeTokenVerifier {
+            http_client,
+            verify_url,
+            secret,
+        })
+    }
+}
+
+#[async_trait]
+impl FormSubmissionVerifier for ChallengeTokenVerifier {
+    async fn verify(&self, attempt: &FormSubmissionAttempt) -> Result<bool> {
+        if attempt.challenge_token.is_empty() {
+            return Ok(false);
+        }
+
+        let params = [
+            ("secret", self.secret.as_str()),
+            ("response", attempt.challenge_token.as_str()),
+            ("remoteip", attempt.client_ip.as_str()),
+        ];
+        let body = self
+            .http_client
+            .post(&self.verify_url)
+            .form(&params)
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+        let verify_response: ChallengeTokenVerifyResponse = serde_json::from_str(&body)?;
+
+        Ok(verify_response.success)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum FormSpamReason {
+    IpRateLimit,
+    FormRateLimit,
+    Honeypot,
+    Verification,
+}
+
+impl FormSpamReason {
+    fn as_str(&self) -> &'static str {
+        match self {
+            FormSpamReason::IpRateLimit => "ip_rate_limit",
+            FormSpamReason::FormRateLimit => "form_rate_limit",
+            FormSpamReason::Honeypot => "honeypot",
+            FormSpamReason::Verification => "verification",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct FormSpamStats {
+    pub accepted: u64,
+    pub ip_rate_limited: u64,
+    pub form_rate_limited: u64,
+    pub honeypot: u64,
+    pub verification_failed: u64,
+}
+
+#[derive(Default)]
+struct FormSpamCounters {
+    accepted: AtomicU64,
+    ip_rate_limited: AtomicU64,
+    form_rate_limited: AtomicU64,
+    honeypot: AtomicU64,
+    verification_failed: AtomicU64,
+}
+
+pub struct FormSpamService {
+    ip_rate_limit: AvoRedRateLimitProvider<String>,
+    form_rate_limit: AvoRedRateLimitProvider<String>,
+    honeypot_field: String,
+    verifier: Option<Arc<dyn FormSubmissionVerifier>>,
+    counters: FormSpamCounters,
+}
+
+impl FormSpamService {
+    pub fn new(
+        ip_max_submissions: u32,
+        form_max_submissions: u32,
+        rate_limit_window: Duration,
+        honeypot_field: String,
+        verifier: Option<Arc<dyn FormSubmissionVerifier>>,
+    ) -> Result<Self> {
+        Ok(FormSpamService {
+            ip_rate_limit: AvoRedRateLimitProvider::register(ip_max_submissions, rate_limit_window),
+            form_rate_limit: AvoRedRateLimitProvider::register(form_max_submissions, rate_limit_window),
+            honeypot_field,
+            verifier,
+            counters: FormSpamCounters::default(),
+        })
+    }
+}
+
+impl FormSpamService {
+    /// Counts the attempt against the per ip and the per form limit.
+    pub fn check_rate_limits(&self, attempt: &FormSubmissionAttempt) -> Result<()> {
+        if !self.ip_rate_limit.hit(attempt.client_ip.clone()) {
+            self.block(FormSpamReason::IpRateLimit, attempt);
+            let seconds = self.ip_rate_limit.retry_after(&attempt.client_ip);
+            return Err(Error::TooManyRequests(t!("too_many_submissions", seconds = seconds).to_string()));
+        }
+        if !self.form_rate_limit.hit(attempt.form_identifier.clone()) {
+            self.block(FormSpamReason::FormRateLimit, attempt);
+            let seconds = self.form_rate_limit.retry_after(&attempt.form_identifier);
+            return Err(Error::TooManyRequests(t!("too_many_submissions", seconds = seconds).to_string()));
+        }
+
+        Ok(())
+    }
+
+    /// Takes the honeypot value out of the submitted data, so it never gets
+    /// stored, and returns true when a bot filled it in.
+    pub fn take_honeypot(&self, attempt: &mut FormSubmissionAttempt) -> bool {
+        let is_filled = attempt
+            .data
+            .remove(&self.honeypot_field)
+            .is_some_and(|value| !value.trim().is_empty());
+        if is_filled {
+            self.block(FormSpamReason::Honeypot, attempt);
+        }
+
+        is_filled
+    }
+
+    /// Passes the attempt through the verification hook when one is configured.
+    pub async fn verify(&self, attempt: &FormSubmissionAttempt) -> Result<()> {
+        let verifier = match &self.verifier {
+            Some(verifier) => verifier,
+            None => return Ok(()),
+        };
+
+        if !verifier.verify(attempt).await? {
+            self.block(FormSpamReason::Verification, attempt);
+
+            let error_response = ErrorResponse {
+                status: false,
+                errors: vec![ErrorMessage {
+                    key: String::from("challenge_token"),
+                    message: t!("challenge_failed").to_string(),
+                }],
+            };
+            return Err(Error::InvalidArgument(serde_json::to_string(&error_response)?));
+        }
+
+        Ok(())
+    }
+
+    pub fn record_accepted(&self, attempt: &FormSubmissionAttempt) {
+        self.counters.accepted.fetch_add(1, Ordering::Relaxed);
+        info!(target: "metrics", form = attempt.form_identifier, "form_submission_accepted");
+    }
+
+    pub fn stats(&self) -> FormSpamStats {
+        FormSpamStats {
+            accepted: self.counters.accepted.load(Ordering::Relaxed),
+            ip_rate_limited: self.counters.ip_rate_limited.load(Ordering::Relaxed),
+            form_rate_limited: self.counters.form_rate_limited.load(Ordering::Relaxed),
+            honeypot: self.counters.honeypot.load(Ordering::Relaxed),
+            verification_failed: self.counters.verification_failed.load(Ordering::Relaxed),
+        }
+    }
+
+    fn block(&self, reason: FormSpamReason, attempt: &FormSubmissionAttempt) {
+        let counter = match reason {
+            FormSpamReason::IpRateLimit => &self.counters.ip_rate_limited,
+            FormSpamReason::FormRateLimit => &self.counters.form_rate_limited,
+            FormSpamReason::Honeypot => &self.counters.honeypot,
+            FormSpamReason::Verification => &self.counters.verification_failed,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+
+        warn!(
+            "blocked a submission of the {} form from {}: {}",
+            attempt.form_identifier,
+            attempt.client_ip,
+            reason.as_str()
+        );
+        info!(target: "metrics", form = attempt.form_identifier, reason = reason.as_str(), "form_submission_blocked");
+    }
+}
+
@@ -1,6 +1,6 @@
 use crate::api::proto::admin_user::AdminUserModel as GrpcAdminUserModel;
 use crate::api::proto::general::{LoggedInUserResponse};
-use crate::models::token_claim_model::TokenClaims;
+use crate::models::admin_user_model::AdminUserModel;
 
 pub struct GeneralService {
     
@@ -9,10 +9,8 @@ pub struct GeneralService {
 impl GeneralService {
     pub async fn logged_in_user(
         &self,
-        claims: TokenClaims,
+        logged_in_user: AdminUserModel,
     ) -> crate::error::Result<LoggedInUserResponse> {
-        let logged_in_user = claims.admin_user_model;
-        
         let model: GrpcAdminUserModel = logged_in_user.try_into()?;
         
         let logged_in_user = LoggedInUserResponse {
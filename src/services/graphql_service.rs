@@ -0,0 +1,444 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use async_graphql::dynamic::{Field, FieldFuture, FieldValue, InputValue, Object, Schema, TypeRef};
+use async_graphql::Value;
+use rust_i18n::t;
+use tracing::warn;
+use crate::avored_state::AvoRedState;
+use crate::error::{Error, Result};
+use crate::models::cms_api_key_model::CmsApiKeyModel;
+use crate::models::collection_model::{CollectionModel, GraphqlSchemaCache};
+use crate::models::content_model::{ContentFieldModel, ContentModel};
+use crate::models::validation_error::{ErrorMessage, ErrorResponse};
+use crate::providers::avored_database_provider::DB;
+use crate::repositories::collection_repository::CollectionRepository;
+use crate::repositories::content_repository::ContentRepository;
+
+const QUERY_TYPE: &str = "Query";
+const COLLECTION_TYPE: &str = "Collection";
+const CONTENT_FIELD_TYPE: &str = "ContentField";
+const COLLECTIONS_FIELD: &str = "collections";
+
+/// Columns the public `order` argument can sort by. It ends up in the query
+/// text, so nothing else is let through.
+const ORDER_COLUMNS: [&str; 5] = ["id", "name", "identifier", "created_at", "updated_at"];
+const ORDER_TYPES: [&str; 2] = ["ASC", "DESC"];
+
+pub struct GraphqlService {
+    content_repository: ContentRepository,
+    collection_repository: CollectionRepository,
+    schema_cache: Arc<GraphqlSchemaCache>,
+}
+
+impl GraphqlService {
+    pub fn new(
+        content_repository: ContentRepository,
+        collection_repository: CollectionRepository,
+        schema_cache: Arc<GraphqlSchemaCache>,
+    ) -> Result<Self> {
+        Ok(GraphqlService {
+            content_repository,
+            collection_repository,
+            schema_cache,
+        })
+    }
+}
+
+impl GraphqlService {
+    /// The schema for the collections the api key can read, built once and
+    /// shared by every key that can read the same collections.
+    pub async fn schema(&self, db: &DB, cms_api_key_model: &CmsApiKeyModel) -> Result<Schema> {
+        let mut cache_key = cms_api_key_model.collections.clone();
+        cache_key.sort();
+        cache_key.dedup();
+        if let Some(schema) = self.schema_cache.get(&cache_key) {
+            return Ok(schema);
+        }
+
+        let schema = self.build_schema(db, cms_api_key_model).await?;
+        self.schema_cache.insert(cache_key, schema.clone());
+
+        Ok(schema)
+    }
+
+    /// Builds a read only schema with one content type per collection the api key can read.
+    /// For a collection with identifier `blog-posts` the query root gets
+    /// `blogPosts(identifier: String!)` and `allBlogPosts(page: Int, order: String)`.
+    /// A collection whose names clash with the ones taken already is left out.
+    async fn build_schema(
+        &self,
+        db: &DB,
+        cms_api_key_model: &CmsApiKeyModel,
+    ) -> Result<Schema> {
+        let mut collection_models = self.all_collection(db, cms_api_key_model).await?;
+        collection_models.sort_by(|a, b| a.identifier.cmp(&b.identifier));
+
+        let mut query = Object::new(QUERY_TYPE).field(collections_field());
+        let mut content_types: Vec<Object> = vec![];
+        let mut type_names: HashSet<String> = [QUERY_TYPE, COLLECTION_TYPE, CONTENT_FIELD_TYPE]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let mut field_names: HashSet<String> = HashSet::from([String::from(COLLECTIONS_FIELD)]);
+
+        for collection_model in collection_models {
+            let Some((type_name, field_name, list_field_name)) = graphql_names(&collection_model.identifier) else {
+                warn!("collection {} has no usable graphql name, it is left out of the schema", collection_model.identifier);
+                continue;
+            };
+            if type_names.contains(&type_name) || field_names.contains(&field_name) || field_names.contains(&list_field_name) {
+                warn!("graphql names of collection {} are taken already, it is left out of the schema", collection_model.identifier);
+                continue;
+            }
+
+            query = query
+                .field(content_field(&field_name, &type_name, &collection_model.identifier))
+                .field(content_list_field(&list_field_name, &type_name, &collection_model.identifier));
+            content_types.push(content_object(&type_name));
+            type_names.insert(type_name);
+            field_names.insert(field_name);
+            field_names.insert(list_field_name);
+        }
+
+        let mut schema_builder = Schema::build(QUERY_TYPE, None, None)
+            .register(query)
+            .register(collection_object())
+            .register(content_field_object());
+        for content_type in content_types {
+            schema_builder = schema_builder.register(content_type);
+        }
+
+        schema_builder
+            .finish()
+            .map_err(|e| Error::Generic(format!("graphql schema error: {e}")))
+    }
+
+    pub(crate) async fn all_collection(
+        &self,
+        (datastore, database_session): &DB,
+        cms_api_key_model: &CmsApiKeyModel,
+    ) -> Result<Vec<CollectionModel>> {
+        let collection_models = self
+            .collection_repository
+            .all_collection(datastore, database_session)
+            .await?;
+
+        Ok(collection_models
+            .into_iter()
+            .filter(|collection_model| cms_api_key_model.has_collection(&collection_model.identifier))
+            .collect())
+    }
+
+    /// `None` when there is no published content with the identifier.
+    pub(crate) async fn find_content_by_identifier(
+        &self,
+        (datastore, database_session): &DB,
+        collection_type: &str,
+        identifier: &str,
+    ) -> Result<Option<ContentModel>> {
+        self.content_repository
+            .find_published_by_identifier(datastore, database_session, collection_type, identifier)
+            .await
+    }
+
+    pub(crate) async fn paginate_content(
+        &self,
+        (datastore, database_session): &DB,
+        collection_type: &str,
+        page: i64,
+        order: String,
+    ) -> Result<Vec<ContentModel>> {
+        if page < 0 {
+            return Err(invalid_argument("page"));
+        }
+        let start = page.saturating_mul(crate::PER_PAGE as i64);
+        let (order_column, order_type) = match order.is_empty() {
+            true => ("id", String::from("DESC")),
+            false => parse_order(&order).ok_or_else(|| invalid_argument("order"))?,
+        };
+
+        self.content_repository
+            .paginate_published(
+                datastore,
+                database_session,
+                collection_type,
+                start,
+                order_column.to_string(),
+                order_type,
+            )
+            .await
+    }
+}
+
+/// `column:direction`, with the column and direction from the allowed lists.
+fn parse_order(order: &str) -> Option<(&'static str, String)> {
+    let (column, direction) = order.split_once(':')?;
+    let column = ORDER_COLUMNS.into_iter().find(|allowed| *allowed == column.trim())?;
+    let direction = direction.trim().to_uppercase();
+    if !ORDER_TYPES.contains(&direction.as_str()) {
+        return None;
+    }
+
+    Some((column, direction))
+}
+
+fn invalid_argument(key: &str) -> Error {
+    let error_response = ErrorResponse {
+        status: false,
+        errors: vec![ErrorMessage {
+            key: key.to_string(),
+            message: t!("validation_invalid", attribute = key).to_string(),
+        }],
+    };
+
+    match serde_json::to_string(&error_response) {
+        Ok(error_string) => Error::InvalidArgument(error_string),
+        Err(e) => Error::Generic(e.to_string()),
+    }
+}
+
+// region: schema types
+
+fn collections_field() -> Field {
+    Field::new(COLLECTIONS_FIELD, TypeRef::named_nn_list_nn(COLLECTION_TYPE), |ctx| {
+        FieldFuture::new(async move {
+            let state = ctx.data::<Arc<AvoRedState>>()?;
+            let cms_api_key_model = ctx.data::<CmsApiKeyModel>()?;
+            let collection_models = state
+                .graphql_service
+                .all_collection(&state.db, cms_api_key_model)
+                .await?;
+
+            Ok(Some(FieldValue::list(
+                collection_models.into_iter().map(FieldValue::owned_any),
+            )))
+        })
+    })
+}
+
+fn content_field(field_name: &str, type_name: &str, collection_type: &str) -> Field {
+    let collection_type = collection_type.to_string();
+
+    Field::new(field_name, TypeRef::named(type_name), move |ctx| {
+        let collection_type = collection_type.clone();
+
+        FieldFuture::new(async move {
+            let state = ctx.data::<Arc<AvoRedState>>()?;
+            let identifier = ctx.args.try_get("identifier")?.string()?;
+
+            let content_model = state
+                .graphql_service
+                .find_content_by_identifier(&state.db, &collection_type, identifier)
+                .await?;
+
+            Ok(content_model.map(FieldValue::owned_any))
+        })
+    })
+    .argument(InputValue::new("identifier", TypeRef::named_nn(TypeRef::STRING)))
+}
+
+fn content_list_field(field_name: &str, type_name: &str, collection_type: &str) -> Field {
+    let collection_type = collection_type.to_string();
+
+    Field::new(field_name, TypeRef::named_nn_list_nn(type_name), move |ctx| {
+        let collection_type = collection_type.clone();
+
+        FieldFuture::new(async move {
+            let state = ctx.data::<Arc<AvoRedState>>()?;
+            let page = match ctx.args.get("page") {
+                Some(page) => page.i64()?,
+                None => 0,
+            };
+            let order = match ctx.args.get("order") {
+                Some(order) => order.string()?.to_string(),
+                None => String::new(),
+            };
+
+            let content_models = state
+                .graphql_service
+                .paginate_content(&state.db, &collection_type, page, order)
+                .await?;
+
+            Ok(Some(FieldValue::list(
+                content_models.into_iter().map(FieldValue::owned_any),
+            )))
+        })
+    })
+    .argument(InputValue::new("page", TypeRef::named(TypeRef::INT)))
+    .argument(InputValue::new("order", TypeRef::named(TypeRef::STRING)))
+}
+
+fn collection_object() -> Object {
+    Object::new(COLLECTION_TYPE)
+        .field(string_field::<CollectionModel>("id", |model| model.id.clone()))
+        .field(string_field::<CollectionModel>("name", |model| model.name.clone()))
+        .field(string_field::<CollectionModel>("identifier", |model| model.identifier.clone()))
+        .field(string_field::<CollectionModel>("createdAt", |model| model.created_at.to_string()))
+        .field(string_field::<CollectionModel>("updatedAt", |model| model.updated_at.to_string()))
+}
+
+fn content_object(type_name: &str) -> Object {
+    Object::new(type_name)
+        .field(string_field::<ContentModel>("id", |model| model.id.clone()))
+        .field(string_field::<ContentModel>("name", |model| model.name.clone()))
+        .field(string_field::<ContentModel>("identifier", |model| model.identifier.clone()))
+        .field(string_field::<ContentModel>("createdAt", |model| model.created_at.to_string()))
+        .field(string_field::<ContentModel>("updatedAt", |model| model.updated_at.to_string()))
+        .field(string_field::<ContentModel>("createdBy", |model| model.created_by.clone()))
+        .field(string_field::<ContentModel>("updatedBy", |model| model.updated_by.clone()))
+        .field(Field::new(
+            "fields",
+            TypeRef::named_nn_list_nn(CONTENT_FIELD_TYPE),
+            |ctx| {
+                FieldFuture::new(async move {
+                    let content_model = ctx.parent_value.try_downcast_ref::<ContentModel>()?;
+
+                    Ok(Some(FieldValue::list(
+                        content_model
+                            .content_fields
+                            .iter()
+                            .cloned()
+                            .map(FieldValue::owned_any),
+                    )))
+                })
+            },
+        ))
+        .field(
+            Field::new("field", TypeRef::named(CONTENT_FIELD_TYPE), |ctx| {
+                FieldFuture::new(async move {
+                    let content_model = ctx.parent_value.try_downcast_ref::<ContentModel>()?;
+                    let identifier = ctx.args.try_get("identifier")?.string()?;
+
+                    let content_field = content_model
+                        .content_fields
+                        .iter()
+                        .find(|content_field| content_field.identifier == identifier)
+                        .cloned();
+
+                    Ok(content_field.map(FieldValue::owned_any))
+                })
+            })
+            .argument(InputValue::new("identifier", TypeRef::named_nn(TypeRef::STRING))),
+        )
+}
+
+fn content_field_object() -> Object {
+    Object::new(CONTENT_FIELD_TYPE)
+        .field(string_field::<ContentFieldModel>("name", |field| field.name.clone()))
+        .field(string_field::<ContentFieldModel>("identifier", |field| field.identifier.clone()))
+        .field(string_field::<ContentFieldModel>("dataType", |field| {
+            field.data_type.clone().try_into().unwrap_or_default()
+        }))
+        .field(string_field::<ContentFieldModel>("fieldType", |field| {
+            field.field_type.clone().try_into().unwrap_or_default()
+        }))
+        .field(Field::new("textValue", TypeRef::named(TypeRef::STRING), |ctx| {
+            FieldFuture::new(async move {
+                let field = ctx.parent_value.try_downcast_ref::<ContentFieldModel>()?;
+                Ok(field.field_content.text_value.clone().map(Value::from))
+            })
+        }))
+        .field(Field::new("intValue", TypeRef::named(TypeRef::INT), |ctx| {
+            FieldFuture::new(async move {
+                let field = ctx.parent_value.try_downcast_ref::<ContentFieldModel>()?;
+                Ok(field.field_content.int_value.map(Value::from))
+            })
+        }))
+        .field(Field::new("floatValue", TypeRef::named(TypeRef::FLOAT), |ctx| {
+            FieldFuture::new(async move {
+                let field = ctx.parent_value.try_downcast_ref::<ContentFieldModel>()?;
+                Ok(field.field_content.float_value.map(Value::from))
+            })
+        }))
+        .field(Field::new("boolValue", TypeRef::named(TypeRef::BOOLEAN), |ctx| {
+            FieldFuture::new(async move {
+                let field = ctx.parent_value.try_downcast_ref::<ContentFieldModel>()?;
+                Ok(field.field_content.bool_value.map(Value::from))
+            })
+        }))
+        .field(Field::new(
+            "arrayValue",
+            TypeRef::named_nn_list_nn(TypeRef::STRING),
+            |ctx| {
+                FieldFuture::new(async move {
+                    let field = ctx.parent_value.try_downcast_ref::<ContentFieldModel>()?;
+                    Ok(Some(Value::List(
+                        field
+                            .field_content
+                            .array_value
+                            .iter()
+                            .cloned()
+                            .map(Value::from)
+                            .collect(),
+                    )))
+                })
+            },
+        ))
+}
+
+fn string_field<T: Send + Sync + 'static>(
+    name: &str,
+    getter: fn(&T) -> String,
+) -> Field {
+    Field::new(name, TypeRef::named_nn(TypeRef::STRING), move |ctx| {
+        FieldFuture::new(async move {
+            let model = ctx.parent_value.try_downcast_ref::<T>()?;
+            Ok(Some(Value::from(getter(model))))
+        })
+    })
+}
+
+// endregion: schema types
+
+fn pascal_case(identifier: &str) -> String {
+    identifier
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+fn camel_case(pascal: &str) -> String {
+    let mut chars = pascal.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+/// Type, single and list field name of a collection. Names are made of the
+/// ascii letters and digits of the identifier and may not start with a
+/// digit, `None` when nothing is left.
+fn graphql_names(identifier: &str) -> Option<(String, String, String)> {
+    let mut pascal = pascal_case(identifier);
+    if pascal.is_empty() {
+        return None;
+    }
+    if pascal.starts_with(|c: char| c.is_ascii_digit()) {
+        pascal.insert(0, '_');
+    }
+
+    Some((format!("{pascal}Content"), camel_case(&pascal), format!("all{pascal}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_order;
+
+    #[test]
+    fn parse_order_only_allows_listed_columns_and_directions() {
+        assert_eq!(parse_order("name:asc"), Some(("name", String::from("ASC"))));
+        assert_eq!(parse_order("created_at:DESC"), Some(("created_at", String::from("DESC"))));
+        assert_eq!(parse_order("name"), None);
+        assert_eq!(parse_order("password:asc"), None);
+        assert_eq!(parse_order("name:sideways"), None);
+        assert_eq!(parse_order("id; DELETE content:asc"), None);
+        assert_eq!(parse_order("id:asc; REMOVE TABLE content"), None);
+    }
+}
@@ -0,0 +1,372 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use rust_i18n::t;
+use tonic::Status;
+use tracing::warn;
+use crate::error::Error::TonicError;
+use crate::error::Result;
+use crate::models::audit_log_model::{AuditLogAction, AuditLogModel, CreatableAuditLogModel};
+use crate::providers::avored_clock_provider::AvoRedClockProvider;
+use crate::providers::avored_database_provider::DB;
+use crate::providers::avored_rate_limit_provider::AvoRedRateLimitProvider;
+use crate::repositories::audit_log_repository::AuditLogRepository;
+
+/// Longest wait between two attempts of an account before it is locked.
+const LOGIN_DELAY_MAX_SECONDS: i64 = 60;
+
+/// Most accounts tracked in memory. Once reached the ones without recent
+/// failures are swept, then the ones that are only delayed, and while every
+/// tracked account is locked new ones are not counted.
+const MAX_TRACKED_ACCOUNTS: usize = 10_000;
+
+/// Address of a request the client ip could not be read for, it is shared
+/// by every such client and so not limited.
+const UNKNOWN_IP_ADDRESS: &str = "unknown";
+
+/// Failed logins of an account. After every failure the next attempt has to
+/// wait twice as long, after `max_attempts` the account is locked.
+#[derive(Default)]
+struct LoginFailure {
+    attempts: u32,
+    last_failed_at: i64,
+    locked_until: i64,
+}
+
+/// Throttles logins per account and per ip. Kept in process memory like the
+/// other rate limits, so a restart lifts every lockout and several instances
+/// each count on their own. Lockouts are written to the audit log.
+pub struct LoginThrottleService {
+    audit_log_repository: AuditLogRepository,
+    ip_failures: AvoRedRateLimitProvider<String>,
+    account_failures: Mutex<HashMap<String, LoginFailure>>,
+    max_attempts: u32,
+    lockout_seconds: i64,
+    clock: Arc<AvoRedClockProvider>,
+}
+
+impl LoginThrottleService {
+    pub fn new(
+        audit_log_repository: AuditLogRepository,
+        max_attempts: u32,
+        ip_max_attempts: u32,
+        lockout_seconds: i64,
+        clock: Arc<AvoRedClockProvider>,
+    ) -> Result<Self> {
+        Ok(LoginThrottleService {
+            audit_log_repository,
+            ip_failures: AvoRedRateLimitProvider::register(
+                ip_max_attempts,
+                Duration::from_secs(lockout_seconds.max(0) as u64),
+            ),
+            account_failures: Mutex::new(HashMap::new()),
+            max_attempts,
+            lockout_seconds,
+            clock,
+        })
+    }
+}
+
+impl LoginThrottleService {
+    /// Refuses the attempt while the ip or the account is locked, or the
+    /// account still has to wait after its last failure.
+    pub fn check(&self, email: &str, ip_address: &str) -> Result<()> {
+        if ip_address != UNKNOWN_IP_ADDRESS && self.ip_failures.is_limited(&ip_address.to_string()) {
+            let seconds = self.ip_failures.retry_after(&ip_address.to_string());
+            return Err(too_many_attempts(seconds as i64));
+        }
+
+        let now = self.clock.timestamp();
+        let account_failures = self.account_failures.lock().unwrap_or_else(|e| e.into_inner());
+        let login_failure = match account_failures.get(&account_key(email)) {
+            Some(login_failure) => login_failure,
+            None => return Ok(()),
+        };
+
+        if login_failure.locked_until > now {
+            return Err(too_many_attempts(login_failure.locked_until - now));
+        }
+        let retry_at = login_failure.last_failed_at + delay_seconds(login_failure.attempts);
+        if login_failure.locked_until == 0 && retry_at > now {
+            return Err(too_many_attempts(retry_at - now));
+        }
+
+        Ok(())
+    }
+
+    /// Counts a failed attempt against the ip and the account and locks the
+    /// account once it ran out of attempts.
+    pub async fn record_failure(&self, (datastore, database_session): &DB, email: &str, ip_address: &str) -> Result<()> {
+        if ip_address != UNKNOWN_IP_ADDRESS {
+            self.ip_failures.hit(ip_address.to_string());
+        }
+        let attempts = match self.count_account_failure(email) {
+            Some(attempts) => attempts,
+            None => return Ok(()),
+        };
+
+        warn!("login locked after {attempts} failed attempts: {email}");
+        let creatable_audit_log = CreatableAuditLogModel {
+            action: AuditLogAction::LoginLockout,
+            email: email.to_string(),
+            ip_address: ip_address.to_string(),
+            detail: format!("locked for {} seconds after {attempts} failed attempts", self.lockout_seconds),
+            created_by: email.to_string(),
+        };
+        self.audit_log_repository
+            .create_audit_log(datastore, database_session, creatable_audit_log)
+            .await?;
+
+        Ok(())
+    }
+
+    pub fn record_success(&self, email: &str) {
+        let mut account_failures = self.account_failures.lock().unwrap_or_else(|e| e.into_inner());
+        account_failures.remove(&account_key(email));
+    }
+
+    /// Lifts the lockout and the failed attempts of an account, returns false
+    /// when it was not locked.
+    pub async fn unlock(
+        &self,
+        (datastore, database_session): &DB,
+        email: &str,
+        ip_address: &str,
+        logged_in_username: &str,
+    ) -> Result<bool> {
+        let now = self.clock.timestamp();
+        let login_failure = self
+            .account_failures
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&account_key(email));
+        let was_locked = login_failure.is_some_and(|login_failure| login_failure.locked_until > now);
+        if !was_locked {
+            return Ok(false);
+        }
+
+        let creatable_audit_log = CreatableAuditLogModel {
+            action: AuditLogAction::LoginUnlock,
+            email: email.to_string(),
+            ip_address: ip_address.to_string(),
+            detail: String::from("unlocked by a super admin"),
+            created_by: logged_in_username.to_string(),
+        };
+        self.audit_log_repository
+            .create_audit_log(datastore, database_session, creatable_audit_log)
+            .await?;
+
+        Ok(true)
+    }
+
+    /// Returns the number of failed attempts when this one locked the account.
+    fn count_account_failure(&self, email: &str) -> Option<u32> {
+        if self.max_attempts == 0 {
+            return None;
+        }
+
+        let now = self.clock.timestamp();
+        let mut account_failures = self.account_failures.lock().unwrap_or_else(|e| e.into_inner());
+        let key = account_key(email);
+        if account_failures.len() >= MAX_TRACKED_ACCOUNTS && !account_failures.contains_key(&key) {
+            let lockout_seconds = self.lockout_seconds;
+            account_failures.retain(|_, login_failure| {
+                login_failure.locked_until > now || now - login_failure.last_failed_at < lockout_seconds
+            });
+            if account_failures.len() >= MAX_TRACKED_ACCOUNTS {
+                account_failures.retain(|_, login_failure| login_failure.locked_until > now);
+            }
+            if account_failures.len() >= MAX_TRACKED_ACCOUNTS {
+                warn!("{MAX_TRACKED_ACCOUNTS} accounts are locked, failed logins of other accounts are not counted");
+                return None;
+            }
+        }
+
+        let login_failure = account_failures.entry(key).or_default();
+        // failures from before an ended lockout or long ago start over
+        if (login_failure.locked_until != 0 && login_failure.locked_until <= now)
+            || now - login_failure.last_failed_at >= self.lockout_seconds
+        {
+            *login_failure = LoginFailure::default();
+        }
+        login_failure.attempts += 1;
+        login_failure.last_failed_at = now;
+
+        if login_failure.attempts < self.max_attempts {
+            return None;
+        }
+        login_failure.locked_until = now + self.lockout_seconds;
+
+        Some(login_failure.attempts)
+    }
+
+    pub async fn audit_log_all(&self, (datastore, database_session): &DB, limit: i64) -> Result<Vec<AuditLogModel>> {
+        self.audit_log_repository
+            .latest(datastore, database_session, limit)
+            .await
+    }
+}
+
+/// 1, 2, 4, ... seconds after the first, second, third failure.
+fn delay_seconds(attempts: u32) -> i64 {
+    match attempts {
+        0 => 0,
+        attempts => 2_i64.saturating_pow(attempts - 1).min(LOGIN_DELAY_MAX_SECONDS),
+    }
+}
+
+fn account_key(email: &str) -> String {
+    email.trim().to_lowercase()
+}
+
+fn too_many_attempts(seconds: i64) -> crate::error::Error {
+    TonicError(Status::resource_exhausted(
+        t!("too_many_login_attempts", seconds = seconds.max(1)).to_string(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use crate::models::audit_log_model::AuditLogAction;
+    use crate::providers::avored_clock_provider::AvoRedClockProvider;
+    use crate::providers::avored_database_provider::DB;
+    use crate::repositories::audit_log_repository::AuditLogRepository;
+    use crate::test_helpers::memory_db;
+    use super::{delay_seconds, LoginThrottleService, LOGIN_DELAY_MAX_SECONDS, UNKNOWN_IP_ADDRESS};
+
+    const EMAIL: &str = "jane@example.com";
+
+    async fn setup(
+        database: &str,
+        max_attempts: u32,
+        ip_max_attempts: u32,
+    ) -> (DB, LoginThrottleService, Arc<AvoRedClockProvider>) {
+        let db = memory_db(database).await;
+        let clock = Arc::new(AvoRedClockProvider::register());
+        let login_throttle_service = LoginThrottleService::new(
+            AuditLogRepository::new(),
+            max_attempts,
+            ip_max_attempts,
+            900,
+            clock.clone(),
+        )
+        .unwrap();
+
+        (db, login_throttle_service, clock)
+    }
+
+    #[test]
+    fn delay_doubles_up_to_the_longest_delay() {
+        assert_eq!(delay_seconds(0), 0);
+        assert_eq!(delay_seconds(1), 1);
+        assert_eq!(delay_seconds(3), 4);
+        assert_eq!(delay_seconds(100), 60);
+    }
+
+    #[tokio::test]
+    async fn delay_and_lockout_end_with_time() {
+        let (db, login_throttle_service, clock) = setup("login_delay_test", 3, 100).await;
+        login_throttle_service
+            .record_failure(&db, EMAIL, UNKNOWN_IP_ADDRESS)
+            .await
+            .unwrap();
+        assert!(login_throttle_service.check(EMAIL, UNKNOWN_IP_ADDRESS).is_err());
+
+        clock.advance(delay_seconds(1));
+        assert!(login_throttle_service.check(EMAIL, UNKNOWN_IP_ADDRESS).is_ok());
+
+        for _ in 0..2 {
+            login_throttle_service
+                .record_failure(&db, EMAIL, UNKNOWN_IP_ADDRESS)
+                .await
+                .unwrap();
+        }
+        clock.advance(LOGIN_DELAY_MAX_SECONDS);
+        assert!(login_throttle_service.check(EMAIL, UNKNOWN_IP_ADDRESS).is_err());
+
+        clock.advance(900);
+        assert!(login_throttle_service.check(EMAIL, UNKNOWN_IP_ADDRESS).is_ok());
+    }
+
+    #[tokio::test]
+    async fn account_is_locked_after_max_attempts_and_audited() {
+        let (db, login_throttle_service, _) = setup("login_lockout_test", 3, 100).await;
+        assert!(login_throttle_service.check(EMAIL, UNKNOWN_IP_ADDRESS).is_ok());
+
+        for _ in 0..2 {
+            login_throttle_service
+                .record_failure(&db, EMAIL, UNKNOWN_IP_ADDRESS)
+                .await
+                .unwrap();
+        }
+        // delayed after a failure, not yet locked
+        assert!(login_throttle_service.check(EMAIL, UNKNOWN_IP_ADDRESS).is_err());
+        assert!(login_throttle_service.audit_log_all(&db, 10).await.unwrap().is_empty());
+
+        login_throttle_service
+            .record_failure(&db, " Jane@Example.com", UNKNOWN_IP_ADDRESS)
+            .await
+            .unwrap();
+        assert!(login_throttle_service.check(EMAIL, UNKNOWN_IP_ADDRESS).is_err());
+        assert!(login_throttle_service.check("john@example.com", UNKNOWN_IP_ADDRESS).is_ok());
+
+        let audit_logs = login_throttle_service.audit_log_all(&db, 10).await.unwrap();
+        assert_eq!(audit_logs.len(), 1);
+        assert_eq!(audit_logs[0].action, AuditLogAction::LoginLockout);
+    }
+
+    #[tokio::test]
+    async fn unlock_lifts_a_lockout() {
+        let (db, login_throttle_service, _) = setup("login_unlock_test", 1, 100).await;
+        assert!(!login_throttle_service
+            .unlock(&db, EMAIL, UNKNOWN_IP_ADDRESS, "admin@example.com")
+            .await
+            .unwrap());
+
+        login_throttle_service
+            .record_failure(&db, EMAIL, UNKNOWN_IP_ADDRESS)
+            .await
+            .unwrap();
+        assert!(login_throttle_service.check(EMAIL, UNKNOWN_IP_ADDRESS).is_err());
+
+        assert!(login_throttle_service
+            .unlock(&db, EMAIL, UNKNOWN_IP_ADDRESS, "admin@example.com")
+            .await
+            .unwrap());
+        assert!(login_throttle_service.check(EMAIL, UNKNOWN_IP_ADDRESS).is_ok());
+
+        let audit_logs = login_throttle_service.audit_log_all(&db, 10).await.unwrap();
+        assert_eq!(audit_logs.len(), 2);
+        assert!(audit_logs
+            .iter()
+            .any(|audit_log| audit_log.action == AuditLogAction::LoginUnlock && audit_log.created_by == "admin@example.com"));
+    }
+
+    #[tokio::test]
+    async fn success_clears_the_failed_attempts() {
+        let (db, login_throttle_service, _) = setup("login_success_test", 3, 100).await;
+        login_throttle_service
+            .record_failure(&db, EMAIL, UNKNOWN_IP_ADDRESS)
+            .await
+            .unwrap();
+        assert!(login_throttle_service.check(EMAIL, UNKNOWN_IP_ADDRESS).is_err());
+
+        login_throttle_service.record_success(EMAIL);
+        assert!(login_throttle_service.check(EMAIL, UNKNOWN_IP_ADDRESS).is_ok());
+    }
+
+    #[tokio::test]
+    async fn ip_is_limited_across_accounts() {
+        let (db, login_throttle_service, _) = setup("login_ip_test", 100, 2).await;
+        for email in ["jane@example.com", "john@example.com"] {
+            login_throttle_service
+                .record_failure(&db, email, "203.0.113.7")
+                .await
+                .unwrap();
+        }
+
+        assert!(login_throttle_service.check("joe@example.com", "203.0.113.7").is_err());
+        assert!(login_throttle_service.check("joe@example.com", "203.0.113.8").is_ok());
+    }
+}
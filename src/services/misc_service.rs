@@ -1,8 +1,8 @@
 use std::collections::BTreeMap;
-use argon2::{Argon2, PasswordHasher};
-use argon2::password_hash::SaltString;
 use crate::api::proto::misc::{SetupRequest, SetupResponse};
 use crate::error::Result;
+use crate::extensions::string_extension::StringExtension;
+use crate::providers::avored_config_provider::PasswordHashConfig;
 use crate::providers::avored_database_provider::DB;
 
 pub struct MiscService {}
@@ -11,7 +11,7 @@ impl MiscService {
     pub(crate) async fn setup(
         &self,
         req: SetupRequest,
-        password_salt: &str,
+        password_hash_config: &PasswordHashConfig,
         (ds, ses): &DB
     ) -> Result<SetupResponse> {
         let sql = "
@@ -36,7 +36,34 @@ impl MiscService {
         };
 
         CREATE settings CONTENT {
-            identifier: 'auth_cms_token',
+            identifier: 'seo_robots_txt',
+            value: 'User-agent: *\nAllow: /',
+            created_by: $email,
+            updated_by: $email,
+            created_at: time::now(),
+            updated_at: time::now()
+        };
+
+        CREATE settings CONTENT {
+            identifier: 'email_sender_name',
+            value: 'Avored rust cms',
+            created_by: $email,
+            updated_by: $email,
+            created_at: time::now(),
+            updated_at: time::now()
+        };
+
+        CREATE settings CONTENT {
+            identifier: 'email_sender_address',
+            value: 'info@avored.com',
+            created_by: $email,
+            updated_by: $email,
+            created_at: time::now(),
+            updated_at: time::now()
+        };
+
+        CREATE settings CONTENT {
+            identifier: 'email_reply_to',
             value: '',
             created_by: $email,
             updated_by: $email,
@@ -44,6 +71,144 @@ impl MiscService {
             updated_at: time::now()
         };
 
+        CREATE settings CONTENT {
+            identifier: 'auth_two_factor_required',
+            value: 'false',
+            created_by: $email,
+            updated_by: $email,
+            created_at: time::now(),
+            updated_at: time::now()
+        };
+
+        REMOVE TABLE cms_api_keys;
+        DEFINE TABLE cms_api_keys;
+
+        DEFINE FIELD name ON TABLE cms_api_keys TYPE string;
+        DEFINE FIELD key_prefix ON TABLE cms_api_keys TYPE string;
+        DEFINE FIELD key_hash ON TABLE cms_api_keys TYPE string;
+        DEFINE FIELD collections ON TABLE cms_api_keys TYPE array<string>;
+        DEFINE FIELD operations ON TABLE cms_api_keys TYPE array<string>;
+        DEFINE FIELD status ON TABLE cms_api_keys TYPE string;
+        DEFINE FIELD last_used_at ON TABLE cms_api_keys TYPE option<datetime>;
+        DEFINE FIELD created_by ON TABLE cms_api_keys TYPE string;
+        DEFINE FIELD updated_by ON TABLE cms_api_keys TYPE string;
+        DEFINE FIELD created_at ON TABLE cms_api_keys TYPE datetime;
+        DEFINE FIELD updated_at ON TABLE cms_api_keys TYPE datetime;
+        DEFINE INDEX cms_api_keys_key_hash_index ON TABLE cms_api_keys COLUMNS key_hash UNIQUE;
+
+
+        REMOVE TABLE webhooks;
+        DEFINE TABLE webhooks;
+
+        DEFINE FIELD name ON TABLE webhooks TYPE string;
+        DEFINE FIELD url ON TABLE webhooks TYPE string;
+        DEFINE FIELD events ON TABLE webhooks TYPE array<string>;
+        DEFINE FIELD secret ON TABLE webhooks TYPE string;
+        DEFINE FIELD is_active ON TABLE webhooks TYPE bool;
+        DEFINE FIELD created_by ON TABLE webhooks TYPE string;
+        DEFINE FIELD updated_by ON TABLE webhooks TYPE string;
+        DEFINE FIELD created_at ON TABLE webhooks TYPE datetime;
+        DEFINE FIELD updated_at ON TABLE webhooks TYPE datetime;
+
+
+        REMOVE TABLE webhook_deliveries;
+        DEFINE TABLE webhook_deliveries;
+
+        DEFINE FIELD webhook_id ON TABLE webhook_deliveries TYPE string;
+        DEFINE FIELD event ON TABLE webhook_deliveries TYPE string;
+        DEFINE FIELD payload ON TABLE webhook_deliveries TYPE string;
+        DEFINE FIELD status ON TABLE webhook_deliveries TYPE string;
+        DEFINE FIELD attempts ON TABLE webhook_deliveries TYPE int;
+        DEFINE FIELD response_status ON TABLE webhook_deliveries TYPE int;
+        DEFINE FIELD response_body ON TABLE webhook_deliveries TYPE string;
+        DEFINE FIELD error ON TABLE webhook_deliveries TYPE string;
+        DEFINE FIELD next_attempt_at ON TABLE webhook_deliveries TYPE datetime;
+        DEFINE FIELD created_at ON TABLE webhook_deliveries TYPE datetime;
+        DEFINE FIELD updated_at ON TABLE webhook_deliveries TYPE datetime;
+        DEFINE INDEX webhook_deliveries_webhook_id_index ON TABLE webhook_deliveries COLUMNS webhook_id;
+        DEFINE INDEX webhook_deliveries_due_index ON TABLE webhook_deliveries COLUMNS status, next_attempt_at;
+
+
+        REMOVE TABLE forms;
+        DEFINE TABLE forms;
+
+        DEFINE FIELD name ON TABLE forms TYPE string;
+        DEFINE FIELD identifier ON TABLE forms TYPE string;
+        DEFINE FIELD fields ON TABLE forms TYPE array<object>;
+        DEFINE FIELD recipients ON TABLE forms TYPE array<string>;
+        DEFINE FIELD email_subject ON TABLE forms TYPE string;
+        DEFINE FIELD success_message ON TABLE forms TYPE string;
+        DEFINE FIELD is_active ON TABLE forms TYPE bool;
+        DEFINE FIELD created_by ON TABLE forms TYPE string;
+        DEFINE FIELD updated_by ON TABLE forms TYPE string;
+        DEFINE FIELD created_at ON TABLE forms TYPE datetime;
+        DEFINE FIELD updated_at ON TABLE forms TYPE datetime;
+        DEFINE INDEX forms_identifier_index ON TABLE forms COLUMNS identifier UNIQUE;
+
+        CREATE forms CONTENT {
+            name: 'Contact us',
+            identifier: 'contact-us',
+            fields: [
+                { name: 'First name', identifier: 'first_name', field_type: 'text', is_required: true, min_length: 0, max_length: 255, options: [] },
+                { name: 'Last name', identifier: 'last_name', field_type: 'text', is_required: false, min_length: 0, max_length: 255, options: [] },
+                { name: 'Email', identifier: 'email', field_type: 'email', is_required: true, min_length: 0, max_length: 255, options: [] },
+                { name: 'Phone', identifier: 'phone', field_type: 'phone', is_required: false, min_length: 0, max_length: 40, options: [] },
+                { name: 'Message', identifier: 'message', field_type: 'textarea', is_required: true, min_length: 0, max_length: 5000, options: [] }
+            ],
+            recipients: [],
+            email_subject: 'Contact us message',
+            success_message: 'Thank you for contacting us, we will get back to you soon.',
+            is_active: true,
+            created_by: $email,
+            updated_by: $email,
+            created_at: time::now(),
+            updated_at: time::now()
+        };
+
+
+        REMOVE TABLE emails;
+        DEFINE TABLE emails;
+
+        DEFINE FIELD from_address ON TABLE emails TYPE string;
+        DEFINE FIELD to_address ON TABLE emails TYPE string;
+        DEFINE FIELD subject ON TABLE emails TYPE string;
+        DEFINE FIELD body ON TABLE emails TYPE string;
+        DEFINE FIELD text_body ON TABLE emails TYPE string;
+        DEFINE FIELD reply_to ON TABLE emails TYPE string;
+        DEFINE FIELD attachments ON TABLE emails TYPE array<object>;
+        DEFINE FIELD template ON TABLE emails TYPE string;
+        DEFINE FIELD status ON TABLE emails TYPE string;
+        DEFINE FIELD attempts ON TABLE emails TYPE int;
+        DEFINE FIELD error ON TABLE emails TYPE string;
+        DEFINE FIELD next_attempt_at ON TABLE emails TYPE datetime;
+        DEFINE FIELD created_at ON TABLE emails TYPE datetime;
+        DEFINE FIELD updated_at ON TABLE emails TYPE datetime;
+        DEFINE INDEX emails_due_index ON TABLE emails COLUMNS status, next_attempt_at;
+
+
+        REMOVE TABLE email_templates;
+        DEFINE TABLE email_templates;
+
+        DEFINE FIELD identifier ON TABLE email_templates TYPE string;
+        DEFINE FIELD subject ON TABLE email_templates TYPE string;
+        DEFINE FIELD html_body ON TABLE email_templates TYPE string;
+        DEFINE FIELD text_body ON TABLE email_templates TYPE string;
+        DEFINE FIELD created_by ON TABLE email_templates TYPE string;
+        DEFINE FIELD updated_by ON TABLE email_templates TYPE string;
+        DEFINE FIELD created_at ON TABLE email_templates TYPE datetime;
+        DEFINE FIELD updated_at ON TABLE email_templates TYPE datetime;
+        DEFINE INDEX email_templates_identifier_index ON TABLE email_templates COLUMNS identifier UNIQUE;
+
+
+        REMOVE TABLE form_submissions;
+        DEFINE TABLE form_submissions;
+
+        DEFINE FIELD form_id ON TABLE form_submissions TYPE string;
+        DEFINE FIELD form_identifier ON TABLE form_submissions TYPE string;
+        DEFINE FIELD data ON TABLE form_submissions TYPE object FLEXIBLE;
+        DEFINE FIELD created_at ON TABLE form_submissions TYPE datetime;
+        DEFINE INDEX form_submissions_form_id_index ON TABLE form_submissions COLUMNS form_id;
+
 
         REMOVE TABLE admin_users;
         DEFINE TABLE admin_users;
@@ -71,6 +236,54 @@ impl MiscService {
             updated_at: time::now()
         };
 
+        REMOVE TABLE admin_user_sessions;
+        DEFINE TABLE admin_user_sessions;
+
+        DEFINE FIELD admin_user_id ON TABLE admin_user_sessions TYPE string;
+        DEFINE FIELD status ON TABLE admin_user_sessions TYPE string;
+        DEFINE FIELD user_agent ON TABLE admin_user_sessions TYPE string;
+        DEFINE FIELD ip_address ON TABLE admin_user_sessions TYPE string;
+        DEFINE FIELD last_activity_at ON TABLE admin_user_sessions TYPE datetime;
+        DEFINE FIELD expires_at ON TABLE admin_user_sessions TYPE datetime;
+        DEFINE FIELD created_at ON TABLE admin_user_sessions TYPE datetime;
+        DEFINE FIELD updated_at ON TABLE admin_user_sessions TYPE datetime;
+        DEFINE INDEX admin_user_sessions_admin_user_id_index ON TABLE admin_user_sessions COLUMNS admin_user_id;
+
+
+        REMOVE TABLE refresh_tokens;
+        DEFINE TABLE refresh_tokens;
+
+        DEFINE FIELD session_id ON TABLE refresh_tokens TYPE string;
+        DEFINE FIELD token_hash ON TABLE refresh_tokens TYPE string;
+        DEFINE FIELD status ON TABLE refresh_tokens TYPE string;
+        DEFINE FIELD expires_at ON TABLE refresh_tokens TYPE datetime;
+        DEFINE FIELD created_at ON TABLE refresh_tokens TYPE datetime;
+        DEFINE INDEX refresh_tokens_token_hash_index ON TABLE refresh_tokens COLUMNS token_hash UNIQUE;
+
+
+        REMOVE TABLE admin_user_two_factors;
+        DEFINE TABLE admin_user_two_factors;
+
+        DEFINE FIELD admin_user_id ON TABLE admin_user_two_factors TYPE string;
+        DEFINE FIELD secret ON TABLE admin_user_two_factors TYPE string;
+        DEFINE FIELD is_enabled ON TABLE admin_user_two_factors TYPE bool;
+        DEFINE FIELD recovery_codes ON TABLE admin_user_two_factors TYPE array<string>;
+        DEFINE FIELD last_used_step ON TABLE admin_user_two_factors TYPE int;
+        DEFINE FIELD created_at ON TABLE admin_user_two_factors TYPE datetime;
+        DEFINE FIELD updated_at ON TABLE admin_user_two_factors TYPE datetime;
+
+
+        REMOVE TABLE audit_logs;
+        DEFINE TABLE audit_logs;
+
+        DEFINE FIELD action ON TABLE audit_logs TYPE string;
+        DEFINE FIELD email ON TABLE audit_logs TYPE string;
+        DEFINE FIELD ip_address ON TABLE audit_logs TYPE string;
+        DEFINE FIELD detail ON TABLE audit_logs TYPE string;
+        DEFINE FIELD created_by ON TABLE audit_logs TYPE string;
+        DEFINE FIELD created_at ON TABLE audit_logs TYPE datetime;
+        DEFINE INDEX audit_logs_created_at_index ON TABLE audit_logs COLUMNS created_at;
+
         REMOVE TABLE password_rest;
         DEFINE TABLE password_rest;
 
@@ -143,6 +356,7 @@ impl MiscService {
 
         DEFINE FIELD name ON TABLE collections TYPE string;
         DEFINE FIELD identifier ON TABLE collections TYPE string;
+        DEFINE FIELD is_routable ON TABLE collections TYPE bool DEFAULT false;
         DEFINE FIELD created_by ON TABLE collections TYPE string;
         DEFINE FIELD updated_by ON TABLE collections TYPE string;
         DEFINE FIELD created_at ON TABLE collections TYPE datetime;
@@ -153,6 +367,7 @@ impl MiscService {
         CREATE collections CONTENT {
             name: 'Pages',
             identifier: 'pages',
+            is_routable: true,
             created_by: $email,
             updated_by: $email,
             created_at: time::now(),
@@ -161,14 +376,7 @@ impl MiscService {
 
     ";
 
-        let password = req.password.as_bytes();
-        let salt = SaltString::from_b64(password_salt).unwrap();
-
-        let argon2 = Argon2::default();
-        let password_hash = argon2
-            .hash_password(password, &salt)
-            .expect("Error occurred while encrypted password")
-            .to_string();
+        let password_hash = req.password.get_password_hash(password_hash_config)?;
 
         let vars = BTreeMap::from([
             ("full_name".into(), "Admin".into()),
@@ -6,3 +6,16 @@ pub mod asset_service;
 pub mod setting_service;
 pub mod cms_service;
 pub mod general_service;
+pub mod graphql_service;
+pub mod cms_api_key_service;
+pub mod page_service;
+pub mod sitemap_service;
+pub mod feed_service;
+pub mod webhook_service;
+pub mod static_site_service;
+pub mod form_service;
+pub mod form_spam_service;
+pub mod email_service;
+pub mod email_template_service;
+pub mod login_throttle_service;
+pub mod password_policy_service;
@@ -0,0 +1,182 @@
+use std::sync::Arc;
+use serde_json::{json, Map, Value as JsonValue};
+use sha2::{Digest, Sha256};
+use crate::error::{Error, Result};
+use crate::models::collection_model::{CollectionModel, PageMenuCache};
+use crate::models::content_model::ContentModel;
+use crate::providers::avored_database_provider::DB;
+use crate::providers::avored_theme_provider::AvoRedThemeProvider;
+use crate::repositories::collection_repository::CollectionRepository;
+use crate::repositories::content_repository::ContentRepository;
+use crate::repositories::setting_repository::SettingRepository;
+
+const DEFAULT_TEMPLATE: &str = "templates/default";
+const MENU_ITEM_LIMIT: i64 = 50;
+
+pub struct PageService {
+    content_repository: ContentRepository,
+    collection_repository: CollectionRepository,
+    setting_repository: SettingRepository,
+    page_menu_cache: Arc<PageMenuCache>,
+}
+
+pub struct RenderedPage {
+    pub html: String,
+}
+
+impl RenderedPage {
+    /// Menus, settings and templates change a page as well as its content
+    /// record, so the validator is a hash of the rendered html.
+    pub fn etag(&self) -> String {
+        format!("\"{}\"", &hex::encode(Sha256::digest(&self.html))[..32])
+    }
+}
+
+impl PageService {
+    pub fn new(
+        content_repository: ContentRepository,
+        collection_repository: CollectionRepository,
+        setting_repository: SettingRepository,
+        page_menu_cache: Arc<PageMenuCache>,
+    ) -> Result<Self> {
+        Ok(PageService {
+            content_repository,
+            collection_repository,
+            setting_repository,
+            page_menu_cache,
+        })
+    }
+}
+
+impl PageService {
+    /// Renders `/{collection}/{identifier}` with the most specific theme template:
+    /// `templates/{collection}/{identifier}`, then `templates/{collection}`, then `templates/default`.
+    pub async fn render_page(
+        &self,
+        db: &DB,
+        theme: &AvoRedThemeProvider,
+        collection: &str,
+        identifier: &str,
+    ) -> Result<RenderedPage> {
+        let (datastore, database_session) = db;
+        let collection_model = self
+            .collection_repository
+            .find_by_identifier(datastore, database_session, collection)
+            .await?;
+        if !collection_model.is_routable {
+            return Err(Error::NotFound(format!("collection {collection}")));
+        }
+
+        let content_model = self
+            .content_repository
+            .find_by_identifier(datastore, database_session, collection, identifier)
+            .await
+            .map_err(|_| Error::NotFound(format!("content {collection}/{identifier}")))?;
+        if !content_model.is_published() {
+            return Err(Error::NotFound(format!("content {collection}/{identifier}")));
+        }
+
+        let template_name = [
+            format!("templates/{collection}/{identifier}"),
+            format!("templates/{collection}"),
+            DEFAULT_TEMPLATE.to_string(),
+        ]
+        .into_iter()
+        .find(|name| theme.has_template(name))
+        .ok_or_else(|| Error::NotFound(String::from(DEFAULT_TEMPLATE)))?;
+
+        let mut page_context = self.page_context(db, theme).await?;
+        page_context["collection"] = json!({
+            "name": collection_model.name,
+            "identifier": collection_model.identifier,
+        });
+        page_context["content"] = content_context(&collection_model, &content_model);
+
+        let html = theme.handlebars.render(&template_name, &page_context)?;
+
+        Ok(RenderedPage { html })
+    }
+
+    /// Renders `templates/404` when the theme has one.
+    pub async fn render_not_found(
+        &self,
+        db: &DB,
+        theme: &AvoRedThemeProvider,
+    ) -> Result<Option<String>> {
+        if !theme.has_template("templates/404") {
+            return Ok(None);
+        }
+        let page_context = self.page_context(db, theme).await?;
+
+        Ok(Some(theme.handlebars.render("templates/404", &page_context)?))
+    }
+
+    /// Data shared by every page: site settings, theme urls and the menus of routable collections.
+    async fn page_context(
+        &self,
+        db: &DB,
+        theme: &AvoRedThemeProvider,
+    ) -> Result<JsonValue> {
+        let (datastore, database_session) = db;
+        let site_name = self
+            .setting_repository
+            .find_by_identifier(datastore, database_session, "general_site_name")
+            .await
+            .map(|setting_model| setting_model.value)
+            .unwrap_or_default();
+        let menus = self.menus(db).await?;
+
+        Ok(json!({
+            "site": { "name": site_name },
+            "theme": { "name": theme.theme, "assets_url": theme.assets_url() },
+            "menus": menus,
+        }))
+    }
+
+    /// Menus of the routable collections, loaded once and kept until content or
+    /// a collection changes.
+    async fn menus(&self, (datastore, database_session): &DB) -> Result<Map<String, JsonValue>> {
+        if let Some(menus) = self.page_menu_cache.get(&()) {
+            return Ok(menus);
+        }
+
+        let collection_models = self
+            .collection_repository
+            .all_collection(datastore, database_session)
+            .await?;
+
+        let mut menus = Map::new();
+        for collection_model in collection_models.iter().filter(|c| c.is_routable) {
+            let content_models = self
+                .content_repository
+                .all_by_collection(
+                    datastore,
+                    database_session,
+                    &collection_model.identifier,
+                    MENU_ITEM_LIMIT,
+                )
+                .await?;
+
+            let items = content_models
+                .iter()
+                .map(|content_model| content_context(collection_model, content_model))
+                .collect::<Vec<JsonValue>>();
+            menus.insert(collection_model.identifier.clone(), JsonValue::Array(items));
+        }
+        self.page_menu_cache.insert((), menus.clone());
+
+        Ok(menus)
+    }
+}
+
+fn content_context(collection_model: &CollectionModel, content_model: &ContentModel) -> JsonValue {
+    json!({
+        "id": content_model.id,
+        "name": content_model.name,
+        "identifier": content_model.identifier,
+        "url": format!("/{}/{}", collection_model.identifier, content_model.identifier),
+        "fields": content_model.compact_fields(),
+        "created_at": content_model.created_at.to_string(),
+        "updated_at": content_model.updated_at.to_string(),
+    })
+}
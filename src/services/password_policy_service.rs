@@ -0,0 +1,292 @@
+use std::collections::HashSet;
+use argon2::{Argon2, PasswordHash, PasswordVerifier};
+use rust_i18n::t;
+use tracing::warn;
+use crate::error::Result;
+use crate::extensions::string_extension::StringExtension;
+use crate::models::password_history_model::CreatablePasswordHistoryModel;
+use crate::models::validation_error::ErrorMessage;
+use crate::providers::avored_config_provider::{PasswordHashConfig, PasswordPolicyConfig};
+use crate::providers::avored_database_provider::DB;
+use crate::repositories::admin_user_repository::AdminUserRepository;
+use crate::repositories::password_history_repository::PasswordHistoryRepository;
+
+/// Bundled list of passwords too common to be accepted, one per line.
+const COMMON_PASSWORDS: &str = include_str!("../../resources/passwords/common-passwords.txt");
+
+/// Checks a new password against the configured policy, the common password
+/// list and the passwords the admin user had last.
+pub struct PasswordPolicyService {
+    admin_user_repository: AdminUserRepository,
+    password_history_repository: PasswordHistoryRepository,
+    password_policy: PasswordPolicyConfig,
+    password_hash_config: PasswordHashConfig,
+    common_passwords: HashSet<String>,
+}
+
+impl PasswordPolicyService {
+    pub fn new(
+        admin_user_repository: AdminUserRepository,
+        password_history_repository: PasswordHistoryRepository,
+        password_policy: PasswordPolicyConfig,
+        password_hash_config: PasswordHashConfig,
+    ) -> Result<Self> {
+        let common_passwords = COMMON_PASSWORDS
+            .lines()
+            .map(|line| line.trim().to_lowercase())
+            .filter(|line| !line.is_empty())
+            .collect();
+
+        Ok(PasswordPolicyService {
+            admin_user_repository,
+            password_history_repository,
+            password_policy,
+            password_hash_config,
+            common_passwords,
+        })
+    }
+}
+
+impl PasswordPolicyService {
+    /// Error messages for the `password` key, empty when the password can be
+    /// set. `email` is the admin user the password is for, it may not exist yet.
+    pub async fn validate(&self, db: &DB, password: &str, email: &str) -> Result<Vec<ErrorMessage>> {
+        let mut errors: Vec<ErrorMessage> = vec![];
+        let password_policy = &self.password_policy;
+        let length = password.chars().count();
+
+        if length < password_policy.min_length {
+            errors.push(password_error(
+                t!("validation_min_length", attribute = t!("password"), min = password_policy.min_length).to_string(),
+            ));
+        }
+        if password_policy.max_length > 0 && length > password_policy.max_length {
+            errors.push(password_error(
+                t!("validation_max_length", attribute = t!("password"), max = password_policy.max_length).to_string(),
+            ));
+        }
+        if password_policy.require_lowercase && !password.chars().any(char::is_lowercase) {
+            errors.push(password_error(t!("password_requires_lowercase").to_string()));
+        }
+        if password_policy.require_uppercase && !password.chars().any(char::is_uppercase) {
+            errors.push(password_error(t!("password_requires_uppercase").to_string()));
+        }
+        if password_policy.require_digit && !password.chars().any(|c| c.is_ascii_digit()) {
+            errors.push(password_error(t!("password_requires_digit").to_string()));
+        }
+        if password_policy.require_symbol && !password.chars().any(|c| !c.is_alphanumeric() && !c.is_whitespace()) {
+            errors.push(password_error(t!("password_requires_symbol").to_string()));
+        }
+        if self.common_passwords.contains(&password.to_lowercase()) {
+            errors.push(password_error(t!("password_too_common").to_string()));
+        }
+
+        if errors.is_empty() && self.is_recently_used(db, password, email).await? {
+            errors.push(password_error(
+                t!("password_recently_used", count = password_policy.history).to_string(),
+            ));
+        }
+
+        Ok(errors)
+    }
+
+    /// Adds the password the admin user was just given to their history and
+    /// forgets the ones that no longer count. The password is set by then, so
+    /// a failure here is only logged.
+    pub async fn remember_password(&self, db: &DB, email: &str, password: &str) {
+        if self.password_policy.history == 0 {
+            return;
+        }
+
+        if let Err(e) = self.add_to_history(db, email, password).await {
+            warn!("unable to add the new password of {email} to the password history: {e:?}");
+        }
+    }
+
+    async fn add_to_history(&self, (datastore, database_session): &DB, email: &str, password: &str) -> Result<()> {
+        let admin_user_model = self
+            .admin_user_repository
+            .find_by_email(datastore, database_session, email)
+            .await?;
+        let creatable_password_history = CreatablePasswordHistoryModel {
+            admin_user_id: admin_user_model.id.clone(),
+            password_hash: password.to_string().get_password_hash(&self.password_hash_config)?,
+        };
+        self.password_history_repository
+            .create_password_history(datastore, database_session, creatable_password_history)
+            .await?;
+
+        let password_histories = self
+            .password_history_repository
+            .latest_by_admin_user_id(datastore, database_session, &admin_user_model.id, self.password_policy.history)
+            .await?;
+        if let Some(oldest_kept) = password_histories.last() {
+            self.password_history_repository
+                .delete_older_than(datastore, database_session, &admin_user_model.id, oldest_kept.created_at.clone())
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// The current password counts too, admin users from before the history
+    /// was kept have nothing else in it.
+    async fn is_recently_used(&self, (datastore, database_session): &DB, password: &str, email: &str) -> Result<bool> {
+        if self.password_policy.history == 0 {
+            return Ok(false);
+        }
+
+        // counting fails while there is no admin user at all, as before setup.
+        let admin_user_count = self
+            .admin_user_repository
+            .count_of_email(datastore, database_session, email.to_string())
+            .await
+            .map(|model_count| model_count.total)
+            .unwrap_or(0);
+        if admin_user_count != 1 {
+            return Ok(false);
+        }
+        let admin_user_model = self
+            .admin_user_repository
+            .find_by_email(datastore, database_session, email)
+            .await?;
+
+        let password_histories = self
+            .password_history_repository
+            .latest_by_admin_user_id(datastore, database_session, &admin_user_model.id, self.password_policy.history)
+            .await?;
+
+        let argon2 = Argon2::default();
+        let is_recently_used = std::iter::once(admin_user_model.password)
+            .chain(password_histories.into_iter().map(|password_history| password_history.password_hash))
+            .any(|password_hash| {
+                PasswordHash::new(&password_hash)
+                    .is_ok_and(|parsed_hash| argon2.verify_password(password.as_bytes(), &parsed_hash).is_ok())
+            });
+
+        Ok(is_recently_used)
+    }
+}
+
+fn password_error(message: String) -> ErrorMessage {
+    ErrorMessage {
+        key: String::from("password"),
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_i18n::t;
+    use crate::extensions::string_extension::StringExtension;
+    use crate::models::admin_user_model::CreatableAdminUserModel;
+    use crate::providers::avored_config_provider::PasswordPolicyConfig;
+    use crate::providers::avored_database_provider::DB;
+    use crate::repositories::admin_user_repository::AdminUserRepository;
+    use crate::repositories::password_history_repository::PasswordHistoryRepository;
+    use crate::test_helpers::{memory_db, test_config};
+    use super::PasswordPolicyService;
+
+    const EMAIL: &str = "jane@example.com";
+
+    fn password_policy_service(password_policy: PasswordPolicyConfig) -> PasswordPolicyService {
+        PasswordPolicyService::new(
+            AdminUserRepository::new(),
+            PasswordHistoryRepository::new(),
+            password_policy,
+            test_config().password_hash,
+        )
+        .unwrap()
+    }
+
+    async fn messages(password_policy_service: &PasswordPolicyService, db: &DB, password: &str) -> Vec<String> {
+        password_policy_service
+            .validate(db, password, EMAIL)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|error_message| error_message.message)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn length_and_character_rules() {
+        let db = memory_db("password_policy_rules_test").await;
+        let password_policy_service = password_policy_service(PasswordPolicyConfig {
+            max_length: 20,
+            require_symbol: true,
+            ..test_config().password_policy
+        });
+
+        assert!(messages(&password_policy_service, &db, "Correct-Horse-7").await.is_empty());
+        assert_eq!(
+            messages(&password_policy_service, &db, "Ab-1").await,
+            vec![t!("validation_min_length", attribute = t!("password"), min = 10).to_string()]
+        );
+        assert_eq!(
+            messages(&password_policy_service, &db, "Correct-Horse-Battery-7").await,
+            vec![t!("validation_max_length", attribute = t!("password"), max = 20).to_string()]
+        );
+        assert_eq!(
+            messages(&password_policy_service, &db, "correcthorse").await,
+            vec![
+                t!("password_requires_uppercase").to_string(),
+                t!("password_requires_digit").to_string(),
+                t!("password_requires_symbol").to_string(),
+            ]
+        );
+        assert_eq!(
+            messages(&password_policy_service, &db, "CORRECT-HORSE-7").await,
+            vec![t!("password_requires_lowercase").to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn common_passwords_are_refused() {
+        let db = memory_db("password_policy_common_test").await;
+        let password_policy_service = password_policy_service(test_config().password_policy);
+
+        assert_eq!(
+            messages(&password_policy_service, &db, "Password123").await,
+            vec![t!("password_too_common").to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn current_and_remembered_passwords_are_refused() {
+        let db = memory_db("password_policy_history_test").await;
+        let password_policy_service = password_policy_service(PasswordPolicyConfig {
+            history: 2,
+            ..test_config().password_policy
+        });
+        let recently_used = vec![t!("password_recently_used", count = 2).to_string()];
+
+        let (datastore, database_session) = &db;
+        let creatable_admin_user = CreatableAdminUserModel {
+            full_name: String::from("Jane Doe"),
+            email: String::from(EMAIL),
+            password: String::from("Current-Pass-1")
+                .get_password_hash(&test_config().password_hash)
+                .unwrap(),
+            profile_image: String::from(""),
+            is_super_admin: false,
+            logged_in_username: String::from(EMAIL),
+        };
+        AdminUserRepository::new()
+            .create_admin_user(datastore, database_session, creatable_admin_user)
+            .await
+            .unwrap();
+
+        assert_eq!(messages(&password_policy_service, &db, "Current-Pass-1").await, recently_used);
+
+        for password in ["First-Pass-1", "Second-Pass-2", "Third-Pass-3"] {
+            password_policy_service.remember_password(&db, EMAIL, password).await;
+        }
+
+        // only the last two remembered passwords still count.
+        assert_eq!(messages(&password_policy_service, &db, "Third-Pass-3").await, recently_used);
+        assert_eq!(messages(&password_policy_service, &db, "Second-Pass-2").await, recently_used);
+        assert!(messages(&password_policy_service, &db, "First-Pass-1").await.is_empty());
+        assert!(messages(&password_policy_service, &db, "Fresh-Pass-4").await.is_empty());
+    }
+}
@@ -0,0 +1,150 @@
+use std::sync::Arc;
+use crate::error::{Error, Result};
+use crate::extensions::xml_extension::{W3cDatetimeExtension, XmlExtension};
+use crate::models::sitemap_model::{SitemapCache, SitemapUrlModel};
+use crate::providers::avored_database_provider::DB;
+use crate::repositories::collection_repository::CollectionRepository;
+use crate::repositories::content_repository::ContentRepository;
+use crate::repositories::setting_repository::SettingRepository;
+
+const ROBOTS_TXT_SETTING: &str = "seo_robots_txt";
+const DEFAULT_ROBOTS_TXT: &str = "User-agent: *\nAllow: /";
+
+pub struct SitemapService {
+    content_repository: ContentRepository,
+    collection_repository: CollectionRepository,
+    setting_repository: SettingRepository,
+    sitemap_cache: Arc<SitemapCache>,
+    base_url: String,
+    max_urls: usize,
+}
+
+impl SitemapService {
+    pub fn new(
+        content_repository: ContentRepository,
+        collection_repository: CollectionRepository,
+        setting_repository: SettingRepository,
+        sitemap_cache: Arc<SitemapCache>,
+        base_url: String,
+        max_urls: usize,
+    ) -> Result<Self> {
+        Ok(SitemapService {
+            content_repository,
+            collection_repository,
+            setting_repository,
+            sitemap_cache,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            max_urls: max_urls.max(1),
+        })
+    }
+}
+
+impl SitemapService {
+    /// `/sitemap.xml` is a plain url set while the site fits in one file,
+    /// otherwise it becomes an index of `/sitemaps/sitemap-{n}.xml` files.
+    pub async fn sitemap_xml(&self, db: &DB) -> Result<String> {
+        let sitemap_urls = self.sitemap_urls(db).await?;
+        if sitemap_urls.len() <= self.max_urls {
+            return Ok(url_set_xml(&sitemap_urls));
+        }
+
+        let mut xml = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <sitemapindex xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+        );
+        for (index, chunk) in sitemap_urls.chunks(self.max_urls).enumerate() {
+            let lastmod = chunk.iter().map(|url| url.lastmod.clone()).max().unwrap_or_default();
+            xml.push_str(&format!(
+                "  <sitemap>\n    <loc>{}</loc>\n    <lastmod>{}</lastmod>\n  </sitemap>\n",
+                format!("{}/sitemaps/sitemap-{}.xml", self.base_url, index + 1).xml_escape(),
+                lastmod.to_utc().to_w3c_datetime(),
+            ));
+        }
+        xml.push_str("</sitemapindex>\n");
+
+        Ok(xml)
+    }
+
+    /// One page of a split sitemap, numbered from 1.
+    pub async fn sitemap_page_xml(&self, db: &DB, page: usize) -> Result<String> {
+        let sitemap_urls = self.sitemap_urls(db).await?;
+
+        page.checked_sub(1)
+            .and_then(|index| sitemap_urls.chunks(self.max_urls).nth(index))
+            .map(url_set_xml)
+            .ok_or_else(|| Error::NotFound(format!("sitemap page {page}")))
+    }
+
+    /// The `seo_robots_txt` setting, with the sitemap location appended when
+    /// the admin did not add one.
+    pub async fn robots_txt(&self, (datastore, database_session): &DB) -> Result<String> {
+        let robots_txt = self
+            .setting_repository
+            .find_by_identifier(datastore, database_session, ROBOTS_TXT_SETTING)
+            .await
+            .map(|setting_model| setting_model.value)
+            .unwrap_or_else(|_| String::from(DEFAULT_ROBOTS_TXT));
+
+        let has_sitemap = robots_txt
+            .lines()
+            .any(|line| line.trim().to_ascii_lowercase().starts_with("sitemap:"));
+
+        let mut robots_txt = robots_txt.trim_end().to_string();
+        if !has_sitemap {
+            robots_txt.push_str(&format!("\n\nSitemap: {}/sitemap.xml", self.base_url));
+        }
+        robots_txt.push('\n');
+
+        Ok(robots_txt)
+    }
+
+    async fn sitemap_urls(&self, (datastore, database_session): &DB) -> Result<Vec<SitemapUrlModel>> {
+        if let Some(sitemap_urls) = self.sitemap_cache.get(&()) {
+            return Ok(sitemap_urls);
+        }
+
+        let collection_models = self
+            .collection_repository
+            .all_collection(datastore, database_session)
+            .await?;
+
+        let mut sitemap_urls: Vec<SitemapUrlModel> = vec![];
+        for collection_model in collection_models.iter().filter(|c| c.is_routable) {
+            let content_models = self
+                .content_repository
+                .all_published(datastore, database_session, &collection_model.identifier)
+                .await?;
+
+            for content_model in content_models {
+                sitemap_urls.push(SitemapUrlModel {
+                    loc: format!(
+                        "{}/{}/{}",
+                        self.base_url, collection_model.identifier, content_model.identifier
+                    ),
+                    lastmod: content_model.updated_at,
+                });
+            }
+        }
+
+        self.sitemap_cache.insert((), sitemap_urls.clone());
+
+        Ok(sitemap_urls)
+    }
+}
+
+fn url_set_xml(sitemap_urls: &[SitemapUrlModel]) -> String {
+    let mut xml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+    );
+    for sitemap_url in sitemap_urls {
+        xml.push_str(&format!(
+            "  <url>\n    <loc>{}</loc>\n    <lastmod>{}</lastmod>\n  </url>\n",
+            sitemap_url.loc.xml_escape(),
+            sitemap_url.lastmod.to_utc().to_w3c_datetime(),
+        ));
+    }
+    xml.push_str("</urlset>\n");
+
+    xml
+}
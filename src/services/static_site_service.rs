@@ -0,0 +1,380 @@
+use std::collections::{BTreeSet, VecDeque};
+use std::fs::{self, File};
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Mutex;
+use chrono::Utc;
+use rust_i18n::t;
+use tonic::Status;
+use tracing::{error, info, warn};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+use crate::api::proto::content::{ExportStaticSiteResponse, StaticSiteExportStatus, StaticSiteFormat};
+use crate::error::{Error, Result};
+use crate::providers::avored_database_provider::DB;
+use crate::providers::avored_theme_provider::AvoRedThemeProvider;
+use crate::repositories::collection_repository::CollectionRepository;
+use crate::repositories::content_repository::ContentRepository;
+use crate::services::page_service::PageService;
+
+/// Uploaded files are served from here, any reference to it in a page is copied along.
+const PUBLIC_URL_PREFIX: &str = "/public/";
+
+/// Every export folder and zip file is named `site-{timestamp}`.
+const EXPORT_NAME_PREFIX: &str = "site-";
+
+/// Exports whose status is kept in memory for GetStaticSiteExport.
+const EXPORT_HISTORY: usize = 20;
+
+/// One rendered page, `path` is relative to the export root.
+struct StaticPage {
+    path: PathBuf,
+    html: String,
+}
+
+pub struct StaticSiteService {
+    content_repository: ContentRepository,
+    collection_repository: CollectionRepository,
+    export_dir: PathBuf,
+    base_url: String,
+    keep_exports: usize,
+    exports: Mutex<VecDeque<ExportStaticSiteResponse>>,
+}
+
+impl StaticSiteService {
+    pub fn new(
+        content_repository: ContentRepository,
+        collection_repository: CollectionRepository,
+        export_dir: String,
+        base_url: String,
+        keep_exports: usize,
+    ) -> Result<Self> {
+        Ok(StaticSiteService {
+            content_repository,
+            collection_repository,
+            export_dir: PathBuf::from(export_dir),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            keep_exports,
+            exports: Mutex::new(VecDeque::new()),
+        })
+    }
+}
+
+impl StaticSiteService {
+    /// Registers a running export and returns it, the caller runs it with
+    /// `run_export`. Only one export runs at a time.
+    pub fn start_export(&self) -> Result<ExportStaticSiteResponse> {
+        let mut exports = self.exports.lock().unwrap_or_else(|e| e.into_inner());
+        if exports
+            .iter()
+            .any(|export| export.export_status() == StaticSiteExportStatus::Running)
+        {
+            return Err(Error::TonicError(Status::failed_precondition(
+                t!("static_export_running").to_string(),
+            )));
+        }
+
+        let export = ExportStaticSiteResponse {
+            status: true,
+            id: format!("{EXPORT_NAME_PREFIX}{}", Utc::now().format("%Y%m%d%H%M%S%3f")),
+            export_status: StaticSiteExportStatus::Running.into(),
+            ..Default::default()
+        };
+        if exports.len() >= EXPORT_HISTORY {
+            exports.pop_front();
+        }
+        exports.push_back(export.clone());
+
+        Ok(export)
+    }
+
+    /// Status of an export started since the server is up.
+    pub fn get_export(&self, export_id: &str) -> Result<ExportStaticSiteResponse> {
+        self.exports
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .find(|export| export.id == export_id)
+            .cloned()
+            .ok_or_else(|| Error::NotFound(format!("static export {export_id}")))
+    }
+
+    /// Runs an export registered by `start_export`, records how it ended and
+    /// removes the oldest exports beyond the ones to keep.
+    pub async fn run_export(
+        &self,
+        db: &DB,
+        page_service: &PageService,
+        theme: &AvoRedThemeProvider,
+        export_id: &str,
+        static_site_format: StaticSiteFormat,
+    ) {
+        let result = self
+            .export_static_site(db, page_service, theme, export_id, static_site_format)
+            .await;
+
+        {
+            let mut exports = self.exports.lock().unwrap_or_else(|e| e.into_inner());
+            let Some(export) = exports.iter_mut().find(|export| export.id == export_id) else {
+                return;
+            };
+            match result {
+                Ok((path, pages, assets)) => {
+                    info!("static export {export_id} written to {path} with {pages} pages and {assets} assets");
+                    export.path = path;
+                    export.pages = pages;
+                    export.assets = assets;
+                    export.set_export_status(StaticSiteExportStatus::Succeeded);
+                }
+                Err(e) => {
+                    error!("static export {export_id} failed: {e:?}");
+                    export.error = e.to_string();
+                    export.set_export_status(StaticSiteExportStatus::Failed);
+                }
+            }
+        }
+
+        let export_dir = self.export_dir.clone();
+        let keep_exports = self.keep_exports;
+        let pruned = tokio::task::spawn_blocking(move || prune_exports(&export_dir, keep_exports)).await;
+        match pruned {
+            Ok(Err(e)) => warn!("unable to remove old static exports: {e:?}"),
+            Err(e) => warn!("unable to remove old static exports: {e}"),
+            Ok(Ok(())) => {}
+        }
+    }
+
+    /// Writes every published page of the routable collections to
+    /// `{collection}/{identifier}/index.html`, next to the theme assets and the
+    /// uploaded files the pages link to. Absolute links to this server are made
+    /// root relative so the result can be served by any static host.
+    /// Returns the export path, the number of pages and of assets.
+    async fn export_static_site(
+        &self,
+        db: &DB,
+        page_service: &PageService,
+        theme: &AvoRedThemeProvider,
+        name: &str,
+        static_site_format: StaticSiteFormat,
+    ) -> Result<(String, i64, i64)> {
+        let (datastore, database_session) = db;
+        let collection_models = self
+            .collection_repository
+            .all_collection(datastore, database_session)
+            .await?;
+
+        let mut static_pages: Vec<StaticPage> = vec![];
+        for collection_model in collection_models.iter().filter(|c| c.is_routable) {
+            let content_models = self
+                .content_repository
+                .all_published(datastore, database_session, &collection_model.identifier)
+                .await?;
+
+            for content_model in content_models {
+                let path = Path::new(&collection_model.identifier)
+                    .join(&content_model.identifier)
+                    .join("index.html");
+                if !is_safe_relative_path(&path) {
+                    warn!("static export skipped {}", path.display());
+                    continue;
+                }
+
+                let rendered_page = match page_service
+                    .render_page(db, theme, &collection_model.identifier, &content_model.identifier)
+                    .await
+                {
+                    Ok(rendered_page) => rendered_page,
+                    Err(Error::NotFound(resource)) => {
+                        warn!("static export skipped {}: {resource} not found", path.display());
+                        continue;
+                    }
+                    Err(e) => return Err(e),
+                };
+
+                static_pages.push(StaticPage {
+                    path,
+                    html: self.root_relative(&rendered_page.html),
+                });
+            }
+        }
+        let pages = static_pages.len() as i64;
+
+        if let Some(not_found_html) = page_service.render_not_found(db, theme).await? {
+            static_pages.push(StaticPage {
+                path: PathBuf::from("404.html"),
+                html: self.root_relative(&not_found_html),
+            });
+        }
+
+        let site_dir = self.export_dir.join(name);
+        let export_path = match static_site_format {
+            StaticSiteFormat::Zip => self.export_dir.join(format!("{name}.zip")),
+            StaticSiteFormat::Directory => site_dir.clone(),
+        };
+        let zip_path = export_path.clone();
+        let theme_assets_dir = theme.theme_path.join("assets");
+        let theme_assets_url = theme.assets_url();
+
+        // file system work is blocking, keep it off the async workers
+        let assets = tokio::task::spawn_blocking(move || -> Result<i64> {
+            let assets = write_site(&site_dir, &static_pages, &theme_assets_dir, &theme_assets_url)?;
+
+            if static_site_format == StaticSiteFormat::Zip {
+                zip_dir(&site_dir, &zip_path)?;
+                fs::remove_dir_all(&site_dir)?;
+            }
+
+            Ok(assets)
+        })
+        .await
+        .map_err(|e| Error::Generic(format!("static export task failed: {e}")))??;
+
+        Ok((export_path.display().to_string(), pages, assets))
+    }
+
+    fn root_relative(&self, html: &str) -> String {
+        match self.base_url.is_empty() {
+            true => html.to_string(),
+            false => html.replace(&format!("{}/", self.base_url), "/"),
+        }
+    }
+}
+
+/// Writes the pages, the theme assets and every referenced upload.
+/// Returns how many asset files were copied.
+fn write_site(
+    site_dir: &Path,
+    static_pages: &[StaticPage],
+    theme_assets_dir: &Path,
+    theme_assets_url: &str,
+) -> Result<i64> {
+    fs::create_dir_all(site_dir)?;
+
+    let mut public_paths: BTreeSet<String> = BTreeSet::new();
+    for static_page in static_pages {
+        let page_path = site_dir.join(&static_page.path);
+        if let Some(parent) = page_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&page_path, &static_page.html)?;
+
+        public_paths.extend(public_references(&static_page.html));
+    }
+
+    let mut assets = 0;
+    if theme_assets_dir.is_dir() {
+        let target_dir = site_dir.join(theme_assets_url.trim_start_matches('/'));
+        assets += copy_dir(theme_assets_dir, &target_dir)?;
+    }
+
+    for public_path in public_paths {
+        let relative_path = PathBuf::from(public_path.trim_start_matches('/'));
+        if !is_safe_relative_path(&relative_path) || !relative_path.is_file() {
+            continue;
+        }
+        let target_path = site_dir.join(&relative_path);
+        if let Some(parent) = target_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::copy(&relative_path, &target_path)?;
+        assets += 1;
+    }
+
+    Ok(assets)
+}
+
+/// Removes the oldest `site-*` folders and zip files beyond the newest
+/// `keep_exports`, 0 keeps every export.
+fn prune_exports(export_dir: &Path, keep_exports: usize) -> Result<()> {
+    if keep_exports == 0 || !export_dir.is_dir() {
+        return Ok(());
+    }
+
+    let mut export_paths = fs::read_dir(export_dir)?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|file_name| file_name.to_str())
+                .is_some_and(|file_name| file_name.starts_with(EXPORT_NAME_PREFIX))
+        })
+        .collect::<Vec<PathBuf>>();
+    // the names start with the export time, so the newest sort last
+    export_paths.sort();
+
+    let stale = export_paths.len().saturating_sub(keep_exports);
+    for export_path in &export_paths[..stale] {
+        match export_path.is_dir() {
+            true => fs::remove_dir_all(export_path)?,
+            false => fs::remove_file(export_path)?,
+        }
+    }
+
+    Ok(())
+}
+
+/// `/public/...` urls used in a page, without query string or fragment.
+fn public_references(html: &str) -> Vec<String> {
+    html.match_indices(PUBLIC_URL_PREFIX)
+        .map(|(index, _)| {
+            html[index..]
+                .split(|c: char| c.is_whitespace() || "\"'()<>?#,".contains(c))
+                .next()
+                .unwrap_or_default()
+                .to_string()
+        })
+        .filter(|path| path.len() > PUBLIC_URL_PREFIX.len())
+        .collect()
+}
+
+/// Only plain relative paths, so content identifiers or links can not
+/// reach outside of the export or public folder.
+fn is_safe_relative_path(path: &Path) -> bool {
+    path.components().all(|component| matches!(component, Component::Normal(_)))
+}
+
+fn copy_dir(source_dir: &Path, target_dir: &Path) -> Result<i64> {
+    fs::create_dir_all(target_dir)?;
+
+    let mut copied = 0;
+    for entry in fs::read_dir(source_dir)? {
+        let entry = entry?;
+        let target_path = target_dir.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copied += copy_dir(&entry.path(), &target_path)?;
+        } else {
+            fs::copy(entry.path(), target_path)?;
+            copied += 1;
+        }
+    }
+
+    Ok(copied)
+}
+
+fn zip_dir(site_dir: &Path, zip_path: &Path) -> Result<()> {
+    let mut zip_writer = ZipWriter::new(File::create(zip_path)?);
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    let mut pending_dirs = vec![site_dir.to_path_buf()];
+    while let Some(dir) = pending_dirs.pop() {
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                pending_dirs.push(path);
+                continue;
+            }
+
+            let name = path
+                .strip_prefix(site_dir)
+                .map_err(|e| Error::Generic(e.to_string()))?
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            zip_writer.start_file(name, options)?;
+            io::copy(&mut File::open(&path)?, &mut zip_writer)?;
+        }
+    }
+    zip_writer.finish()?;
+
+    Ok(())
+}
@@ -0,0 +1,808 @@
+use std::collections::BTreeMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::Utc;
+use futures::future::join_all;
+use futures::stream::{self, StreamExt};
+use hmac::{Hmac, Mac};
+use rand::distr::Alphanumeric;
+use rand::Rng;
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use reqwest::header::{CONTENT_TYPE, USER_AGENT};
+use rust_i18n::t;
+use serde_json::json;
+use sha2::Sha256;
+use tokio::sync::broadcast::error::RecvError;
+use tracing::{error, info, warn};
+use crate::api::proto::webhook::webhook_delivery_paginate_response::{WebhookDeliveryPaginateData, WebhookDeliveryPagination};
+use crate::api::proto::webhook::{
+    PingWebhookResponse, RedeliverWebhookResponse, StoreWebhookRequest, StoreWebhookResponse,
+    UpdateWebhookRequest, UpdateWebhookResponse, WebhookAllResponse, WebhookDeliveryModel as WebhookDeliveryModelGrpc,
+    WebhookDeliveryPaginateResponse, WebhookModel as WebhookModelGrpc,
+};
+use crate::error::{Error, Result};
+use crate::models::asset_event_model::AssetEventBus;
+use crate::models::content_event_model::ContentEventBus;
+use crate::models::webhook_model::{
+    CreatableWebhookModel, UpdatableWebhookDeliveryModel, UpdatableWebhookModel, WebhookDeliveryModel,
+    WebhookDeliveryStatus, WebhookEventModel, WebhookModel, WEBHOOK_PING_EVENT,
+};
+use crate::models::validation_error::{ErrorMessage, ErrorResponse};
+use crate::providers::avored_clock_provider::AvoRedClockProvider;
+use crate::providers::avored_database_provider::DB;
+use crate::providers::avored_worker_provider::AvoRedWorkerProvider;
+use crate::repositories::webhook_delivery_repository::WebhookDeliveryRepository;
+use crate::repositories::webhook_repository::WebhookRepository;
+use crate::PER_PAGE;
+
+const WEBHOOK_SECRET_PREFIX: &str = "whsec_";
+const WEBHOOK_SECRET_LENGTH: usize = 32;
+
+/// The worker also wakes up on this interval to pick up retries that became due.
+const DELIVERY_POLL_SECONDS: u64 = 5;
+const DELIVERY_BATCH_SIZE: i64 = 50;
+
+/// Deliveries of one webhook in flight at the same time, a slow endpoint
+/// holds up its own deliveries and not the ones of other webhooks.
+const MAX_CONCURRENT_DELIVERIES_PER_WEBHOOK: usize = 4;
+
+/// Only the start of a response is kept in the delivery log.
+const RESPONSE_BODY_MAX_CHARS: usize = 2000;
+
+/// Longest wait between two attempts, whatever the retry base is.
+const MAX_RETRY_DELAY_SECONDS: i64 = 24 * 60 * 60;
+
+pub struct WebhookService {
+    webhook_repository: WebhookRepository,
+    webhook_delivery_repository: WebhookDeliveryRepository,
+    content_event_bus: Arc<ContentEventBus>,
+    asset_event_bus: Arc<AssetEventBus>,
+    http_client: reqwest::Client,
+    delivery_worker: AvoRedWorkerProvider,
+    allow_private_targets: bool,
+}
+
+impl WebhookService {
+    /// Webhooks can only reach public addresses, unless `allow_private_targets`
+    /// is set for a development setup. Redirects are never followed.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        webhook_repository: WebhookRepository,
+        webhook_delivery_repository: WebhookDeliveryRepository,
+        content_event_bus: Arc<ContentEventBus>,
+        asset_event_bus: Arc<AssetEventBus>,
+        timeout_seconds: u64,
+        max_attempts: i64,
+        retry_base_seconds: i64,
+        allow_private_targets: bool,
+        clock: Arc<AvoRedClockProvider>,
+    ) -> Result<Self> {
+        let mut http_client_builder = reqwest::Client::builder()
+            .timeout(Duration::from_secs(timeout_seconds))
+            .redirect(reqwest::redirect::Policy::none());
+        if !allow_private_targets {
+            http_client_builder = http_client_builder.dns_resolver(Arc::new(PublicAddressResolver));
+        }
+        let http_client = http_client_builder.build()?;
+
+        Ok(WebhookService {
+            webhook_repository,
+            webhook_delivery_repository,
+            content_event_bus,
+            asset_event_bus,
+            http_client,
+            delivery_worker: AvoRedWorkerProvider::register(
+                Duration::from_secs(DELIVERY_POLL_SECONDS),
+                max_attempts,
+                retry_base_seconds,
+                MAX_RETRY_DELAY_SECONDS,
+                clock,
+            ),
+            allow_private_targets,
+        })
+    }
+}
+
+impl WebhookService {
+    pub async fn webhook_all(&self, (datastore, database_session): &DB) -> Result<WebhookAllResponse> {
+        let webhook_models = self
+            .webhook_repository
+            .all(datastore, database_session)
+            .await?;
+
+        let mut webhook_grpc_models = vec![];
+        for webhook_model in webhook_models {
+            let webhook_grpc_model: WebhookModelGrpc = webhook_model.try_into()?;
+            webhook_grpc_models.push(webhook_grpc_model);
+        }
+
+        let response = WebhookAllResponse {
+            status: true,
+            data: webhook_grpc_models,
+        };
+
+        Ok(response)
+    }
+
+    /// The signing secret is only returned here, when none is given one is generated.
+    pub async fn store_webhook(
+        &self,
+        (datastore, database_session): &DB,
+        request: StoreWebhookRequest,
+        logged_in_username: String,
+    ) -> Result<StoreWebhookResponse> {
+        self.validate_target(&request.url).await?;
+
+        let secret = match request.secret {
+            Some(secret) if !secret.trim().is_empty() => secret,
+            _ => generate_secret(),
+        };
+        let creatable_webhook = CreatableWebhookModel {
+            name: request.name,
+            url: request.url,
+            events: request.events,
+            secret: secret.clone(),
+            is_active: request.is_active,
+            logged_in_username,
+        };
+
+        let webhook_model = self
+            .webhook_repository
+            .create_webhook(datastore, database_session, creatable_webhook)
+            .await?;
+
+        let response = StoreWebhookResponse {
+            status: true,
+            data: Some(webhook_model.try_into()?),
+            secret,
+        };
+
+        Ok(response)
+    }
+
+    pub async fn update_webhook(
+        &self,
+        (datastore, database_session): &DB,
+        request: UpdateWebhookRequest,
+        logged_in_username: String,
+    ) -> Result<UpdateWebhookResponse> {
+        self.validate_target(&request.url).await?;
+
+        let updatable_webhook = UpdatableWebhookModel {
+            id: request.webhook_id,
+            name: request.name,
+            url: request.url,
+            events: request.events,
+            secret: request.secret.filter(|secret| !secret.trim().is_empty()),
+            is_active: request.is_active,
+            logged_in_username,
+        };
+
+        let webhook_model = self
+            .webhook_repository
+            .update_webhook(datastore, database_session, updatable_webhook)
+            .await?;
+
+        let response = UpdateWebhookResponse {
+            status: true,
+            data: Some(webhook_model.try_into()?),
+        };
+
+        Ok(response)
+    }
+
+    pub async fn delete_webhook(&self, (datastore, database_session): &DB, webhook_id: &str) -> Result<bool> {
+        self.webhook_repository
+            .delete_by_id(datastore, database_session, webhook_id)
+            .await
+    }
+
+    pub async fn webhook_delivery_paginate(
+        &self,
+        (datastore, database_session): &DB,
+        webhook_id: &str,
+        current_page: i64,
+    ) -> Result<WebhookDeliveryPaginateResponse> {
+        let total_count = self
+            .webhook_delivery_repository
+            .get_total_count(datastore, database_session, webhook_id)
+            .await?;
+
+        let start = current_page * PER_PAGE as i64;
+        let webhook_delivery_models = self
+            .webhook_delivery_repository
+            .paginate(datastore, database_session, webhook_id, start)
+            .await?;
+
+        let mut webhook_delivery_grpc_models = vec![];
+        for webhook_delivery_model in webhook_delivery_models {
+            let webhook_delivery_grpc_model: WebhookDeliveryModelGrpc = webhook_delivery_model.try_into()?;
+            webhook_delivery_grpc_models.push(webhook_delivery_grpc_model);
+        }
+
+        let pagination = WebhookDeliveryPagination {
+            total: total_count.total,
+        };
+        let paginate_data = WebhookDeliveryPaginateData {
+            pagination: Some(pagination),
+            data: webhook_delivery_grpc_models,
+        };
+
+        let response = WebhookDeliveryPaginateResponse {
+            status: true,
+            data: Some(paginate_data),
+        };
+
+        Ok(response)
+    }
+
+    /// Sends the body of an earlier delivery again as a new log entry.
+    pub async fn redeliver_webhook(
+        &self,
+        (datastore, database_session): &DB,
+        webhook_delivery_id: &str,
+    ) -> Result<RedeliverWebhookResponse> {
+        let webhook_delivery_model = self
+            .webhook_delivery_repository
+            .find_by_id(datastore, database_session, webhook_delivery_id)
+            .await?;
+
+        let webhook_delivery_model = self
+            .webhook_delivery_repository
+            .create_webhook_delivery(
+                datastore,
+                database_session,
+                &webhook_delivery_model.webhook_id,
+                &webhook_delivery_model.event,
+                &webhook_delivery_model.payload,
+            )
+            .await?;
+        self.delivery_worker.notify();
+
+        let response = RedeliverWebhookResponse {
+            status: true,
+            data: Some(webhook_delivery_model.try_into()?),
+        };
+
+        Ok(response)
+    }
+
+    /// Queues a `ping` event for one webhook, whatever events it listens to.
+    pub async fn ping_webhook(&self, (datastore, database_session): &DB, webhook_id: &str) -> Result<PingWebhookResponse> {
+        let webhook_model = self
+            .webhook_repository
+            .find_by_id(datastore, database_session, webhook_id)
+            .await?;
+
+        let webhook_event = WebhookEventModel {
+            event: String::from(WEBHOOK_PING_EVENT),
+            occurred_at: Utc::now().to_rfc3339(),
+            data: json!({ "webhook_id": webhook_model.id, "name": webhook_model.name }),
+        };
+        let payload = serde_json::to_string(&webhook_event)?;
+
+        let webhook_delivery_model = self
+            .webhook_delivery_repository
+            .create_webhook_delivery(datastore, database_session, &webhook_model.id, WEBHOOK_PING_EVENT, &payload)
+            .await?;
+        self.delivery_worker.notify();
+
+        let response = PingWebhookResponse {
+            status: true,
+            data: Some(webhook_delivery_model.try_into()?),
+        };
+
+        Ok(response)
+    }
+
+    /// Turns content and asset events into queued deliveries for every
+    /// active webhook subscribed to them. Runs for the lifetime of the server.
+    pub async fn run_dispatcher(&self, db: &DB) {
+        let mut content_events = self.content_event_bus.subscribe();
+        let mut asset_events = self.asset_event_bus.subscribe();
+
+        loop {
+            let webhook_event: Result<WebhookEventModel> = tokio::select! {
+                event = content_events.recv() => match event {
+                    Ok(content_event) => content_event.try_into(),
+                    Err(RecvError::Lagged(skipped)) => {
+                        warn!("webhook dispatcher skipped {skipped} content events");
+                        continue;
+                    }
+                    Err(RecvError::Closed) => break,
+                },
+                event = asset_events.recv() => match event {
+                    Ok(asset_event) => asset_event.try_into(),
+                    Err(RecvError::Lagged(skipped)) => {
+                        warn!("webhook dispatcher skipped {skipped} asset events");
+                        continue;
+                    }
+                    Err(RecvError::Closed) => break,
+                },
+            };
+
+            let result = match webhook_event {
+                Ok(webhook_event) => self.enqueue(db, webhook_event).await,
+                Err(e) => Err(e),
+            };
+            if let Err(e) = result {
+                error!("unable to queue webhook deliveries: {e:?}");
+            }
+        }
+    }
+
+    /// Sends due deliveries one after the other. Runs for the lifetime of the server.
+    pub async fn run_delivery_worker(&self, db: &DB) {
+        self.delivery_worker
+            .run("webhook delivery", || self.deliver_due(db))
+            .await
+    }
+
+    async fn enqueue(&self, (datastore, database_session): &DB, webhook_event: WebhookEventModel) -> Result<()> {
+        let webhook_models = self
+            .webhook_repository
+            .active_for_event(datastore, database_session, &webhook_event.event)
+            .await?;
+        if webhook_models.is_empty() {
+            return Ok(());
+        }
+
+        let payload = serde_json::to_string(&webhook_event)?;
+        for webhook_model in webhook_models {
+            self.webhook_delivery_repository
+                .create_webhook_delivery(datastore, database_session, &webhook_model.id, &webhook_event.event, &payload)
+                .await?;
+        }
+        self.delivery_worker.notify();
+
+        Ok(())
+    }
+
+    async fn deliver_due(&self, db: &DB) -> Result<()> {
+        let (datastore, database_session) = db;
+        let webhook_delivery_models = self
+            .webhook_delivery_repository
+            .due(datastore, database_session, self.delivery_worker.now(), DELIVERY_BATCH_SIZE)
+            .await?;
+
+        let mut deliveries_by_webhook: BTreeMap<String, Vec<WebhookDeliveryModel>> = BTreeMap::new();
+        for webhook_delivery_model in webhook_delivery_models {
+            deliveries_by_webhook
+                .entry(webhook_delivery_model.webhook_id.clone())
+                .or_default()
+                .push(webhook_delivery_model);
+        }
+
+        let results = join_all(
+            deliveries_by_webhook
+                .into_iter()
+                .map(|(webhook_id, webhook_delivery_models)| self.deliver_webhook(db, webhook_id, webhook_delivery_models)),
+        )
+        .await;
+
+        results.into_iter().collect()
+    }
+
+    /// Sends the due deliveries of one webhook, a few at a time.
+    async fn deliver_webhook(
+        &self,
+        db: &DB,
+        webhook_id: String,
+        webhook_delivery_models: Vec<WebhookDeliveryModel>,
+    ) -> Result<()> {
+        let (datastore, database_session) = db;
+        let webhook_model = self
+            .webhook_repository
+            .find_by_id(datastore, database_session, &webhook_id)
+            .await
+            .ok();
+
+        let results: Vec<Result<WebhookDeliveryModel>> = stream::iter(webhook_delivery_models)
+            .map(|webhook_delivery_model| {
+                let webhook_model = webhook_model.as_ref();
+
+                async move {
+                    let updatable_webhook_delivery = match webhook_model {
+                        Some(webhook_model)
+                            if webhook_model.is_active || webhook_delivery_model.event == WEBHOOK_PING_EVENT =>
+                        {
+                            self.attempt(webhook_model, &webhook_delivery_model).await
+                        }
+                        _ => failed_delivery(
+                            &webhook_delivery_model,
+                            webhook_delivery_model.attempts,
+                            String::from("webhook is not active"),
+                        ),
+                    };
+
+                    self.webhook_delivery_repository
+                        .update_attempt(datastore, database_session, updatable_webhook_delivery)
+                        .await
+                }
+            })
+            .buffer_unordered(MAX_CONCURRENT_DELIVERIES_PER_WEBHOOK)
+            .collect()
+            .await;
+
+        for result in results {
+            result?;
+        }
+
+        Ok(())
+    }
+
+    /// Posts the payload once and works out when, if ever, to try again.
+    async fn attempt(
+        &self,
+        webhook_model: &WebhookModel,
+        webhook_delivery_model: &WebhookDeliveryModel,
+    ) -> UpdatableWebhookDeliveryModel {
+        let attempts = webhook_delivery_model.attempts + 1;
+
+        // neither gets better with another try.
+        if let Err(error) = self.check_target(&webhook_model.url).await {
+            warn!("webhook delivery {} refused: {error}", webhook_delivery_model.id);
+            return failed_delivery(webhook_delivery_model, attempts, error);
+        }
+        let timestamp = Utc::now().timestamp().to_string();
+        let signature = match sign_payload(&webhook_model.secret, &timestamp, &webhook_delivery_model.payload) {
+            Ok(signature) => signature,
+            Err(e) => return failed_delivery(webhook_delivery_model, attempts, e.to_string()),
+        };
+
+        let result = self
+            .http_client
+            .post(&webhook_model.url)
+            .header(CONTENT_TYPE, "application/json")
+            .header(USER_AGENT, "AvoRed-Webhook")
+            .header("X-AvoRed-Event", &webhook_delivery_model.event)
+            .header("X-AvoRed-Delivery", &webhook_delivery_model.id)
+            .header("X-AvoRed-Timestamp", &timestamp)
+            .header("X-AvoRed-Signature", format!("sha256={signature}"))
+            .body(webhook_delivery_model.payload.clone())
+            .send()
+            .await;
+
+        let (succeeded, response_status, response_body, error) = match result {
+            Ok(response) => {
+                let response_status = response.status();
+                let response_body = response.text().await.unwrap_or_default();
+                let error = match response_status.is_success() {
+                    true => String::from(""),
+                    false => format!("endpoint responded with {response_status}"),
+                };
+
+                (
+                    response_status.is_success(),
+                    response_status.as_u16() as i64,
+                    response_body.chars().take(RESPONSE_BODY_MAX_CHARS).collect(),
+                    error,
+                )
+            }
+            Err(e) => (false, 0, String::from(""), e.to_string()),
+        };
+
+        let (status, next_attempt_at) = match (succeeded, self.delivery_worker.is_last_attempt(attempts)) {
+            (true, _) => (WebhookDeliveryStatus::Succeeded, webhook_delivery_model.next_attempt_at.clone()),
+            (false, true) => (WebhookDeliveryStatus::Failed, webhook_delivery_model.next_attempt_at.clone()),
+            (false, false) => (WebhookDeliveryStatus::Pending, self.delivery_worker.next_attempt_at(attempts)),
+        };
+
+        info!(
+            "webhook delivery {} to {} attempt {attempts}: {status:?}",
+            webhook_delivery_model.id, webhook_model.url
+        );
+
+        UpdatableWebhookDeliveryModel {
+            id: webhook_delivery_model.id.clone(),
+            status,
+            attempts,
+            response_status,
+            response_body,
+            error,
+            next_attempt_at,
+        }
+    }
+
+    /// Refuses the webhook url with a validation error when it can not be sent to.
+    async fn validate_target(&self, url: &str) -> Result<()> {
+        if let Err(error) = self.check_target(url).await {
+            info!("webhook url {url} refused: {error}");
+            let error_response = ErrorResponse {
+                status: false,
+                errors: vec![ErrorMessage {
+                    key: String::from("url"),
+                    message: t!("webhook_url_not_allowed").to_string(),
+                }],
+            };
+            return Err(Error::InvalidArgument(serde_json::to_string(&error_response)?));
+        }
+
+        Ok(())
+    }
+
+    /// Only http and https urls whose host is, or resolves only to, public
+    /// addresses. The resolver of the http client checks again when sending,
+    /// in case the host resolves differently by then.
+    async fn check_target(&self, url: &str) -> std::result::Result<(), String> {
+        let url = reqwest::Url::parse(url.trim()).map_err(|e| e.to_string())?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(format!("{} urls are not allowed", url.scheme()));
+        }
+        let host = url
+            .host_str()
+            .ok_or("the url has no host")?
+            .trim_start_matches('[')
+            .trim_end_matches(']');
+        if self.allow_private_targets {
+            return Ok(());
+        }
+
+        let port = url.port_or_known_default().unwrap_or(80);
+        let addresses: Vec<SocketAddr> = tokio::net::lookup_host((host, port))
+            .await
+            .map_err(|e| format!("unable to resolve {host}: {e}"))?
+            .collect();
+        if addresses.is_empty() || addresses.iter().any(|address| !is_public_address(&address.ip())) {
+            return Err(format!("{host} is not a public address"));
+        }
+
+        Ok(())
+    }
+}
+
+fn generate_secret() -> String {
+    let secret: String = rand::rng()
+        .sample_iter(&Alphanumeric)
+        .take(WEBHOOK_SECRET_LENGTH)
+        .map(char::from)
+        .collect();
+
+    format!("{WEBHOOK_SECRET_PREFIX}{secret}")
+}
+
+/// Hex HMAC-SHA256 of `{timestamp}.{payload}`. Receivers recompute it with
+/// the shared secret and the `X-AvoRed-Timestamp` header.
+fn sign_payload(secret: &str, timestamp: &str, payload: &str) -> Result<String> {
+    let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(secret.as_bytes())
+        .map_err(|e| Error::Generic(format!("unable to sign the webhook payload: {e}")))?;
+    mac.update(timestamp.as_bytes());
+    mac.update(b".");
+    mac.update(payload.as_bytes());
+
+    Ok(hex::encode(mac.finalize().into_bytes()))
+}
+
+/// A delivery that is not tried again.
+fn failed_delivery(
+    webhook_delivery_model: &WebhookDeliveryModel,
+    attempts: i64,
+    error: String,
+) -> UpdatableWebhookDeliveryModel {
+    UpdatableWebhookDeliveryModel {
+        id: webhook_delivery_model.id.clone(),
+        status: WebhookDeliveryStatus::Failed,
+        attempts,
+        response_status: 0,
+        response_body: String::from(""),
+        error,
+        next_attempt_at: webhook_delivery_model.next_attempt_at.clone(),
+    }
+}
+
+/// Loopback, private, link-local, shared and other addresses that do not
+/// belong to a public endpoint.
+fn is_public_address(ip: &IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [first_octet, second_octet, ..] = ip.octets();
+
+            !(ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_unspecified()
+                || ip.is_broadcast()
+                || ip.is_documentation()
+                || ip.is_multicast()
+                // shared address space, 100.64.0.0/10
+                || (first_octet == 100 && (second_octet & 0xc0) == 64))
+        }
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public_address(&IpAddr::V4(ip)),
+            None => {
+                let first_segment = ip.segments()[0];
+
+                !(ip.is_loopback()
+                    || ip.is_unspecified()
+                    || ip.is_multicast()
+                    // unique local, fc00::/7, and link-local, fe80::/10
+                    || (first_segment & 0xfe00) == 0xfc00
+                    || (first_segment & 0xffc0) == 0xfe80)
+            }
+        },
+    }
+}
+
+/// Leaves out every address that is not public, a host without any left
+/// does not resolve.
+struct PublicAddressResolver;
+
+impl Resolve for PublicAddressResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let host = name.as_str().to_string();
+            let addresses: Vec<SocketAddr> = tokio::net::lookup_host((host.as_str(), 0))
+                .await?
+                .filter(|address| is_public_address(&address.ip()))
+                .collect();
+            if addresses.is_empty() {
+                return Err(format!("{host} does not resolve to a public address").into());
+            }
+
+            let addresses: Addrs = Box::new(addresses.into_iter());
+            Ok::<Addrs, Box<dyn std::error::Error + Send + Sync>>(addresses)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::IpAddr;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use axum::extract::State;
+    use axum::http::{HeaderMap, StatusCode};
+    use axum::routing::post;
+    use axum::Router;
+    use tokio::net::TcpListener;
+    use crate::models::webhook_model::{CreatableWebhookModel, WebhookDeliveryModel, WebhookDeliveryStatus};
+    use crate::providers::avored_clock_provider::AvoRedClockProvider;
+    use crate::providers::avored_database_provider::DB;
+    use crate::providers::avored_event_bus_provider::AvoRedEventBusProvider;
+    use crate::repositories::webhook_delivery_repository::WebhookDeliveryRepository;
+    use crate::repositories::webhook_repository::WebhookRepository;
+    use crate::test_helpers::memory_db;
+    use super::{is_public_address, sign_payload, WebhookService};
+
+    const SECRET: &str = "whsec_test";
+    const EVENT: &str = "content.published";
+
+    /// Requests the stub endpoint received, it answers 500 to the first `failures_left`.
+    struct StubEndpoint {
+        requests: Vec<(HeaderMap, String)>,
+        failures_left: usize,
+    }
+
+    async fn receive(
+        State(endpoint): State<Arc<Mutex<StubEndpoint>>>,
+        headers: HeaderMap,
+        body: String,
+    ) -> StatusCode {
+        let mut endpoint = endpoint.lock().unwrap();
+        endpoint.requests.push((headers, body));
+        if endpoint.failures_left > 0 {
+            endpoint.failures_left -= 1;
+            return StatusCode::INTERNAL_SERVER_ERROR;
+        }
+
+        StatusCode::OK
+    }
+
+    async fn start_stub_endpoint(failures: usize) -> (String, Arc<Mutex<StubEndpoint>>) {
+        let endpoint = Arc::new(Mutex::new(StubEndpoint {
+            requests: vec![],
+            failures_left: failures,
+        }));
+        let app = Router::new()
+            .route("/hook", post(receive))
+            .with_state(endpoint.clone());
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await });
+
+        (format!("http://{address}/hook"), endpoint)
+    }
+
+    async fn queue_delivery((datastore, database_session): &DB, url: &str) -> WebhookDeliveryModel {
+        let creatable_webhook = CreatableWebhookModel {
+            name: String::from("stub"),
+            url: url.to_string(),
+            events: vec![String::from(EVENT)],
+            secret: String::from(SECRET),
+            is_active: true,
+            logged_in_username: String::from("admin@admin.com"),
+        };
+        let webhook_model = WebhookRepository::new()
+            .create_webhook(datastore, database_session, creatable_webhook)
+            .await
+            .unwrap();
+
+        WebhookDeliveryRepository::new()
+            .create_webhook_delivery(datastore, database_session, &webhook_model.id, EVENT, r#"{"event":"content.published"}"#)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn delivery_worker_signs_retries_and_dead_letters() {
+        let db = memory_db("webhook_test").await;
+        let webhook_service = Arc::new(
+            WebhookService::new(
+                WebhookRepository::new(),
+                WebhookDeliveryRepository::new(),
+                Arc::new(AvoRedEventBusProvider::register(8)),
+                Arc::new(AvoRedEventBusProvider::register(8)),
+                5,
+                2,
+                1,
+                true,
+                Arc::new(AvoRedClockProvider::register()),
+            )
+            .unwrap(),
+        );
+
+        let (recovering_url, recovering_endpoint) = start_stub_endpoint(1).await;
+        let (failing_url, failing_endpoint) = start_stub_endpoint(usize::MAX).await;
+        let recovering_delivery = queue_delivery(&db, &recovering_url).await;
+        let failing_delivery = queue_delivery(&db, &failing_url).await;
+
+        let db = Arc::new(db);
+        let worker_db = db.clone();
+        let worker_service = webhook_service.clone();
+        let worker = tokio::spawn(async move { worker_service.run_delivery_worker(&worker_db).await });
+
+        let webhook_delivery_repository = WebhookDeliveryRepository::new();
+        let (recovering_delivery, failing_delivery) = tokio::time::timeout(Duration::from_secs(30), async {
+            loop {
+                tokio::time::sleep(Duration::from_millis(200)).await;
+                let recovering = webhook_delivery_repository
+                    .find_by_id(&db.0, &db.1, &recovering_delivery.id)
+                    .await
+                    .unwrap();
+                let failing = webhook_delivery_repository
+                    .find_by_id(&db.0, &db.1, &failing_delivery.id)
+                    .await
+                    .unwrap();
+                if recovering.status != WebhookDeliveryStatus::Pending && failing.status != WebhookDeliveryStatus::Pending {
+                    return (recovering, failing);
+                }
+            }
+        })
+        .await
+        .expect("the deliveries are still pending");
+        worker.abort();
+
+        // a 500 is tried again and the second attempt goes through.
+        assert_eq!(recovering_delivery.status, WebhookDeliveryStatus::Succeeded);
+        assert_eq!(recovering_delivery.attempts, 2);
+        // one that keeps failing is dead after the last attempt.
+        assert_eq!(failing_delivery.status, WebhookDeliveryStatus::Failed);
+        assert_eq!(failing_delivery.attempts, 2);
+        assert_eq!(failing_endpoint.lock().unwrap().requests.len(), 2);
+
+        let recovering_endpoint = recovering_endpoint.lock().unwrap();
+        assert_eq!(recovering_endpoint.requests.len(), 2);
+        for (headers, body) in &recovering_endpoint.requests {
+            let timestamp = headers["x-avored-timestamp"].to_str().unwrap();
+            let signature = headers["x-avored-signature"].to_str().unwrap();
+
+            assert_eq!(signature, format!("sha256={}", sign_payload(SECRET, timestamp, body).unwrap()));
+            assert_eq!(headers["x-avored-event"], EVENT);
+            assert_eq!(headers["x-avored-delivery"], recovering_delivery.id.as_str());
+        }
+    }
+
+    #[test]
+    fn only_public_addresses_are_webhook_targets() {
+        for address in [
+            "127.0.0.1", "10.1.2.3", "172.16.0.1", "192.168.1.1", "169.254.169.254", "100.64.0.1",
+            "0.0.0.0", "::1", "fd00::1", "fe80::1", "::ffff:127.0.0.1",
+        ] {
+            assert!(!is_public_address(&address.parse::<IpAddr>().unwrap()), "{address}");
+        }
+        for address in ["93.184.216.34", "2606:2800:220:1:248:1893:25c8:1946"] {
+            assert!(is_public_address(&address.parse::<IpAddr>().unwrap()), "{address}");
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+use surrealdb::dbs::Session;
+use surrealdb::kvs::Datastore;
+use crate::avored_state::AvoRedState;
+use crate::providers::avored_config_provider::{
+    AvoRedConfigProvider, ClientIpConfig, PasswordHashConfig, PasswordPolicyConfig,
+};
+use crate::providers::avored_database_provider::DB;
+
+/// A fresh in-memory database, `database` only names it in the session.
+pub async fn memory_db(database: &str) -> DB {
+    (
+        Datastore::new("memory").await.unwrap(),
+        Session::default().with_ns("avored").with_db(database),
+    )
+}
+
+/// Config of a server on an in-memory database that sends no email, with
+/// the defaults of `EXAMPLE.env` otherwise.
+pub fn test_config() -> AvoRedConfigProvider {
+    AvoRedConfigProvider {
+        database_folder_name: String::from("memory"),
+        database_namespace: String::from("avored"),
+        database_name: String::from("avored_test"),
+        jwt_secret_key: String::from("avored_test_secret"),
+        jwt_expired_in_seconds: 15 * 60,
+        refresh_token_expired_in_seconds: 14 * 24 * 60 * 60,
+        password_reset_token_expired_in_seconds: 60 * 60,
+        login_max_attempts: 5,
+        login_ip_max_attempts: 20,
+        login_lockout_seconds: 15 * 60,
+        react_admin_app_url: String::from("http://localhost:3000"),
+        back_end_app_url: String::from("http://localhost:50051"),
+        cors_allowed_app_url: vec![String::from("http://localhost:3000")],
+        password_hash: PasswordHashConfig {
+            params: argon2::Params::default(),
+            legacy_salt: String::from(""),
+        },
+        password_policy: PasswordPolicyConfig {
+            min_length: 10,
+            max_length: 128,
+            require_lowercase: true,
+            require_uppercase: true,
+            require_digit: true,
+            require_symbol: false,
+            history: 5,
+        },
+        smtp: None,
+        mail_transport: String::from("memory"),
+        mail_file_dir: String::from("mail"),
+        cms_cache_max_entries: 1000,
+        cms_cache_ttl_seconds: 300,
+        cms_watch_query_api_key: false,
+        admin_user_cache_ttl_seconds: 30,
+        themes_dir: String::from("resources/themes"),
+        theme: String::from("default"),
+        sitemap_max_urls: 50000,
+        feed_items: 20,
+        webhook_max_attempts: 5,
+        webhook_timeout_seconds: 10,
+        webhook_retry_base_seconds: 30,
+        webhook_allow_private_targets: false,
+        static_export_dir: String::from("exports"),
+        static_export_keep: 5,
+        form_ip_max_submissions: 5,
+        form_max_submissions: 100,
+        form_rate_limit_window_seconds: 600,
+        form_honeypot_field: String::from("company_website"),
+        form_challenge_verify_url: String::from(""),
+        form_challenge_secret: String::from(""),
+        form_upload_dir: String::from("storage/form-uploads"),
+        form_max_upload_kb: 5120,
+        client_ip: ClientIpConfig {
+            header: String::from("x-forwarded-for"),
+            trusted_proxies: vec![],
+        },
+        email_max_attempts: 6,
+        email_retry_base_seconds: 60,
+    }
+}
+
+/// The state the api handlers run with, on the given config.
+pub async fn test_state(config: AvoRedConfigProvider) -> Arc<AvoRedState> {
+    Arc::new(AvoRedState::with_config(config).await.unwrap())
+}
@@ -2,7 +2,6 @@ import InputField from "../../components/InputField";
 import {useTranslation} from "react-i18next";
 import {joiResolver} from "@hookform/resolvers/joi";
 import {useForm} from "react-hook-form";
-import AvoRedButton, {ButtonType} from "../../components/AvoRedButton";
 import {SaveSettingType, SettingType} from "../../types/setting/SettingType";
 import {SettingSaveSchema} from "../../schemas/setting/SettingSaveSchema";
 import {UseSettingHook} from "../../hooks/setting/UseSettingHook";
@@ -26,7 +25,6 @@ export const SettingPage = () => {
     const {
         register,
         handleSubmit,
-        setValue,
         // formState: {}
     } = useForm<SaveSettingType>({
         resolver: joiResolver(SettingSaveSchema, {allowUnknown: true}),
@@ -53,12 +51,6 @@ export const SettingPage = () => {
         mutate(request)
     })
 
-    const generateTokenOnClick = ((e: React.MouseEvent<HTMLButtonElement>) => {
-        e.preventDefault()
-        e.stopPropagation()
-        setValue(`settings.${getIdentifierIndex('auth_cms_token')}.value`, "randomstringgoeshere")
-    })
-
     return (
         <>
             <div className="p-5">
@@ -91,27 +83,6 @@ export const SettingPage = () => {
                                         autoFocus
                                     />
                                 </div>
-
-                                <div className="flex items-end mb-5">
-
-                                    <div className="flex-1">
-                                        <InputField
-                                            label={t("cms_frontend_auth_token")}
-                                            register={register(`settings.${getIdentifierIndex('auth_cms_token')}.value`)}
-                                            autoFocus
-                                        />
-                                    </div>
-                                    <div className="ml-3 mr-auto">
-                                        <AvoRedButton
-                                            className="bg-primary-500"
-                                            onClick={generateTokenOnClick}
-                                            type={ButtonType.button}
-                                            label={t("generate_token")}
-                                        />
-                                    </div>
-
-
-                                </div>
                             </div>
 
                             <div className="flex">